@@ -0,0 +1,188 @@
+use lyon::algorithms::hit_test::hit_test_path;
+use lyon::geom::Box2D;
+use lyon::math::{point, Point};
+use lyon::path::{FillRule, PathEvent};
+
+/// Tolerance used when flattening curves to test whether a point is clipped
+const TOLERANCE: f32 = 0.02;
+
+/// A single path added to the clipping path by `W` or `W*`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipPath {
+    pub events: Vec<PathEvent>,
+    pub fill_rule: FillRule,
+}
+
+impl ClipPath {
+    pub fn new(events: Vec<PathEvent>, fill_rule: FillRule) -> Self {
+        ClipPath { events, fill_rule }
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        hit_test_path(&p, self.events.iter().cloned(), self.fill_rule, TOLERANCE)
+    }
+
+    pub fn bounds(&self) -> Box2D<f32> {
+        let mut min = point(f32::MAX, f32::MAX);
+        let mut max = point(f32::MIN, f32::MIN);
+        for p in self.events.iter().flat_map(event_points) {
+            min = min.min(p);
+            max = max.max(p);
+        }
+        Box2D::new(min, max)
+    }
+}
+
+/// The current clipping path. The PDF spec only ever lets the clipping path
+/// shrink, so rather than computing the intersection of every path we keep
+/// them all around: a point is visible if every one of them contains it.
+///
+/// A clip with no paths is unbounded, which is the state at the start of
+/// every page.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Clip {
+    paths: Vec<ClipPath>,
+}
+
+impl Clip {
+    pub fn paths(&self) -> &[ClipPath] {
+        &self.paths
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    pub fn intersect(&mut self, path: ClipPath) {
+        self.paths.push(path);
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.paths.iter().all(|path| path.contains(p))
+    }
+
+    /// The bounding box of the visible area, or `None` if nothing is clipped.
+    /// An empty intersection produces a zero-sized box.
+    pub fn bounds(&self) -> Option<Box2D<f32>> {
+        let mut paths = self.paths.iter();
+        let first = paths.next()?.bounds();
+        let bounds = paths.fold(first, |acc, path| {
+            let b = path.bounds();
+            Box2D::new(acc.min.max(b.min), acc.max.min(b.max))
+        });
+        if bounds.min.x > bounds.max.x || bounds.min.y > bounds.max.y {
+            return Some(Box2D::new(bounds.min, bounds.min));
+        }
+        Some(bounds)
+    }
+}
+
+fn event_points(event: &PathEvent) -> Vec<Point> {
+    match *event {
+        PathEvent::Begin { at } => vec![at],
+        PathEvent::Line { from, to } => vec![from, to],
+        PathEvent::Quadratic { from, ctrl, to } => vec![from, ctrl, to],
+        PathEvent::Cubic {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        } => vec![from, ctrl1, ctrl2, to],
+        PathEvent::End { last, first, .. } => vec![last, first],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lyon::geom::Box2D;
+    use lyon::math::{point, Point};
+    use lyon::path::{FillRule, PathEvent};
+
+    use super::{Clip, ClipPath};
+
+    fn square(min: Point, size: f32) -> Vec<PathEvent> {
+        let corners = [
+            min,
+            point(min.x + size, min.y),
+            point(min.x + size, min.y + size),
+            point(min.x, min.y + size),
+        ];
+        vec![
+            PathEvent::Begin { at: corners[0] },
+            PathEvent::Line {
+                from: corners[0],
+                to: corners[1],
+            },
+            PathEvent::Line {
+                from: corners[1],
+                to: corners[2],
+            },
+            PathEvent::Line {
+                from: corners[2],
+                to: corners[3],
+            },
+            PathEvent::End {
+                last: corners[3],
+                first: corners[0],
+                close: true,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let clip = Clip::default();
+        assert!(clip.is_unbounded());
+        assert!(clip.contains(point(-1000.0, 1000.0)));
+        assert_eq!(clip.bounds(), None);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let mut clip = Clip::default();
+        clip.intersect(ClipPath::new(
+            square(point(0.0, 0.0), 10.0),
+            FillRule::NonZero,
+        ));
+        clip.intersect(ClipPath::new(
+            square(point(5.0, 5.0), 10.0),
+            FillRule::NonZero,
+        ));
+        assert!(clip.contains(point(7.0, 7.0)));
+        assert!(!clip.contains(point(2.0, 2.0)));
+        assert!(!clip.contains(point(12.0, 12.0)));
+        assert_eq!(
+            clip.bounds(),
+            Some(Box2D::new(point(5.0, 5.0), point(10.0, 10.0)))
+        );
+    }
+
+    #[test]
+    fn test_empty_intersection() {
+        let mut clip = Clip::default();
+        clip.intersect(ClipPath::new(
+            square(point(0.0, 0.0), 10.0),
+            FillRule::NonZero,
+        ));
+        clip.intersect(ClipPath::new(
+            square(point(20.0, 20.0), 10.0),
+            FillRule::NonZero,
+        ));
+        let bounds = clip.bounds().unwrap();
+        assert!(bounds.is_empty());
+    }
+
+    #[test]
+    fn test_even_odd() {
+        let mut events = square(point(0.0, 0.0), 10.0);
+        events.extend(square(point(2.0, 2.0), 6.0));
+        let mut clip = Clip::default();
+        clip.intersect(ClipPath::new(events.clone(), FillRule::EvenOdd));
+        assert!(clip.contains(point(1.0, 1.0)));
+        assert!(!clip.contains(point(5.0, 5.0)));
+
+        let mut clip = Clip::default();
+        clip.intersect(ClipPath::new(events, FillRule::NonZero));
+        assert!(clip.contains(point(5.0, 5.0)));
+    }
+}
@@ -0,0 +1,454 @@
+use lyon::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
+use lyon::math::Point;
+use lyon::path::PathEvent;
+use shared::DashPattern;
+
+/// Upper bound on the number of samples used to measure a curve
+const MAX_CURVE_SAMPLES: usize = 256;
+
+/// Splits the events of a stroked path into dashes according to `pattern`.
+///
+/// Every dash becomes its own open subpath, so the stroker applies the
+/// current line cap to both ends of each dash. Zero-length dashes become
+/// a single zero-length line which, with round or square caps, is drawn as a
+/// dot. The dash pattern restarts from `phase` at the start of every
+/// subpath. Curves are split without being flattened; `tolerance` is only
+/// used to measure their length.
+pub fn dash(events: &[PathEvent], pattern: &DashPattern, tolerance: f32) -> Vec<PathEvent> {
+    if pattern.is_solid() {
+        return events.to_vec();
+    }
+    let mut dasher = Dasher::new(pattern);
+    for event in events {
+        match *event {
+            PathEvent::Begin { .. } => dasher.reset(),
+            PathEvent::Line { from, to } => dasher.segment(&Segment::line(from, to), tolerance),
+            PathEvent::Quadratic { from, ctrl, to } => dasher.segment(
+                &Segment::Quadratic(QuadraticBezierSegment { from, ctrl, to }),
+                tolerance,
+            ),
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => dasher.segment(
+                &Segment::Cubic(CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                }),
+                tolerance,
+            ),
+            PathEvent::End { last, first, close } => {
+                if close {
+                    dasher.segment(&Segment::line(last, first), tolerance);
+                }
+                dasher.finish_dash();
+            }
+        }
+    }
+    dasher.events
+}
+
+enum Segment {
+    Line(LineSegment<f32>),
+    Quadratic(QuadraticBezierSegment<f32>),
+    Cubic(CubicBezierSegment<f32>),
+}
+
+impl Segment {
+    fn line(from: Point, to: Point) -> Self {
+        Segment::Line(LineSegment { from, to })
+    }
+
+    fn sample(&self, t: f32) -> Point {
+        match self {
+            Segment::Line(l) => l.sample(t),
+            Segment::Quadratic(q) => q.sample(t),
+            Segment::Cubic(c) => c.sample(t),
+        }
+    }
+
+    /// The part of the segment between `t0` and `t1` as a path event
+    fn event(&self, t0: f32, t1: f32) -> PathEvent {
+        match self {
+            Segment::Line(l) => {
+                let l = l.split_range(t0..t1);
+                PathEvent::Line {
+                    from: l.from,
+                    to: l.to,
+                }
+            }
+            Segment::Quadratic(q) => {
+                let q = q.split_range(t0..t1);
+                PathEvent::Quadratic {
+                    from: q.from,
+                    ctrl: q.ctrl,
+                    to: q.to,
+                }
+            }
+            Segment::Cubic(c) => {
+                let c = c.split_range(t0..t1);
+                PathEvent::Cubic {
+                    from: c.from,
+                    ctrl1: c.ctrl1,
+                    ctrl2: c.ctrl2,
+                    to: c.to,
+                }
+            }
+        }
+    }
+
+    /// Pairs of `(t, distance along the segment)`, used to find the `t`
+    /// at which a dash starts or ends.
+    fn lengths(&self, tolerance: f32) -> Vec<(f32, f32)> {
+        let polygon_length = match self {
+            Segment::Line(l) => return vec![(0.0, 0.0), (1.0, l.length())],
+            Segment::Quadratic(q) => (q.ctrl - q.from).length() + (q.to - q.ctrl).length(),
+            Segment::Cubic(c) => {
+                (c.ctrl1 - c.from).length()
+                    + (c.ctrl2 - c.ctrl1).length()
+                    + (c.to - c.ctrl2).length()
+            }
+        };
+        let samples = ((polygon_length / tolerance.max(f32::EPSILON)).sqrt().ceil() as usize)
+            .clamp(4, MAX_CURVE_SAMPLES);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut previous = self.sample(0.0);
+        let mut distance = 0.0;
+        lengths.push((0.0, 0.0));
+        for i in 1..=samples {
+            let t = i as f32 / samples as f32;
+            let p = self.sample(t);
+            distance += (p - previous).length();
+            lengths.push((t, distance));
+            previous = p;
+        }
+        lengths
+    }
+}
+
+/// Finds `t` for the given distance along a segment measured by `Segment::lengths`
+fn t_at(lengths: &[(f32, f32)], distance: f32) -> f32 {
+    let i = lengths
+        .iter()
+        .position(|(_, d)| *d >= distance)
+        .unwrap_or(lengths.len() - 1)
+        .max(1);
+    let (t0, d0) = lengths[i - 1];
+    let (t1, d1) = lengths[i];
+    if d1 <= d0 {
+        return t1;
+    }
+    t0 + (t1 - t0) * ((distance - d0) / (d1 - d0)).clamp(0.0, 1.0)
+}
+
+struct Dasher {
+    /// Always has an even number of entries, alternating on and off
+    array: Vec<f32>,
+    start_index: usize,
+    start_remaining: f32,
+    index: usize,
+    /// How much of the current dash or gap is left
+    remaining: f32,
+    /// The start of the dash currently being drawn, if any
+    first: Option<Point>,
+    last: Point,
+    events: Vec<PathEvent>,
+}
+
+impl Dasher {
+    fn new(pattern: &DashPattern) -> Self {
+        let mut array: Vec<f32> = pattern.array().iter().map(|l| l.max(0.0)).collect();
+        // An odd number of elements repeats, e.g. [3] is 3 on, 3 off
+        if array.len() % 2 == 1 {
+            array.extend_from_within(..);
+        }
+        let total: f32 = array.iter().sum();
+        let mut phase = pattern.phase().rem_euclid(total);
+        let mut start_index = 0;
+        while phase > array[start_index] {
+            phase -= array[start_index];
+            start_index = (start_index + 1) % array.len();
+        }
+        let start_remaining = array[start_index] - phase;
+        Dasher {
+            array,
+            start_index,
+            start_remaining,
+            index: start_index,
+            remaining: start_remaining,
+            first: None,
+            last: Point::zero(),
+            events: vec![],
+        }
+    }
+
+    fn is_on(&self) -> bool {
+        self.index & 1 == 0
+    }
+
+    fn reset(&mut self) {
+        self.finish_dash();
+        self.index = self.start_index;
+        self.remaining = self.start_remaining;
+    }
+
+    fn finish_dash(&mut self) {
+        if let Some(first) = self.first.take() {
+            // The stroker only draws caps on a subpath with at least one edge
+            if let Some(PathEvent::Begin { at }) = self.events.last() {
+                let at = *at;
+                self.events.push(PathEvent::Line { from: at, to: at });
+            }
+            self.events.push(PathEvent::End {
+                last: self.last,
+                first,
+                close: false,
+            });
+        }
+    }
+
+    fn segment(&mut self, segment: &Segment, tolerance: f32) {
+        let lengths = segment.lengths(tolerance);
+        let length = lengths[lengths.len() - 1].1;
+        let mut distance = 0.0;
+        loop {
+            let step = self.remaining.min(length - distance);
+            // A dash that would start exactly at the end of this segment is
+            // left for the next one, unless it really is zero-length
+            let draw = step > 0.0 || self.array[self.index] == 0.0;
+            if self.is_on() && self.first.is_none() && draw {
+                let at = segment.sample(t_at(&lengths, distance));
+                self.events.push(PathEvent::Begin { at });
+                self.first = Some(at);
+                self.last = at;
+            }
+            if self.is_on() && step > 0.0 {
+                let t1 = t_at(&lengths, distance + step);
+                self.events
+                    .push(segment.event(t_at(&lengths, distance), t1));
+                self.last = segment.sample(t1);
+            }
+            distance += step;
+            self.remaining -= step;
+            if self.remaining > 0.0 {
+                break;
+            }
+            self.finish_dash();
+            self.index = (self.index + 1) % self.array.len();
+            self.remaining = self.array[self.index];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lyon::math::{point, Point};
+    use lyon::path::{LineCap, PathEvent};
+    use lyon::tessellation::geometry_builder::simple_builder;
+    use lyon::tessellation::{StrokeOptions, StrokeTessellator, VertexBuffers};
+    use shared::DashPattern;
+
+    use super::dash;
+
+    fn line(from: Point, to: Point) -> Vec<PathEvent> {
+        vec![
+            PathEvent::Begin { at: from },
+            PathEvent::Line { from, to },
+            PathEvent::End {
+                last: to,
+                first: from,
+                close: false,
+            },
+        ]
+    }
+
+    /// The (start, end) of every dash, assuming dashes are straight
+    fn dashes(events: &[PathEvent]) -> Vec<(Point, Point)> {
+        events
+            .iter()
+            .filter_map(|e| match *e {
+                PathEvent::End { last, first, .. } => Some((first, last)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_solid() {
+        let events = line(point(0.0, 0.0), point(10.0, 0.0));
+        assert_eq!(dash(&events, &DashPattern::default(), 0.1), events);
+        let zeros = DashPattern::new(vec![0.0, 0.0], 0.0);
+        assert_eq!(dash(&events, &zeros, 0.1), events);
+    }
+
+    #[test]
+    fn test_phase() {
+        let events = line(point(0.0, 0.0), point(10.0, 0.0));
+        let dashed = dash(&events, &DashPattern::new(vec![3.0, 2.0], 0.0), 0.1);
+        assert_eq!(
+            dashes(&dashed),
+            vec![
+                (point(0.0, 0.0), point(3.0, 0.0)),
+                (point(5.0, 0.0), point(8.0, 0.0)),
+            ]
+        );
+        let dashed = dash(&events, &DashPattern::new(vec![3.0, 2.0], 1.0), 0.1);
+        assert_eq!(
+            dashes(&dashed),
+            vec![
+                (point(0.0, 0.0), point(2.0, 0.0)),
+                (point(4.0, 0.0), point(7.0, 0.0)),
+                (point(9.0, 0.0), point(10.0, 0.0)),
+            ]
+        );
+        // A phase that starts in the gap, and one larger than the pattern
+        let dashed = dash(&events, &DashPattern::new(vec![3.0, 2.0], 14.0), 0.1);
+        assert_eq!(
+            dashes(&dashed),
+            vec![
+                (point(1.0, 0.0), point(4.0, 0.0)),
+                (point(6.0, 0.0), point(9.0, 0.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_odd_length_array() {
+        let events = line(point(0.0, 0.0), point(10.0, 0.0));
+        let dashed = dash(&events, &DashPattern::new(vec![2.0, 1.0, 3.0], 0.0), 0.1);
+        // [2 1 3] is treated as [2 1 3 2 1 3]
+        assert_eq!(
+            dashes(&dashed),
+            vec![
+                (point(0.0, 0.0), point(2.0, 0.0)),
+                (point(3.0, 0.0), point(6.0, 0.0)),
+                (point(8.0, 0.0), point(9.0, 0.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dash_across_segments() {
+        let events = vec![
+            PathEvent::Begin {
+                at: point(0.0, 0.0),
+            },
+            PathEvent::Line {
+                from: point(0.0, 0.0),
+                to: point(4.0, 0.0),
+            },
+            PathEvent::Line {
+                from: point(4.0, 0.0),
+                to: point(4.0, 4.0),
+            },
+            PathEvent::End {
+                last: point(4.0, 4.0),
+                first: point(0.0, 0.0),
+                close: false,
+            },
+        ];
+        let dashed = dash(&events, &DashPattern::new(vec![6.0, 1.0], 0.0), 0.1);
+        assert_eq!(
+            dashed,
+            vec![
+                PathEvent::Begin {
+                    at: point(0.0, 0.0)
+                },
+                PathEvent::Line {
+                    from: point(0.0, 0.0),
+                    to: point(4.0, 0.0),
+                },
+                PathEvent::Line {
+                    from: point(4.0, 0.0),
+                    to: point(4.0, 2.0),
+                },
+                PathEvent::End {
+                    last: point(4.0, 2.0),
+                    first: point(0.0, 0.0),
+                    close: false,
+                },
+                PathEvent::Begin {
+                    at: point(4.0, 3.0)
+                },
+                PathEvent::Line {
+                    from: point(4.0, 3.0),
+                    to: point(4.0, 4.0),
+                },
+                PathEvent::End {
+                    last: point(4.0, 4.0),
+                    first: point(4.0, 3.0),
+                    close: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_curve() {
+        // A quarter circle of radius 10, roughly 15.7 long
+        let k = 0.552_284_8 * 10.0;
+        let events = vec![
+            PathEvent::Begin {
+                at: point(10.0, 0.0),
+            },
+            PathEvent::Cubic {
+                from: point(10.0, 0.0),
+                ctrl1: point(10.0, k),
+                ctrl2: point(k, 10.0),
+                to: point(0.0, 10.0),
+            },
+            PathEvent::End {
+                last: point(0.0, 10.0),
+                first: point(10.0, 0.0),
+                close: false,
+            },
+        ];
+        let dashed = dash(&events, &DashPattern::new(vec![5.0], 0.0), 0.01);
+        let curves: Vec<_> = dashed
+            .iter()
+            .filter(|e| matches!(e, PathEvent::Cubic { .. }))
+            .collect();
+        assert_eq!(curves.len(), 2);
+        // Each dash should still lie on the circle
+        for (first, last) in dashes(&dashed) {
+            assert!((first.to_vector().length() - 10.0).abs() < 0.01);
+            assert!((last.to_vector().length() - 10.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_zero_length_dashes() {
+        let events = line(point(0.0, 0.0), point(10.0, 0.0));
+        let dashed = dash(&events, &DashPattern::new(vec![0.0, 5.0], 0.0), 0.1);
+        assert_eq!(
+            dashes(&dashed),
+            vec![
+                (point(0.0, 0.0), point(0.0, 0.0)),
+                (point(5.0, 0.0), point(5.0, 0.0)),
+                (point(10.0, 0.0), point(10.0, 0.0)),
+            ]
+        );
+
+        // With round caps, each of them is drawn as a dot
+        let mut geometry: VertexBuffers<Point, u16> = VertexBuffers::new();
+        StrokeTessellator::new()
+            .tessellate(
+                dashed,
+                &StrokeOptions::default()
+                    .with_line_cap(LineCap::Round)
+                    .with_line_width(2.0),
+                &mut simple_builder(&mut geometry),
+            )
+            .unwrap();
+        for x in [0.0, 5.0, 10.0] {
+            assert!(geometry
+                .vertices
+                .iter()
+                .any(|v| (v.x - x).abs() < 1.1 && (v.x - x).abs() > 0.9));
+        }
+    }
+}
@@ -93,6 +93,12 @@ impl DrawState {
         assert!(matches!(self.0, State::Commands(_)));
         Ok(result)
     }
+
+    pub fn set_current(&mut self, at: Point) -> Result<()> {
+        let current = *self.assert_is_commands()?;
+        self.0 = State::Commands(current.with_current(at));
+        Ok(())
+    }
 }
 
 impl From<Active> for Commands {
@@ -148,6 +154,10 @@ impl Commands {
             },
         }
     }
+
+    pub fn with_current(self, current: Point) -> Self {
+        Commands { current, ..self }
+    }
 }
 
 #[derive(Display, Debug)]
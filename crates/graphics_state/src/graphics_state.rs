@@ -1,9 +1,15 @@
+use crate::clip::{Clip, ClipPath};
 use crate::path::Path;
+use crate::shade::{corners, polygon, Shade};
+use crate::text::{FontRef, TextState};
 use anyhow::{Ok, Result};
-use lyon::path::{math::Vector, LineCap, PathEvent};
+use lyon::math::{point, vector, Transform};
+use lyon::path::{math::Vector, FillRule, LineCap, LineJoin, PathEvent};
 
 use shared::{
-    Color, ColorSpace, DashPattern, Height, LineWidth, NonStrokeColor, StrokeColor, Width,
+    Alpha, BlendMode, Color, ColorSpace, ColorSpaceWithColor, DashPattern, ExtGState, Flatness,
+    Height, LineWidth, MiterLimit, NonStrokeColor, RenderingIntent, Shading, SoftMask, StrokeColor,
+    TextRenderingMode, Width,
 };
 use thiserror::Error;
 
@@ -13,6 +19,8 @@ pub(crate) enum GraphicsStateError {
     InvalidStateTransition(&'static str, &'static str),
     #[error("invalid attempt to access {0} state while not in {0} mode")]
     InvalidAttemptToAccessState(&'static str),
+    #[error("tried to restore the graphics state without a matching save")]
+    UnbalancedRestore,
 }
 
 #[derive(Debug)]
@@ -36,15 +44,30 @@ pub struct GraphicsState {
     page_height: Height,
     // ... Shared Values
     state: State,
+    /// Properties saved by `q`, restored by `Q`
+    stack: Vec<Properties>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Properties {
     pub line_width: LineWidth,
     pub line_cap: LineCap,
+    pub line_join: LineJoin,
+    pub miter_limit: MiterLimit,
+    pub flatness: Flatness,
+    pub rendering_intent: RenderingIntent,
     pub dash_pattern: DashPattern,
     pub stroke_color: StrokeColor,
     pub non_stroke_color: NonStrokeColor,
+    pub clip: Clip,
+    pub stroke_adjustment: bool,
+    pub stroke_alpha: Alpha,
+    pub non_stroke_alpha: Alpha,
+    pub alpha_is_shape: bool,
+    pub text_knockout: bool,
+    pub blend_mode: BlendMode,
+    pub soft_mask: Option<SoftMask>,
+    pub text: TextState,
 }
 
 impl Default for Properties {
@@ -52,9 +75,22 @@ impl Default for Properties {
         Properties {
             line_width: LineWidth::default(),
             line_cap: LineCap::Square,
+            line_join: LineJoin::Miter,
+            miter_limit: MiterLimit::default(),
+            flatness: Flatness::default(),
+            rendering_intent: RenderingIntent::default(),
             dash_pattern: DashPattern::default(),
             stroke_color: StrokeColor::default(),
             non_stroke_color: NonStrokeColor::default(),
+            clip: Clip::default(),
+            stroke_adjustment: false,
+            stroke_alpha: Alpha::default(),
+            non_stroke_alpha: Alpha::default(),
+            alpha_is_shape: false,
+            text_knockout: true,
+            blend_mode: BlendMode::default(),
+            soft_mask: None,
+            text: TextState::default(),
         }
     }
 }
@@ -72,22 +108,30 @@ impl Default for PageDescription {
 
 #[derive(Debug)]
 struct Text {
-    // ... Specific State Values
+    /// `Tm`, which maps text space to user space and moves along as glyphs
+    /// are shown
+    matrix: Transform,
+    /// Where the current line started, which `Td`, `TD` and `T*` move from
+    line_matrix: Transform,
 }
 
 impl Default for Text {
     fn default() -> Self {
-        Text {}
+        Text {
+            matrix: Transform::identity(),
+            line_matrix: Transform::identity(),
+        }
     }
 }
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 struct ClippingPath {
-    // ... Specific State Values
+    path: Path,
+    fill_rule: FillRule,
 }
 
-impl Default for ClippingPath {
-    fn default() -> Self {
-        ClippingPath {}
+impl ClippingPath {
+    fn new(path: Path, fill_rule: FillRule) -> Self {
+        ClippingPath { path, fill_rule }
     }
 }
 
@@ -100,6 +144,7 @@ impl GraphicsState {
             page_height,
             // ...
             state: State::default(),
+            stack: vec![],
         }
     }
 
@@ -135,10 +180,32 @@ impl GraphicsState {
         ))
     }
 
+    /// Takes the path that's about to be painted, along with the fill rule
+    /// it should be added to the clipping path with if `W` or `W*` was used.
+    fn take_painted_path(&mut self) -> Result<(Path, Option<FillRule>)> {
+        match std::mem::take(&mut self.state) {
+            State::ClippingPath(c) => Ok((c.path, Some(c.fill_rule))),
+            other => {
+                self.state = other;
+                self.path()?;
+                Ok((self.take_path()?, None))
+            }
+        }
+    }
+
+    /// The clipping path only changes once the path has been painted, so the
+    /// painting operator itself is still subject to the previous clip.
+    fn clip_if_needed(&mut self, path: &Path, fill_rule: Option<FillRule>) {
+        if let Some(fill_rule) = fill_rule {
+            let clip_path = ClipPath::new(path.events().to_vec(), fill_rule);
+            self.properties.clip.intersect(clip_path);
+        }
+    }
+
     pub fn fill(&mut self) -> Result<Vec<PathEvent>> {
-        self.path()?;
-        let mut path = self.take_path()?;
+        let (mut path, fill_rule) = self.take_painted_path()?;
         path.close()?;
+        self.clip_if_needed(&path, fill_rule);
         path.make_fillable_if_needed();
         let paths = path.build()?;
         self.page_description()?;
@@ -146,54 +213,409 @@ impl GraphicsState {
     }
 
     pub fn stroke(&mut self, close: bool) -> Result<Vec<PathEvent>> {
-        self.path()?;
-        let mut path = self.take_path()?;
+        let (mut path, fill_rule) = self.take_painted_path()?;
         path.end(close)?;
+        self.clip_if_needed(&path, fill_rule);
         let paths = path.build()?;
         self.page_description()?;
         Ok(paths)
     }
 
-    pub fn set_line_width(&mut self, w: LineWidth) -> Result<()> {
+    /// Ends the path without painting it, i.e. `n`. Almost always used to
+    /// apply a clipping path.
+    pub fn end_path(&mut self) -> Result<()> {
+        let (mut path, fill_rule) = self.take_painted_path()?;
+        path.close()?;
+        self.clip_if_needed(&path, fill_rule);
         self.page_description()?;
+        Ok(())
+    }
+
+    /// Paints a shading over the clipped area, i.e. `sh`
+    pub fn shade(&mut self, shading: Shading) -> Result<Shade> {
+        self.page_description()?;
+        let shading = self.place_shading(shading);
+        let area = match shading.bbox() {
+            Some(bbox) => {
+                let points = corners(bbox).map(|p| {
+                    let [x, y] = shading.transform([p.x, p.y]);
+                    point(x, y)
+                });
+                polygon(&points)
+            }
+            None => {
+                let (w, h) = (*self.page_width / 2.0, *self.page_height / 2.0);
+                polygon(&corners([-w, -h, w, h]))
+            }
+        };
+        Ok(Shade { shading, area })
+    }
+
+    /// Maps a shading from user space to the coordinates that paths are
+    /// output in, e.g. for filling with a shading pattern
+    pub fn place_shading(&self, shading: Shading) -> Shading {
+        let matrix = self.place_matrix(shading.matrix());
+        shading.with_matrix(matrix)
+    }
+
+    /// Maps a matrix from pattern space to user space onto one to the
+    /// coordinates that paths are output in
+    pub fn place_matrix(&self, matrix: [f32; 6]) -> [f32; 6] {
+        let [a, b, c, d, e, f] = matrix;
+        let (w, h) = (*self.page_width / 2.0, *self.page_height / 2.0);
+        // Paths are output relative to the center of the page, with y down
+        [a, -b, c, -d, e - w, h - f]
+    }
+
+    pub fn clip(&mut self, fill_rule: FillRule) -> Result<()> {
+        self.clipping_path(fill_rule)
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        self.outside_path()?;
+        self.stack.push(self.properties.clone());
+        Ok(())
+    }
+
+    pub fn restore(&mut self) -> Result<()> {
+        self.outside_path()?;
+        self.properties = self
+            .stack
+            .pop()
+            .ok_or(GraphicsStateError::UnbalancedRestore)?;
+        Ok(())
+    }
+
+    pub fn set_line_width(&mut self, w: LineWidth) -> Result<()> {
+        self.outside_path()?;
         self.properties.line_width.set(w);
         Ok(())
     }
 
     pub fn set_non_stroke_color(&mut self, c: Vec<f32>) -> Result<()> {
-        self.page_description()?;
+        self.outside_path()?;
         self.properties.non_stroke_color.set_color(c)
     }
 
     pub fn set_stroke_color(&mut self, c: Vec<f32>) -> Result<()> {
-        self.page_description()?;
+        self.outside_path()?;
         self.properties.stroke_color.set_color(c)
     }
 
+    pub fn set_non_stroke_device_color(&mut self, c: ColorSpaceWithColor) -> Result<()> {
+        self.outside_path()?;
+        self.properties.non_stroke_color.set_device_color(c);
+        Ok(())
+    }
+
+    pub fn set_stroke_device_color(&mut self, c: ColorSpaceWithColor) -> Result<()> {
+        self.outside_path()?;
+        self.properties.stroke_color.set_device_color(c);
+        Ok(())
+    }
+
+    pub fn set_non_stroke_color_n(&mut self, c: Vec<f32>, pattern: Option<Vec<u8>>) -> Result<()> {
+        self.outside_path()?;
+        if !c.is_empty() {
+            self.properties.non_stroke_color.set_color(c)?;
+        }
+        if let Some(name) = pattern {
+            self.properties.non_stroke_color.set_pattern(name);
+        }
+        Ok(())
+    }
+
+    pub fn set_stroke_color_n(&mut self, c: Vec<f32>, pattern: Option<Vec<u8>>) -> Result<()> {
+        self.outside_path()?;
+        if !c.is_empty() {
+            self.properties.stroke_color.set_color(c)?;
+        }
+        if let Some(name) = pattern {
+            self.properties.stroke_color.set_pattern(name);
+        }
+        Ok(())
+    }
+
     pub fn set_non_stroke_color_space(&mut self, c: ColorSpace) -> Result<()> {
-        self.page_description()?;
+        self.outside_path()?;
         self.properties.non_stroke_color.set_color_space(c);
         Ok(())
     }
 
     pub fn set_stroke_color_space(&mut self, c: ColorSpace) -> Result<()> {
-        self.page_description()?;
+        self.outside_path()?;
         self.properties.stroke_color.set_color_space(c);
         Ok(())
     }
 
     pub fn set_cap_style(&mut self, c: LineCap) -> Result<()> {
-        self.page_description()?;
+        self.outside_path()?;
         self.properties.line_cap = c;
         Ok(())
     }
 
+    pub fn set_join_style(&mut self, j: LineJoin) -> Result<()> {
+        self.outside_path()?;
+        self.properties.line_join = j;
+        Ok(())
+    }
+
+    pub fn set_miter_limit(&mut self, m: MiterLimit) -> Result<()> {
+        self.outside_path()?;
+        self.properties.miter_limit.set(m);
+        Ok(())
+    }
+
+    pub fn set_flatness(&mut self, f: Flatness) -> Result<()> {
+        self.outside_path()?;
+        self.properties.flatness.set(f);
+        Ok(())
+    }
+
+    pub fn set_rendering_intent(&mut self, r: RenderingIntent) -> Result<()> {
+        self.outside_path()?;
+        self.properties.rendering_intent = r;
+        Ok(())
+    }
+
     pub fn set_dash_pattern(&mut self, d: DashPattern) -> Result<()> {
-        self.page_description()?;
+        self.outside_path()?;
         self.properties.dash_pattern = d;
         Ok(())
     }
 
+    /// Applies the entries of an `/ExtGState` dictionary, i.e. `gs`
+    pub fn set_ext_g_state(&mut self, e: ExtGState) -> Result<()> {
+        self.outside_path()?;
+        let properties = &mut self.properties;
+        if let Some(w) = e.line_width {
+            properties.line_width.set(w);
+        }
+        if let Some(c) = e.line_cap {
+            properties.line_cap = c;
+        }
+        if let Some(j) = e.line_join {
+            properties.line_join = j;
+        }
+        if let Some(m) = e.miter_limit {
+            properties.miter_limit.set(m);
+        }
+        if let Some(d) = e.dash_pattern {
+            properties.dash_pattern = d;
+        }
+        if let Some(r) = e.rendering_intent {
+            properties.rendering_intent = r;
+        }
+        if let Some(f) = e.flatness {
+            properties.flatness.set(f);
+        }
+        if let Some(sa) = e.stroke_adjustment {
+            properties.stroke_adjustment = sa;
+        }
+        if let Some(a) = e.stroke_alpha {
+            properties.stroke_alpha.set(a);
+        }
+        if let Some(a) = e.non_stroke_alpha {
+            properties.non_stroke_alpha.set(a);
+        }
+        if let Some(ais) = e.alpha_is_shape {
+            properties.alpha_is_shape = ais;
+        }
+        if let Some(tk) = e.text_knockout {
+            properties.text_knockout = tk;
+        }
+        if let Some(bm) = e.blend_mode {
+            properties.blend_mode = bm;
+        }
+        if let Some(mask) = e.soft_mask {
+            properties.soft_mask = mask;
+        }
+        if let Some((font, size)) = e.font {
+            properties.text.font = Some(FontRef::Indirect(font));
+            properties.text.font_size = size;
+        }
+        Ok(())
+    }
+
+    /// Begins a text object, i.e. `BT`
+    pub fn begin_text(&mut self) -> Result<()> {
+        self.text()?;
+        *self.assert_is_text_mut()? = Text::default();
+        Ok(())
+    }
+
+    /// Ends a text object, i.e. `ET`
+    pub fn end_text(&mut self) -> Result<()> {
+        self.assert_is_text_mut()?;
+        self.page_description()
+    }
+
+    pub fn set_char_spacing(&mut self, spacing: f32) -> Result<()> {
+        self.outside_path()?;
+        self.properties.text.char_spacing = spacing;
+        Ok(())
+    }
+
+    pub fn set_word_spacing(&mut self, spacing: f32) -> Result<()> {
+        self.outside_path()?;
+        self.properties.text.word_spacing = spacing;
+        Ok(())
+    }
+
+    /// Sets the horizontal scaling from a percentage, i.e. `Tz`
+    pub fn set_horizontal_scaling(&mut self, percentage: f32) -> Result<()> {
+        self.outside_path()?;
+        self.properties.text.horizontal_scaling = percentage / 100.0;
+        Ok(())
+    }
+
+    pub fn set_leading(&mut self, leading: f32) -> Result<()> {
+        self.outside_path()?;
+        self.properties.text.leading = leading;
+        Ok(())
+    }
+
+    /// Selects a `/Font` resource and its size, i.e. `Tf`
+    pub fn set_font(&mut self, name: Vec<u8>, size: f32) -> Result<()> {
+        self.outside_path()?;
+        self.properties.text.font = Some(FontRef::Named(name));
+        self.properties.text.font_size = size;
+        Ok(())
+    }
+
+    pub fn set_text_rendering_mode(&mut self, mode: TextRenderingMode) -> Result<()> {
+        self.outside_path()?;
+        self.properties.text.render_mode = mode;
+        Ok(())
+    }
+
+    pub fn set_text_rise(&mut self, rise: f32) -> Result<()> {
+        self.outside_path()?;
+        self.properties.text.rise = rise;
+        Ok(())
+    }
+
+    /// Moves to the start of a line, offset from the start of the current
+    /// one, i.e. `Td`
+    pub fn move_text(&mut self, offset: Vector) -> Result<()> {
+        let text = self.assert_is_text_mut()?;
+        text.line_matrix = Transform::translation(offset.x, offset.y).then(&text.line_matrix);
+        text.matrix = text.line_matrix;
+        Ok(())
+    }
+
+    /// Like `move_text`, but also sets the leading to the downwards offset,
+    /// i.e. `TD`
+    pub fn move_text_set_leading(&mut self, offset: Vector) -> Result<()> {
+        self.assert_is_text_mut()?;
+        self.properties.text.leading = -offset.y;
+        self.move_text(offset)
+    }
+
+    /// Sets both the text matrix and the line matrix, i.e. `Tm`
+    pub fn set_text_matrix(&mut self, matrix: [f32; 6]) -> Result<()> {
+        let text = self.assert_is_text_mut()?;
+        let [a, b, c, d, e, f] = matrix;
+        text.matrix = Transform::new(a, b, c, d, e, f);
+        text.line_matrix = text.matrix;
+        Ok(())
+    }
+
+    /// Moves to the start of the next line, i.e. `T*`
+    pub fn next_line(&mut self) -> Result<()> {
+        let leading = self.properties.text.leading;
+        self.move_text(vector(0.0, -leading))
+    }
+
+    /// Moves past a glyph that was just shown. `width` is its horizontal
+    /// displacement in thousandths of the font size, and word spacing only
+    /// applies to single-byte character code 32.
+    pub fn advance_glyph(&mut self, width: f32, is_word_space: bool) -> Result<()> {
+        let text_state = &self.properties.text;
+        let mut advance = width / 1000.0 * text_state.font_size + text_state.char_spacing;
+        if is_word_space {
+            advance += text_state.word_spacing;
+        }
+        self.advance_text(advance * text_state.horizontal_scaling, 0.0)
+    }
+
+    /// Like `advance_glyph`, but for a font that's written top to bottom.
+    /// `height` is the glyph's vertical displacement, which is usually
+    /// negative, and horizontal scaling doesn't apply.
+    pub fn advance_glyph_vertically(&mut self, height: f32, is_word_space: bool) -> Result<()> {
+        let text_state = &self.properties.text;
+        let mut advance = height / 1000.0 * text_state.font_size + text_state.char_spacing;
+        if is_word_space {
+            advance += text_state.word_spacing;
+        }
+        self.advance_text(0.0, advance)
+    }
+
+    /// Applies a number in the array of `TJ`, which moves back by
+    /// thousandths of the font size
+    pub fn adjust_text(&mut self, adjustment: f32) -> Result<()> {
+        let text_state = &self.properties.text;
+        let advance = -adjustment / 1000.0 * text_state.font_size;
+        self.advance_text(advance * text_state.horizontal_scaling, 0.0)
+    }
+
+    /// Like `adjust_text`, but for a font that's written top to bottom,
+    /// which moves down rather than left, as vertical displacements are
+    /// negative
+    pub fn adjust_text_vertically(&mut self, adjustment: f32) -> Result<()> {
+        let advance = -adjustment / 1000.0 * self.properties.text.font_size;
+        self.advance_text(0.0, advance)
+    }
+
+    fn advance_text(&mut self, tx: f32, ty: f32) -> Result<()> {
+        let text = self.assert_is_text_mut()?;
+        text.matrix = Transform::translation(tx, ty).then(&text.matrix);
+        Ok(())
+    }
+
+    /// The text matrix, `Tm`
+    pub fn text_matrix(&self) -> Result<[f32; 6]> {
+        Ok(self.assert_is_text()?.matrix.to_array())
+    }
+
+    /// Maps text space, scaled by the font size, to the coordinates that
+    /// paths are output in. Glyphs are drawn through this after their font's
+    /// matrix maps them to text space.
+    pub fn text_rendering_matrix(&self) -> Result<[f32; 6]> {
+        Ok(self.place_matrix(self.user_text_rendering_matrix()?))
+    }
+
+    /// Like `text_rendering_matrix`, but maps to user space, where y is up
+    /// and the origin is at the page's corner, e.g. for finding where text is
+    pub fn user_text_rendering_matrix(&self) -> Result<[f32; 6]> {
+        let text_state = &self.properties.text;
+        let size = text_state.font_size;
+        let rendering = Transform::new(
+            size * text_state.horizontal_scaling,
+            0.0,
+            0.0,
+            size,
+            0.0,
+            text_state.rise,
+        )
+        .then(&self.assert_is_text()?.matrix);
+        Ok(rendering.to_array())
+    }
+
+    fn assert_is_text_mut(&mut self) -> Result<&mut Text> {
+        match &mut self.state {
+            State::Text(data) => Ok(data),
+            _ => Err(GraphicsStateError::InvalidAttemptToAccessState("Text").into()),
+        }
+    }
+
+    fn assert_is_text(&self) -> Result<&Text> {
+        match &self.state {
+            State::Text(data) => Ok(data),
+            _ => Err(GraphicsStateError::InvalidAttemptToAccessState("Text").into()),
+        }
+    }
+
     fn assert_is_path_mut(&mut self) -> Result<&mut Path> {
         match &mut self.state {
             State::Path(data) => Ok(data),
@@ -242,6 +664,15 @@ impl GraphicsState {
         Ok(result)
     }
 
+    /// General graphics state, color and text state operators are allowed
+    /// in text objects as well as outside of them, so those stay open
+    fn outside_path(&mut self) -> Result<()> {
+        match self.state {
+            State::Text(_) => Ok(()),
+            _ => self.page_description(),
+        }
+    }
+
     fn text(&mut self) -> Result<()> {
         let result = match &self.state {
             State::PageDescription(p) => {
@@ -282,7 +713,7 @@ impl GraphicsState {
         Ok(result)
     }
 
-    fn clipping_path(&mut self) -> Result<()> {
+    fn clipping_path(&mut self, fill_rule: FillRule) -> Result<()> {
         let result = match &self.state {
             State::PageDescription(_) => Err(GraphicsStateError::InvalidStateTransition(
                 "PageDescription",
@@ -292,8 +723,9 @@ impl GraphicsState {
             State::Text(_) => {
                 Err(GraphicsStateError::InvalidStateTransition("ClippingPath", "Text").into())
             }
-            State::Path(p) => {
-                self.state = State::ClippingPath(p.into());
+            State::Path(_) => {
+                let path = self.take_path()?;
+                self.state = State::ClippingPath(ClippingPath::new(path, fill_rule));
                 Ok(())
             }
             State::ClippingPath(_) => Ok(()),
@@ -335,8 +767,230 @@ fn convert_page_description_to_path(
     Path::new(page_width, page_height)
 }
 
-impl From<&Path> for ClippingPath {
-    fn from(_p: &Path) -> Self {
-        ClippingPath::default()
+#[cfg(test)]
+mod tests {
+    use lyon::math::{point, vector};
+    use lyon::path::{FillRule, LineJoin, PathEvent};
+
+    use super::GraphicsState;
+    use crate::text::FontRef;
+    use shared::{
+        Alpha, BlendMode, ColorSpace, ColorSpaceWithColor, ExtGState, Gradient, GradientKind, Gray,
+        Height, LineWidth, Rgb, Shading, Width, IDENTITY,
+    };
+
+    #[test]
+    fn test_clipping_path() {
+        let mut state = GraphicsState::new(Width::new(100.0), Height::new(100.0));
+        assert!(state.properties().clip.is_unbounded());
+        state.save().unwrap();
+        state
+            .rect(vector(0.0, 0.0), Width::new(10.0), Height::new(10.0))
+            .unwrap();
+        state.clip(FillRule::NonZero).unwrap();
+        // The clip only applies once the path has been painted
+        assert!(state.properties().clip.is_unbounded());
+        state.end_path().unwrap();
+        let clip = &state.properties().clip;
+        assert_eq!(clip.paths().len(), 1);
+        // The page is flipped and centered, so PDF (5, 5) is (-45, 45)
+        assert!(clip.contains(point(-45.0, 45.0)));
+        assert!(!clip.contains(point(0.0, 0.0)));
+
+        // Filling with a pending clip intersects it with the current clip
+        state
+            .rect(vector(5.0, 5.0), Width::new(10.0), Height::new(10.0))
+            .unwrap();
+        state.clip(FillRule::EvenOdd).unwrap();
+        assert!(state.fill().is_ok());
+        let clip = &state.properties().clip;
+        assert_eq!(clip.paths().len(), 2);
+        assert!(clip.contains(point(-43.0, 43.0)));
+        assert!(!clip.contains(point(-47.0, 47.0)));
+
+        state.restore().unwrap();
+        assert!(state.properties().clip.is_unbounded());
+        assert!(state.restore().is_err());
+    }
+
+    #[test]
+    fn test_multiple_subpaths() {
+        let mut state = GraphicsState::new(Width::new(100.0), Height::new(100.0));
+        state.move_to(vector(0.0, 0.0)).unwrap();
+        state.line_to(vector(10.0, 0.0)).unwrap();
+        state.line_to(vector(10.0, 10.0)).unwrap();
+        state.move_to(vector(20.0, 20.0)).unwrap();
+        state.line_to(vector(30.0, 20.0)).unwrap();
+        let events = state.stroke(false).unwrap();
+        assert_eq!(events.len(), 7);
+    }
+
+    #[test]
+    fn test_shade() {
+        let mut state = GraphicsState::new(Width::new(100.0), Height::new(200.0));
+        let gradient = Gradient {
+            kind: GradientKind::Axial {
+                start: [0.0, 0.0],
+                end: [100.0, 0.0],
+            },
+            stops: vec![
+                (0.0, Rgb::new(0.0, 0.0, 0.0)),
+                (1.0, Rgb::new(1.0, 1.0, 1.0)),
+            ],
+            extend: [false, false],
+            background: None,
+            bbox: None,
+            matrix: IDENTITY,
+        };
+        let shade = state.shade(Shading::Gradient(gradient.clone())).unwrap();
+        // The bottom left of the page
+        assert_eq!(shade.shading.transform([0.0, 0.0]), [-50.0, 100.0]);
+        assert_eq!(shade.area.len(), 5);
+        assert_eq!(
+            shade.area[0],
+            PathEvent::Begin {
+                at: point(-50.0, -100.0)
+            }
+        );
+
+        let shade = state
+            .shade(Shading::Gradient(Gradient {
+                bbox: Some([10.0, 10.0, 20.0, 20.0]),
+                matrix: [2.0, 0.0, 0.0, 2.0, 0.0, 0.0],
+                ..gradient
+            }))
+            .unwrap();
+        assert_eq!(
+            shade.area[0],
+            PathEvent::Begin {
+                at: point(-30.0, 80.0)
+            }
+        );
+        assert_eq!(
+            state.place_matrix([1.0, 0.0, 0.0, 1.0, 10.0, 20.0]),
+            [1.0, 0.0, 0.0, -1.0, -40.0, 80.0]
+        );
+    }
+
+    #[test]
+    fn test_ext_g_state() {
+        let mut state = GraphicsState::new(Width::new(100.0), Height::new(100.0));
+        state.set_line_width(LineWidth::new(3.0)).unwrap();
+        state.save().unwrap();
+        state
+            .set_ext_g_state(ExtGState {
+                line_join: Some(LineJoin::Round),
+                non_stroke_alpha: Some(Alpha::new(0.5)),
+                blend_mode: Some(BlendMode::Multiply),
+                ..ExtGState::default()
+            })
+            .unwrap();
+        let properties = state.properties();
+        assert_eq!(properties.line_join, LineJoin::Round);
+        assert_eq!(*properties.non_stroke_alpha, 0.5);
+        assert_eq!(*properties.stroke_alpha, 1.0);
+        assert_eq!(properties.blend_mode, BlendMode::Multiply);
+        // Entries that aren't in the dictionary are left alone
+        assert_eq!(*properties.line_width, 3.0);
+        state.restore().unwrap();
+        assert_eq!(state.properties().blend_mode, BlendMode::Normal);
+    }
+
+    #[test]
+    fn test_device_color_sets_color_space() {
+        let mut state = GraphicsState::new(Width::new(100.0), Height::new(100.0));
+        state
+            .set_non_stroke_device_color(ColorSpaceWithColor::DeviceGray(Gray::new(0.5)))
+            .unwrap();
+        let color = &state.properties().non_stroke_color;
+        assert_eq!(color.color_space, ColorSpace::DeviceGray);
+        assert_eq!(color.gray, Gray::new(0.5));
+
+        state
+            .set_stroke_device_color(ColorSpaceWithColor::DeviceRGB(Rgb::new(1.0, 0.0, 0.0)))
+            .unwrap();
+        assert_eq!(
+            state.properties().stroke_color.color_space,
+            ColorSpace::DeviceRGB
+        );
+
+        state
+            .set_non_stroke_color_n(vec![], Some(b"P0".to_vec()))
+            .unwrap();
+        assert_eq!(
+            state.properties().non_stroke_color.pattern,
+            Some(b"P0".to_vec())
+        );
+        // Setting a device color deselects the pattern
+        state
+            .set_non_stroke_device_color(ColorSpaceWithColor::DeviceGray(Gray::new(1.0)))
+            .unwrap();
+        assert_eq!(state.properties().non_stroke_color.pattern, None);
+    }
+
+    #[test]
+    fn test_text_state() {
+        let mut state = GraphicsState::new(Width::new(100.0), Height::new(100.0));
+        // Text positioning is only allowed in text objects
+        assert!(state.move_text(vector(1.0, 1.0)).is_err());
+        state.set_font(b"F1".to_vec(), 10.0).unwrap();
+        state.begin_text().unwrap();
+        state.set_leading(12.0).unwrap();
+        state.set_horizontal_scaling(50.0).unwrap();
+        // Colors may change inside text objects without ending them
+        state
+            .set_non_stroke_device_color(ColorSpaceWithColor::DeviceGray(Gray::new(0.5)))
+            .unwrap();
+        state.move_text(vector(10.0, 50.0)).unwrap();
+        state.next_line().unwrap();
+        assert_eq!(
+            state.text_matrix().unwrap(),
+            [1.0, 0.0, 0.0, 1.0, 10.0, 38.0]
+        );
+
+        // Glyphs advance by their width, spacing and scaling, and TJ
+        // adjustments move back
+        state.set_char_spacing(1.0).unwrap();
+        state.set_word_spacing(2.0).unwrap();
+        state.advance_glyph(500.0, true).unwrap();
+        state.adjust_text(-200.0).unwrap();
+        // (5 + 1 + 2) * 0.5 + 2 * 0.5
+        assert_eq!(state.text_matrix().unwrap()[4], 15.0);
+        // Vertical fonts advance down by their displacement and spacing, and
+        // TJ adjustments move down, all without scaling
+        state.advance_glyph_vertically(-1000.0, false).unwrap();
+        state.adjust_text_vertically(200.0).unwrap();
+        // 38 - 10 + 1 - 2
+        assert_eq!(state.text_matrix().unwrap()[4..], [15.0, 27.0]);
+        // A new line starts from the line matrix, not from the text matrix
+        state.move_text_set_leading(vector(0.0, -20.0)).unwrap();
+        assert_eq!(state.properties().text.leading, 20.0);
+        assert_eq!(state.text_matrix().unwrap()[4..], [10.0, 18.0]);
+
+        state.set_text_rise(2.0).unwrap();
+        state
+            .set_text_matrix([2.0, 0.0, 0.0, 2.0, 50.0, 50.0])
+            .unwrap();
+        // Scaled by the font size, horizontally scaled and raised, then
+        // flipped and centered
+        assert_eq!(
+            state.text_rendering_matrix().unwrap(),
+            [10.0, 0.0, 0.0, -20.0, 0.0, -4.0]
+        );
+        assert_eq!(
+            state.user_text_rendering_matrix().unwrap(),
+            [10.0, 0.0, 0.0, 20.0, 50.0, 54.0]
+        );
+
+        state.end_text().unwrap();
+        assert!(state.text_matrix().is_err());
+        assert_eq!(
+            state.properties().text.font,
+            Some(FontRef::Named(b"F1".to_vec()))
+        );
+        assert_eq!(state.properties().text.horizontal_scaling, 0.5);
+        // Path construction isn't allowed in text objects
+        state.begin_text().unwrap();
+        assert!(state.move_to(vector(0.0, 0.0)).is_err());
     }
 }
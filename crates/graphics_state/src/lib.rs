@@ -1,7 +1,15 @@
+mod clip;
+mod dash;
 mod draw_state;
 mod geom;
 mod graphics_state;
 mod path;
+mod shade;
+mod text;
 
+pub use crate::clip::{Clip, ClipPath};
+pub use crate::dash::dash;
 pub use crate::graphics_state::{GraphicsState, Properties};
+pub use crate::shade::Shade;
+pub use crate::text::{FontRef, TextState};
 pub use shared::{Height, Width};
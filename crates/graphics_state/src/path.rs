@@ -43,6 +43,7 @@ impl Path {
         let to = vector(to.x, -to.y);
         let to = self.bottom_left_of_page + to;
         self.events.push(PathEvent::Line { from, to });
+        self.draw_state.set_current(to)?;
         Ok(())
     }
 
@@ -85,6 +86,10 @@ impl Path {
         Ok(())
     }
 
+    pub fn events(&self) -> &[PathEvent] {
+        &self.events
+    }
+
     pub fn build(&mut self) -> Result<Vec<PathEvent>> {
         let events = std::mem::take(&mut self.events);
         Ok(events)
@@ -111,10 +116,13 @@ impl Path {
                     close: false,
                 })
             }
-            // Maybe we just do nothing if the last command was LineTo etc?
-            Some(_) => unimplemented!(),
+            // The previous subpath was already ended, e.g. by `h`
+            Some(PathEvent::End { .. }) => {}
+            // A new subpath leaves the previous one open
+            Some(_) => self.end(false)?,
             None => {}
         }
+        self.draw_state.make_inactive()?;
         Ok(())
     }
 
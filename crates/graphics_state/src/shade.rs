@@ -0,0 +1,34 @@
+use lyon::math::{point, Point};
+use lyon::path::PathEvent;
+use shared::Shading;
+
+/// A shading painted by `sh`, in the same coordinates as painted paths
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shade {
+    pub shading: Shading,
+    /// The area to paint, which is the shading's bounding box or else the
+    /// whole page. The clipping path still applies on top of it.
+    pub area: Vec<PathEvent>,
+}
+
+/// A closed path through `points`
+pub(crate) fn polygon(points: &[Point]) -> Vec<PathEvent> {
+    let first = points[0];
+    let mut events = vec![PathEvent::Begin { at: first }];
+    events.extend(points.windows(2).map(|w| PathEvent::Line {
+        from: w[0],
+        to: w[1],
+    }));
+    events.push(PathEvent::End {
+        last: points[points.len() - 1],
+        first,
+        close: true,
+    });
+    events
+}
+
+/// The corners of the `[x_min y_min x_max y_max]` box
+pub(crate) fn corners(bbox: [f32; 4]) -> [Point; 4] {
+    let [x0, y0, x1, y1] = bbox;
+    [point(x0, y0), point(x1, y0), point(x1, y1), point(x0, y1)]
+}
@@ -0,0 +1,45 @@
+use shared::{ObjectRef, TextRenderingMode};
+
+/// The font that text is shown in
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontRef {
+    /// Selected by `Tf`, a `/Font` resource name
+    Named(Vec<u8>),
+    /// Selected by the `/Font` entry of an `/ExtGState`
+    Indirect(ObjectRef),
+}
+
+/// The text state parameters. They're part of the graphics state, so unlike
+/// the text matrices they outlive text objects and are saved by `q`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextState {
+    /// `Tc`, added to the advance of every glyph
+    pub char_spacing: f32,
+    /// `Tw`, added to the advance of every single-byte space
+    pub word_spacing: f32,
+    /// `Tz`, which stretches text horizontally, as a fraction rather than a
+    /// percentage
+    pub horizontal_scaling: f32,
+    /// `TL`, how far `T*` moves down
+    pub leading: f32,
+    pub font: Option<FontRef>,
+    pub font_size: f32,
+    pub render_mode: TextRenderingMode,
+    /// `Ts`, how far text is raised above the baseline
+    pub rise: f32,
+}
+
+impl Default for TextState {
+    fn default() -> Self {
+        TextState {
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            horizontal_scaling: 1.0,
+            leading: 0.0,
+            font: None,
+            font_size: 0.0,
+            render_mode: TextRenderingMode::default(),
+            rise: 0.0,
+        }
+    }
+}
@@ -0,0 +1,512 @@
+use anyhow::Result;
+use std::convert::TryFrom;
+
+use crate::{
+    encodings::glyph_unicode,
+    error::{CMapError, ParseError},
+};
+
+use self::parsers::{tokens, Token};
+
+pub(crate) mod parsers;
+
+/// Limit on how many CMaps may be chained through `usecmap`, which protects
+/// against cycles
+const MAX_NESTING: usize = 8;
+
+/// Adobe's predefined CMap resources that are bundled, by name
+const PREDEFINED: &[(&str, &[u8])] = &[
+    ("Identity-H", include_bytes!("../assets/cmaps/Identity-H")),
+    ("Identity-V", include_bytes!("../assets/cmaps/Identity-V")),
+];
+
+/// A CMap, which splits the strings shown with a Type 0 font into codes one
+/// to four bytes long and maps them to the CIDs of its descendant font. A
+/// font's `/ToUnicode` CMap maps codes to text instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CMap {
+    pub name: String,
+    /// `/WMode`, whether glyphs are laid out top to bottom
+    pub vertical: bool,
+    codespace_ranges: Vec<CodespaceRange>,
+    cid_ranges: Vec<CidRange>,
+    unicode_ranges: Vec<UnicodeRange>,
+}
+
+/// The codes of one length that a CMap accepts. Each byte of a code must be
+/// within the bounds of the same byte of `low` and `high`.
+#[derive(Debug, Clone, PartialEq)]
+struct CodespaceRange {
+    low: Vec<u8>,
+    high: Vec<u8>,
+}
+
+/// Codes `low` to `high` map to consecutive CIDs, starting with `cid`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CidRange {
+    low: u32,
+    high: u32,
+    cid: u32,
+}
+
+/// Codes `low` to `high` map to text, given as UTF-16 values
+#[derive(Debug, Clone, PartialEq)]
+struct UnicodeRange {
+    low: u32,
+    high: u32,
+    text: UnicodeText,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum UnicodeText {
+    /// The text of the first code, whose last value is incremented for each
+    /// code after it
+    Consecutive(Vec<u16>),
+    /// The text of each code
+    Each(Vec<Vec<u16>>),
+}
+
+impl CMap {
+    /// One of the CMaps that a Type 0 font's `/Encoding` can name, read from
+    /// the bundled resources along with those it's based on. Names that
+    /// aren't bundled have none rather than one that maps every code to
+    /// CID 0.
+    pub fn predefined(name: &str) -> Option<CMap> {
+        CMap::predefined_nested(name, 0).ok()
+    }
+
+    fn predefined_nested(name: &str, depth: usize) -> Result<CMap> {
+        let (_, content) = PREDEFINED
+            .iter()
+            .find(|(predefined, _)| *predefined == name)
+            .ok_or_else(|| CMapError::UnknownCMap(name.to_owned()))?;
+        CMap::parse_nested(content, depth)
+    }
+
+    /// Whether the CMap gives the lengths of codes, which ToUnicode CMaps
+    /// also do
+    pub fn has_codespace(&self) -> bool {
+        !self.codespace_ranges.is_empty()
+    }
+
+    /// Reads an embedded CMap stream. A CMap based on another through
+    /// `usecmap` includes its mappings, after its own.
+    pub fn parse(content: &[u8]) -> Result<CMap> {
+        CMap::parse_nested(content, 0)
+    }
+
+    fn parse_nested(content: &[u8], depth: usize) -> Result<CMap> {
+        if depth > MAX_NESTING {
+            return Err(CMapError::TooDeeplyNested.into());
+        }
+        let (rest, tokens) = tokens(content)?;
+        if !rest.is_empty() {
+            return Err(ParseError::FailedToParseAllStreamContent(
+                String::from_utf8_lossy(rest).to_string(),
+            )
+            .into());
+        }
+        let mut cmap = CMap {
+            name: String::new(),
+            vertical: false,
+            codespace_ranges: Vec::new(),
+            cid_ranges: Vec::new(),
+            unicode_ranges: Vec::new(),
+        };
+        let mut base = None;
+        let mut operands = Vec::new();
+        for token in tokens {
+            let operator = match token {
+                Token::Operator(operator) => operator,
+                operand => {
+                    operands.push(operand);
+                    continue;
+                }
+            };
+            match &operator[..] {
+                b"endcodespacerange" => {
+                    for operands in sections(&operands, 2, "codespacerange")? {
+                        match operands {
+                            [Token::String(low), Token::String(high)]
+                                if low.len() == high.len() && (1..=4).contains(&low.len()) =>
+                            {
+                                cmap.codespace_ranges.push(range(low, high));
+                            }
+                            _ => return Err(CMapError::InvalidSection("codespacerange").into()),
+                        }
+                    }
+                }
+                b"endcidrange" => {
+                    for operands in sections(&operands, 3, "cidrange")? {
+                        match operands {
+                            [Token::String(low), Token::String(high), Token::Integer(cid)] => {
+                                cmap.cid_ranges.push(CidRange {
+                                    low: code(low, "cidrange")?,
+                                    high: code(high, "cidrange")?,
+                                    cid: cid_value(*cid, "cidrange")?,
+                                });
+                            }
+                            _ => return Err(CMapError::InvalidSection("cidrange").into()),
+                        }
+                    }
+                }
+                b"endcidchar" => {
+                    for operands in sections(&operands, 2, "cidchar")? {
+                        match operands {
+                            [Token::String(code_bytes), Token::Integer(cid)] => {
+                                let code = code(code_bytes, "cidchar")?;
+                                cmap.cid_ranges.push(CidRange {
+                                    low: code,
+                                    high: code,
+                                    cid: cid_value(*cid, "cidchar")?,
+                                });
+                            }
+                            _ => return Err(CMapError::InvalidSection("cidchar").into()),
+                        }
+                    }
+                }
+                b"endbfchar" => {
+                    for operands in sections(&operands, 2, "bfchar")? {
+                        let code = match &operands[0] {
+                            Token::String(code_bytes) => code(code_bytes, "bfchar")?,
+                            _ => return Err(CMapError::InvalidSection("bfchar").into()),
+                        };
+                        cmap.unicode_ranges.push(UnicodeRange {
+                            low: code,
+                            high: code,
+                            text: UnicodeText::Consecutive(unicode_text(&operands[1])?),
+                        });
+                    }
+                }
+                b"endbfrange" => {
+                    let mut operands = operands.iter();
+                    while let Some(low) = operands.next() {
+                        let (low, high) = match (low, operands.next()) {
+                            (Token::String(low), Some(Token::String(high))) => {
+                                (code(low, "bfrange")?, code(high, "bfrange")?)
+                            }
+                            _ => return Err(CMapError::InvalidSection("bfrange").into()),
+                        };
+                        let text = match operands.next() {
+                            Some(Token::ArrayStart) => {
+                                let mut texts = Vec::new();
+                                loop {
+                                    match operands.next() {
+                                        Some(Token::ArrayEnd) => break,
+                                        Some(text) => texts.push(unicode_text(text)?),
+                                        None => {
+                                            return Err(CMapError::InvalidSection("bfrange").into())
+                                        }
+                                    }
+                                }
+                                UnicodeText::Each(texts)
+                            }
+                            Some(text) => UnicodeText::Consecutive(unicode_text(text)?),
+                            None => return Err(CMapError::InvalidSection("bfrange").into()),
+                        };
+                        cmap.unicode_ranges.push(UnicodeRange { low, high, text });
+                    }
+                }
+                // A CMap can only be based on another by name, so only
+                // predefined ones are found. Without one, the codes that only
+                // it maps have no CIDs.
+                b"usecmap" => {
+                    if let Some(Token::Name(name)) = operands.last() {
+                        let name = String::from_utf8_lossy(name);
+                        match CMap::predefined_nested(&name, depth + 1) {
+                            Ok(cmap) => base = Some(cmap),
+                            Err(error) => log::warn!("Ignoring usecmap /{}: {}", name, error),
+                        }
+                    }
+                }
+                b"def" => match &operands[operands.len().saturating_sub(2)..] {
+                    [Token::Name(key), Token::Name(name)] if key == b"CMapName" => {
+                        cmap.name = String::from_utf8_lossy(name).into_owned();
+                    }
+                    [Token::Name(key), Token::Integer(mode)] if key == b"WMode" => {
+                        cmap.vertical = *mode == 1;
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+            operands.clear();
+        }
+        if let Some(base) = base {
+            cmap.codespace_ranges.extend(base.codespace_ranges);
+            cmap.cid_ranges.extend(base.cid_ranges);
+            cmap.unicode_ranges.extend(base.unicode_ranges);
+        }
+        Ok(cmap)
+    }
+
+    /// Reads the code at the start of `bytes`, returning it and its length.
+    /// Bytes that don't start any code are read as a code as long as the
+    /// shortest codespace range, which maps to no CID.
+    pub fn next_code(&self, bytes: &[u8]) -> (u32, usize) {
+        let len = self
+            .codespace_ranges
+            .iter()
+            .filter(|range| range.contains(bytes))
+            .map(|range| range.low.len())
+            .min()
+            .or_else(|| self.codespace_ranges.iter().map(|r| r.low.len()).min())
+            .unwrap_or(1)
+            .min(bytes.len());
+        let code = bytes[..len]
+            .iter()
+            .fold(0, |code, &byte| code << 8 | u32::from(byte));
+        (code, len)
+    }
+
+    /// Splits a string into its codes and their lengths
+    pub fn codes(&self, bytes: &[u8]) -> Vec<(u32, usize)> {
+        let mut codes = Vec::new();
+        let mut remaining = bytes;
+        while !remaining.is_empty() {
+            let (code, len) = self.next_code(remaining);
+            codes.push((code, len));
+            remaining = &remaining[len.max(1)..];
+        }
+        codes
+    }
+
+    /// The text that a code of a ToUnicode CMap maps to, if any
+    pub fn unicode(&self, code: u32) -> Option<String> {
+        let range = self
+            .unicode_ranges
+            .iter()
+            .find(|range| (range.low..=range.high).contains(&code))?;
+        let offset = code - range.low;
+        match &range.text {
+            UnicodeText::Consecutive(first) => {
+                let mut text = first.clone();
+                let last = text.last_mut()?;
+                *last = last.wrapping_add(offset as u16);
+                Some(String::from_utf16_lossy(&text))
+            }
+            UnicodeText::Each(texts) => texts
+                .get(offset as usize)
+                .map(|text| String::from_utf16_lossy(text)),
+        }
+    }
+
+    /// The CID that a code maps to, if any
+    pub fn cid(&self, code: u32) -> Option<u32> {
+        self.cid_ranges
+            .iter()
+            .find(|range| (range.low..=range.high).contains(&code))
+            .map(|range| range.cid + (code - range.low))
+    }
+}
+
+fn range(low: &[u8], high: &[u8]) -> CodespaceRange {
+    CodespaceRange {
+        low: low.to_vec(),
+        high: high.to_vec(),
+    }
+}
+
+/// Splits the operands of a section into its entries, each of `len` operands
+fn sections<'t>(
+    operands: &'t [Token],
+    len: usize,
+    section: &'static str,
+) -> Result<std::slice::ChunksExact<'t, Token>> {
+    let sections = operands.chunks_exact(len);
+    if !sections.remainder().is_empty() {
+        return Err(CMapError::InvalidSection(section).into());
+    }
+    Ok(sections)
+}
+
+/// The value of a code given as a string of its bytes
+fn code(bytes: &[u8], section: &'static str) -> Result<u32> {
+    if bytes.is_empty() || bytes.len() > 4 {
+        return Err(CMapError::InvalidSection(section).into());
+    }
+    Ok(bytes
+        .iter()
+        .fold(0, |code, &byte| code << 8 | u32::from(byte)))
+}
+
+/// The destination of a `bfchar` or `bfrange` entry: UTF-16BE text, or
+/// rarely, the name of a glyph
+fn unicode_text(token: &Token) -> Result<Vec<u16>> {
+    match token {
+        Token::String(bytes) => Ok(bytes
+            .chunks(2)
+            .map(|unit| {
+                unit.iter()
+                    .fold(0, |unit, &byte| unit << 8 | u16::from(byte))
+            })
+            .collect()),
+        Token::Name(name) => Ok(glyph_unicode(&String::from_utf8_lossy(name))
+            .unwrap_or_default()
+            .encode_utf16()
+            .collect()),
+        _ => Err(CMapError::InvalidSection("bfchar").into()),
+    }
+}
+
+fn cid_value(cid: i64, section: &'static str) -> Result<u32> {
+    u32::try_from(cid).map_err(|_| CMapError::InvalidSection(section).into())
+}
+
+impl CodespaceRange {
+    fn contains(&self, bytes: &[u8]) -> bool {
+        bytes.len() >= self.low.len()
+            && self
+                .low
+                .iter()
+                .zip(&self.high)
+                .zip(bytes)
+                .all(|((low, high), byte)| (low..=high).contains(&byte))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CMap, CidRange, CodespaceRange};
+
+    #[test]
+    fn test_identity() {
+        let cmap = CMap::predefined("Identity-H").unwrap();
+        assert!(!cmap.vertical);
+        assert_eq!(cmap.codes(b"\x00\x41\x12\x34"), [(0x41, 2), (0x1234, 2)]);
+        assert_eq!(cmap.cid(0x1234), Some(0x1234));
+        // A trailing odd byte is still read, as a short code
+        assert_eq!(cmap.codes(b"\x00\x41\x12"), [(0x41, 2), (0x12, 1)]);
+        assert!(CMap::predefined("Identity-V").unwrap().vertical);
+    }
+
+    #[test]
+    fn test_predefined() {
+        // Identity-V is based on Identity-H, and keeps its own writing mode
+        let cmap = CMap::predefined("Identity-V").unwrap();
+        assert_eq!(cmap.name, "Identity-V");
+        assert!(cmap.vertical);
+        assert_eq!(cmap.codes(b"\x30\x42\xff\xfe"), [(0x3042, 2), (0xfffe, 2)]);
+        assert_eq!(cmap.cid(0x3042), Some(0x3042));
+        assert_eq!(cmap.cid(0xfffe), Some(0xfffe));
+        // The CJK CMaps aren't bundled
+        assert!(CMap::predefined("90ms-RKSJ-H").is_none());
+        assert!(CMap::predefined("Unknown").is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        let content = b"%!PS-Adobe-3.0 Resource-CMap
+/CIDInit /ProcSet findresource begin
+12 dict begin
+begincmap
+/CIDSystemInfo << /Registry (Adobe) /Ordering (Japan1) /Supplement 2 >> def
+/CMapName /Custom-V def
+/CMapType 1 def
+/WMode 1 def
+/Identity-H usecmap
+1 begincodespacerange
+<00> <80>
+endcodespacerange
+2 begincidrange
+<20> <7e> 231
+<80> <80> 1000
+endcidrange
+1 begincidchar
+<7f> 9999
+endcidchar
+endcmap
+CMapName currentdict /CMap defineresource pop
+end
+end
+";
+        let cmap = CMap::parse(content).unwrap();
+        assert_eq!(cmap.name, "Custom-V");
+        assert!(cmap.vertical);
+        // Both its own codespace and that of the CMap it's based on
+        assert_eq!(cmap.codes(b"A\x81\x41"), [(0x41, 1), (0x8141, 2)]);
+        assert_eq!(cmap.cid(0x41), Some(264));
+        assert_eq!(cmap.cid(0x80), Some(1000));
+        assert_eq!(cmap.cid(0x7f), Some(9999));
+        assert_eq!(cmap.cid(0x8141), Some(0x8141));
+
+        assert!(CMap::parse(b"1 begincidrange <20> <7e> endcidrange").is_err());
+        // A base that isn't bundled leaves only its own mappings
+        let cmap = CMap::parse(b"/Unknown-H usecmap 1 begincidrange <20> <7e> 1 endcidrange");
+        let cmap = cmap.unwrap();
+        assert_eq!(cmap.cid(0x20), Some(1));
+        assert_eq!(cmap.cid(0x8141), None);
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        let content = b"/CIDInit /ProcSet findresource begin
+12 dict begin
+begincmap
+/CMapName /Adobe-Identity-UCS def
+1 begincodespacerange
+<0000> <FFFF>
+endcodespacerange
+3 beginbfchar
+<0003> <0020>
+<0011> <00660069>
+<0012> <D835DC00>
+endbfchar
+2 beginbfrange
+<0024> <0026> <0041>
+<0030> <0032> [<0061> <0062> /eacute]
+endbfrange
+endcmap
+CMapName currentdict /CMap defineresource pop
+end
+end";
+        let cmap = CMap::parse(content).unwrap();
+        assert_eq!(cmap.unicode(0x03).as_deref(), Some(" "));
+        assert_eq!(cmap.unicode(0x11).as_deref(), Some("fi"));
+        assert_eq!(cmap.unicode(0x12).as_deref(), Some("\u{1d400}"));
+        assert_eq!(cmap.unicode(0x25).as_deref(), Some("B"));
+        assert_eq!(cmap.unicode(0x26).as_deref(), Some("C"));
+        assert_eq!(cmap.unicode(0x30).as_deref(), Some("a"));
+        assert_eq!(cmap.unicode(0x32).as_deref(), Some("\u{e9}"));
+        assert_eq!(cmap.unicode(0x27), None);
+        assert_eq!(cmap.cid(0x03), None);
+    }
+
+    #[test]
+    fn test_mixed_lengths() {
+        // Like 90ms-RKSJ-H, with one-byte ASCII and two-byte Shift-JIS codes
+        let cmap = CMap {
+            name: "Mixed".to_owned(),
+            vertical: false,
+            codespace_ranges: vec![
+                CodespaceRange {
+                    low: vec![0x00],
+                    high: vec![0x80],
+                },
+                CodespaceRange {
+                    low: vec![0x81, 0x40],
+                    high: vec![0x9f, 0xfc],
+                },
+            ],
+            cid_ranges: vec![
+                CidRange {
+                    low: 0x20,
+                    high: 0x7e,
+                    cid: 231,
+                },
+                CidRange {
+                    low: 0x8140,
+                    high: 0x817e,
+                    cid: 633,
+                },
+            ],
+            unicode_ranges: Vec::new(),
+        };
+        let codes = cmap.codes(b"A\x81\x41");
+        assert_eq!(codes, [(0x41, 1), (0x8141, 2)]);
+        let cids: Vec<_> = codes.iter().map(|&(code, _)| cmap.cid(code)).collect();
+        assert_eq!(cids, [Some(264), Some(634)]);
+        assert_eq!(cmap.codes(b"\xa0"), [(0xa0, 1)]);
+        assert_eq!(cmap.cid(0xa0), None);
+    }
+}
@@ -0,0 +1,96 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    combinator::map,
+    multi::many0,
+};
+
+use crate::{
+    document::parsers::_string,
+    utils::{_name, _real, int1, ws},
+    NomResult,
+};
+
+/// The tokens of a CMap, which is a PostScript program that only uses a
+/// handful of operators
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    Integer(i64),
+    Real(f64),
+    String(Vec<u8>),
+    Name(Vec<u8>),
+    Operator(Vec<u8>),
+    ArrayStart,
+    ArrayEnd,
+    /// `<<`, `>>`, `{` or `}`, which CMaps only use in parts that are ignored
+    Delimiter,
+}
+
+#[inline]
+fn is_regular(c: u8) -> bool {
+    !b" \t\n\r\0\x0C()<>[]{}/%".contains(&c)
+}
+
+pub(crate) fn tokens(input: &[u8]) -> NomResult<Vec<Token>> {
+    many0(token)(input)
+}
+
+fn token(input: &[u8]) -> NomResult<Token> {
+    alt((
+        map(ws(delimiter), |_| Token::Delimiter),
+        map(ws(tag("[")), |_| Token::ArrayStart),
+        map(ws(tag("]")), |_| Token::ArrayEnd),
+        map(_string, |(string, _)| Token::String(string)),
+        map(_name, Token::Name),
+        map(ws(_real::<f64>), Token::Real),
+        map(ws(int1::<i64>), Token::Integer),
+        map(ws(operator), |operator| Token::Operator(operator.to_vec())),
+    ))(input)
+}
+
+fn delimiter(input: &[u8]) -> NomResult<&[u8]> {
+    alt((tag("<<"), tag(">>"), tag("{"), tag("}")))(input)
+}
+
+fn operator(input: &[u8]) -> NomResult<&[u8]> {
+    take_while1(is_regular)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokens, Token};
+
+    #[test]
+    fn test_tokens() {
+        let input = b"%!PS-Adobe-3.0 Resource-CMap\n/CIDInit /ProcSet findresource begin\n\
+            /CIDSystemInfo << /Registry (Adobe) >> def\n\
+            1 begincodespacerange <00> <ff> endcodespacerange\n\
+            [<0041> 1.5]";
+        let (rest, tokens) = tokens(input).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(
+            tokens,
+            [
+                Token::Name(b"CIDInit".to_vec()),
+                Token::Name(b"ProcSet".to_vec()),
+                Token::Operator(b"findresource".to_vec()),
+                Token::Operator(b"begin".to_vec()),
+                Token::Name(b"CIDSystemInfo".to_vec()),
+                Token::Delimiter,
+                Token::Name(b"Registry".to_vec()),
+                Token::String(b"Adobe".to_vec()),
+                Token::Delimiter,
+                Token::Operator(b"def".to_vec()),
+                Token::Integer(1),
+                Token::Operator(b"begincodespacerange".to_vec()),
+                Token::String(vec![0x00]),
+                Token::String(vec![0xff]),
+                Token::Operator(b"endcodespacerange".to_vec()),
+                Token::ArrayStart,
+                Token::String(vec![0x00, 0x41]),
+                Token::Real(1.5),
+                Token::ArrayEnd,
+            ]
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use anyhow::Result;
+use shared::{
+    CalGray, CalRgb, ColorSpace, ColorSpaceError, DeviceN, IccProfile, Indexed, Lab, Separation,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::{
+    dictionary::Dictionary, document::Document, error::HandlingError, function::Function,
+    object::Object, stream::Stream,
+};
+
+/// Limit on how deeply base and alternate color spaces may be nested, which
+/// protects against cycles in malformed files
+const MAX_NESTING: usize = 8;
+
+/// Resolves a color space from its name or array form, e.g. `/DeviceRGB`
+/// or `[/ICCBased 5 0 R]`
+pub(crate) fn resolve<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<ColorSpace> {
+    resolve_nested(document, object, 0)
+}
+
+fn resolve_nested<'a>(
+    document: &Document<'a>,
+    object: &Object<'a>,
+    depth: usize,
+) -> Result<ColorSpace> {
+    if depth > MAX_NESTING {
+        return Err(ColorSpaceError::TooDeeplyNested.into());
+    }
+    let resolve = |object: &Object<'a>| resolve_nested(document, object, depth + 1);
+    match document.dereference(object)? {
+        Object::Name(name) => ColorSpace::from_str(&String::from_utf8_lossy(name)),
+        Object::Array(array) => {
+            let family = array
+                .first()
+                .ok_or(HandlingError::ObjectNotFound)?
+                .as_name_str()?;
+            let operand = |i: usize| -> Result<&Object<'a>> {
+                let operand = array.get(i).ok_or(HandlingError::ObjectNotFound)?;
+                document.dereference(operand)
+            };
+            match family {
+                "CalGray" | "CalRGB" | "Lab" => cie_based(document, family, operand(1)?.as_dict()?),
+                "ICCBased" => icc_based(document, operand(1)?.as_stream()?, depth),
+                "Indexed" => {
+                    let base = resolve(operand(1)?)?;
+                    let hival = operand(2)?.as_i64()?.clamp(0, 255) as u8;
+                    let lookup = match operand(3)? {
+                        Object::Stream(stream) => stream.decoded_content()?.into_owned(),
+                        other => other.as_str()?.to_vec(),
+                    };
+                    Ok(ColorSpace::Indexed(Arc::new(Indexed::new(
+                        base, hival, lookup,
+                    )?)))
+                }
+                "Separation" => Ok(ColorSpace::Separation(Arc::new(Separation {
+                    name: operand(1)?.as_name()?.to_vec(),
+                    alternate: resolve(operand(2)?)?,
+                    tint_transform: Arc::new(Function::from_object(document, operand(3)?)?),
+                }))),
+                "DeviceN" => Ok(ColorSpace::DeviceN(Arc::new(DeviceN {
+                    names: operand(1)?
+                        .as_array()?
+                        .iter()
+                        .map(|name| Ok(document.dereference(name)?.as_name()?.to_vec()))
+                        .collect::<Result<_>>()?,
+                    alternate: resolve(operand(2)?)?,
+                    tint_transform: Arc::new(Function::from_object(document, operand(3)?)?),
+                }))),
+                // The base is only there for uncolored tiling patterns
+                "Pattern" if array.len() > 1 => {
+                    Ok(ColorSpace::Pattern(Some(Arc::new(resolve(operand(1)?)?))))
+                }
+                // Families without parameters may still be written as an array
+                _ if array.len() == 1 => ColorSpace::from_str(family),
+                other => Err(ColorSpaceError::UnrecognizedColorSpace(other.to_owned()).into()),
+            }
+        }
+        _ => Err(HandlingError::ObjectCast.into()),
+    }
+}
+
+/// A `/CalGray`, `/CalRGB` or `/Lab` color space. The `/BlackPoint` is
+/// ignored, which the spec allows.
+fn cie_based<'a>(
+    document: &Document<'a>,
+    family: &str,
+    dict: &Dictionary<'a>,
+) -> Result<ColorSpace> {
+    let floats = |key: &[u8], default: &[f32]| -> Result<Vec<f32>> {
+        let values = match dict.get(key) {
+            Ok(value) => document.get_floats(value)?,
+            Err(_) => default.to_vec(),
+        };
+        if values.len() != default.len() {
+            return Err(HandlingError::ObjectCast.into());
+        }
+        Ok(values)
+    };
+    let white_point = document.get_floats(dict.get(b"WhitePoint")?)?;
+    let white_point = match white_point[..] {
+        [x, y, z] if y == 1.0 => [x, y, z],
+        _ => return Err(HandlingError::ObjectCast.into()),
+    };
+    Ok(match family {
+        "CalGray" => ColorSpace::CalGray(CalGray {
+            white_point,
+            gamma: floats(b"Gamma", &[1.0])?[0],
+        }),
+        "CalRGB" => {
+            let gamma = floats(b"Gamma", &[1.0; 3])?;
+            let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+            let m = floats(b"Matrix", &identity)?;
+            ColorSpace::CalRGB(CalRgb {
+                white_point,
+                gamma: [gamma[0], gamma[1], gamma[2]],
+                matrix: [[m[0], m[1], m[2]], [m[3], m[4], m[5]], [m[6], m[7], m[8]]],
+            })
+        }
+        _ => {
+            let r = floats(b"Range", &[-100.0, 100.0, -100.0, 100.0])?;
+            if r[0] > r[1] || r[2] > r[3] {
+                return Err(HandlingError::ObjectCast.into());
+            }
+            ColorSpace::Lab(Lab {
+                white_point,
+                range: [r[0], r[1], r[2], r[3]],
+            })
+        }
+    })
+}
+
+/// An `/ICCBased` color space, or its alternate if the profile can't be used
+fn icc_based<'a>(document: &Document<'a>, stream: &Stream<'a>, depth: usize) -> Result<ColorSpace> {
+    let n = document.dereference(stream.dict.get(b"N")?)?.as_i64()? as usize;
+    let profile = stream
+        .decoded_content()
+        .and_then(|data| IccProfile::parse(&data));
+    match profile {
+        Ok(profile) if profile.components() == n => Ok(ColorSpace::ICCBased(Arc::new(profile))),
+        _ => match stream.dict.get(b"Alternate") {
+            Ok(alternate) => resolve_nested(document, alternate, depth + 1),
+            Err(_) => match n {
+                1 => Ok(ColorSpace::DeviceGray),
+                3 => Ok(ColorSpace::DeviceRGB),
+                4 => Ok(ColorSpace::DeviceCMYK),
+                n => Err(ColorSpaceError::UnrecognizedColorSpace(format!(
+                    "ICCBased with {} components",
+                    n
+                ))
+                .into()),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::{Cmyk, ColorSpace, ColorSpaceWithColor, Rgb};
+    use std::sync::Arc;
+
+    use super::resolve;
+    use crate::{
+        array, dict,
+        dictionary::Dictionary,
+        dictionary_struct,
+        document::Document,
+        int, name,
+        object::{Object, StringFormat},
+        real, reference,
+        stream::Stream,
+        string_hex,
+    };
+
+    const SRGB: &[u8] = include_bytes!(concat!(
+        env!("CARGO_WORKSPACE_DIR"),
+        "/crates/shared/assets/icc/sRGB-v4.icc"
+    ));
+
+    fn icc_stream(dict: Dictionary<'static>, content: &'static [u8]) -> Object<'static> {
+        Object::Stream(Stream {
+            dict,
+            content,
+            allows_compression: true,
+            start_position: None,
+        })
+    }
+
+    #[test]
+    fn test_device() {
+        let document = Document::new();
+        assert_eq!(
+            resolve(&document, &name!("DeviceCMYK")).unwrap(),
+            ColorSpace::DeviceCMYK
+        );
+        assert_eq!(
+            resolve(&document, &array!(name!("DeviceGray"))).unwrap(),
+            ColorSpace::DeviceGray
+        );
+        assert!(resolve(&document, &name!("Unknown")).is_err());
+        assert_eq!(
+            resolve(&document, &name!("Pattern")).unwrap(),
+            ColorSpace::Pattern(None)
+        );
+        assert_eq!(
+            resolve(&document, &array!(name!("Pattern"), name!("DeviceRGB"))).unwrap(),
+            ColorSpace::Pattern(Some(Arc::new(ColorSpace::DeviceRGB)))
+        );
+    }
+
+    #[test]
+    fn test_icc_based() {
+        let mut document = Document::new();
+        document.objects.insert(
+            (5, 0),
+            icc_stream(dictionary_struct! { "N" => int!(3) }, SRGB),
+        );
+        let color_space = resolve(&document, &array!(name!("ICCBased"), reference!(5, 0)));
+        match color_space.unwrap() {
+            ColorSpace::ICCBased(profile) => assert_eq!(profile.components(), 3),
+            other => panic!("expected an ICC profile, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_icc_based_fallback() {
+        let mut document = Document::new();
+        document.objects.insert(
+            (5, 0),
+            icc_stream(
+                dictionary_struct! {
+                    "N" => int!(4),
+                    "Alternate" => name!("DeviceCMYK"),
+                },
+                b"not a profile",
+            ),
+        );
+        // /N doesn't match the profile
+        document.objects.insert(
+            (6, 0),
+            icc_stream(dictionary_struct! { "N" => int!(1) }, SRGB),
+        );
+        assert_eq!(
+            resolve(&document, &array!(name!("ICCBased"), reference!(5, 0))).unwrap(),
+            ColorSpace::DeviceCMYK
+        );
+        assert_eq!(
+            resolve(&document, &array!(name!("ICCBased"), reference!(6, 0))).unwrap(),
+            ColorSpace::DeviceGray
+        );
+    }
+
+    #[test]
+    fn test_indexed() {
+        let document = Document::new();
+        let object = array!(
+            name!("Indexed"),
+            name!("DeviceRGB"),
+            int!(1),
+            string_hex!(b"\xff\x00\x00\x00\x00\xff")
+        );
+        let color = resolve(&document, &object)
+            .unwrap()
+            .with_components(vec![1.0])
+            .unwrap();
+        assert_eq!(
+            color.to_base(),
+            ColorSpaceWithColor::DeviceRGB(Rgb::new(0.0, 0.0, 1.0))
+        );
+        // The table is missing an entry
+        let object = array!(
+            name!("Indexed"),
+            name!("DeviceRGB"),
+            int!(2),
+            string_hex!(b"\xff\x00\x00\x00\x00\xff")
+        );
+        assert!(resolve(&document, &object).is_err());
+    }
+
+    #[test]
+    fn test_self_reference() {
+        let mut document = Document::new();
+        document.objects.insert(
+            (5, 0),
+            array!(
+                name!("Indexed"),
+                reference!(5, 0),
+                int!(0),
+                string_hex!(b"\x00")
+            ),
+        );
+        document.objects.insert(
+            (6, 0),
+            array!(
+                name!("Separation"),
+                name!("Spot"),
+                reference!(6, 0),
+                black()
+            ),
+        );
+        document.objects.insert(
+            (7, 0),
+            icc_stream(
+                dictionary_struct! {
+                    "N" => int!(1),
+                    "Alternate" => array!(name!("ICCBased"), reference!(7, 0)),
+                },
+                b"not a profile",
+            ),
+        );
+        assert!(resolve(&document, &reference!(5, 0)).is_err());
+        assert!(resolve(&document, &reference!(6, 0)).is_err());
+        assert!(resolve(&document, &array!(name!("ICCBased"), reference!(7, 0))).is_err());
+    }
+
+    /// A tint transform putting the tint in the black component of CMYK
+    fn black() -> Object<'static> {
+        dict!(dictionary_struct! {
+            "FunctionType" => int!(2),
+            "Domain" => array!(int!(0), int!(1)),
+            "C0" => array!(int!(0), int!(0), int!(0), int!(0)),
+            "C1" => array!(int!(0), int!(0), int!(0), int!(1)),
+            "N" => int!(1),
+        })
+    }
+
+    #[test]
+    fn test_separation() {
+        let mut document = Document::new();
+        document.objects.insert((7, 0), black());
+        let object = array!(
+            name!("Separation"),
+            name!("Spot"),
+            name!("DeviceCMYK"),
+            reference!(7, 0)
+        );
+        let color_space = resolve(&document, &object).unwrap();
+        assert_eq!(color_space.initial_components(), vec![1.0]);
+        let color = color_space.with_components(vec![0.5]).unwrap();
+        assert_eq!(
+            color.to_base(),
+            ColorSpaceWithColor::DeviceCMYK(Cmyk::new(0.0, 0.0, 0.0, 0.5))
+        );
+    }
+
+    #[test]
+    fn test_device_n() {
+        let document = Document::new();
+        let object = array!(
+            name!("DeviceN"),
+            array!(name!("Black")),
+            name!("DeviceCMYK"),
+            black()
+        );
+        let color_space = resolve(&document, &object).unwrap();
+        assert_eq!(color_space.components(), 1);
+        // Functions other than Type 2 aren't supported yet
+        let object = array!(
+            name!("DeviceN"),
+            array!(name!("Black")),
+            name!("DeviceCMYK"),
+            dict!(dictionary_struct! {
+                "FunctionType" => int!(4),
+                "Domain" => array!(int!(0), int!(1)),
+            })
+        );
+        assert!(resolve(&document, &object).is_err());
+    }
+
+    #[test]
+    fn test_cie_based() {
+        let document = Document::new();
+        let white_point = || array!(real!(0.9505), real!(1.0), real!(1.089));
+        let object = array!(
+            name!("CalRGB"),
+            dict!(dictionary_struct! {
+                "WhitePoint" => white_point(),
+                "Gamma" => array!(real!(2.2), real!(2.2), real!(2.2)),
+            })
+        );
+        match resolve(&document, &object).unwrap() {
+            ColorSpace::CalRGB(cal) => {
+                assert_eq!(cal.gamma, [2.2; 3]);
+                assert_eq!(cal.matrix[1], [0.0, 1.0, 0.0]);
+            }
+            other => panic!("expected CalRGB, got {:?}", other),
+        }
+
+        let object = array!(
+            name!("Lab"),
+            dict!(dictionary_struct! {
+                "WhitePoint" => white_point(),
+                "Range" => array!(int!(-128), int!(127), int!(10), int!(127)),
+            })
+        );
+        let color_space = resolve(&document, &object).unwrap();
+        assert_eq!(color_space.initial_components(), vec![0.0, 0.0, 10.0]);
+
+        // The white point is required
+        let object = array!(name!("CalGray"), dict!(dictionary_struct! {}));
+        assert!(resolve(&document, &object).is_err());
+    }
+}
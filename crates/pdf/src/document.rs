@@ -3,14 +3,21 @@ use nom::{
     error::{Error as NomError, VerboseError},
     ErrorConvert,
 };
-use std::collections::BTreeMap;
+use std::{cell::RefCell, collections::BTreeMap, collections::HashMap, rc::Rc};
 
-mod parsers;
+pub(crate) mod parsers;
 
 use self::parsers::{all_objects, make_xref_table};
-use crate::{dictionary::Dictionary, error::HandlingError, object::Object, xref::Xref, ObjectId};
+use crate::{
+    dictionary::Dictionary, error::HandlingError, font::Font, object::Object, page::Page,
+    xref::Xref, ObjectId,
+};
 use parsers::version;
 
+/// Limit on how many references are followed in a row, which protects
+/// against cycles in malformed files
+const MAX_REFERENCE_DEPTH: usize = 32;
+
 #[derive(Debug, PartialEq)]
 pub struct Document<'a> {
     pub version: f64,
@@ -20,6 +27,9 @@ pub struct Document<'a> {
     pub trailer: Dictionary<'a>,
 
     pub objects: BTreeMap<ObjectId, Object<'a>>,
+
+    /// Fonts that have been read, which pages commonly share
+    fonts: RefCell<HashMap<ObjectId, Rc<Font>>>,
 }
 
 impl<'a> Default for Document<'a> {
@@ -35,6 +45,7 @@ impl<'a> Document<'a> {
             xref: Xref::default(),
             trailer: Dictionary::default(),
             objects: BTreeMap::new(),
+            fonts: RefCell::default(),
         }
     }
 
@@ -69,6 +80,49 @@ impl<'a> Document<'a> {
             .ok_or_else(|| HandlingError::ObjectNotFound.into())
     }
 
+    /// Follows `object` through any indirect references to the object it
+    /// ultimately refers to
+    pub fn dereference<'b>(&'b self, object: &'b Object<'a>) -> Result<&'b Object<'a>> {
+        let mut object = object;
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            match object {
+                Object::Reference(id) => object = self.get_object(*id)?,
+                other => return Ok(other),
+            }
+        }
+        Err(HandlingError::ObjectNotFound.into())
+    }
+
+    /// Dereferences an array of numbers, such as a `/Domain` or `/Matrix`
+    pub fn get_floats(&self, object: &Object<'a>) -> Result<Vec<f32>> {
+        self.dereference(object)?
+            .as_array()?
+            .iter()
+            .map(|v| Ok(self.dereference(v)?.as_float()? as f32))
+            .collect()
+    }
+
+    /// Reads the font dictionary with the given id, only once per document
+    pub fn get_font(&self, font_id: ObjectId) -> Result<Rc<Font>> {
+        if let Some(font) = self.fonts.borrow().get(&font_id) {
+            return Ok(Rc::clone(font));
+        }
+        let font = Rc::new(Font::from_object(self, self.get_object(font_id)?)?);
+        self.fonts.borrow_mut().insert(font_id, Rc::clone(&font));
+        Ok(font)
+    }
+
+    pub fn get_page(&self, page_id: ObjectId) -> Result<Page<'_, 'a>> {
+        Page::new(self, page_id)
+    }
+
+    pub fn get_pages(&self) -> Result<Vec<Page<'_, 'a>>> {
+        self.get_page_ids()?
+            .into_iter()
+            .map(|id| self.get_page(id))
+            .collect()
+    }
+
     pub fn get_page_ids(&self) -> Result<Vec<ObjectId>> {
         let catalog = self.get_catalog()?;
         let pages_loc = catalog.get(b"Pages").and_then(Object::as_reference)?;
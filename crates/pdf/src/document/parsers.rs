@@ -2,7 +2,7 @@ use crate::error::{HandlingError, XrefError};
 use crate::{
     dictionary::Dictionary,
     object::{Name, Object, StringFormat},
-    stream::{Stream, TextContent},
+    stream::Stream,
     utils::{_name, _real, hex_char2, int1, take_until_unmatched, ws},
     xref::{Xref, XrefEntry},
     NomResult, ObjectId, ObjectNumber,
@@ -292,7 +292,7 @@ fn string(input: &[u8]) -> NomResult<Object<'_>> {
     map(_string, |(a, b)| Object::String(a, b))(input)
 }
 
-fn _string(input: &[u8]) -> NomResult<(Vec<u8>, StringFormat)> {
+pub(crate) fn _string(input: &[u8]) -> NomResult<(Vec<u8>, StringFormat)> {
     alt((
         map(ws(string_literal), |s| (s, StringFormat::Literal)),
         map(ws(string_hex), |s| (s, StringFormat::Hexadecimal)),
@@ -300,11 +300,8 @@ fn _string(input: &[u8]) -> NomResult<(Vec<u8>, StringFormat)> {
 }
 
 fn string_literal(input: &[u8]) -> NomResult<Vec<u8>> {
-    let (rest, raw) = delimited(
-        ws(char('(')),
-        take_until_unmatched(b"(", b")"),
-        ws(char(')')),
-    )(input)?;
+    // Whitespace inside the parentheses is part of the string
+    let (rest, raw) = delimited(char('('), string_literal_body, char(')'))(input)?;
     let (rest_inner, result) = map(
         many0(alt((
             preceded(char('\\'), char('(')),
@@ -333,6 +330,26 @@ fn string_literal(input: &[u8]) -> NomResult<Vec<u8>> {
     Ok((rest, result))
 }
 
+/// Everything up to the closing parenthesis of a literal string. Parentheses
+/// inside it are balanced unless they're escaped.
+fn string_literal_body(input: &[u8]) -> NomResult<&[u8]> {
+    let mut depth = 0;
+    let mut escaped = false;
+    let end = input.iter().position(|&c| {
+        match c {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'(' => depth += 1,
+            b')' if depth == 0 => return true,
+            b')' => depth -= 1,
+            _ => {}
+        }
+        false
+    });
+    let (input, end) = to_nom(end.ok_or(()), input, ErrorKind::TakeUntil)?;
+    Ok((&input[end..], &input[..end]))
+}
+
 fn string_hex(input: &[u8]) -> NomResult<Vec<u8>> {
     let (rest, raw) = delimited(
         ws(char('<')),
@@ -637,8 +654,10 @@ mod tests {
 		);
         assert_string_eq!(
             b"(Strings may have escaped unbalanced \\( in addition to (balanced)))",
-            b"Strings may have escaped unbalanced ( in addition to (balanced))"
+            b"Strings may have escaped unbalanced ( in addition to (balanced)"
         );
+        assert_string_eq!(b"(escaped \\) too)", b"escaped ) too");
+        assert_string_eq!(b"( leading space)", b" leading space");
         assert_string_eq!(b"(literal backslack \\\\)", b"literal backslack \\");
         assert_string_eq!(b"(this is all \\none line)", b"this is all one line");
         assert_string_eq!(b"(\\032)", &[26]);
@@ -1,6 +1,7 @@
 mod glyphnames;
 mod mappings;
 
+pub use self::glyphnames::Glyph;
 pub use self::mappings::*;
 
 use encoding::all::UTF_16BE;
@@ -21,6 +22,12 @@ pub fn string_to_bytes(encoding: [Option<u16>; 256], text: &str) -> Vec<u8> {
         .collect()
 }
 
+/// Whether a predefined CMap's codes are UTF-16BE, e.g. `UniGB-UCS2-H` or
+/// `UniJIS-UTF16-V`
+pub(crate) fn is_unicode_cmap(name: &str) -> bool {
+    name.starts_with("Uni") && (name.contains("-UCS2-") || name.contains("-UTF16-"))
+}
+
 pub fn decode_text(encoding: Option<&str>, bytes: &[u8]) -> String {
     if let Some(encoding) = encoding {
         match encoding {
@@ -28,10 +35,7 @@ pub fn decode_text(encoding: Option<&str>, bytes: &[u8]) -> String {
             "MacRomanEncoding" => bytes_to_string(MAC_ROMAN_ENCODING, bytes),
             "MacExpertEncoding" => bytes_to_string(MAC_EXPERT_ENCODING, bytes),
             "WinAnsiEncoding" => bytes_to_string(WIN_ANSI_ENCODING, bytes),
-            "UniGB-UCS2-H" | "UniGB−UTF16−H" => {
-                UTF_16BE.decode(bytes, DecoderTrap::Ignore).unwrap()
-            }
-            "Identity-H" => unimplemented!(),
+            name if is_unicode_cmap(name) => UTF_16BE.decode(bytes, DecoderTrap::Ignore).unwrap(),
             _ => String::from_utf8_lossy(bytes).to_string(),
         }
     } else {
@@ -46,13 +50,151 @@ pub fn encode_text(encoding: Option<&str>, text: &str) -> Vec<u8> {
             "MacRomanEncoding" => string_to_bytes(MAC_ROMAN_ENCODING, text),
             "MacExpertEncoding" => string_to_bytes(MAC_EXPERT_ENCODING, text),
             "WinAnsiEncoding" => string_to_bytes(WIN_ANSI_ENCODING, text),
-            "UniGB-UCS2-H" | "UniGB−UTF16−H" => {
-                UTF_16BE.encode(text, EncoderTrap::Ignore).unwrap()
-            }
-            "Identity-H" => unimplemented!(),
+            name if is_unicode_cmap(name) => UTF_16BE.encode(text, EncoderTrap::Ignore).unwrap(),
             _ => text.as_bytes().to_vec(),
         }
     } else {
         string_to_bytes(STANDARD_ENCODING, text)
     }
 }
+
+/// The text a glyph name stands for, following the Adobe Glyph List
+/// specification: a name in the glyph list, `uniXXXX` with one or more
+/// UTF-16 values, or `uXXXX` to `uXXXXXX` with a single code point. Anything
+/// after a period is a suffix that's ignored, and underscores join the
+/// components of a ligature.
+pub fn glyph_unicode(name: &str) -> Option<String> {
+    let name = name.split('.').next().unwrap_or_default();
+    if name.contains('_') {
+        let text: String = name.split('_').filter_map(glyph_unicode).collect();
+        return if text.is_empty() { None } else { Some(text) };
+    }
+    if let Some(unicode) = Glyph::from_name(name) {
+        return char::from_u32(unicode.into()).map(String::from);
+    }
+    let is_hex = |digits: &str| {
+        digits
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b))
+    };
+    if let Some(digits) = name.strip_prefix("uni") {
+        let units = digits.as_bytes().chunks_exact(4);
+        if digits.is_empty() || !units.remainder().is_empty() || !is_hex(digits) {
+            return None;
+        }
+        return units
+            .map(|unit| {
+                let unit = u32::from_str_radix(std::str::from_utf8(unit).ok()?, 16).ok()?;
+                char::from_u32(unit)
+            })
+            .collect();
+    }
+    if let Some(digits) = name.strip_prefix('u') {
+        if !(4..=6).contains(&digits.len()) || !is_hex(digits) {
+            return None;
+        }
+        return char::from_u32(u32::from_str_radix(digits, 16).ok()?).map(String::from);
+    }
+    None
+}
+
+/// The glyph names and Unicode values that a simple font's one-byte codes
+/// map to, from a base encoding along with any `/Differences`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodingTable {
+    glyphs: Vec<Option<(String, Option<String>)>>,
+}
+
+impl EncodingTable {
+    pub fn from_glyph_names(names: &[Option<&str>]) -> EncodingTable {
+        let mut table = EncodingTable {
+            glyphs: vec![None; 256],
+        };
+        for (code, name) in names.iter().take(256).enumerate() {
+            if let Some(name) = name {
+                table.set(code as u8, name);
+            }
+        }
+        table
+    }
+
+    /// One of the predefined encodings a font's `/Encoding` or
+    /// `/BaseEncoding` can name
+    pub fn predefined(name: &str) -> Option<EncodingTable> {
+        let names = match name {
+            "StandardEncoding" => STANDARD_GLYPH_NAMES,
+            "MacRomanEncoding" => MAC_ROMAN_GLYPH_NAMES,
+            "MacExpertEncoding" => MAC_EXPERT_GLYPH_NAMES,
+            "WinAnsiEncoding" => WIN_ANSI_GLYPH_NAMES,
+            _ => return None,
+        };
+        Some(EncodingTable::from_glyph_names(&names))
+    }
+
+    /// Maps a code to a glyph, as an entry of `/Differences` does
+    pub fn set(&mut self, code: u8, name: &str) {
+        self.glyphs[code as usize] = Some((name.to_owned(), glyph_unicode(name)));
+    }
+
+    pub fn glyph_name(&self, code: u8) -> Option<&str> {
+        self.glyphs[code as usize]
+            .as_ref()
+            .map(|(name, _)| name.as_str())
+    }
+
+    pub fn unicode(&self, code: u8) -> Option<&str> {
+        self.glyphs[code as usize]
+            .as_ref()
+            .and_then(|(_, unicode)| unicode.as_deref())
+    }
+
+    /// The text a string shows, leaving out codes without Unicode values
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .filter_map(|&code| self.unicode(code))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glyph_unicode, EncodingTable, Glyph};
+
+    #[test]
+    fn test_glyph_names() {
+        assert_eq!(Glyph::from_name("A"), Some(0x41));
+        assert_eq!(Glyph::from_name("eacute"), Some(0xe9));
+        assert_eq!(Glyph::from_name("zukatakana"), Some(0x30ba));
+        assert_eq!(Glyph::from_name("a1"), None);
+    }
+
+    #[test]
+    fn test_glyph_unicode() {
+        assert_eq!(glyph_unicode("Lcommaaccent").as_deref(), Some("\u{13b}"));
+        assert_eq!(glyph_unicode("uni20AC").as_deref(), Some("\u{20ac}"));
+        assert_eq!(glyph_unicode("uni00660069").as_deref(), Some("fi"));
+        assert_eq!(glyph_unicode("u1040C").as_deref(), Some("\u{1040c}"));
+        assert_eq!(glyph_unicode("f_f_i.alt").as_deref(), Some("ffi"));
+        assert_eq!(glyph_unicode("a.sc").as_deref(), Some("a"));
+        // Lowercase digits, surrogates and the wrong number of digits aren't
+        // allowed
+        assert_eq!(glyph_unicode("uni20ac"), None);
+        assert_eq!(glyph_unicode("uniD800"), None);
+        assert_eq!(glyph_unicode("u12"), None);
+        assert_eq!(glyph_unicode("g123"), None);
+    }
+
+    #[test]
+    fn test_encoding_table() {
+        let mut table = EncodingTable::predefined("WinAnsiEncoding").unwrap();
+        assert_eq!(table.glyph_name(0x27), Some("quotesingle"));
+        assert_eq!(table.glyph_name(0x80), Some("Euro"));
+        assert_eq!(table.decode(b"caf\xe9"), "caf\u{e9}");
+        table.set(0x41, "Alpha");
+        table.set(0x42, "a1");
+        assert_eq!(table.glyph_name(0x42), Some("a1"));
+        assert_eq!(table.decode(b"AB!"), "\u{391}!");
+        assert!(EncodingTable::predefined("Identity-H").is_none());
+    }
+}
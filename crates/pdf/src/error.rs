@@ -24,10 +24,84 @@ pub enum XrefError {
     XrefTableInvalidEntry(ObjectId),
 }
 
+#[derive(Error, Debug)]
+pub enum StreamError {
+    #[error("Unsupported stream filter: {0}")]
+    UnsupportedFilter(String),
+    #[error("Unsupported predictor for stream filter: {0}")]
+    UnsupportedPredictor(i64),
+}
+
+#[derive(Error, Debug)]
+pub enum FunctionError {
+    #[error("Unsupported function type: {0}")]
+    UnsupportedType(i64),
+    #[error("Function is missing required entry: {0}")]
+    MissingEntry(&'static str),
+    #[error("Function has an invalid entry: {0}")]
+    InvalidEntry(&'static str),
+    #[error("Sampled function has fewer samples than its /Size needs")]
+    NotEnoughSamples,
+    #[error("Failed to parse PostScript calculator function")]
+    InvalidPostScript,
+}
+
+#[derive(Error, Debug)]
+pub enum ShadingError {
+    #[error("Unsupported shading type: {0}")]
+    UnsupportedShadingType(i64),
+    #[error("Unsupported pattern type: {0}")]
+    UnsupportedPatternType(i64),
+    #[error("Shading has an invalid entry: {0}")]
+    InvalidEntry(&'static str),
+}
+
 #[derive(Error, Debug)]
 pub enum ParseError {
     #[error("Cap style should be 0, 1, 2 but was {0}")]
     InvalidCapStyle(i32),
+    #[error("Join style should be 0, 1, 2 but was {0}")]
+    InvalidJoinStyle(i32),
     #[error("Found leftover content in stream. leftover content: ({0})")]
     FailedToParseAllStreamContent(String),
 }
+
+#[derive(Error, Debug)]
+pub enum XObjectError {
+    #[error("Unsupported XObject subtype: {0}")]
+    UnsupportedSubtype(String),
+    #[error("XObject has an invalid entry: {0}")]
+    InvalidEntry(&'static str),
+}
+
+#[derive(Error, Debug)]
+pub enum FontError {
+    #[error("Unsupported font subtype: {0}")]
+    UnsupportedSubtype(String),
+    #[error("Font has an invalid entry: {0}")]
+    InvalidEntry(&'static str),
+    #[error("Text was shown without a font selected")]
+    NotSelected,
+    #[error("Font has no embedded program that can be read")]
+    NotEmbedded,
+    #[error("Font program has an invalid or missing table: {0}")]
+    InvalidTable(&'static str),
+    #[error("Font program is invalid: {0}")]
+    InvalidProgram(&'static str),
+}
+
+#[derive(Error, Debug)]
+pub enum CMapError {
+    #[error("CMap has an invalid {0} section")]
+    InvalidSection(&'static str),
+    #[error("CMap isn't bundled: {0}")]
+    UnknownCMap(String),
+    #[error("CMaps are based on each other too deeply")]
+    TooDeeplyNested,
+}
+
+#[derive(Error, Debug)]
+pub enum ExtGStateError {
+    #[error("Graphics state parameter dictionary has an invalid entry: {0}")]
+    InvalidEntry(&'static str),
+}
@@ -0,0 +1,243 @@
+use anyhow::Result;
+use lyon::path::{LineCap, LineJoin};
+use shared::{
+    Alpha, BlendMode, DashPattern, ExtGState, Flatness, LineWidth, MiterLimit, ObjectRef,
+    RenderingIntent, SoftMask, SoftMaskKind, TRANSFER_SAMPLES,
+};
+
+use crate::{
+    dictionary::Dictionary, document::Document, error::ExtGStateError, error::ParseError,
+    function::Function, object::Object,
+};
+
+/// Reads a graphics state parameter dictionary, the operand of `gs`
+pub(crate) fn ext_g_state<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<ExtGState> {
+    let dict = document.dereference(object)?.as_dict()?;
+    let float = |key: &[u8]| -> Result<Option<f32>> {
+        entry(document, dict, key)?
+            .map(|value| Ok(value.as_float()? as f32))
+            .transpose()
+    };
+    let boolean = |key: &[u8]| -> Result<Option<bool>> {
+        entry(document, dict, key)?.map(Object::as_bool).transpose()
+    };
+    Ok(ExtGState {
+        line_width: float(b"LW")?.map(LineWidth::new),
+        line_cap: entry(document, dict, b"LC")?.map(line_cap).transpose()?,
+        line_join: entry(document, dict, b"LJ")?.map(line_join).transpose()?,
+        miter_limit: float(b"ML")?.map(MiterLimit::new),
+        dash_pattern: entry(document, dict, b"D")?
+            .map(|dash| dash_pattern(document, dash))
+            .transpose()?,
+        rendering_intent: entry(document, dict, b"RI")?
+            .map(|intent| Ok::<_, anyhow::Error>(RenderingIntent::from(intent.as_name_str()?)))
+            .transpose()?,
+        flatness: float(b"FL")?.map(Flatness::new),
+        stroke_adjustment: boolean(b"SA")?,
+        stroke_alpha: float(b"CA")?.map(Alpha::new),
+        non_stroke_alpha: float(b"ca")?.map(Alpha::new),
+        alpha_is_shape: boolean(b"AIS")?,
+        text_knockout: boolean(b"TK")?,
+        blend_mode: entry(document, dict, b"BM")?
+            .map(|mode| blend_mode(document, mode))
+            .transpose()?,
+        soft_mask: match dict.get(b"SMask") {
+            Ok(mask) => Some(soft_mask(document, mask)?),
+            Err(_) => None,
+        },
+        font: entry(document, dict, b"Font")?
+            .map(|f| font(document, f))
+            .transpose()?,
+    })
+}
+
+/// An entry of the dictionary with references followed, if it's present
+fn entry<'d, 'a>(
+    document: &'d Document<'a>,
+    dict: &'d Dictionary<'a>,
+    key: &[u8],
+) -> Result<Option<&'d Object<'a>>> {
+    match dict.get(key) {
+        Ok(value) => Ok(Some(document.dereference(value)?)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn line_cap(object: &Object) -> Result<LineCap> {
+    match object.as_i64()? {
+        0 => Ok(LineCap::Butt),
+        1 => Ok(LineCap::Round),
+        2 => Ok(LineCap::Square),
+        other => Err(ParseError::InvalidCapStyle(other as i32).into()),
+    }
+}
+
+fn line_join(object: &Object) -> Result<LineJoin> {
+    match object.as_i64()? {
+        0 => Ok(LineJoin::Miter),
+        1 => Ok(LineJoin::Round),
+        2 => Ok(LineJoin::Bevel),
+        other => Err(ParseError::InvalidJoinStyle(other as i32).into()),
+    }
+}
+
+/// `/Font`, which is the operands of `Tf` in an array, except that the font
+/// is a reference to its dictionary rather than a resource name
+fn font<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<(ObjectRef, f32)> {
+    match object.as_array()?.as_slice() {
+        [font, size] => Ok((
+            font.as_reference()?,
+            document.dereference(size)?.as_float()? as f32,
+        )),
+        _ => Err(ExtGStateError::InvalidEntry("Font").into()),
+    }
+}
+
+/// `/D`, which is the operands of `d` in an array: `[[dashes] phase]`
+fn dash_pattern<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<DashPattern> {
+    match object.as_array()?.as_slice() {
+        [array, phase] => Ok(DashPattern::new(
+            document.get_floats(array)?,
+            document.dereference(phase)?.as_float()? as f32,
+        )),
+        _ => Err(ExtGStateError::InvalidEntry("D").into()),
+    }
+}
+
+/// `/BM` may be an array of modes, of which the first one that's recognized
+/// is used
+fn blend_mode<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<BlendMode> {
+    let modes = match object {
+        Object::Array(modes) => modes.iter().collect(),
+        other => vec![other],
+    };
+    for mode in modes {
+        if let Some(mode) = BlendMode::from_name(document.dereference(mode)?.as_name_str()?) {
+            return Ok(mode);
+        }
+    }
+    Ok(BlendMode::Normal)
+}
+
+fn soft_mask<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<Option<SoftMask>> {
+    let dict = match document.dereference(object)? {
+        Object::Name(name) if name == b"None" => return Ok(None),
+        other => other.as_dict()?,
+    };
+    let kind = match document.dereference(dict.get(b"S")?)?.as_name()? {
+        b"Alpha" => SoftMaskKind::Alpha,
+        b"Luminosity" => SoftMaskKind::Luminosity,
+        _ => return Err(ExtGStateError::InvalidEntry("SMask").into()),
+    };
+    let backdrop = match dict.get(b"BC") {
+        Ok(backdrop) => Some(document.get_floats(backdrop)?),
+        Err(_) => None,
+    };
+    let transfer = match dict.get(b"TR") {
+        Ok(transfer) => match document.dereference(transfer)? {
+            Object::Name(name) if name == b"Identity" => None,
+            function => {
+                let function = Function::from_object(document, function)?;
+                let samples = (0..TRANSFER_SAMPLES)
+                    .map(|i| {
+                        let x = i as f32 / (TRANSFER_SAMPLES - 1) as f32;
+                        // A function without outputs leaves the mask as is
+                        function.evaluate(&[x]).first().copied().unwrap_or(x)
+                    })
+                    .collect();
+                Some(samples)
+            }
+        },
+        Err(_) => None,
+    };
+    Ok(Some(SoftMask {
+        kind,
+        // The group must be an indirect reference to a stream
+        group: dict.get(b"G")?.as_reference()?,
+        backdrop,
+        transfer,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use lyon::path::LineJoin;
+    use shared::{BlendMode, DashPattern, SoftMaskKind};
+
+    use super::ext_g_state;
+    use crate::{
+        array, bool, dict, dictionary::Dictionary, dictionary_struct, document::Document, int,
+        name, object::Object, real, reference,
+    };
+
+    #[test]
+    fn test_ext_g_state() {
+        let mut document = Document::new();
+        document.objects.insert((4, 0), real!(0.5));
+        let object = dict!(dictionary_struct! {
+            "Type" => name!("ExtGState"),
+            "LW" => int!(2),
+            "LJ" => int!(1),
+            "D" => array!(array!(int!(3), int!(1)), int!(0)),
+            "CA" => reference!(4, 0),
+            "ca" => real!(1.5),
+            "SA" => bool!(true),
+            "BM" => array!(name!("Dissolve"), name!("Multiply")),
+            "SMask" => dict!(dictionary_struct! {
+                "S" => name!("Luminosity"),
+                "G" => reference!(5, 0),
+                "TR" => dict!(dictionary_struct! {
+                    "FunctionType" => int!(2),
+                    "Domain" => array!(int!(0), int!(1)),
+                    "C0" => array!(int!(1)),
+                    "C1" => array!(int!(0)),
+                    "N" => int!(1),
+                }),
+            }),
+            "Font" => array!(reference!(6, 0), int!(12)),
+        });
+        let state = ext_g_state(&document, &object).unwrap();
+        assert_eq!(state.line_width.map(|w| *w), Some(2.0));
+        assert_eq!(state.line_cap, None);
+        assert_eq!(state.line_join, Some(LineJoin::Round));
+        assert_eq!(
+            state.dash_pattern,
+            Some(DashPattern::new(vec![3.0, 1.0], 0.0))
+        );
+        assert_eq!(state.stroke_alpha.map(|a| *a), Some(0.5));
+        // Alpha is clamped to 0..1
+        assert_eq!(state.non_stroke_alpha.map(|a| *a), Some(1.0));
+        assert_eq!(state.stroke_adjustment, Some(true));
+        assert_eq!(state.blend_mode, Some(BlendMode::Multiply));
+        let mask = state.soft_mask.unwrap().unwrap();
+        assert_eq!(mask.kind, SoftMaskKind::Luminosity);
+        assert_eq!(mask.group, (5, 0));
+        let transfer = mask.transfer.unwrap();
+        assert_eq!((transfer[0], transfer[255]), (1.0, 0.0));
+        assert_eq!(state.font, Some(((6, 0), 12.0)));
+
+        let object = dict!(dictionary_struct! { "SMask" => name!("None") });
+        assert_eq!(
+            ext_g_state(&document, &object).unwrap().soft_mask,
+            Some(None)
+        );
+        let object = dict!(dictionary_struct! {
+            "SMask" => dict!(dictionary_struct! {
+                "S" => name!("Alpha"),
+                "G" => reference!(5, 0),
+                "TR" => dict!(dictionary_struct! {
+                    "FunctionType" => int!(2),
+                    "Domain" => array!(int!(0), int!(1)),
+                    "C0" => array!(),
+                    "C1" => array!(),
+                    "N" => int!(1),
+                }),
+            }),
+        });
+        let mask = ext_g_state(&document, &object).unwrap().soft_mask.unwrap();
+        let transfer = mask.unwrap().transfer.unwrap();
+        assert_eq!((transfer[0], transfer[255]), (0.0, 1.0));
+        let object = dict!(dictionary_struct! { "LC" => int!(3) });
+        assert!(ext_g_state(&document, &object).is_err());
+    }
+}
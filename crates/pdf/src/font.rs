@@ -0,0 +1,1229 @@
+use anyhow::Result;
+use lyon::path::PathEvent;
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use crate::{
+    cmap::CMap,
+    dictionary::Dictionary,
+    document::Document,
+    encodings::{
+        decode_text, glyph_unicode, is_unicode_cmap, EncodingTable, MAC_ROMAN_GLYPH_NAMES,
+    },
+    error::FontError,
+    object::Object,
+};
+
+mod cff;
+mod metrics;
+mod outline;
+mod standard;
+mod truetype;
+mod type1;
+
+pub use self::cff::Cff;
+pub use self::standard::StandardFont;
+pub use self::truetype::TrueType;
+pub use self::type1::Type1;
+
+/// Maps glyph space to text space for every kind of font but Type 3, whose
+/// glyphs are 1000 units to the em
+const GLYPH_SPACE: [f32; 6] = [0.001, 0.0, 0.0, 0.001, 0.0, 0.0];
+
+/// The width of CIDs that `/W` leaves out, for CIDFonts without a `/DW`
+const DEFAULT_CID_WIDTH: f32 = 1000.0;
+
+/// `[v_y w1_y]` of CIDs that `/W2` leaves out, for CIDFonts without a `/DW2`
+const DEFAULT_CID_VERTICAL_METRICS: [f32; 2] = [880.0, -1000.0];
+
+// Bits of a font descriptor's `/Flags`
+const FIXED_PITCH: u32 = 1;
+const SERIF: u32 = 1 << 1;
+const SYMBOLIC: u32 = 1 << 2;
+const NONSYMBOLIC: u32 = 1 << 5;
+const ITALIC: u32 = 1 << 6;
+
+/// A font dictionary, which determines how the strings shown by the text
+/// operators are painted
+#[derive(Debug, Clone, PartialEq)]
+pub struct Font {
+    pub subtype: FontSubtype,
+    /// `/BaseFont`, the PostScript name of the font. Type 3 fonts have none.
+    pub base_font: Option<String>,
+    /// `/FirstChar`, the code of the first entry of `widths`
+    pub first_char: u32,
+    /// `/Widths`, in thousandths of text space units for all but Type 3 fonts
+    pub widths: Vec<f32>,
+    pub descriptor: Option<FontDescriptor>,
+    pub encoding: Option<FontEncoding>,
+    /// What each code of a simple font maps to, given `encoding`
+    pub encoding_table: Option<EncodingTable>,
+    /// `/FontMatrix`, which maps glyph space to text space
+    pub font_matrix: [f32; 6],
+    /// `/CharProcs` of a Type 3 font, the content streams that draw each
+    /// glyph, by glyph name
+    pub char_procs: BTreeMap<String, Vec<u8>>,
+    /// The CIDFont in the `/DescendantFonts` of a Type 0 font
+    pub descendant: Option<Box<Font>>,
+    /// What the codes of a Type 0 font map to, given `encoding`. Fonts with
+    /// predefined CMaps that aren't bundled have none.
+    pub cmap: Option<CMap>,
+    /// `/W` of a CIDFont, `(first, last, width)` for ranges of CIDs, in
+    /// thousandths of text space units
+    pub cid_widths: Vec<(u32, u32, f32)>,
+    /// `/DW` of a CIDFont
+    pub default_width: f32,
+    /// `/W2` of a CIDFont, `(first, last, [w1_y v_x v_y])` for ranges of
+    /// CIDs: the vertical displacement, and where the vertical origin is from
+    /// the horizontal one, in thousandths of text space units
+    pub cid_vertical_metrics: Vec<(u32, u32, [f32; 3])>,
+    /// `/DW2` of a CIDFont, `[v_y w1_y]`
+    pub default_vertical_metrics: [f32; 2],
+    /// `/CIDToGIDMap` of a CIDFontType2 font
+    pub cid_to_gid: Option<CidToGidMap>,
+    /// `/ToUnicode`, which maps codes to the text they stand for
+    pub to_unicode: Option<CMap>,
+    /// The standard font that `/BaseFont` names, whose metrics stand in for
+    /// those the dictionary leaves out
+    pub standard: Option<&'static StandardFont>,
+    /// The embedded font program, if there's one that can be read
+    pub program: Option<FontProgram>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontSubtype {
+    Type1,
+    MMType1,
+    TrueType,
+    Type3,
+    Type0,
+    CIDFontType0,
+    CIDFontType2,
+}
+
+/// `/Encoding`, which maps the codes of a string to glyphs
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontEncoding {
+    /// A predefined encoding, e.g. `/WinAnsiEncoding`, or the name of a
+    /// predefined CMap for a Type 0 font, e.g. `/Identity-H`
+    Named(String),
+    /// An encoding dictionary, which replaces the glyph names of some codes
+    /// of `base_encoding`, or of the font's built-in encoding if there's none
+    Differences {
+        base_encoding: Option<String>,
+        differences: Vec<(u8, String)>,
+    },
+    /// The content of an embedded CMap stream of a Type 0 font
+    CMap(Vec<u8>),
+}
+
+/// Maps the CIDs of a CIDFont to the glyph ids of its TrueType font program
+#[derive(Debug, Clone, PartialEq)]
+pub enum CidToGidMap {
+    Identity,
+    /// The glyph id of each CID, in order
+    Map(Vec<u16>),
+}
+
+/// A font program that glyph outlines are read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontProgram {
+    TrueType(TrueType),
+    Type1(Type1),
+    Cff(Cff),
+}
+
+impl CidToGidMap {
+    pub fn glyph_id(&self, cid: u32) -> Option<u16> {
+        match self {
+            CidToGidMap::Identity => u16::try_from(cid).ok(),
+            CidToGidMap::Map(glyph_ids) => glyph_ids.get(cid as usize).copied(),
+        }
+    }
+}
+
+impl FontProgram {
+    /// The encoding that the program itself gives, which TrueType and
+    /// CID-keyed programs don't have
+    pub fn built_in_encoding(&self) -> Option<&EncodingTable> {
+        match self {
+            FontProgram::TrueType(_) => None,
+            FontProgram::Type1(program) => Some(&program.encoding),
+            FontProgram::Cff(program) => program.encoding(),
+        }
+    }
+}
+
+/// `/FontDescriptor`, the metrics and style of a font other than Type 0,
+/// along with its embedded font program if any
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontDescriptor {
+    /// `/Flags`, e.g. bit 3 for a symbolic font and bit 6 for a nonsymbolic one
+    pub flags: u32,
+    /// `[x_min y_min x_max y_max]` bounds of all the glyphs, in glyph space
+    pub font_bbox: [f32; 4],
+    pub italic_angle: f32,
+    pub ascent: f32,
+    pub descent: f32,
+    pub cap_height: f32,
+    pub stem_v: f32,
+    /// `/MissingWidth`, for codes that `/Widths` doesn't cover
+    pub missing_width: f32,
+    pub font_file: Option<FontFile>,
+}
+
+/// The decoded content of `/FontFile`, `/FontFile2` or `/FontFile3`
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontFile {
+    /// A Type 1 font program
+    Type1(Vec<u8>),
+    /// A TrueType font program
+    TrueType(Vec<u8>),
+    /// A font program whose format is given by the stream's `/Subtype`,
+    /// e.g. `/Type1C`, `/CIDFontType0C` or `/OpenType`
+    FontFile3(String, Vec<u8>),
+}
+
+impl Font {
+    /// Reads a font dictionary, the value of a `/Font` resource
+    pub(crate) fn from_object<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<Font> {
+        let object = document.dereference(object)?;
+        let dict = object.as_dict()?;
+        let subtype = font_subtype(document, object)?;
+        let base_font = match entry(document, dict, b"BaseFont")? {
+            Some(name) => Some(String::from_utf8_lossy(name.as_name()?).into_owned()),
+            None => None,
+        };
+        let first_char = match entry(document, dict, b"FirstChar")? {
+            Some(first_char) => u32::try_from(first_char.as_i64()?)
+                .map_err(|_| FontError::InvalidEntry("FirstChar"))?,
+            None => 0,
+        };
+        let widths = match dict.get(b"Widths") {
+            Ok(widths) => document.get_floats(widths)?,
+            Err(_) => Vec::new(),
+        };
+        let standard = match (subtype, &base_font) {
+            (FontSubtype::Type1 | FontSubtype::MMType1 | FontSubtype::TrueType, Some(name)) => {
+                StandardFont::find(name)
+            }
+            _ => None,
+        };
+        let descriptor = match entry(document, dict, b"FontDescriptor")? {
+            Some(descriptor) => Some(font_descriptor(document, descriptor.as_dict()?)?),
+            None => standard.map(StandardFont::descriptor),
+        };
+        let encoding = match entry(document, dict, b"Encoding")? {
+            Some(encoding) => Some(font_encoding(document, encoding)?),
+            None => None,
+        };
+        let program = descriptor
+            .as_ref()
+            .and_then(|descriptor| descriptor.font_file.as_ref())
+            .and_then(font_program);
+        let encoding_table = match subtype {
+            FontSubtype::Type0 | FontSubtype::CIDFontType0 | FontSubtype::CIDFontType2 => None,
+            _ => Some(encoding_table(
+                subtype,
+                encoding.as_ref(),
+                program.as_ref().and_then(FontProgram::built_in_encoding),
+                standard,
+                descriptor.as_ref(),
+            )),
+        };
+        let font_matrix = match dict.get(b"FontMatrix") {
+            Ok(matrix) => match document.get_floats(matrix)?[..] {
+                [a, b, c, d, e, f] => [a, b, c, d, e, f],
+                _ => return Err(FontError::InvalidEntry("FontMatrix").into()),
+            },
+            Err(_) => GLYPH_SPACE,
+        };
+        let mut char_procs = BTreeMap::new();
+        if let Some(procs) = entry(document, dict, b"CharProcs")? {
+            for (name, proc) in procs.as_dict()?.iter() {
+                let content = document.dereference(proc)?.as_stream()?.decoded_content()?;
+                char_procs.insert(
+                    String::from_utf8_lossy(name).into_owned(),
+                    content.into_owned(),
+                );
+            }
+        }
+        let descendant = match (subtype, entry(document, dict, b"DescendantFonts")?) {
+            (FontSubtype::Type0, Some(fonts)) => {
+                let font = fonts
+                    .as_array()?
+                    .first()
+                    .ok_or(FontError::InvalidEntry("DescendantFonts"))?;
+                // Only a CIDFont may descend from a Type 0 font, and CIDFonts
+                // have no descendants, so fonts can't nest or refer to
+                // themselves
+                let descendant_subtype = font_subtype(document, document.dereference(font)?)?;
+                if !matches!(
+                    descendant_subtype,
+                    FontSubtype::CIDFontType0 | FontSubtype::CIDFontType2
+                ) {
+                    return Err(FontError::InvalidEntry("DescendantFonts").into());
+                }
+                Some(Box::new(Font::from_object(document, font)?))
+            }
+            _ => None,
+        };
+        if subtype == FontSubtype::Type0 && descendant.is_none() {
+            return Err(FontError::InvalidEntry("DescendantFonts").into());
+        }
+        let cmap = match (subtype, &encoding) {
+            (FontSubtype::Type0, Some(FontEncoding::Named(name))) => CMap::predefined(name),
+            (FontSubtype::Type0, Some(FontEncoding::CMap(content))) => Some(CMap::parse(content)?),
+            _ => None,
+        };
+        let cid_widths = match entry(document, dict, b"W")? {
+            Some(widths) => cid_metrics(document, widths.as_array()?, "W")?
+                .into_iter()
+                .map(|(first, last, [width])| (first, last, width))
+                .collect(),
+            None => Vec::new(),
+        };
+        let default_width = match entry(document, dict, b"DW")? {
+            Some(width) => width.as_float()? as f32,
+            None => DEFAULT_CID_WIDTH,
+        };
+        let cid_vertical_metrics = match entry(document, dict, b"W2")? {
+            Some(metrics) => cid_metrics(document, metrics.as_array()?, "W2")?,
+            None => Vec::new(),
+        };
+        let default_vertical_metrics = match entry(document, dict, b"DW2")? {
+            Some(metrics) => match document.get_floats(metrics)?[..] {
+                [v_y, w1_y] => [v_y, w1_y],
+                _ => return Err(FontError::InvalidEntry("DW2").into()),
+            },
+            None => DEFAULT_CID_VERTICAL_METRICS,
+        };
+        // `/ToUnicode` only helps extract text, so one that can't be read is
+        // left out in favor of glyph names rather than losing the font
+        let to_unicode = match entry(document, dict, b"ToUnicode")? {
+            Some(Object::Stream(stream)) => {
+                match stream
+                    .decoded_content()
+                    .and_then(|content| CMap::parse(&content))
+                {
+                    Ok(cmap) => Some(cmap),
+                    Err(error) => {
+                        log::warn!("Ignoring a ToUnicode CMap that can't be read: {}", error);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        let cid_to_gid = match (subtype, entry(document, dict, b"CIDToGIDMap")?) {
+            (FontSubtype::CIDFontType2, Some(Object::Stream(stream))) => Some(CidToGidMap::Map(
+                stream
+                    .decoded_content()?
+                    .chunks_exact(2)
+                    .map(|gid| u16::from_be_bytes([gid[0], gid[1]]))
+                    .collect(),
+            )),
+            (FontSubtype::CIDFontType2, _) => Some(CidToGidMap::Identity),
+            _ => None,
+        };
+        Ok(Font {
+            subtype,
+            base_font,
+            first_char,
+            widths,
+            descriptor,
+            encoding,
+            encoding_table,
+            font_matrix,
+            char_procs,
+            descendant,
+            cmap,
+            cid_widths,
+            default_width,
+            cid_vertical_metrics,
+            default_vertical_metrics,
+            cid_to_gid,
+            to_unicode,
+            standard,
+            program,
+        })
+    }
+
+    /// Splits a string into its codes and their lengths in bytes. Codes of
+    /// simple fonts are single bytes, and those of Type 0 fonts are read
+    /// through their CMap. Without a supported one, the codespace of
+    /// `/ToUnicode` is used, or codes are taken to be two bytes.
+    pub fn codes(&self, bytes: &[u8]) -> Vec<(u32, usize)> {
+        let to_unicode = self.to_unicode.as_ref().filter(|cmap| cmap.has_codespace());
+        match (self.subtype, &self.cmap, to_unicode) {
+            (_, Some(cmap), _) => cmap.codes(bytes),
+            (FontSubtype::Type0, None, Some(to_unicode)) => to_unicode.codes(bytes),
+            (FontSubtype::Type0, None, None) => bytes
+                .chunks(2)
+                .map(|code| {
+                    let value = code.iter().fold(0, |value, &b| value << 8 | u32::from(b));
+                    // A string of odd length ends in a shorter code
+                    (value, code.len())
+                })
+                .collect(),
+            _ => bytes.iter().map(|&code| (u32::from(code), 1)).collect(),
+        }
+    }
+
+    /// The CID a code of a Type 0 font selects from its descendant font,
+    /// which isn't known without a bundled or embedded CMap
+    pub fn cid(&self, code: u32) -> Option<u32> {
+        self.cmap.as_ref()?.cid(code)
+    }
+
+    /// The width of the glyph for a code, in glyph space. A standard font
+    /// without `/Widths` uses its built-in metrics, and codes otherwise
+    /// outside of `/Widths` use the descriptor's `/MissingWidth`. Type 0 fonts
+    /// use the widths of their descendant's CIDs.
+    pub fn width(&self, code: u32) -> f32 {
+        if let Some(descendant) = &self.descendant {
+            return match self.cid(code) {
+                Some(cid) => descendant.cid_width(cid),
+                None => descendant.default_width,
+            };
+        }
+        if let Some(width) = code
+            .checked_sub(self.first_char)
+            .and_then(|i| self.widths.get(i as usize))
+        {
+            return *width;
+        }
+        let standard = self.standard.filter(|_| self.widths.is_empty());
+        let standard = standard.and_then(|standard| {
+            let code = u8::try_from(code).ok()?;
+            standard.width(self.encoding_table.as_ref()?.glyph_name(code)?)
+        });
+        standard.unwrap_or_else(|| self.descriptor.as_ref().map_or(0.0, |d| d.missing_width))
+    }
+
+    /// Whether the font is written top to bottom, which only Type 0 fonts
+    /// with a vertical CMap are, e.g. `/Identity-V`
+    pub fn is_vertical(&self) -> bool {
+        self.cmap.as_ref().map_or(false, |cmap| cmap.vertical)
+    }
+
+    /// The vertical displacement of the glyph for a code of a Type 0 font,
+    /// which is usually negative, and where its vertical origin is from its
+    /// horizontal one, in glyph space. CIDs that `/W2` leaves out use
+    /// `/DW2`, and their vertical origin is halfway across the glyph.
+    pub fn vertical_metrics(&self, code: u32) -> (f32, [f32; 2]) {
+        let font = self.descendant.as_deref().unwrap_or(self);
+        let metrics = self.cid(code).and_then(|cid| {
+            font.cid_vertical_metrics
+                .iter()
+                .find(|(first, last, _)| (*first..=*last).contains(&cid))
+        });
+        match metrics {
+            Some((_, _, [w1_y, v_x, v_y])) => (*w1_y, [*v_x, *v_y]),
+            None => {
+                let [v_y, w1_y] = font.default_vertical_metrics;
+                (w1_y, [self.width(code) / 2.0, v_y])
+            }
+        }
+    }
+
+    /// The width of a CID of a CIDFont, in glyph space
+    fn cid_width(&self, cid: u32) -> f32 {
+        self.cid_widths
+            .iter()
+            .find(|(first, last, _)| (*first..=*last).contains(&cid))
+            .map_or(self.default_width, |(_, _, width)| *width)
+    }
+
+    /// The text that a code stands for. `/ToUnicode` is preferred, and
+    /// otherwise simple fonts use the names of the glyphs their encoding
+    /// maps codes to, and Type 0 fonts look up the glyph of the code's CID.
+    pub fn unicode(&self, code: u32) -> Option<String> {
+        if let Some(text) = self.to_unicode.as_ref().and_then(|cmap| cmap.unicode(code)) {
+            return Some(text);
+        }
+        if let Some(descendant) = &self.descendant {
+            return descendant.cid_unicode(self.cid(code)?);
+        }
+        let table = self.encoding_table.as_ref()?;
+        table.unicode(u8::try_from(code).ok()?).map(String::from)
+    }
+
+    /// The text of the glyph for a CID of a CIDFont, which only its embedded
+    /// program tells: through a Unicode `cmap` subtable of a TrueType
+    /// program, or the glyph name of a CFF program that isn't CID-keyed
+    fn cid_unicode(&self, cid: u32) -> Option<String> {
+        let glyph_id = self.glyph_id(cid)?;
+        match self.program.as_ref()? {
+            FontProgram::TrueType(program) => program.unicode(glyph_id).map(String::from),
+            FontProgram::Cff(program) => glyph_unicode(program.glyph_name(glyph_id)?),
+            FontProgram::Type1(_) => None,
+        }
+    }
+
+    /// The text that a string shown with the font stands for, leaving out
+    /// codes that stand for none. Type 0 fonts without `/ToUnicode` whose
+    /// CMap has Unicode codes, e.g. `UniGB-UCS2-H`, are decoded as UTF-16BE.
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        match (&self.to_unicode, &self.encoding_table, &self.encoding) {
+            (None, None, Some(FontEncoding::Named(name))) if is_unicode_cmap(name) => {
+                decode_text(Some(name), bytes)
+            }
+            _ => self
+                .codes(bytes)
+                .into_iter()
+                .filter_map(|(code, _)| self.unicode(code))
+                .collect(),
+        }
+    }
+
+    /// The glyph of the embedded font program that a code selects. CIDFonts
+    /// map CIDs through `/CIDToGIDMap` or the charset of a CID-keyed CFF
+    /// program, simple TrueType fonts map codes through the program's
+    /// `cmap`, and other simple fonts look up the glyph name that their
+    /// encoding maps a code to.
+    pub fn glyph_id(&self, code: u32) -> Option<u16> {
+        if let Some(descendant) = &self.descendant {
+            return descendant.glyph_id(self.cid(code)?);
+        }
+        match (&self.cid_to_gid, &self.program) {
+            (Some(cid_to_gid), _) => cid_to_gid.glyph_id(code),
+            (None, Some(FontProgram::TrueType(program))) => {
+                self.truetype_glyph_id(program, u8::try_from(code).ok()?)
+            }
+            (None, Some(FontProgram::Cff(program)))
+                if self.subtype == FontSubtype::CIDFontType0 =>
+            {
+                program.glyph_id_by_cid(code)
+            }
+            (None, Some(program)) => {
+                let table = self.encoding_table.as_ref()?;
+                let name = table.glyph_name(u8::try_from(code).ok()?)?;
+                match program {
+                    FontProgram::Type1(program) => program.glyph_id(name),
+                    FontProgram::Cff(program) => program.glyph_id_by_name(name),
+                    FontProgram::TrueType(_) => None,
+                }
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// The outline of the glyph for a code, in glyph space. Codes without a
+    /// glyph are drawn as `.notdef`, glyph 0.
+    pub fn outline(&self, code: u32) -> Result<Vec<PathEvent>> {
+        let font = self.descendant.as_deref().unwrap_or(self);
+        let glyph_id = self.glyph_id(code).unwrap_or(0);
+        match &font.program {
+            Some(FontProgram::TrueType(program)) => program.outline(glyph_id),
+            Some(FontProgram::Type1(program)) => program.outline(glyph_id),
+            Some(FontProgram::Cff(program)) => program.outline(glyph_id),
+            None => Err(FontError::NotEmbedded.into()),
+        }
+    }
+
+    /// Nonsymbolic fonts look up the glyph name that their encoding maps a
+    /// code to, by its Unicode value in a (3, 1) subtable or by its Mac OS
+    /// Roman code in a (1, 0) one. Symbolic fonts, and codes that aren't
+    /// found that way, look up the code itself, which (3, 0) subtables may
+    /// have put in the private use area at `0xF000`, `0xF100` or `0xF200`.
+    fn truetype_glyph_id(&self, program: &TrueType, code: u8) -> Option<u16> {
+        let symbolic = self
+            .descriptor
+            .as_ref()
+            .is_some_and(|d| d.flags & SYMBOLIC != 0);
+        let table = self.encoding_table.as_ref().filter(|_| !symbolic);
+        if let Some(table) = table {
+            let unicode = table.unicode(code).and_then(|text| text.chars().next());
+            if let Some(glyph_id) = unicode.and_then(|c| program.glyph_id(3, 1, u32::from(c))) {
+                return Some(glyph_id);
+            }
+            let name = table.glyph_name(code);
+            let mac_roman = MAC_ROMAN_GLYPH_NAMES
+                .iter()
+                .position(|mac_roman| name.is_some() && *mac_roman == name);
+            if let Some(glyph_id) = mac_roman.and_then(|c| program.glyph_id(1, 0, c as u32)) {
+                return Some(glyph_id);
+            }
+        }
+        let code = u32::from(code);
+        [0, 0xf000, 0xf100, 0xf200]
+            .iter()
+            .find_map(|base| program.glyph_id(3, 0, base + code))
+            .or_else(|| program.glyph_id(1, 0, code))
+    }
+}
+
+fn font_subtype<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<FontSubtype> {
+    let dict = object.as_dict()?;
+    Ok(
+        match document.dereference(dict.get(b"Subtype")?)?.as_name_str()? {
+            "Type1" => FontSubtype::Type1,
+            "MMType1" => FontSubtype::MMType1,
+            "TrueType" => FontSubtype::TrueType,
+            "Type3" => FontSubtype::Type3,
+            "Type0" => FontSubtype::Type0,
+            "CIDFontType0" => FontSubtype::CIDFontType0,
+            "CIDFontType2" => FontSubtype::CIDFontType2,
+            other => return Err(FontError::UnsupportedSubtype(other.to_owned()).into()),
+        },
+    )
+}
+
+/// Reads an embedded font program. Those that can't be read are left out,
+/// so the font is drawn as if it weren't embedded.
+fn font_program(file: &FontFile) -> Option<FontProgram> {
+    match file {
+        FontFile::TrueType(data) => TrueType::parse(data).ok().map(FontProgram::TrueType),
+        FontFile::Type1(data) => Type1::parse(data).ok().map(FontProgram::Type1),
+        FontFile::FontFile3(subtype, data) if subtype == "Type1C" || subtype == "CIDFontType0C" => {
+            Cff::parse(data).ok().map(FontProgram::Cff)
+        }
+        // OpenType fonts have either TrueType outlines or a `CFF ` table
+        FontFile::FontFile3(subtype, data) if subtype == "OpenType" => {
+            match TrueType::parse(data) {
+                Ok(program) => Some(FontProgram::TrueType(program)),
+                Err(_) => {
+                    let tables = truetype::tables(data)?;
+                    let (_, cff) = tables.iter().find(|(tag, _)| tag == b"CFF ")?;
+                    Cff::parse(cff).ok().map(FontProgram::Cff)
+                }
+            }
+        }
+        FontFile::FontFile3(..) => None,
+    }
+}
+
+/// Maps the codes of a simple font to glyphs. Codes that `/Differences`
+/// doesn't cover use the base encoding, or without one, the font's built-in
+/// encoding: that of its embedded program, a standard font's own, or
+/// `StandardEncoding` for a nonsymbolic font.
+fn encoding_table(
+    subtype: FontSubtype,
+    encoding: Option<&FontEncoding>,
+    built_in: Option<&EncodingTable>,
+    standard: Option<&StandardFont>,
+    descriptor: Option<&FontDescriptor>,
+) -> EncodingTable {
+    let (base_encoding, differences) = match encoding {
+        Some(FontEncoding::Named(name)) => (Some(name.as_str()), &[][..]),
+        Some(FontEncoding::Differences {
+            base_encoding,
+            differences,
+        }) => (base_encoding.as_deref(), &differences[..]),
+        Some(FontEncoding::CMap(_)) | None => (None, &[][..]),
+    };
+    let symbolic = descriptor.is_some_and(|d| d.flags & SYMBOLIC != 0);
+    let base_encoding = base_encoding.and_then(EncodingTable::predefined);
+    let mut table = match (base_encoding, built_in, standard) {
+        (Some(table), _, _) => table,
+        (None, Some(built_in), _) => built_in.clone(),
+        (None, None, Some(standard)) => standard.built_in_encoding(),
+        (None, None, None) if subtype == FontSubtype::Type3 || symbolic => {
+            EncodingTable::from_glyph_names(&[])
+        }
+        (None, None, None) => {
+            EncodingTable::from_glyph_names(&crate::encodings::STANDARD_GLYPH_NAMES)
+        }
+    };
+    for (code, name) in differences {
+        table.set(*code, name);
+    }
+    table
+}
+
+/// An entry of the dictionary with references followed, if it's present
+fn entry<'d, 'a>(
+    document: &'d Document<'a>,
+    dict: &'d Dictionary<'a>,
+    key: &[u8],
+) -> Result<Option<&'d Object<'a>>> {
+    match dict.get(key) {
+        Ok(value) => Ok(Some(document.dereference(value)?)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Reads `/W`, whose elements are either a CID followed by an array of the
+/// widths of it and the CIDs after it, or the first and last CIDs of a range
+/// followed by the width of all of them
+/// Reads `/W` or `/W2` of a CIDFont, which give `N` numbers for each CID:
+/// either a CID followed by an array of them for it and the CIDs after it,
+/// or the first and last CIDs of a range followed by them for all of it
+fn cid_metrics<'a, const N: usize>(
+    document: &Document<'a>,
+    array: &[Object<'a>],
+    key: &'static str,
+) -> Result<Vec<(u32, u32, [f32; N])>> {
+    let cid = |object: &Object<'a>| -> Result<u32> {
+        let cid = document.dereference(object)?.as_i64()?;
+        Ok(u32::try_from(cid).map_err(|_| FontError::InvalidEntry(key))?)
+    };
+    let values = |items: &mut dyn Iterator<Item = &Object<'a>>| -> Result<[f32; N]> {
+        let mut values = [0.0; N];
+        for value in values.iter_mut() {
+            let item = items.next().ok_or(FontError::InvalidEntry(key))?;
+            *value = document.dereference(item)?.as_float()? as f32;
+        }
+        Ok(values)
+    };
+    let mut metrics = Vec::new();
+    let mut items = array.iter();
+    while let Some(first) = items.next() {
+        let first = cid(first)?;
+        match items.next().map(|item| document.dereference(item)) {
+            Some(Ok(Object::Array(list))) => {
+                for (i, chunk) in list.chunks(N).enumerate() {
+                    let cid = first + i as u32;
+                    metrics.push((cid, cid, values(&mut chunk.iter())?));
+                }
+            }
+            Some(Ok(last)) => {
+                let last = cid(last)?;
+                metrics.push((first, last, values(&mut items)?));
+            }
+            Some(Err(err)) => return Err(err),
+            None => return Err(FontError::InvalidEntry(key).into()),
+        }
+    }
+    Ok(metrics)
+}
+
+fn font_descriptor<'a>(document: &Document<'a>, dict: &Dictionary<'a>) -> Result<FontDescriptor> {
+    let float = |key: &[u8]| -> Result<f32> {
+        match entry(document, dict, key)? {
+            Some(value) => Ok(value.as_float()? as f32),
+            None => Ok(0.0),
+        }
+    };
+    let font_bbox = match dict.get(b"FontBBox") {
+        Ok(bbox) => match document.get_floats(bbox)?[..] {
+            [x0, y0, x1, y1] => [x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)],
+            _ => return Err(FontError::InvalidEntry("FontBBox").into()),
+        },
+        Err(_) => [0.0; 4],
+    };
+    let flags = match entry(document, dict, b"Flags")? {
+        Some(flags) => flags.as_i64()? as u32,
+        None => 0,
+    };
+    Ok(FontDescriptor {
+        flags,
+        font_bbox,
+        italic_angle: float(b"ItalicAngle")?,
+        ascent: float(b"Ascent")?,
+        descent: float(b"Descent")?,
+        cap_height: float(b"CapHeight")?,
+        stem_v: float(b"StemV")?,
+        missing_width: float(b"MissingWidth")?,
+        font_file: font_file(document, dict)?,
+    })
+}
+
+fn font_file<'a>(document: &Document<'a>, dict: &Dictionary<'a>) -> Result<Option<FontFile>> {
+    if let Some(file) = entry(document, dict, b"FontFile")? {
+        let content = file.as_stream()?.decoded_content()?;
+        return Ok(Some(FontFile::Type1(content.into_owned())));
+    }
+    if let Some(file) = entry(document, dict, b"FontFile2")? {
+        let content = file.as_stream()?.decoded_content()?;
+        return Ok(Some(FontFile::TrueType(content.into_owned())));
+    }
+    if let Some(file) = entry(document, dict, b"FontFile3")? {
+        let stream = file.as_stream()?;
+        let subtype = document.dereference(stream.dict.get(b"Subtype")?)?;
+        return Ok(Some(FontFile::FontFile3(
+            subtype.as_name_str()?.to_owned(),
+            stream.decoded_content()?.into_owned(),
+        )));
+    }
+    Ok(None)
+}
+
+fn font_encoding<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<FontEncoding> {
+    let dict = match object {
+        Object::Name(name) => {
+            return Ok(FontEncoding::Named(
+                String::from_utf8_lossy(name).into_owned(),
+            ))
+        }
+        Object::Stream(stream) => {
+            return Ok(FontEncoding::CMap(stream.decoded_content()?.into_owned()))
+        }
+        other => other.as_dict()?,
+    };
+    let base_encoding = match entry(document, dict, b"BaseEncoding")? {
+        Some(name) => Some(name.as_name_str()?.to_owned()),
+        None => None,
+    };
+    // A code, followed by the names of the glyphs for it and the codes after it
+    let mut differences = Vec::new();
+    let mut code = None;
+    if let Some(array) = entry(document, dict, b"Differences")? {
+        for item in array.as_array()? {
+            match document.dereference(item)? {
+                Object::Name(name) => {
+                    let current = code.ok_or(FontError::InvalidEntry("Differences"))?;
+                    let current = u8::try_from(current)
+                        .map_err(|_| FontError::InvalidEntry("Differences"))?;
+                    differences.push((current, String::from_utf8_lossy(name).into_owned()));
+                    code = Some(u32::from(current) + 1);
+                }
+                other => {
+                    let next = u32::try_from(other.as_i64()?)
+                        .map_err(|_| FontError::InvalidEntry("Differences"))?;
+                    code = Some(next);
+                }
+            }
+        }
+    }
+    Ok(FontEncoding::Differences {
+        base_encoding,
+        differences,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::{
+        cff, truetype, type1, Cff, Font, FontEncoding, FontProgram, FontSubtype, TrueType, Type1,
+    };
+    use crate::{
+        array, dict, dictionary::Dictionary, dictionary_struct, document::Document, int, name,
+        object::Object, real, reference, resources::Resources, stream, stream::Stream,
+    };
+
+    #[test]
+    fn test_simple_font() {
+        let mut document = Document::new();
+        document.objects.insert(
+            (2, 0),
+            dict!(dictionary_struct! {
+                "Type" => name!("FontDescriptor"),
+                "FontName" => name!("Helvetica"),
+                "Flags" => int!(32),
+                "FontBBox" => array!(int!(-166), int!(-225), int!(1000), int!(931)),
+                "Ascent" => int!(718),
+                "Descent" => int!(-207),
+                "MissingWidth" => int!(250),
+            }),
+        );
+        let object = dict!(dictionary_struct! {
+            "Type" => name!("Font"),
+            "Subtype" => name!("Type1"),
+            "BaseFont" => name!("Helvetica"),
+            "FirstChar" => int!(32),
+            "Widths" => array!(int!(278), real!(278.5)),
+            "FontDescriptor" => reference!(2, 0),
+            "Encoding" => dict!(dictionary_struct! {
+                "BaseEncoding" => name!("WinAnsiEncoding"),
+                "Differences" => array!(int!(39), name!("quotesingle"), int!(96), name!("grave"), name!("a")),
+            }),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.subtype, FontSubtype::Type1);
+        assert_eq!(font.base_font.as_deref(), Some("Helvetica"));
+        assert_eq!(font.width(32), 278.0);
+        assert_eq!(font.width(33), 278.5);
+        assert_eq!(font.width(34), 250.0);
+        assert_eq!(font.width(0), 250.0);
+        let descriptor = font.descriptor.as_ref().unwrap();
+        assert_eq!(descriptor.font_bbox, [-166.0, -225.0, 1000.0, 931.0]);
+        assert_eq!(descriptor.descent, -207.0);
+        assert_eq!(descriptor.font_file, None);
+        assert_eq!(
+            font.encoding,
+            Some(FontEncoding::Differences {
+                base_encoding: Some("WinAnsiEncoding".to_owned()),
+                differences: vec![
+                    (39, "quotesingle".to_owned()),
+                    (96, "grave".to_owned()),
+                    (97, "a".to_owned()),
+                ],
+            })
+        );
+        assert_eq!(font.font_matrix, super::GLYPH_SPACE);
+        assert_eq!(font.decode(b"'`a"), "'`a");
+    }
+
+    #[test]
+    fn test_standard_font() {
+        let document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "Type" => name!("Font"),
+            "Subtype" => name!("Type1"),
+            "BaseFont" => name!("Helvetica"),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(
+            font.standard.map(|standard| standard.name),
+            Some("Helvetica")
+        );
+        assert_eq!(font.width(u32::from(b'H')), 722.0);
+        assert_eq!(font.width(u32::from(b' ')), 278.0);
+        assert_eq!(font.width(0x100), 0.0);
+        let descriptor = font.descriptor.as_ref().unwrap();
+        assert_eq!(descriptor.ascent, 718.0);
+        assert_eq!(descriptor.descent, -207.0);
+        // Without an /Encoding, codes map through Helvetica's own encoding,
+        // where 0xe9 is Oslash
+        assert_eq!(font.decode(b"H\xe9"), "H\u{d8}");
+
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type1"),
+            "BaseFont" => name!("Helvetica"),
+            "Encoding" => dict!(dictionary_struct! {
+                "BaseEncoding" => name!("WinAnsiEncoding"),
+                "Differences" => array!(int!(0x41), name!("Alpha"), name!("a1")),
+            }),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.width(0xe9), 556.0);
+        assert_eq!(font.decode(b"caf\xe9"), "caf\u{e9}");
+        // Differences replace the base encoding, even with glyphs the font
+        // doesn't have or that have no Unicode value
+        assert_eq!(font.decode(b"ABC"), "\u{391}C");
+        assert_eq!(font.width(0x41), 0.0);
+        assert_eq!(font.width(0x43), 722.0);
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        let to_unicode = || {
+            stream!(Stream {
+                dict: dictionary_struct! {},
+                content: b"1 begincodespacerange <00> <ff> endcodespacerange
+                    2 beginbfchar <01> <0048> <41> <005A> endbfchar",
+                allows_compression: true,
+                start_position: None,
+            })
+        };
+        let document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("TrueType"),
+            "BaseFont" => name!("ABCDEF+Subset"),
+            "Encoding" => dict!(dictionary_struct! {
+                "Differences" => array!(int!(0x41), name!("g3"), name!("uni00E9"), name!("B")),
+            }),
+            "ToUnicode" => to_unicode(),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        // ToUnicode comes first, then glyph names, which g3 isn't
+        assert_eq!(font.unicode(0x41).as_deref(), Some("Z"));
+        assert_eq!(font.unicode(0x42).as_deref(), Some("\u{e9}"));
+        assert_eq!(font.decode(b"\x01ABCD"), "HZ\u{e9}BD");
+
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("Identity-H"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType2"),
+            })),
+            "ToUnicode" => stream!(Stream {
+                dict: dictionary_struct! {},
+                content: b"1 beginbfrange <0001> <0003> <0061> endbfrange",
+                allows_compression: true,
+                start_position: None,
+            }),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.decode(b"\x00\x03\x00\x01\x00\x09"), "ca");
+
+        // Falls back to glyph names
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("TrueType"),
+            "Encoding" => name!("WinAnsiEncoding"),
+            "ToUnicode" => stream!(Stream {
+                dict: dictionary_struct! {},
+                content: b"1 beginbfchar <01> endbfchar",
+                allows_compression: true,
+                start_position: None,
+            }),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.to_unicode, None);
+        assert_eq!(font.decode(b"AB"), "AB");
+    }
+
+    #[test]
+    fn test_truetype_glyphs() {
+        let document = Document::new();
+        let program = TrueType::parse(&truetype::tests::font()).unwrap();
+        let simple = |flags: i64| {
+            let object = dict!(dictionary_struct! {
+                "Subtype" => name!("TrueType"),
+                "BaseFont" => name!("Triangles"),
+                "Encoding" => name!("WinAnsiEncoding"),
+                "FontDescriptor" => dict!(dictionary_struct! {
+                    "Flags" => int!(flags),
+                }),
+            });
+            let mut font = Font::from_object(&document, &object).unwrap();
+            assert_eq!(font.program, None);
+            assert!(font.outline(0x41).is_err());
+            font.program = Some(FontProgram::TrueType(program.clone()));
+            font
+        };
+        // Through the Unicode values of the glyph names of the encoding
+        let nonsymbolic = simple(32);
+        assert_eq!(nonsymbolic.glyph_id(0x41), Some(1));
+        assert_eq!(nonsymbolic.glyph_id(0x61), Some(2));
+        assert_eq!(nonsymbolic.glyph_id(0x62), None);
+        assert_eq!(
+            nonsymbolic.outline(0x41).unwrap(),
+            program.outline(1).unwrap()
+        );
+        assert_eq!(
+            nonsymbolic.outline(0x62).unwrap(),
+            program.outline(0).unwrap()
+        );
+        // Through the private use area of the (3, 0) subtable
+        let symbolic = simple(4);
+        assert_eq!(symbolic.glyph_id(0x42), Some(2));
+        assert_eq!(symbolic.glyph_id(0x61), None);
+
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("Identity-H"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType2"),
+            })),
+        });
+        let mut font = Font::from_object(&document, &object).unwrap();
+        font.descendant.as_mut().unwrap().program = Some(FontProgram::TrueType(program.clone()));
+        assert_eq!(font.glyph_id(2), Some(2));
+        assert_eq!(font.outline(2).unwrap(), program.outline(2).unwrap());
+        // Text comes from the glyphs of the CIDs
+        assert_eq!(font.decode(b"\x00\x01\x00\x02\x00\x03"), "AB");
+    }
+
+    #[test]
+    fn test_type1_and_cff_glyphs() {
+        let document = Document::new();
+        let font_file = |subtype: &str, program: Vec<u8>| {
+            stream!(Stream {
+                dict: dictionary_struct! {
+                    "Subtype" => name!(subtype),
+                },
+                content: Box::leak(program.into_boxed_slice()),
+                allows_compression: false,
+                start_position: None,
+            })
+        };
+        // Codes that `/Differences` doesn't cover use the program's built-in
+        // encoding, which maps 193 to `Aacute` rather than `grave`
+        let program = Type1::parse(&type1::tests::font()).unwrap();
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type1"),
+            "BaseFont" => name!("Test"),
+            "Encoding" => dict!(dictionary_struct! {
+                "Differences" => array!(int!(0x42), name!("A")),
+            }),
+            "FontDescriptor" => dict!(dictionary_struct! {
+                "Flags" => int!(32),
+                "FontFile" => font_file("Type1", type1::tests::font()),
+            }),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.program, Some(FontProgram::Type1(program.clone())));
+        assert_eq!(font.glyph_id(0x41), Some(1));
+        assert_eq!(font.glyph_id(0x42), Some(1));
+        assert_eq!(font.glyph_id(193), Some(3));
+        assert_eq!(font.glyph_id(0x43), None);
+        assert_eq!(font.outline(193).unwrap(), program.outline(3).unwrap());
+        assert_eq!(font.outline(0x43).unwrap(), program.outline(0).unwrap());
+
+        // CIDs select glyphs through the charset
+        let program = Cff::parse(&cff::tests::cid_keyed_font()).unwrap();
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("Identity-H"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType0"),
+                "FontDescriptor" => dict!(dictionary_struct! {
+                    "Flags" => int!(4),
+                    "FontFile3" => font_file("CIDFontType0C", cff::tests::cid_keyed_font()),
+                }),
+            })),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert!(matches!(
+            font.descendant.as_ref().unwrap().program,
+            Some(FontProgram::Cff(_))
+        ));
+        assert_eq!(font.glyph_id(101), Some(2));
+        assert_eq!(font.glyph_id(102), None);
+        assert_eq!(font.outline(101).unwrap(), program.outline(2).unwrap());
+        assert_eq!(font.unicode(101), None);
+    }
+
+    #[test]
+    fn test_type0_font() {
+        let document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "BaseFont" => name!("NotoSansCJK"),
+            "Encoding" => name!("Identity-H"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType2"),
+                "BaseFont" => name!("NotoSansCJK"),
+                "DW" => int!(800),
+                "W" => array!(int!(1), array!(int!(500), int!(600)), int!(10), int!(20), int!(250)),
+                "CIDToGIDMap" => stream!(Stream {
+                    dict: dictionary_struct! {},
+                    content: b"\x00\x00\x00\x07\x00\x08",
+                    allows_compression: true,
+                    start_position: None,
+                }),
+            })),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.subtype, FontSubtype::Type0);
+        assert_eq!(
+            font.encoding,
+            Some(FontEncoding::Named("Identity-H".to_owned()))
+        );
+        let descendant = font.descendant.as_ref().unwrap();
+        assert_eq!(descendant.subtype, FontSubtype::CIDFontType2);
+        assert_eq!(
+            descendant.cid_widths,
+            [(1, 1, 500.0), (2, 2, 600.0), (10, 20, 250.0)]
+        );
+        let codes = font.codes(b"\x00\x01\x00\x02\x00\x0f\x00\x30");
+        assert_eq!(codes, [(1, 2), (2, 2), (15, 2), (48, 2)]);
+        let widths: Vec<_> = codes.iter().map(|&(code, _)| font.width(code)).collect();
+        assert_eq!(widths, [500.0, 600.0, 250.0, 800.0]);
+        // CIDs aren't Unicode values, and there's no program to look them up
+        assert_eq!(font.decode(b"\x00\x41"), "");
+        let cid_to_gid = descendant.cid_to_gid.as_ref().unwrap();
+        assert_eq!(cid_to_gid.glyph_id(1), Some(7));
+        assert_eq!(cid_to_gid.glyph_id(3), None);
+
+        // CIDFontType0 fonts find glyphs through the CFF charset instead
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("Identity-H"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType0"),
+                "CIDToGIDMap" => stream!(Stream {
+                    dict: dictionary_struct! {},
+                    content: b"\x00\x00\x00\x07",
+                    allows_compression: true,
+                    start_position: None,
+                }),
+            })),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.descendant.as_ref().unwrap().cid_to_gid, None);
+
+        // Vertical fonts are written down by `/W2` and `/DW2`
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("Identity-V"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType2"),
+                "DW2" => array!(int!(900), int!(-1100)),
+                "W2" => array!(
+                    int!(1),
+                    array!(int!(-900), int!(250), int!(800)),
+                    int!(5),
+                    int!(6),
+                    int!(-500),
+                    int!(300),
+                    int!(880)
+                ),
+            })),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert!(font.is_vertical());
+        assert_eq!(font.vertical_metrics(1), (-900.0, [250.0, 800.0]));
+        assert_eq!(font.vertical_metrics(6), (-500.0, [300.0, 880.0]));
+        // Centered on the default width
+        assert_eq!(font.vertical_metrics(2), (-1100.0, [500.0, 900.0]));
+
+        let missing = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("Identity-H"),
+        });
+        assert!(Font::from_object(&document, &missing).is_err());
+
+        // CMaps whose mappings aren't bundled select no CIDs, but their codes
+        // can still be split by the ToUnicode CMap
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("90ms-RKSJ-H"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType2"),
+                "DW" => int!(800),
+                "W" => array!(int!(0), array!(int!(500))),
+            })),
+            "ToUnicode" => stream!(Stream {
+                dict: dictionary_struct! {},
+                content: b"2 begincodespacerange <00> <80> <8140> <9ffc> endcodespacerange
+                    2 beginbfchar <41> <0041> <82a0> <3042> endbfchar",
+                allows_compression: true,
+                start_position: None,
+            }),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.cmap, None);
+        assert_eq!(font.codes(b"A\x82\xa0"), [(0x41, 1), (0x82a0, 2)]);
+        assert_eq!(font.cid(0x41), None);
+        assert_eq!(font.glyph_id(0x41), None);
+        assert_eq!(font.width(0x41), 800.0);
+        assert_eq!(font.decode(b"A\x82\xa0"), "A\u{3042}");
+
+        // Without either, codes are two bytes, except at the end of a string
+        // of odd length
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("90ms-RKSJ-H"),
+            "DescendantFonts" => array!(dict!(dictionary_struct! {
+                "Subtype" => name!("CIDFontType2"),
+            })),
+        });
+        let font = Font::from_object(&document, &object).unwrap();
+        assert_eq!(font.codes(b"\x82\xa0A"), [(0x82a0, 2), (0x41, 1)]);
+
+        // A font that descends from itself
+        let mut document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "Subtype" => name!("Type0"),
+            "Encoding" => name!("Identity-H"),
+            "DescendantFonts" => array!(reference!(1, 0)),
+        });
+        document.objects.insert((1, 0), object.clone());
+        assert!(Font::from_object(&document, &object).is_err());
+    }
+
+    #[test]
+    fn test_font_resource() {
+        let mut document = Document::new();
+        document.objects.insert(
+            (3, 0),
+            dict!(dictionary_struct! {
+                "Subtype" => name!("TrueType"),
+                "BaseFont" => name!("Arial"),
+            }),
+        );
+        let dict = dictionary_struct! {
+            "Font" => dict!(dictionary_struct! {
+                "F1" => reference!(3, 0),
+                "F2" => dict!(dictionary_struct! {
+                    "Subtype" => name!("Type3"),
+                    "FontMatrix" => array!(real!(0.01), int!(0), int!(0), real!(0.01), int!(0), int!(0)),
+                }),
+            }),
+        };
+        let resources = Resources::new(&document, Some(&dict));
+        let font = resources.font(b"F1").unwrap();
+        assert_eq!(font.subtype, FontSubtype::TrueType);
+        assert!(Rc::ptr_eq(&font, &resources.font(b"F1").unwrap()));
+        assert!(Rc::ptr_eq(&font, &resources.indirect_font((3, 0)).unwrap()));
+        let type3 = resources.font(b"F2").unwrap();
+        assert_eq!(type3.base_font, None);
+        assert_eq!(type3.font_matrix, [0.01, 0.0, 0.0, 0.01, 0.0, 0.0]);
+        assert!(resources.font(b"F3").is_err());
+    }
+}
@@ -0,0 +1,1036 @@
+use anyhow::Result;
+use lyon::math::{point, vector, Point, Transform, Vector};
+use lyon::path::PathEvent;
+use nom::number::complete::{be_i16, be_i32, be_u16, be_u8};
+use std::convert::TryFrom;
+
+use self::strings::STANDARD_STRINGS;
+use super::{outline::Outline, truetype::read};
+use crate::{
+    encodings::{EncodingTable, STANDARD_GLYPH_NAMES},
+    error::FontError,
+};
+
+mod strings;
+
+// Operators of the Top and Private DICTs. Escaped ones are 1200 plus the
+// byte after the escape.
+const CHARSET: u16 = 15;
+const ENCODING: u16 = 16;
+const CHAR_STRINGS: u16 = 17;
+const PRIVATE: u16 = 18;
+const SUBRS: u16 = 19;
+const CHARSTRING_TYPE: u16 = 1206;
+const FONT_MATRIX: u16 = 1207;
+const ROS: u16 = 1230;
+const FD_ARRAY: u16 = 1236;
+const FD_SELECT: u16 = 1237;
+
+/// The charset that a `charset` offset of 0 selects, whose glyph ids are
+/// their string ids
+const ISO_ADOBE: usize = 0;
+
+/// Limit on how deeply subroutines may call each other, as in the spec
+const MAX_SUBR_DEPTH: usize = 10;
+
+/// A CFF font program, as embedded by `/FontFile3` with a `/Subtype` of
+/// `/Type1C` or `/CIDFontType0C`, or in the `CFF ` table of an OpenType font.
+/// Only the first font of the program is read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cff {
+    /// The Type 2 charstrings of the glyphs, by glyph id
+    char_strings: Vec<Vec<u8>>,
+    global_subrs: Vec<Vec<u8>>,
+    /// The string id of each glyph's name, or for a CID-keyed font, its CID
+    charset: Vec<u16>,
+    /// The font's own strings, whose ids start after the standard ones
+    strings: Vec<String>,
+    /// The code of each glyph, from `Encoding`. CID-keyed fonts have none.
+    encoding: Option<EncodingTable>,
+    /// The Private DICTs, with the FontMatrix of each of a CID-keyed font's
+    /// Font DICTs. Other fonts have one.
+    private: Vec<Private>,
+    /// Which of `private` each glyph uses. Fonts that aren't CID-keyed have
+    /// none.
+    fd_select: Vec<u8>,
+    cid_keyed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Private {
+    /// Maps the font's units to text space
+    font_matrix: [f32; 6],
+    subrs: Vec<Vec<u8>>,
+}
+
+type Dict = Vec<(u16, Vec<f32>)>;
+
+impl Cff {
+    pub fn parse(data: &[u8]) -> Result<Cff> {
+        let invalid = FontError::InvalidProgram;
+        // The header ends where it says, after the version and its own size
+        let header_size = read(data, 2, be_u8).ok_or(invalid("header"))?;
+        let (_, end) = index(data, usize::from(header_size)).ok_or(invalid("Name INDEX"))?;
+        let (top_dicts, end) = index(data, end).ok_or(invalid("Top DICT INDEX"))?;
+        let (strings, end) = index(data, end).ok_or(invalid("String INDEX"))?;
+        let (global_subrs, _) = index(data, end).ok_or(invalid("Global Subr INDEX"))?;
+        let top = top_dicts
+            .first()
+            .and_then(|top| dict(top))
+            .ok_or(invalid("Top DICT"))?;
+        let get = |dict: &Dict, operator| {
+            dict.iter()
+                .find(|(op, _)| *op == operator)
+                .map(|(_, operands)| operands.clone())
+        };
+        let offset = |dict: &Dict, operator| -> Option<usize> {
+            let operands = get(dict, operator)?;
+            usize::try_from(*operands.last()? as i64).ok()
+        };
+        if get(&top, CHARSTRING_TYPE).is_some_and(|t| t != [2.0]) {
+            return Err(invalid("CharstringType").into());
+        }
+        let char_strings = offset(&top, CHAR_STRINGS)
+            .and_then(|offset| index(data, offset))
+            .ok_or(invalid("CharStrings INDEX"))?
+            .0;
+        let num_glyphs = char_strings.len();
+        let cid_keyed = get(&top, ROS).is_some();
+        let charset = match offset(&top, CHARSET).unwrap_or(ISO_ADOBE) {
+            ISO_ADOBE => (0..num_glyphs).map(|gid| gid as u16).collect(),
+            // The expert charsets, whose names aren't read
+            1 | 2 => vec![],
+            offset => charset(data, offset, num_glyphs).ok_or(invalid("charset"))?,
+        };
+        let top_matrix = get(&top, FONT_MATRIX).and_then(|m| matrix(&m));
+        let private_dict = |parent: &Dict, font_matrix: [f32; 6]| -> Option<Private> {
+            let (size, start) = match get(parent, PRIVATE)?[..] {
+                [size, start] => (size as usize, start as usize),
+                _ => return None,
+            };
+            let private = dict(data.get(start..start.checked_add(size)?)?)?;
+            let subrs = match offset(&private, SUBRS) {
+                // Relative to the Private DICT
+                Some(subrs) => index(data, start + subrs)?.0,
+                None => vec![],
+            };
+            let subrs = subrs.iter().map(|subr| subr.to_vec()).collect();
+            Some(Private { font_matrix, subrs })
+        };
+        let default_matrix = top_matrix.unwrap_or([0.001, 0.0, 0.0, 0.001, 0.0, 0.0]);
+        let (private, fd_select) = match cid_keyed {
+            true => {
+                let fd_array = offset(&top, FD_ARRAY)
+                    .and_then(|offset| index(data, offset))
+                    .ok_or(invalid("FDArray"))?
+                    .0;
+                let private = fd_array
+                    .iter()
+                    .map(|font| {
+                        let font = dict(font)?;
+                        // A Font DICT's matrix applies before the Top DICT's
+                        let font_matrix = match (get(&font, FONT_MATRIX), top_matrix) {
+                            (Some(fd), Some(top)) => multiply(matrix(&fd)?, top),
+                            (Some(fd), None) => matrix(&fd)?,
+                            (None, _) => default_matrix,
+                        };
+                        private_dict(&font, font_matrix)
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(invalid("FDArray"))?;
+                let fd_select = offset(&top, FD_SELECT)
+                    .and_then(|offset| fd_select(data, offset, num_glyphs))
+                    .ok_or(invalid("FDSelect"))?;
+                (private, fd_select)
+            }
+            false => {
+                let private = private_dict(&top, default_matrix).unwrap_or(Private {
+                    font_matrix: default_matrix,
+                    subrs: vec![],
+                });
+                (vec![private], vec![])
+            }
+        };
+        let strings = strings
+            .iter()
+            .map(|string| String::from_utf8_lossy(string).into_owned())
+            .collect();
+        let mut font = Cff {
+            char_strings: char_strings.iter().map(|c| c.to_vec()).collect(),
+            global_subrs: global_subrs.iter().map(|s| s.to_vec()).collect(),
+            charset,
+            strings,
+            encoding: None,
+            private,
+            fd_select,
+            cid_keyed,
+        };
+        if !cid_keyed {
+            font.encoding = match offset(&top, ENCODING).unwrap_or(0) {
+                0 => EncodingTable::predefined("StandardEncoding"),
+                // The expert encoding, which isn't read
+                1 => None,
+                offset => Some(font.encoding_at(data, offset).ok_or(invalid("Encoding"))?),
+            };
+        }
+        Ok(font)
+    }
+
+    /// Whether glyphs are selected by CID rather than by name
+    pub fn is_cid_keyed(&self) -> bool {
+        self.cid_keyed
+    }
+
+    /// The font's built-in encoding, which CID-keyed fonts don't have
+    pub fn encoding(&self) -> Option<&EncodingTable> {
+        self.encoding.as_ref()
+    }
+
+    /// The glyph with a name, in a font that isn't CID-keyed
+    pub fn glyph_id_by_name(&self, name: &str) -> Option<u16> {
+        if self.cid_keyed {
+            return None;
+        }
+        let gid = (0..self.charset.len()).find(|&gid| self.name(gid) == Some(name))?;
+        u16::try_from(gid).ok()
+    }
+
+    /// The name of a glyph, in a font that isn't CID-keyed
+    pub fn glyph_name(&self, glyph_id: u16) -> Option<&str> {
+        if self.cid_keyed {
+            return None;
+        }
+        self.name(usize::from(glyph_id))
+    }
+
+    /// The glyph for a CID, which is the glyph id itself in a font that
+    /// isn't CID-keyed
+    pub fn glyph_id_by_cid(&self, cid: u32) -> Option<u16> {
+        if !self.cid_keyed {
+            return u16::try_from(cid)
+                .ok()
+                .filter(|&gid| usize::from(gid) < self.char_strings.len());
+        }
+        let cid = u16::try_from(cid).ok()?;
+        let gid = self.charset.iter().position(|&c| c == cid)?;
+        u16::try_from(gid).ok()
+    }
+
+    /// The outline of a glyph, in glyph space, i.e. scaled to 1000 units to
+    /// the em. Contours are closed, and made of lines and cubic curves.
+    pub fn outline(&self, glyph_id: u16) -> Result<Vec<PathEvent>> {
+        let gid = usize::from(glyph_id);
+        let charstring = self
+            .char_strings
+            .get(gid)
+            .ok_or(FontError::InvalidProgram("CharStrings INDEX"))?;
+        let private = self
+            .fd_select
+            .get(gid)
+            .map_or(self.private.first(), |&fd| {
+                self.private.get(usize::from(fd))
+            })
+            .ok_or(FontError::InvalidProgram("FDSelect"))?;
+        let [a, b, c, d, e, f] = private.font_matrix.map(|value| value * 1000.0);
+        let mut outline = Outline::new(Transform::new(a, b, c, d, e, f));
+        Interpreter::new(self, &private.subrs, &mut outline, vector(0.0, 0.0))
+            .run(charstring, 0)?;
+        Ok(outline.build())
+    }
+
+    fn name(&self, gid: usize) -> Option<&str> {
+        let sid = usize::from(*self.charset.get(gid)?);
+        match STANDARD_STRINGS.get(sid) {
+            Some(name) => Some(*name),
+            None => self
+                .strings
+                .get(sid - STANDARD_STRINGS.len())
+                .map(String::as_str),
+        }
+    }
+
+    /// Reads a custom encoding, which gives the codes of glyphs in order,
+    /// starting after `.notdef`
+    fn encoding_at(&self, data: &[u8], offset: usize) -> Option<EncodingTable> {
+        let mut table = EncodingTable::from_glyph_names(&[]);
+        let format = read(data, offset, be_u8)?;
+        let mut set = |code: u8, gid: usize| {
+            if let Some(name) = self.name(gid) {
+                table.set(code, name);
+            }
+        };
+        let count = usize::from(read(data, offset + 1, be_u8)?);
+        let mut end = offset + 2;
+        match format & 0x7f {
+            0 => {
+                for gid in 1..=count {
+                    set(read(data, offset + 1 + gid, be_u8)?, gid);
+                }
+                end += count;
+            }
+            1 => {
+                let mut gid = 1;
+                for range in 0..count {
+                    let first = read(data, end + range * 2, be_u8)?;
+                    let left = read(data, end + range * 2 + 1, be_u8)?;
+                    for code in first..=first.checked_add(left)? {
+                        set(code, gid);
+                        gid += 1;
+                    }
+                }
+                end += count * 2;
+            }
+            _ => return None,
+        }
+        // Supplements give more codes for glyphs that already have one
+        if format & 0x80 != 0 {
+            let count = usize::from(read(data, end, be_u8)?);
+            for supplement in 0..count {
+                let code = read(data, end + 1 + supplement * 3, be_u8)?;
+                let sid = usize::from(read(data, end + 2 + supplement * 3, be_u16)?);
+                let name = match STANDARD_STRINGS.get(sid) {
+                    Some(name) => Some(*name),
+                    None => self
+                        .strings
+                        .get(sid - STANDARD_STRINGS.len())
+                        .map(String::as_str),
+                };
+                if let Some(name) = name {
+                    table.set(code, name);
+                }
+            }
+        }
+        Some(table)
+    }
+
+    /// The glyph that a code of the standard encoding selects, which is how
+    /// `endchar` names the parts of an accented glyph
+    fn standard_glyph(&self, code: f32) -> Option<&[u8]> {
+        let name = (*STANDARD_GLYPH_NAMES.get(usize::try_from(code as i64).ok()?)?)?;
+        let gid = (0..self.charset.len()).find(|&gid| self.name(gid) == Some(name))?;
+        self.char_strings.get(gid).map(Vec::as_slice)
+    }
+}
+
+/// Reads an INDEX, returning its objects and where it ends
+fn index(data: &[u8], offset: usize) -> Option<(Vec<&[u8]>, usize)> {
+    let count = usize::from(read(data, offset, be_u16)?);
+    if count == 0 {
+        return Some((vec![], offset + 2));
+    }
+    let offset_size = usize::from(read(data, offset + 2, be_u8)?);
+    let offsets = (0..=count)
+        .map(|i| {
+            let start = offset + 3 + i * offset_size;
+            let bytes = data.get(start..start + offset_size)?;
+            Some(bytes.iter().fold(0, |value, &b| value << 8 | b as usize))
+        })
+        .collect::<Option<Vec<usize>>>()?;
+    // Offsets start at 1, from the byte before the objects
+    let base = offset + 2 + (count + 1) * offset_size;
+    let objects = offsets
+        .windows(2)
+        .map(|pair| data.get(base + pair[0]..base + pair[1]))
+        .collect::<Option<Vec<&[u8]>>>()?;
+    Some((objects, base + offsets[count]))
+}
+
+/// Reads a DICT, whose operands come before their operators
+fn dict(data: &[u8]) -> Option<Dict> {
+    let mut entries = vec![];
+    let mut operands = vec![];
+    let mut i = 0;
+    while let Some(&byte) = data.get(i) {
+        i += 1;
+        match byte {
+            12 => {
+                let operator = 1200 + u16::from(*data.get(i)?);
+                i += 1;
+                entries.push((operator, std::mem::take(&mut operands)));
+            }
+            0..=21 => entries.push((u16::from(byte), std::mem::take(&mut operands))),
+            28 => {
+                operands.push(f32::from(read(data, i, be_i16)?));
+                i += 2;
+            }
+            29 => {
+                operands.push(read(data, i, be_i32)? as f32);
+                i += 4;
+            }
+            30 => {
+                let (value, length) = real(data.get(i..)?)?;
+                operands.push(value);
+                i += length;
+            }
+            32..=246 => operands.push(f32::from(byte) - 139.0),
+            247..=250 => {
+                let w = f32::from(*data.get(i)?);
+                operands.push((f32::from(byte) - 247.0) * 256.0 + w + 108.0);
+                i += 1;
+            }
+            251..=254 => {
+                let w = f32::from(*data.get(i)?);
+                operands.push(-(f32::from(byte) - 251.0) * 256.0 - w - 108.0);
+                i += 1;
+            }
+            _ => return None,
+        }
+    }
+    Some(entries)
+}
+
+/// Reads a real number operand, which is written in nibbles, returning it
+/// and how many bytes it took
+fn real(data: &[u8]) -> Option<(f32, usize)> {
+    let mut text = String::new();
+    for (i, byte) in data.iter().enumerate() {
+        for nibble in [byte >> 4, byte & 0xf] {
+            match nibble {
+                0..=9 => text.push(char::from(b'0' + nibble)),
+                0xa => text.push('.'),
+                0xb => text.push('E'),
+                0xc => text.push_str("E-"),
+                0xe => text.push('-'),
+                0xf => return Some((text.parse().ok()?, i + 1)),
+                _ => return None,
+            }
+        }
+    }
+    None
+}
+
+fn matrix(operands: &[f32]) -> Option<[f32; 6]> {
+    match *operands {
+        [a, b, c, d, e, f] => Some([a, b, c, d, e, f]),
+        _ => None,
+    }
+}
+
+/// The matrix that maps by `first` and then by `second`
+fn multiply(first: [f32; 6], second: [f32; 6]) -> [f32; 6] {
+    let [a, b, c, d, e, f] = first;
+    let [a2, b2, c2, d2, e2, f2] = second;
+    [
+        a * a2 + b * c2,
+        a * b2 + b * d2,
+        c * a2 + d * c2,
+        c * b2 + d * d2,
+        e * a2 + f * c2 + e2,
+        e * b2 + f * d2 + f2,
+    ]
+}
+
+/// Reads a charset, the string id or CID of each glyph after `.notdef`
+fn charset(data: &[u8], offset: usize, num_glyphs: usize) -> Option<Vec<u16>> {
+    let mut charset = vec![0];
+    let format = read(data, offset, be_u8)?;
+    let mut position = offset + 1;
+    while charset.len() < num_glyphs {
+        match format {
+            0 => {
+                charset.push(read(data, position, be_u16)?);
+                position += 2;
+            }
+            // Ranges of consecutive ids, whose lengths take a byte or two
+            1 | 2 => {
+                let first = read(data, position, be_u16)?;
+                let left = match format {
+                    1 => u16::from(read(data, position + 2, be_u8)?),
+                    _ => read(data, position + 2, be_u16)?,
+                };
+                charset.extend((0..=left).map(|i| first.wrapping_add(i)));
+                position += 3 + usize::from(format - 1);
+            }
+            _ => return None,
+        }
+    }
+    charset.truncate(num_glyphs);
+    Some(charset)
+}
+
+/// Reads an FDSelect, which Font DICT each glyph uses
+fn fd_select(data: &[u8], offset: usize, num_glyphs: usize) -> Option<Vec<u8>> {
+    match read(data, offset, be_u8)? {
+        0 => Some(data.get(offset + 1..offset + 1 + num_glyphs)?.to_vec()),
+        3 => {
+            let count = usize::from(read(data, offset + 1, be_u16)?);
+            let mut fd_select = vec![0; num_glyphs];
+            for range in 0..count {
+                let start = offset + 3 + range * 3;
+                let first = usize::from(read(data, start, be_u16)?);
+                let fd = read(data, start + 2, be_u8)?;
+                // The next range's first glyph, or the sentinel, ends it
+                let end = usize::from(read(data, start + 3, be_u16)?).min(num_glyphs);
+                fd_select.get_mut(first..end)?.fill(fd);
+            }
+            Some(fd_select)
+        }
+        _ => None,
+    }
+}
+
+/// The number that subroutine numbers are offset by, so that more of them
+/// take fewer bytes
+fn bias(subrs: &[Vec<u8>]) -> i32 {
+    match subrs.len() {
+        0..=1239 => 107,
+        1240..=33899 => 1131,
+        _ => 32768,
+    }
+}
+
+/// Runs Type 2 charstrings, drawing what they draw onto an outline
+struct Interpreter<'f, 'o> {
+    font: &'f Cff,
+    subrs: &'f [Vec<u8>],
+    outline: &'o mut Outline,
+    /// Where the glyph's origin is, which only isn't 0 for the parts of an
+    /// accented glyph
+    origin: Vector,
+    stack: Vec<f32>,
+    /// How many stem hints have been declared, which the length of a
+    /// `hintmask` depends on
+    stems: usize,
+    /// Whether the glyph's width, which may come before the first operator
+    /// that clears the stack, has been read past
+    width_read: bool,
+    current: Point,
+}
+
+impl<'f, 'o> Interpreter<'f, 'o> {
+    fn new(font: &'f Cff, subrs: &'f [Vec<u8>], outline: &'o mut Outline, origin: Vector) -> Self {
+        Interpreter {
+            font,
+            subrs,
+            outline,
+            origin,
+            stack: vec![],
+            stems: 0,
+            width_read: false,
+            current: point(0.0, 0.0) + origin,
+        }
+    }
+
+    /// Runs a charstring, returning whether it ended the glyph
+    fn run(&mut self, charstring: &[u8], depth: usize) -> Result<bool> {
+        let invalid = || FontError::InvalidProgram("charstring");
+        let mut i = 0;
+        while let Some(&byte) = charstring.get(i) {
+            i += 1;
+            match byte {
+                32..=246 => self.stack.push(f32::from(byte) - 139.0),
+                247..=254 => {
+                    let w = f32::from(*charstring.get(i).ok_or_else(invalid)?);
+                    i += 1;
+                    self.stack.push(match byte {
+                        247..=250 => (f32::from(byte) - 247.0) * 256.0 + w + 108.0,
+                        _ => -(f32::from(byte) - 251.0) * 256.0 - w - 108.0,
+                    });
+                }
+                28 => {
+                    let value = read(charstring, i, be_i16).ok_or_else(invalid)?;
+                    self.stack.push(f32::from(value));
+                    i += 2;
+                }
+                // A 16.16 fixed-point number
+                255 => {
+                    let value = read(charstring, i, be_i32).ok_or_else(invalid)?;
+                    self.stack.push(value as f32 / 65536.0);
+                    i += 4;
+                }
+                // hstem, vstem, hstemhm, vstemhm
+                1 | 3 | 18 | 23 => {
+                    self.width(self.stack.len() % 2 == 1);
+                    self.stems += self.stack.len() / 2;
+                    self.stack.clear();
+                }
+                // hintmask, cntrmask, which may declare vstems first
+                19 | 20 => {
+                    self.width(self.stack.len() % 2 == 1);
+                    self.stems += self.stack.len() / 2;
+                    self.stack.clear();
+                    i += self.stems.div_ceil(8);
+                }
+                // rmoveto
+                21 => {
+                    self.width(self.stack.len() > 2);
+                    let [dx, dy] = self.args()?;
+                    self.move_by(dx, dy);
+                }
+                // hmoveto
+                22 => {
+                    self.width(self.stack.len() > 1);
+                    let [dx] = self.args()?;
+                    self.move_by(dx, 0.0);
+                }
+                // vmoveto
+                4 => {
+                    self.width(self.stack.len() > 1);
+                    let [dy] = self.args()?;
+                    self.move_by(0.0, dy);
+                }
+                // rlineto
+                5 => {
+                    for pair in std::mem::take(&mut self.stack).chunks_exact(2) {
+                        self.line_by(pair[0], pair[1]);
+                    }
+                }
+                // hlineto, vlineto, which alternate between the two
+                6 | 7 => {
+                    let mut horizontal = byte == 6;
+                    for d in std::mem::take(&mut self.stack) {
+                        match horizontal {
+                            true => self.line_by(d, 0.0),
+                            false => self.line_by(0.0, d),
+                        }
+                        horizontal = !horizontal;
+                    }
+                }
+                // rrcurveto
+                8 => {
+                    for d in std::mem::take(&mut self.stack).chunks_exact(6) {
+                        self.curve_by(d[0], d[1], d[2], d[3], d[4], d[5]);
+                    }
+                }
+                // callsubr, callgsubr
+                10 | 29 => {
+                    let subrs = match byte {
+                        10 => self.subrs,
+                        _ => &self.font.global_subrs[..],
+                    };
+                    let subr = self.stack.pop().ok_or_else(invalid)? as i32 + bias(subrs);
+                    let subr = usize::try_from(subr)
+                        .ok()
+                        .and_then(|subr| subrs.get(subr))
+                        .ok_or_else(invalid)?;
+                    if depth >= MAX_SUBR_DEPTH {
+                        return Err(invalid().into());
+                    }
+                    if self.run(subr, depth + 1)? {
+                        return Ok(true);
+                    }
+                }
+                // return
+                11 => return Ok(false),
+                // endchar, which may draw an accented glyph like `seac`
+                14 => {
+                    self.width(self.stack.len() == 1 || self.stack.len() == 5);
+                    if let [adx, ady, base, accent] = self.stack[..] {
+                        let base = self.font.standard_glyph(base).ok_or_else(invalid)?;
+                        let accent = self.font.standard_glyph(accent).ok_or_else(invalid)?;
+                        let origin = self.origin;
+                        Interpreter::new(self.font, self.subrs, self.outline, origin)
+                            .run(base, 0)?;
+                        let origin = origin + vector(adx, ady);
+                        Interpreter::new(self.font, self.subrs, self.outline, origin)
+                            .run(accent, 0)?;
+                    }
+                    self.outline.close();
+                    return Ok(true);
+                }
+                // rcurveline
+                24 => {
+                    let args = std::mem::take(&mut self.stack);
+                    let (curves, line) = args.split_at(args.len().saturating_sub(2));
+                    for d in curves.chunks_exact(6) {
+                        self.curve_by(d[0], d[1], d[2], d[3], d[4], d[5]);
+                    }
+                    if let [dx, dy] = *line {
+                        self.line_by(dx, dy);
+                    }
+                }
+                // rlinecurve
+                25 => {
+                    let args = std::mem::take(&mut self.stack);
+                    let (lines, curve) = args.split_at(args.len().saturating_sub(6));
+                    for pair in lines.chunks_exact(2) {
+                        self.line_by(pair[0], pair[1]);
+                    }
+                    if let [dx1, dy1, dx2, dy2, dx3, dy3] = *curve {
+                        self.curve_by(dx1, dy1, dx2, dy2, dx3, dy3);
+                    }
+                }
+                // vvcurveto, hhcurveto, whose first curve may start at an
+                // angle
+                26 | 27 => {
+                    let mut args = std::mem::take(&mut self.stack);
+                    let mut first = 0.0;
+                    if args.len() % 4 == 1 {
+                        first = args.remove(0);
+                    }
+                    for d in args.chunks_exact(4) {
+                        match byte {
+                            26 => self.curve_by(first, d[0], d[1], d[2], 0.0, d[3]),
+                            _ => self.curve_by(d[0], first, d[1], d[2], d[3], 0.0),
+                        }
+                        first = 0.0;
+                    }
+                }
+                // vhcurveto, hvcurveto, which alternate between starting
+                // vertically and horizontally, and whose last curve may end
+                // at an angle
+                30 | 31 => {
+                    let args = std::mem::take(&mut self.stack);
+                    let mut horizontal = byte == 31;
+                    let count = args.len() / 4;
+                    for (n, d) in args.chunks_exact(4).enumerate() {
+                        let last = match n + 1 == count && args.len() % 4 == 1 {
+                            true => args[args.len() - 1],
+                            false => 0.0,
+                        };
+                        match horizontal {
+                            true => self.curve_by(d[0], 0.0, d[1], d[2], last, d[3]),
+                            false => self.curve_by(0.0, d[0], d[1], d[2], d[3], last),
+                        }
+                        horizontal = !horizontal;
+                    }
+                }
+                12 => {
+                    let operator = *charstring.get(i).ok_or_else(invalid)?;
+                    i += 1;
+                    self.escape(operator)?;
+                }
+                _ => self.stack.clear(),
+            }
+        }
+        Ok(false)
+    }
+
+    /// Runs an operator that follows the escape byte: flex, which is drawn
+    /// as the two curves it's made of, or arithmetic
+    fn escape(&mut self, operator: u8) -> Result<()> {
+        let invalid = || FontError::InvalidProgram("charstring");
+        match operator {
+            // flex
+            35 => {
+                let [dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, dx6, dy6, _] =
+                    self.args()?;
+                self.curve_by(dx1, dy1, dx2, dy2, dx3, dy3);
+                self.curve_by(dx4, dy4, dx5, dy5, dx6, dy6);
+            }
+            // hflex
+            34 => {
+                let [dx1, dx2, dy2, dx3, dx4, dx5, dx6] = self.args()?;
+                self.curve_by(dx1, 0.0, dx2, dy2, dx3, 0.0);
+                self.curve_by(dx4, 0.0, dx5, -dy2, dx6, 0.0);
+            }
+            // hflex1
+            36 => {
+                let [dx1, dy1, dx2, dy2, dx3, dx4, dx5, dy5, dx6] = self.args()?;
+                self.curve_by(dx1, dy1, dx2, dy2, dx3, 0.0);
+                self.curve_by(dx4, 0.0, dx5, dy5, dx6, -(dy1 + dy2 + dy5));
+            }
+            // flex1, whose last point moves along whichever axis the others
+            // move along most, and returns to the start along the other
+            37 => {
+                let [dx1, dy1, dx2, dy2, dx3, dy3, dx4, dy4, dx5, dy5, d6] = self.args()?;
+                let dx = dx1 + dx2 + dx3 + dx4 + dx5;
+                let dy = dy1 + dy2 + dy3 + dy4 + dy5;
+                let (dx6, dy6) = match dx.abs() > dy.abs() {
+                    true => (d6, -dy),
+                    false => (-dx, d6),
+                };
+                self.curve_by(dx1, dy1, dx2, dy2, dx3, dy3);
+                self.curve_by(dx4, dy4, dx5, dy5, dx6, dy6);
+            }
+            // abs, neg, sqrt, dup, drop
+            9 | 14 | 26 | 27 | 18 => {
+                let a = self.stack.pop().ok_or_else(invalid)?;
+                match operator {
+                    9 => self.stack.push(a.abs()),
+                    14 => self.stack.push(-a),
+                    26 => self.stack.push(a.sqrt()),
+                    27 => self.stack.extend([a, a]),
+                    _ => {}
+                }
+            }
+            // add, sub, div, mul, exch
+            10 | 11 | 12 | 24 | 28 => {
+                let b = self.stack.pop().ok_or_else(invalid)?;
+                let a = self.stack.pop().ok_or_else(invalid)?;
+                match operator {
+                    10 => self.stack.push(a + b),
+                    11 => self.stack.push(a - b),
+                    12 => self.stack.push(a / b),
+                    24 => self.stack.push(a * b),
+                    _ => self.stack.extend([b, a]),
+                }
+            }
+            // dotsection, and operators that are rarely used
+            _ => self.stack.clear(),
+        }
+        Ok(())
+    }
+
+    /// Leaves out the width that the first operator to clear the stack may
+    /// have before its operands
+    fn width(&mut self, has_width: bool) {
+        if !self.width_read && has_width && !self.stack.is_empty() {
+            self.stack.remove(0);
+        }
+        self.width_read = true;
+    }
+
+    /// Pops the operands of an operator, clearing the stack
+    fn args<const N: usize>(&mut self) -> Result<[f32; N]> {
+        let start = self
+            .stack
+            .len()
+            .checked_sub(N)
+            .ok_or(FontError::InvalidProgram("charstring"))?;
+        let mut args = [0.0; N];
+        args.copy_from_slice(&self.stack[start..]);
+        self.stack.clear();
+        Ok(args)
+    }
+
+    fn move_by(&mut self, dx: f32, dy: f32) {
+        self.current += vector(dx, dy);
+        self.outline.move_to(self.current);
+    }
+
+    fn line_by(&mut self, dx: f32, dy: f32) {
+        self.current += vector(dx, dy);
+        self.outline.line_to(self.current);
+    }
+
+    fn curve_by(&mut self, dx1: f32, dy1: f32, dx2: f32, dy2: f32, dx3: f32, dy3: f32) {
+        let ctrl1 = self.current + vector(dx1, dy1);
+        let ctrl2 = ctrl1 + vector(dx2, dy2);
+        self.current = ctrl2 + vector(dx3, dy3);
+        self.outline.curve_to(ctrl1, ctrl2, self.current);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use lyon::math::point;
+    use lyon::path::PathEvent;
+
+    use super::Cff;
+
+    fn index(objects: &[Vec<u8>]) -> Vec<u8> {
+        let mut index = (objects.len() as u16).to_be_bytes().to_vec();
+        if objects.is_empty() {
+            return index;
+        }
+        index.push(2);
+        let mut offset = 1u16;
+        index.extend(offset.to_be_bytes());
+        for object in objects {
+            offset += object.len() as u16;
+            index.extend(offset.to_be_bytes());
+        }
+        index.extend(objects.concat());
+        index
+    }
+
+    /// A DICT operand that always takes five bytes, so offsets can be filled
+    /// in once they're known
+    fn int(value: usize) -> Vec<u8> {
+        let mut int = vec![29];
+        int.extend((value as i32).to_be_bytes());
+        int
+    }
+
+    /// Encodes the numbers of a charstring, which are between -107 and 107
+    fn charstring(numbers_and_operators: &[i32]) -> Vec<u8> {
+        numbers_and_operators
+            .iter()
+            .map(|&n| match n {
+                // Operators are given as their negated codes
+                -32..=-1 => (-n) as u8,
+                _ => (n + 139) as u8,
+            })
+            .collect()
+    }
+
+    /// A font whose Top DICT is made by `top` from the offsets of `tables`,
+    /// which follow the INDEXes
+    fn font(top: &dyn Fn(&[usize]) -> Vec<u8>, strings: &[&str], tables: &[Vec<u8>]) -> Vec<u8> {
+        let strings: Vec<Vec<u8>> = strings.iter().map(|s| s.as_bytes().to_vec()).collect();
+        // hvcurveto, return
+        let global_subrs = index(&[charstring(&[25, 25, 25, 25, -31, -11])]);
+        let mut data = vec![1, 0, 4, 4];
+        data.extend(index(&[b"Test".to_vec()]));
+        let start = data.len()
+            + index(&[top(&vec![0; tables.len()])]).len()
+            + index(&strings).len()
+            + global_subrs.len();
+        let offsets: Vec<usize> = tables
+            .iter()
+            .scan(start, |offset, table| {
+                *offset += table.len();
+                Some(*offset - table.len())
+            })
+            .collect();
+        data.extend(index(&[top(&offsets)]));
+        data.extend(index(&strings));
+        data.extend(global_subrs);
+        data.extend(tables.concat());
+        data
+    }
+
+    #[test]
+    fn test_cff() {
+        // .notdef, A, acute, Aacute and one of the font's own strings
+        let charset = [
+            vec![0],
+            34u16.to_be_bytes().to_vec(),
+            125u16.to_be_bytes().to_vec(),
+        ];
+        let mut charset = charset.concat();
+        charset.extend(171u16.to_be_bytes());
+        charset.extend(391u16.to_be_bytes());
+        let char_strings = index(&[
+            charstring(&[-14]),
+            // A width, rmoveto, callsubr, hlineto, endchar
+            charstring(&[50, 100, 0, -21, -107, -10, -100, -6, -14]),
+            // rmoveto, callgsubr, endchar
+            charstring(&[0, 100, -21, -107, -29, -14]),
+            // adx ady bchar achar endchar: `A` is 65, `acute` is 194
+            [charstring(&[20, 10, 65]), vec![247, 86, 14]].concat(),
+            charstring(&[-14]),
+        ]);
+        // rlineto, return
+        let subrs = index(&[charstring(&[100, 0, 0, 100, -5, -11])]);
+        let private = [int(6), vec![19]].concat();
+        let top = |offsets: &[usize]| {
+            let mut top = [int(offsets[0]), vec![15], int(offsets[1]), vec![17]].concat();
+            top.extend([int(private.len()), int(offsets[2]), vec![18]].concat());
+            top
+        };
+        let tables = [charset, char_strings, private.clone(), subrs];
+        let font = Cff::parse(&font(&top, &["custom"], &tables)).unwrap();
+        assert!(!font.is_cid_keyed());
+        assert_eq!(font.encoding().unwrap().glyph_name(65), Some("A"));
+        assert_eq!(font.glyph_id_by_name("A"), Some(1));
+        assert_eq!(font.glyph_id_by_name("custom"), Some(4));
+        assert_eq!(font.glyph_id_by_name("B"), None);
+        assert_eq!(font.glyph_name(4), Some("custom"));
+        assert_eq!(font.glyph_id_by_cid(2), Some(2));
+        assert_eq!(font.outline(0).unwrap(), []);
+
+        let a = font.outline(1).unwrap();
+        let square = [
+            point(100.0, 0.0),
+            point(200.0, 0.0),
+            point(200.0, 100.0),
+            point(100.0, 100.0),
+        ];
+        assert_eq!(
+            a,
+            [
+                PathEvent::Begin { at: square[0] },
+                PathEvent::Line {
+                    from: square[0],
+                    to: square[1]
+                },
+                PathEvent::Line {
+                    from: square[1],
+                    to: square[2]
+                },
+                PathEvent::Line {
+                    from: square[2],
+                    to: square[3]
+                },
+                PathEvent::End {
+                    first: square[0],
+                    last: square[3],
+                    close: true
+                },
+            ]
+        );
+        let acute = font.outline(2).unwrap();
+        assert_eq!(
+            acute[1],
+            PathEvent::Cubic {
+                from: point(0.0, 100.0),
+                ctrl1: point(25.0, 100.0),
+                ctrl2: point(50.0, 125.0),
+                to: point(50.0, 150.0)
+            }
+        );
+
+        // The accent is moved by (adx, ady)
+        let aacute = font.outline(3).unwrap();
+        assert_eq!(aacute[..5], a[..]);
+        assert_eq!(
+            aacute[6],
+            PathEvent::Cubic {
+                from: point(20.0, 110.0),
+                ctrl1: point(45.0, 110.0),
+                ctrl2: point(70.0, 135.0),
+                to: point(70.0, 160.0)
+            }
+        );
+    }
+
+    /// A CID-keyed font with glyphs for CIDs 100 and 101, which use Font
+    /// DICTs with different matrices
+    pub(in crate::font) fn cid_keyed_font() -> Vec<u8> {
+        let charset = [vec![2], 100u16.to_be_bytes().to_vec(), vec![0, 1]].concat();
+        let fd_select = vec![3, 0, 2, 0, 0, 0, 0, 2, 1, 0, 3];
+        // rmoveto, hlineto, endchar
+        let glyph = charstring(&[100, 0, -21, 10, -6, -14]);
+        let char_strings = index(&[charstring(&[-14]), glyph.clone(), glyph]);
+        let matrix: Vec<u8> = [0.002, 0.0, 0.0, 0.002, 0.0, 0.0]
+            .iter()
+            .flat_map(|&value| match value {
+                0.0 => vec![139],
+                // A real number, 0.002
+                _ => vec![30, 0x0a, 0x00, 0x2f],
+            })
+            .chain([12, 7])
+            .collect();
+        let top = |offsets: &[usize]| {
+            // Registry, Ordering and Supplement
+            let mut top = [int(391), int(392), int(0), vec![12, 30]].concat();
+            top.extend([int(offsets[0]), vec![15], int(offsets[1]), vec![17]].concat());
+            top.extend([int(offsets[2]), vec![12, 36], int(offsets[3]), vec![12, 37]].concat());
+            top
+        };
+        // An empty Private DICT for both Font DICTs
+        let private = [int(0), int(0), vec![18]].concat();
+        let fd_array = index(&[private.clone(), [matrix, private].concat()]);
+        let tables = [charset, char_strings, fd_array, fd_select];
+        font(&top, &["Adobe", "Identity"], &tables)
+    }
+
+    #[test]
+    fn test_cid_keyed_cff() {
+        let font = Cff::parse(&cid_keyed_font()).unwrap();
+        assert!(font.is_cid_keyed());
+        assert_eq!(font.encoding(), None);
+        assert_eq!(font.glyph_id_by_cid(100), Some(1));
+        assert_eq!(font.glyph_id_by_cid(101), Some(2));
+        assert_eq!(font.glyph_id_by_cid(1), None);
+        assert_eq!(font.glyph_id_by_name("A"), None);
+        assert_eq!(font.glyph_name(1), None);
+        assert_eq!(
+            font.outline(1).unwrap()[..2],
+            [
+                PathEvent::Begin {
+                    at: point(100.0, 0.0)
+                },
+                PathEvent::Line {
+                    from: point(100.0, 0.0),
+                    to: point(110.0, 0.0)
+                }
+            ]
+        );
+        assert_eq!(
+            font.outline(2).unwrap()[..2],
+            [
+                PathEvent::Begin {
+                    at: point(200.0, 0.0)
+                },
+                PathEvent::Line {
+                    from: point(200.0, 0.0),
+                    to: point(220.0, 0.0)
+                }
+            ]
+        );
+        assert!(Cff::parse(&[1, 0, 4]).is_err());
+    }
+}
@@ -0,0 +1,4237 @@
+//! Glyph widths and kerning pairs of the standard 14 fonts, from Adobe's AFM
+//! files. Each width table is sorted by glyph name and gives the glyph's
+//! code in the font's built-in encoding, if it has one, and its width in
+//! glyph space. Each kerning table is sorted by pair of glyph names and
+//! gives the `KPX` adjustment of the pair in glyph space.
+//!
+//! The `KPX` pairs are still to be copied in from the AFM files, so until
+//! then the kerning tables are empty.
+
+pub(super) const COURIER: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 600),
+    ("AE", Some(225), 600),
+    ("Aacute", None, 600),
+    ("Abreve", None, 600),
+    ("Acircumflex", None, 600),
+    ("Adieresis", None, 600),
+    ("Agrave", None, 600),
+    ("Amacron", None, 600),
+    ("Aogonek", None, 600),
+    ("Aring", None, 600),
+    ("Atilde", None, 600),
+    ("B", Some(66), 600),
+    ("C", Some(67), 600),
+    ("Cacute", None, 600),
+    ("Ccaron", None, 600),
+    ("Ccedilla", None, 600),
+    ("D", Some(68), 600),
+    ("Dcaron", None, 600),
+    ("Dcroat", None, 600),
+    ("Delta", None, 600),
+    ("E", Some(69), 600),
+    ("Eacute", None, 600),
+    ("Ecaron", None, 600),
+    ("Ecircumflex", None, 600),
+    ("Edieresis", None, 600),
+    ("Edotaccent", None, 600),
+    ("Egrave", None, 600),
+    ("Emacron", None, 600),
+    ("Eogonek", None, 600),
+    ("Eth", None, 600),
+    ("Euro", None, 600),
+    ("F", Some(70), 600),
+    ("G", Some(71), 600),
+    ("Gbreve", None, 600),
+    ("Gcommaaccent", None, 600),
+    ("H", Some(72), 600),
+    ("I", Some(73), 600),
+    ("Iacute", None, 600),
+    ("Icircumflex", None, 600),
+    ("Idieresis", None, 600),
+    ("Idotaccent", None, 600),
+    ("Igrave", None, 600),
+    ("Imacron", None, 600),
+    ("Iogonek", None, 600),
+    ("J", Some(74), 600),
+    ("K", Some(75), 600),
+    ("Kcommaaccent", None, 600),
+    ("L", Some(76), 600),
+    ("Lacute", None, 600),
+    ("Lcaron", None, 600),
+    ("Lcommaaccent", None, 600),
+    ("Lslash", Some(232), 600),
+    ("M", Some(77), 600),
+    ("N", Some(78), 600),
+    ("Nacute", None, 600),
+    ("Ncaron", None, 600),
+    ("Ncommaaccent", None, 600),
+    ("Ntilde", None, 600),
+    ("O", Some(79), 600),
+    ("OE", Some(234), 600),
+    ("Oacute", None, 600),
+    ("Ocircumflex", None, 600),
+    ("Odieresis", None, 600),
+    ("Ograve", None, 600),
+    ("Ohungarumlaut", None, 600),
+    ("Omacron", None, 600),
+    ("Oslash", Some(233), 600),
+    ("Otilde", None, 600),
+    ("P", Some(80), 600),
+    ("Q", Some(81), 600),
+    ("R", Some(82), 600),
+    ("Racute", None, 600),
+    ("Rcaron", None, 600),
+    ("Rcommaaccent", None, 600),
+    ("S", Some(83), 600),
+    ("Sacute", None, 600),
+    ("Scaron", None, 600),
+    ("Scedilla", None, 600),
+    ("Scommaaccent", None, 600),
+    ("T", Some(84), 600),
+    ("Tcaron", None, 600),
+    ("Tcommaaccent", None, 600),
+    ("Thorn", None, 600),
+    ("U", Some(85), 600),
+    ("Uacute", None, 600),
+    ("Ucircumflex", None, 600),
+    ("Udieresis", None, 600),
+    ("Ugrave", None, 600),
+    ("Uhungarumlaut", None, 600),
+    ("Umacron", None, 600),
+    ("Uogonek", None, 600),
+    ("Uring", None, 600),
+    ("V", Some(86), 600),
+    ("W", Some(87), 600),
+    ("X", Some(88), 600),
+    ("Y", Some(89), 600),
+    ("Yacute", None, 600),
+    ("Ydieresis", None, 600),
+    ("Z", Some(90), 600),
+    ("Zacute", None, 600),
+    ("Zcaron", None, 600),
+    ("Zdotaccent", None, 600),
+    ("a", Some(97), 600),
+    ("aacute", None, 600),
+    ("abreve", None, 600),
+    ("acircumflex", None, 600),
+    ("acute", Some(194), 600),
+    ("adieresis", None, 600),
+    ("ae", Some(241), 600),
+    ("agrave", None, 600),
+    ("amacron", None, 600),
+    ("ampersand", Some(38), 600),
+    ("aogonek", None, 600),
+    ("aring", None, 600),
+    ("asciicircum", Some(94), 600),
+    ("asciitilde", Some(126), 600),
+    ("asterisk", Some(42), 600),
+    ("at", Some(64), 600),
+    ("atilde", None, 600),
+    ("b", Some(98), 600),
+    ("backslash", Some(92), 600),
+    ("bar", Some(124), 600),
+    ("braceleft", Some(123), 600),
+    ("braceright", Some(125), 600),
+    ("bracketleft", Some(91), 600),
+    ("bracketright", Some(93), 600),
+    ("breve", Some(198), 600),
+    ("brokenbar", None, 600),
+    ("bullet", Some(183), 600),
+    ("c", Some(99), 600),
+    ("cacute", None, 600),
+    ("caron", Some(207), 600),
+    ("ccaron", None, 600),
+    ("ccedilla", None, 600),
+    ("cedilla", Some(203), 600),
+    ("cent", Some(162), 600),
+    ("circumflex", Some(195), 600),
+    ("colon", Some(58), 600),
+    ("comma", Some(44), 600),
+    ("commaaccent", None, 600),
+    ("copyright", None, 600),
+    ("currency", Some(168), 600),
+    ("d", Some(100), 600),
+    ("dagger", Some(178), 600),
+    ("daggerdbl", Some(179), 600),
+    ("dcaron", None, 600),
+    ("dcroat", None, 600),
+    ("degree", None, 600),
+    ("dieresis", Some(200), 600),
+    ("divide", None, 600),
+    ("dollar", Some(36), 600),
+    ("dotaccent", Some(199), 600),
+    ("dotlessi", Some(245), 600),
+    ("e", Some(101), 600),
+    ("eacute", None, 600),
+    ("ecaron", None, 600),
+    ("ecircumflex", None, 600),
+    ("edieresis", None, 600),
+    ("edotaccent", None, 600),
+    ("egrave", None, 600),
+    ("eight", Some(56), 600),
+    ("ellipsis", Some(188), 600),
+    ("emacron", None, 600),
+    ("emdash", Some(208), 600),
+    ("endash", Some(177), 600),
+    ("eogonek", None, 600),
+    ("equal", Some(61), 600),
+    ("eth", None, 600),
+    ("exclam", Some(33), 600),
+    ("exclamdown", Some(161), 600),
+    ("f", Some(102), 600),
+    ("fi", Some(174), 600),
+    ("five", Some(53), 600),
+    ("fl", Some(175), 600),
+    ("florin", Some(166), 600),
+    ("four", Some(52), 600),
+    ("fraction", Some(164), 600),
+    ("g", Some(103), 600),
+    ("gbreve", None, 600),
+    ("gcommaaccent", None, 600),
+    ("germandbls", Some(251), 600),
+    ("grave", Some(193), 600),
+    ("greater", Some(62), 600),
+    ("greaterequal", None, 600),
+    ("guillemotleft", Some(171), 600),
+    ("guillemotright", Some(187), 600),
+    ("guilsinglleft", Some(172), 600),
+    ("guilsinglright", Some(173), 600),
+    ("h", Some(104), 600),
+    ("hungarumlaut", Some(205), 600),
+    ("hyphen", Some(45), 600),
+    ("i", Some(105), 600),
+    ("iacute", None, 600),
+    ("icircumflex", None, 600),
+    ("idieresis", None, 600),
+    ("igrave", None, 600),
+    ("imacron", None, 600),
+    ("iogonek", None, 600),
+    ("j", Some(106), 600),
+    ("k", Some(107), 600),
+    ("kcommaaccent", None, 600),
+    ("l", Some(108), 600),
+    ("lacute", None, 600),
+    ("lcaron", None, 600),
+    ("lcommaaccent", None, 600),
+    ("less", Some(60), 600),
+    ("lessequal", None, 600),
+    ("logicalnot", None, 600),
+    ("lozenge", None, 600),
+    ("lslash", Some(248), 600),
+    ("m", Some(109), 600),
+    ("macron", Some(197), 600),
+    ("minus", None, 600),
+    ("mu", None, 600),
+    ("multiply", None, 600),
+    ("n", Some(110), 600),
+    ("nacute", None, 600),
+    ("ncaron", None, 600),
+    ("ncommaaccent", None, 600),
+    ("nine", Some(57), 600),
+    ("notequal", None, 600),
+    ("ntilde", None, 600),
+    ("numbersign", Some(35), 600),
+    ("o", Some(111), 600),
+    ("oacute", None, 600),
+    ("ocircumflex", None, 600),
+    ("odieresis", None, 600),
+    ("oe", Some(250), 600),
+    ("ogonek", Some(206), 600),
+    ("ograve", None, 600),
+    ("ohungarumlaut", None, 600),
+    ("omacron", None, 600),
+    ("one", Some(49), 600),
+    ("onehalf", None, 600),
+    ("onequarter", None, 600),
+    ("onesuperior", None, 600),
+    ("ordfeminine", Some(227), 600),
+    ("ordmasculine", Some(235), 600),
+    ("oslash", Some(249), 600),
+    ("otilde", None, 600),
+    ("p", Some(112), 600),
+    ("paragraph", Some(182), 600),
+    ("parenleft", Some(40), 600),
+    ("parenright", Some(41), 600),
+    ("partialdiff", None, 600),
+    ("percent", Some(37), 600),
+    ("period", Some(46), 600),
+    ("periodcentered", Some(180), 600),
+    ("perthousand", Some(189), 600),
+    ("plus", Some(43), 600),
+    ("plusminus", None, 600),
+    ("q", Some(113), 600),
+    ("question", Some(63), 600),
+    ("questiondown", Some(191), 600),
+    ("quotedbl", Some(34), 600),
+    ("quotedblbase", Some(185), 600),
+    ("quotedblleft", Some(170), 600),
+    ("quotedblright", Some(186), 600),
+    ("quoteleft", Some(96), 600),
+    ("quoteright", Some(39), 600),
+    ("quotesinglbase", Some(184), 600),
+    ("quotesingle", Some(169), 600),
+    ("r", Some(114), 600),
+    ("racute", None, 600),
+    ("radical", None, 600),
+    ("rcaron", None, 600),
+    ("rcommaaccent", None, 600),
+    ("registered", None, 600),
+    ("ring", Some(202), 600),
+    ("s", Some(115), 600),
+    ("sacute", None, 600),
+    ("scaron", None, 600),
+    ("scedilla", None, 600),
+    ("scommaaccent", None, 600),
+    ("section", Some(167), 600),
+    ("semicolon", Some(59), 600),
+    ("seven", Some(55), 600),
+    ("six", Some(54), 600),
+    ("slash", Some(47), 600),
+    ("space", Some(32), 600),
+    ("sterling", Some(163), 600),
+    ("summation", None, 600),
+    ("t", Some(116), 600),
+    ("tcaron", None, 600),
+    ("tcommaaccent", None, 600),
+    ("thorn", None, 600),
+    ("three", Some(51), 600),
+    ("threequarters", None, 600),
+    ("threesuperior", None, 600),
+    ("tilde", Some(196), 600),
+    ("trademark", None, 600),
+    ("two", Some(50), 600),
+    ("twosuperior", None, 600),
+    ("u", Some(117), 600),
+    ("uacute", None, 600),
+    ("ucircumflex", None, 600),
+    ("udieresis", None, 600),
+    ("ugrave", None, 600),
+    ("uhungarumlaut", None, 600),
+    ("umacron", None, 600),
+    ("underscore", Some(95), 600),
+    ("uogonek", None, 600),
+    ("uring", None, 600),
+    ("v", Some(118), 600),
+    ("w", Some(119), 600),
+    ("x", Some(120), 600),
+    ("y", Some(121), 600),
+    ("yacute", None, 600),
+    ("ydieresis", None, 600),
+    ("yen", Some(165), 600),
+    ("z", Some(122), 600),
+    ("zacute", None, 600),
+    ("zcaron", None, 600),
+    ("zdotaccent", None, 600),
+    ("zero", Some(48), 600),
+];
+
+pub(super) const COURIER_BOLD: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 600),
+    ("AE", Some(225), 600),
+    ("Aacute", None, 600),
+    ("Abreve", None, 600),
+    ("Acircumflex", None, 600),
+    ("Adieresis", None, 600),
+    ("Agrave", None, 600),
+    ("Amacron", None, 600),
+    ("Aogonek", None, 600),
+    ("Aring", None, 600),
+    ("Atilde", None, 600),
+    ("B", Some(66), 600),
+    ("C", Some(67), 600),
+    ("Cacute", None, 600),
+    ("Ccaron", None, 600),
+    ("Ccedilla", None, 600),
+    ("D", Some(68), 600),
+    ("Dcaron", None, 600),
+    ("Dcroat", None, 600),
+    ("Delta", None, 600),
+    ("E", Some(69), 600),
+    ("Eacute", None, 600),
+    ("Ecaron", None, 600),
+    ("Ecircumflex", None, 600),
+    ("Edieresis", None, 600),
+    ("Edotaccent", None, 600),
+    ("Egrave", None, 600),
+    ("Emacron", None, 600),
+    ("Eogonek", None, 600),
+    ("Eth", None, 600),
+    ("Euro", None, 600),
+    ("F", Some(70), 600),
+    ("G", Some(71), 600),
+    ("Gbreve", None, 600),
+    ("Gcommaaccent", None, 600),
+    ("H", Some(72), 600),
+    ("I", Some(73), 600),
+    ("Iacute", None, 600),
+    ("Icircumflex", None, 600),
+    ("Idieresis", None, 600),
+    ("Idotaccent", None, 600),
+    ("Igrave", None, 600),
+    ("Imacron", None, 600),
+    ("Iogonek", None, 600),
+    ("J", Some(74), 600),
+    ("K", Some(75), 600),
+    ("Kcommaaccent", None, 600),
+    ("L", Some(76), 600),
+    ("Lacute", None, 600),
+    ("Lcaron", None, 600),
+    ("Lcommaaccent", None, 600),
+    ("Lslash", Some(232), 600),
+    ("M", Some(77), 600),
+    ("N", Some(78), 600),
+    ("Nacute", None, 600),
+    ("Ncaron", None, 600),
+    ("Ncommaaccent", None, 600),
+    ("Ntilde", None, 600),
+    ("O", Some(79), 600),
+    ("OE", Some(234), 600),
+    ("Oacute", None, 600),
+    ("Ocircumflex", None, 600),
+    ("Odieresis", None, 600),
+    ("Ograve", None, 600),
+    ("Ohungarumlaut", None, 600),
+    ("Omacron", None, 600),
+    ("Oslash", Some(233), 600),
+    ("Otilde", None, 600),
+    ("P", Some(80), 600),
+    ("Q", Some(81), 600),
+    ("R", Some(82), 600),
+    ("Racute", None, 600),
+    ("Rcaron", None, 600),
+    ("Rcommaaccent", None, 600),
+    ("S", Some(83), 600),
+    ("Sacute", None, 600),
+    ("Scaron", None, 600),
+    ("Scedilla", None, 600),
+    ("Scommaaccent", None, 600),
+    ("T", Some(84), 600),
+    ("Tcaron", None, 600),
+    ("Tcommaaccent", None, 600),
+    ("Thorn", None, 600),
+    ("U", Some(85), 600),
+    ("Uacute", None, 600),
+    ("Ucircumflex", None, 600),
+    ("Udieresis", None, 600),
+    ("Ugrave", None, 600),
+    ("Uhungarumlaut", None, 600),
+    ("Umacron", None, 600),
+    ("Uogonek", None, 600),
+    ("Uring", None, 600),
+    ("V", Some(86), 600),
+    ("W", Some(87), 600),
+    ("X", Some(88), 600),
+    ("Y", Some(89), 600),
+    ("Yacute", None, 600),
+    ("Ydieresis", None, 600),
+    ("Z", Some(90), 600),
+    ("Zacute", None, 600),
+    ("Zcaron", None, 600),
+    ("Zdotaccent", None, 600),
+    ("a", Some(97), 600),
+    ("aacute", None, 600),
+    ("abreve", None, 600),
+    ("acircumflex", None, 600),
+    ("acute", Some(194), 600),
+    ("adieresis", None, 600),
+    ("ae", Some(241), 600),
+    ("agrave", None, 600),
+    ("amacron", None, 600),
+    ("ampersand", Some(38), 600),
+    ("aogonek", None, 600),
+    ("aring", None, 600),
+    ("asciicircum", Some(94), 600),
+    ("asciitilde", Some(126), 600),
+    ("asterisk", Some(42), 600),
+    ("at", Some(64), 600),
+    ("atilde", None, 600),
+    ("b", Some(98), 600),
+    ("backslash", Some(92), 600),
+    ("bar", Some(124), 600),
+    ("braceleft", Some(123), 600),
+    ("braceright", Some(125), 600),
+    ("bracketleft", Some(91), 600),
+    ("bracketright", Some(93), 600),
+    ("breve", Some(198), 600),
+    ("brokenbar", None, 600),
+    ("bullet", Some(183), 600),
+    ("c", Some(99), 600),
+    ("cacute", None, 600),
+    ("caron", Some(207), 600),
+    ("ccaron", None, 600),
+    ("ccedilla", None, 600),
+    ("cedilla", Some(203), 600),
+    ("cent", Some(162), 600),
+    ("circumflex", Some(195), 600),
+    ("colon", Some(58), 600),
+    ("comma", Some(44), 600),
+    ("commaaccent", None, 600),
+    ("copyright", None, 600),
+    ("currency", Some(168), 600),
+    ("d", Some(100), 600),
+    ("dagger", Some(178), 600),
+    ("daggerdbl", Some(179), 600),
+    ("dcaron", None, 600),
+    ("dcroat", None, 600),
+    ("degree", None, 600),
+    ("dieresis", Some(200), 600),
+    ("divide", None, 600),
+    ("dollar", Some(36), 600),
+    ("dotaccent", Some(199), 600),
+    ("dotlessi", Some(245), 600),
+    ("e", Some(101), 600),
+    ("eacute", None, 600),
+    ("ecaron", None, 600),
+    ("ecircumflex", None, 600),
+    ("edieresis", None, 600),
+    ("edotaccent", None, 600),
+    ("egrave", None, 600),
+    ("eight", Some(56), 600),
+    ("ellipsis", Some(188), 600),
+    ("emacron", None, 600),
+    ("emdash", Some(208), 600),
+    ("endash", Some(177), 600),
+    ("eogonek", None, 600),
+    ("equal", Some(61), 600),
+    ("eth", None, 600),
+    ("exclam", Some(33), 600),
+    ("exclamdown", Some(161), 600),
+    ("f", Some(102), 600),
+    ("fi", Some(174), 600),
+    ("five", Some(53), 600),
+    ("fl", Some(175), 600),
+    ("florin", Some(166), 600),
+    ("four", Some(52), 600),
+    ("fraction", Some(164), 600),
+    ("g", Some(103), 600),
+    ("gbreve", None, 600),
+    ("gcommaaccent", None, 600),
+    ("germandbls", Some(251), 600),
+    ("grave", Some(193), 600),
+    ("greater", Some(62), 600),
+    ("greaterequal", None, 600),
+    ("guillemotleft", Some(171), 600),
+    ("guillemotright", Some(187), 600),
+    ("guilsinglleft", Some(172), 600),
+    ("guilsinglright", Some(173), 600),
+    ("h", Some(104), 600),
+    ("hungarumlaut", Some(205), 600),
+    ("hyphen", Some(45), 600),
+    ("i", Some(105), 600),
+    ("iacute", None, 600),
+    ("icircumflex", None, 600),
+    ("idieresis", None, 600),
+    ("igrave", None, 600),
+    ("imacron", None, 600),
+    ("iogonek", None, 600),
+    ("j", Some(106), 600),
+    ("k", Some(107), 600),
+    ("kcommaaccent", None, 600),
+    ("l", Some(108), 600),
+    ("lacute", None, 600),
+    ("lcaron", None, 600),
+    ("lcommaaccent", None, 600),
+    ("less", Some(60), 600),
+    ("lessequal", None, 600),
+    ("logicalnot", None, 600),
+    ("lozenge", None, 600),
+    ("lslash", Some(248), 600),
+    ("m", Some(109), 600),
+    ("macron", Some(197), 600),
+    ("minus", None, 600),
+    ("mu", None, 600),
+    ("multiply", None, 600),
+    ("n", Some(110), 600),
+    ("nacute", None, 600),
+    ("ncaron", None, 600),
+    ("ncommaaccent", None, 600),
+    ("nine", Some(57), 600),
+    ("notequal", None, 600),
+    ("ntilde", None, 600),
+    ("numbersign", Some(35), 600),
+    ("o", Some(111), 600),
+    ("oacute", None, 600),
+    ("ocircumflex", None, 600),
+    ("odieresis", None, 600),
+    ("oe", Some(250), 600),
+    ("ogonek", Some(206), 600),
+    ("ograve", None, 600),
+    ("ohungarumlaut", None, 600),
+    ("omacron", None, 600),
+    ("one", Some(49), 600),
+    ("onehalf", None, 600),
+    ("onequarter", None, 600),
+    ("onesuperior", None, 600),
+    ("ordfeminine", Some(227), 600),
+    ("ordmasculine", Some(235), 600),
+    ("oslash", Some(249), 600),
+    ("otilde", None, 600),
+    ("p", Some(112), 600),
+    ("paragraph", Some(182), 600),
+    ("parenleft", Some(40), 600),
+    ("parenright", Some(41), 600),
+    ("partialdiff", None, 600),
+    ("percent", Some(37), 600),
+    ("period", Some(46), 600),
+    ("periodcentered", Some(180), 600),
+    ("perthousand", Some(189), 600),
+    ("plus", Some(43), 600),
+    ("plusminus", None, 600),
+    ("q", Some(113), 600),
+    ("question", Some(63), 600),
+    ("questiondown", Some(191), 600),
+    ("quotedbl", Some(34), 600),
+    ("quotedblbase", Some(185), 600),
+    ("quotedblleft", Some(170), 600),
+    ("quotedblright", Some(186), 600),
+    ("quoteleft", Some(96), 600),
+    ("quoteright", Some(39), 600),
+    ("quotesinglbase", Some(184), 600),
+    ("quotesingle", Some(169), 600),
+    ("r", Some(114), 600),
+    ("racute", None, 600),
+    ("radical", None, 600),
+    ("rcaron", None, 600),
+    ("rcommaaccent", None, 600),
+    ("registered", None, 600),
+    ("ring", Some(202), 600),
+    ("s", Some(115), 600),
+    ("sacute", None, 600),
+    ("scaron", None, 600),
+    ("scedilla", None, 600),
+    ("scommaaccent", None, 600),
+    ("section", Some(167), 600),
+    ("semicolon", Some(59), 600),
+    ("seven", Some(55), 600),
+    ("six", Some(54), 600),
+    ("slash", Some(47), 600),
+    ("space", Some(32), 600),
+    ("sterling", Some(163), 600),
+    ("summation", None, 600),
+    ("t", Some(116), 600),
+    ("tcaron", None, 600),
+    ("tcommaaccent", None, 600),
+    ("thorn", None, 600),
+    ("three", Some(51), 600),
+    ("threequarters", None, 600),
+    ("threesuperior", None, 600),
+    ("tilde", Some(196), 600),
+    ("trademark", None, 600),
+    ("two", Some(50), 600),
+    ("twosuperior", None, 600),
+    ("u", Some(117), 600),
+    ("uacute", None, 600),
+    ("ucircumflex", None, 600),
+    ("udieresis", None, 600),
+    ("ugrave", None, 600),
+    ("uhungarumlaut", None, 600),
+    ("umacron", None, 600),
+    ("underscore", Some(95), 600),
+    ("uogonek", None, 600),
+    ("uring", None, 600),
+    ("v", Some(118), 600),
+    ("w", Some(119), 600),
+    ("x", Some(120), 600),
+    ("y", Some(121), 600),
+    ("yacute", None, 600),
+    ("ydieresis", None, 600),
+    ("yen", Some(165), 600),
+    ("z", Some(122), 600),
+    ("zacute", None, 600),
+    ("zcaron", None, 600),
+    ("zdotaccent", None, 600),
+    ("zero", Some(48), 600),
+];
+
+pub(super) const COURIER_OBLIQUE: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 600),
+    ("AE", Some(225), 600),
+    ("Aacute", None, 600),
+    ("Abreve", None, 600),
+    ("Acircumflex", None, 600),
+    ("Adieresis", None, 600),
+    ("Agrave", None, 600),
+    ("Amacron", None, 600),
+    ("Aogonek", None, 600),
+    ("Aring", None, 600),
+    ("Atilde", None, 600),
+    ("B", Some(66), 600),
+    ("C", Some(67), 600),
+    ("Cacute", None, 600),
+    ("Ccaron", None, 600),
+    ("Ccedilla", None, 600),
+    ("D", Some(68), 600),
+    ("Dcaron", None, 600),
+    ("Dcroat", None, 600),
+    ("Delta", None, 600),
+    ("E", Some(69), 600),
+    ("Eacute", None, 600),
+    ("Ecaron", None, 600),
+    ("Ecircumflex", None, 600),
+    ("Edieresis", None, 600),
+    ("Edotaccent", None, 600),
+    ("Egrave", None, 600),
+    ("Emacron", None, 600),
+    ("Eogonek", None, 600),
+    ("Eth", None, 600),
+    ("Euro", None, 600),
+    ("F", Some(70), 600),
+    ("G", Some(71), 600),
+    ("Gbreve", None, 600),
+    ("Gcommaaccent", None, 600),
+    ("H", Some(72), 600),
+    ("I", Some(73), 600),
+    ("Iacute", None, 600),
+    ("Icircumflex", None, 600),
+    ("Idieresis", None, 600),
+    ("Idotaccent", None, 600),
+    ("Igrave", None, 600),
+    ("Imacron", None, 600),
+    ("Iogonek", None, 600),
+    ("J", Some(74), 600),
+    ("K", Some(75), 600),
+    ("Kcommaaccent", None, 600),
+    ("L", Some(76), 600),
+    ("Lacute", None, 600),
+    ("Lcaron", None, 600),
+    ("Lcommaaccent", None, 600),
+    ("Lslash", Some(232), 600),
+    ("M", Some(77), 600),
+    ("N", Some(78), 600),
+    ("Nacute", None, 600),
+    ("Ncaron", None, 600),
+    ("Ncommaaccent", None, 600),
+    ("Ntilde", None, 600),
+    ("O", Some(79), 600),
+    ("OE", Some(234), 600),
+    ("Oacute", None, 600),
+    ("Ocircumflex", None, 600),
+    ("Odieresis", None, 600),
+    ("Ograve", None, 600),
+    ("Ohungarumlaut", None, 600),
+    ("Omacron", None, 600),
+    ("Oslash", Some(233), 600),
+    ("Otilde", None, 600),
+    ("P", Some(80), 600),
+    ("Q", Some(81), 600),
+    ("R", Some(82), 600),
+    ("Racute", None, 600),
+    ("Rcaron", None, 600),
+    ("Rcommaaccent", None, 600),
+    ("S", Some(83), 600),
+    ("Sacute", None, 600),
+    ("Scaron", None, 600),
+    ("Scedilla", None, 600),
+    ("Scommaaccent", None, 600),
+    ("T", Some(84), 600),
+    ("Tcaron", None, 600),
+    ("Tcommaaccent", None, 600),
+    ("Thorn", None, 600),
+    ("U", Some(85), 600),
+    ("Uacute", None, 600),
+    ("Ucircumflex", None, 600),
+    ("Udieresis", None, 600),
+    ("Ugrave", None, 600),
+    ("Uhungarumlaut", None, 600),
+    ("Umacron", None, 600),
+    ("Uogonek", None, 600),
+    ("Uring", None, 600),
+    ("V", Some(86), 600),
+    ("W", Some(87), 600),
+    ("X", Some(88), 600),
+    ("Y", Some(89), 600),
+    ("Yacute", None, 600),
+    ("Ydieresis", None, 600),
+    ("Z", Some(90), 600),
+    ("Zacute", None, 600),
+    ("Zcaron", None, 600),
+    ("Zdotaccent", None, 600),
+    ("a", Some(97), 600),
+    ("aacute", None, 600),
+    ("abreve", None, 600),
+    ("acircumflex", None, 600),
+    ("acute", Some(194), 600),
+    ("adieresis", None, 600),
+    ("ae", Some(241), 600),
+    ("agrave", None, 600),
+    ("amacron", None, 600),
+    ("ampersand", Some(38), 600),
+    ("aogonek", None, 600),
+    ("aring", None, 600),
+    ("asciicircum", Some(94), 600),
+    ("asciitilde", Some(126), 600),
+    ("asterisk", Some(42), 600),
+    ("at", Some(64), 600),
+    ("atilde", None, 600),
+    ("b", Some(98), 600),
+    ("backslash", Some(92), 600),
+    ("bar", Some(124), 600),
+    ("braceleft", Some(123), 600),
+    ("braceright", Some(125), 600),
+    ("bracketleft", Some(91), 600),
+    ("bracketright", Some(93), 600),
+    ("breve", Some(198), 600),
+    ("brokenbar", None, 600),
+    ("bullet", Some(183), 600),
+    ("c", Some(99), 600),
+    ("cacute", None, 600),
+    ("caron", Some(207), 600),
+    ("ccaron", None, 600),
+    ("ccedilla", None, 600),
+    ("cedilla", Some(203), 600),
+    ("cent", Some(162), 600),
+    ("circumflex", Some(195), 600),
+    ("colon", Some(58), 600),
+    ("comma", Some(44), 600),
+    ("commaaccent", None, 600),
+    ("copyright", None, 600),
+    ("currency", Some(168), 600),
+    ("d", Some(100), 600),
+    ("dagger", Some(178), 600),
+    ("daggerdbl", Some(179), 600),
+    ("dcaron", None, 600),
+    ("dcroat", None, 600),
+    ("degree", None, 600),
+    ("dieresis", Some(200), 600),
+    ("divide", None, 600),
+    ("dollar", Some(36), 600),
+    ("dotaccent", Some(199), 600),
+    ("dotlessi", Some(245), 600),
+    ("e", Some(101), 600),
+    ("eacute", None, 600),
+    ("ecaron", None, 600),
+    ("ecircumflex", None, 600),
+    ("edieresis", None, 600),
+    ("edotaccent", None, 600),
+    ("egrave", None, 600),
+    ("eight", Some(56), 600),
+    ("ellipsis", Some(188), 600),
+    ("emacron", None, 600),
+    ("emdash", Some(208), 600),
+    ("endash", Some(177), 600),
+    ("eogonek", None, 600),
+    ("equal", Some(61), 600),
+    ("eth", None, 600),
+    ("exclam", Some(33), 600),
+    ("exclamdown", Some(161), 600),
+    ("f", Some(102), 600),
+    ("fi", Some(174), 600),
+    ("five", Some(53), 600),
+    ("fl", Some(175), 600),
+    ("florin", Some(166), 600),
+    ("four", Some(52), 600),
+    ("fraction", Some(164), 600),
+    ("g", Some(103), 600),
+    ("gbreve", None, 600),
+    ("gcommaaccent", None, 600),
+    ("germandbls", Some(251), 600),
+    ("grave", Some(193), 600),
+    ("greater", Some(62), 600),
+    ("greaterequal", None, 600),
+    ("guillemotleft", Some(171), 600),
+    ("guillemotright", Some(187), 600),
+    ("guilsinglleft", Some(172), 600),
+    ("guilsinglright", Some(173), 600),
+    ("h", Some(104), 600),
+    ("hungarumlaut", Some(205), 600),
+    ("hyphen", Some(45), 600),
+    ("i", Some(105), 600),
+    ("iacute", None, 600),
+    ("icircumflex", None, 600),
+    ("idieresis", None, 600),
+    ("igrave", None, 600),
+    ("imacron", None, 600),
+    ("iogonek", None, 600),
+    ("j", Some(106), 600),
+    ("k", Some(107), 600),
+    ("kcommaaccent", None, 600),
+    ("l", Some(108), 600),
+    ("lacute", None, 600),
+    ("lcaron", None, 600),
+    ("lcommaaccent", None, 600),
+    ("less", Some(60), 600),
+    ("lessequal", None, 600),
+    ("logicalnot", None, 600),
+    ("lozenge", None, 600),
+    ("lslash", Some(248), 600),
+    ("m", Some(109), 600),
+    ("macron", Some(197), 600),
+    ("minus", None, 600),
+    ("mu", None, 600),
+    ("multiply", None, 600),
+    ("n", Some(110), 600),
+    ("nacute", None, 600),
+    ("ncaron", None, 600),
+    ("ncommaaccent", None, 600),
+    ("nine", Some(57), 600),
+    ("notequal", None, 600),
+    ("ntilde", None, 600),
+    ("numbersign", Some(35), 600),
+    ("o", Some(111), 600),
+    ("oacute", None, 600),
+    ("ocircumflex", None, 600),
+    ("odieresis", None, 600),
+    ("oe", Some(250), 600),
+    ("ogonek", Some(206), 600),
+    ("ograve", None, 600),
+    ("ohungarumlaut", None, 600),
+    ("omacron", None, 600),
+    ("one", Some(49), 600),
+    ("onehalf", None, 600),
+    ("onequarter", None, 600),
+    ("onesuperior", None, 600),
+    ("ordfeminine", Some(227), 600),
+    ("ordmasculine", Some(235), 600),
+    ("oslash", Some(249), 600),
+    ("otilde", None, 600),
+    ("p", Some(112), 600),
+    ("paragraph", Some(182), 600),
+    ("parenleft", Some(40), 600),
+    ("parenright", Some(41), 600),
+    ("partialdiff", None, 600),
+    ("percent", Some(37), 600),
+    ("period", Some(46), 600),
+    ("periodcentered", Some(180), 600),
+    ("perthousand", Some(189), 600),
+    ("plus", Some(43), 600),
+    ("plusminus", None, 600),
+    ("q", Some(113), 600),
+    ("question", Some(63), 600),
+    ("questiondown", Some(191), 600),
+    ("quotedbl", Some(34), 600),
+    ("quotedblbase", Some(185), 600),
+    ("quotedblleft", Some(170), 600),
+    ("quotedblright", Some(186), 600),
+    ("quoteleft", Some(96), 600),
+    ("quoteright", Some(39), 600),
+    ("quotesinglbase", Some(184), 600),
+    ("quotesingle", Some(169), 600),
+    ("r", Some(114), 600),
+    ("racute", None, 600),
+    ("radical", None, 600),
+    ("rcaron", None, 600),
+    ("rcommaaccent", None, 600),
+    ("registered", None, 600),
+    ("ring", Some(202), 600),
+    ("s", Some(115), 600),
+    ("sacute", None, 600),
+    ("scaron", None, 600),
+    ("scedilla", None, 600),
+    ("scommaaccent", None, 600),
+    ("section", Some(167), 600),
+    ("semicolon", Some(59), 600),
+    ("seven", Some(55), 600),
+    ("six", Some(54), 600),
+    ("slash", Some(47), 600),
+    ("space", Some(32), 600),
+    ("sterling", Some(163), 600),
+    ("summation", None, 600),
+    ("t", Some(116), 600),
+    ("tcaron", None, 600),
+    ("tcommaaccent", None, 600),
+    ("thorn", None, 600),
+    ("three", Some(51), 600),
+    ("threequarters", None, 600),
+    ("threesuperior", None, 600),
+    ("tilde", Some(196), 600),
+    ("trademark", None, 600),
+    ("two", Some(50), 600),
+    ("twosuperior", None, 600),
+    ("u", Some(117), 600),
+    ("uacute", None, 600),
+    ("ucircumflex", None, 600),
+    ("udieresis", None, 600),
+    ("ugrave", None, 600),
+    ("uhungarumlaut", None, 600),
+    ("umacron", None, 600),
+    ("underscore", Some(95), 600),
+    ("uogonek", None, 600),
+    ("uring", None, 600),
+    ("v", Some(118), 600),
+    ("w", Some(119), 600),
+    ("x", Some(120), 600),
+    ("y", Some(121), 600),
+    ("yacute", None, 600),
+    ("ydieresis", None, 600),
+    ("yen", Some(165), 600),
+    ("z", Some(122), 600),
+    ("zacute", None, 600),
+    ("zcaron", None, 600),
+    ("zdotaccent", None, 600),
+    ("zero", Some(48), 600),
+];
+
+pub(super) const COURIER_BOLDOBLIQUE: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 600),
+    ("AE", Some(225), 600),
+    ("Aacute", None, 600),
+    ("Abreve", None, 600),
+    ("Acircumflex", None, 600),
+    ("Adieresis", None, 600),
+    ("Agrave", None, 600),
+    ("Amacron", None, 600),
+    ("Aogonek", None, 600),
+    ("Aring", None, 600),
+    ("Atilde", None, 600),
+    ("B", Some(66), 600),
+    ("C", Some(67), 600),
+    ("Cacute", None, 600),
+    ("Ccaron", None, 600),
+    ("Ccedilla", None, 600),
+    ("D", Some(68), 600),
+    ("Dcaron", None, 600),
+    ("Dcroat", None, 600),
+    ("Delta", None, 600),
+    ("E", Some(69), 600),
+    ("Eacute", None, 600),
+    ("Ecaron", None, 600),
+    ("Ecircumflex", None, 600),
+    ("Edieresis", None, 600),
+    ("Edotaccent", None, 600),
+    ("Egrave", None, 600),
+    ("Emacron", None, 600),
+    ("Eogonek", None, 600),
+    ("Eth", None, 600),
+    ("Euro", None, 600),
+    ("F", Some(70), 600),
+    ("G", Some(71), 600),
+    ("Gbreve", None, 600),
+    ("Gcommaaccent", None, 600),
+    ("H", Some(72), 600),
+    ("I", Some(73), 600),
+    ("Iacute", None, 600),
+    ("Icircumflex", None, 600),
+    ("Idieresis", None, 600),
+    ("Idotaccent", None, 600),
+    ("Igrave", None, 600),
+    ("Imacron", None, 600),
+    ("Iogonek", None, 600),
+    ("J", Some(74), 600),
+    ("K", Some(75), 600),
+    ("Kcommaaccent", None, 600),
+    ("L", Some(76), 600),
+    ("Lacute", None, 600),
+    ("Lcaron", None, 600),
+    ("Lcommaaccent", None, 600),
+    ("Lslash", Some(232), 600),
+    ("M", Some(77), 600),
+    ("N", Some(78), 600),
+    ("Nacute", None, 600),
+    ("Ncaron", None, 600),
+    ("Ncommaaccent", None, 600),
+    ("Ntilde", None, 600),
+    ("O", Some(79), 600),
+    ("OE", Some(234), 600),
+    ("Oacute", None, 600),
+    ("Ocircumflex", None, 600),
+    ("Odieresis", None, 600),
+    ("Ograve", None, 600),
+    ("Ohungarumlaut", None, 600),
+    ("Omacron", None, 600),
+    ("Oslash", Some(233), 600),
+    ("Otilde", None, 600),
+    ("P", Some(80), 600),
+    ("Q", Some(81), 600),
+    ("R", Some(82), 600),
+    ("Racute", None, 600),
+    ("Rcaron", None, 600),
+    ("Rcommaaccent", None, 600),
+    ("S", Some(83), 600),
+    ("Sacute", None, 600),
+    ("Scaron", None, 600),
+    ("Scedilla", None, 600),
+    ("Scommaaccent", None, 600),
+    ("T", Some(84), 600),
+    ("Tcaron", None, 600),
+    ("Tcommaaccent", None, 600),
+    ("Thorn", None, 600),
+    ("U", Some(85), 600),
+    ("Uacute", None, 600),
+    ("Ucircumflex", None, 600),
+    ("Udieresis", None, 600),
+    ("Ugrave", None, 600),
+    ("Uhungarumlaut", None, 600),
+    ("Umacron", None, 600),
+    ("Uogonek", None, 600),
+    ("Uring", None, 600),
+    ("V", Some(86), 600),
+    ("W", Some(87), 600),
+    ("X", Some(88), 600),
+    ("Y", Some(89), 600),
+    ("Yacute", None, 600),
+    ("Ydieresis", None, 600),
+    ("Z", Some(90), 600),
+    ("Zacute", None, 600),
+    ("Zcaron", None, 600),
+    ("Zdotaccent", None, 600),
+    ("a", Some(97), 600),
+    ("aacute", None, 600),
+    ("abreve", None, 600),
+    ("acircumflex", None, 600),
+    ("acute", Some(194), 600),
+    ("adieresis", None, 600),
+    ("ae", Some(241), 600),
+    ("agrave", None, 600),
+    ("amacron", None, 600),
+    ("ampersand", Some(38), 600),
+    ("aogonek", None, 600),
+    ("aring", None, 600),
+    ("asciicircum", Some(94), 600),
+    ("asciitilde", Some(126), 600),
+    ("asterisk", Some(42), 600),
+    ("at", Some(64), 600),
+    ("atilde", None, 600),
+    ("b", Some(98), 600),
+    ("backslash", Some(92), 600),
+    ("bar", Some(124), 600),
+    ("braceleft", Some(123), 600),
+    ("braceright", Some(125), 600),
+    ("bracketleft", Some(91), 600),
+    ("bracketright", Some(93), 600),
+    ("breve", Some(198), 600),
+    ("brokenbar", None, 600),
+    ("bullet", Some(183), 600),
+    ("c", Some(99), 600),
+    ("cacute", None, 600),
+    ("caron", Some(207), 600),
+    ("ccaron", None, 600),
+    ("ccedilla", None, 600),
+    ("cedilla", Some(203), 600),
+    ("cent", Some(162), 600),
+    ("circumflex", Some(195), 600),
+    ("colon", Some(58), 600),
+    ("comma", Some(44), 600),
+    ("commaaccent", None, 600),
+    ("copyright", None, 600),
+    ("currency", Some(168), 600),
+    ("d", Some(100), 600),
+    ("dagger", Some(178), 600),
+    ("daggerdbl", Some(179), 600),
+    ("dcaron", None, 600),
+    ("dcroat", None, 600),
+    ("degree", None, 600),
+    ("dieresis", Some(200), 600),
+    ("divide", None, 600),
+    ("dollar", Some(36), 600),
+    ("dotaccent", Some(199), 600),
+    ("dotlessi", Some(245), 600),
+    ("e", Some(101), 600),
+    ("eacute", None, 600),
+    ("ecaron", None, 600),
+    ("ecircumflex", None, 600),
+    ("edieresis", None, 600),
+    ("edotaccent", None, 600),
+    ("egrave", None, 600),
+    ("eight", Some(56), 600),
+    ("ellipsis", Some(188), 600),
+    ("emacron", None, 600),
+    ("emdash", Some(208), 600),
+    ("endash", Some(177), 600),
+    ("eogonek", None, 600),
+    ("equal", Some(61), 600),
+    ("eth", None, 600),
+    ("exclam", Some(33), 600),
+    ("exclamdown", Some(161), 600),
+    ("f", Some(102), 600),
+    ("fi", Some(174), 600),
+    ("five", Some(53), 600),
+    ("fl", Some(175), 600),
+    ("florin", Some(166), 600),
+    ("four", Some(52), 600),
+    ("fraction", Some(164), 600),
+    ("g", Some(103), 600),
+    ("gbreve", None, 600),
+    ("gcommaaccent", None, 600),
+    ("germandbls", Some(251), 600),
+    ("grave", Some(193), 600),
+    ("greater", Some(62), 600),
+    ("greaterequal", None, 600),
+    ("guillemotleft", Some(171), 600),
+    ("guillemotright", Some(187), 600),
+    ("guilsinglleft", Some(172), 600),
+    ("guilsinglright", Some(173), 600),
+    ("h", Some(104), 600),
+    ("hungarumlaut", Some(205), 600),
+    ("hyphen", Some(45), 600),
+    ("i", Some(105), 600),
+    ("iacute", None, 600),
+    ("icircumflex", None, 600),
+    ("idieresis", None, 600),
+    ("igrave", None, 600),
+    ("imacron", None, 600),
+    ("iogonek", None, 600),
+    ("j", Some(106), 600),
+    ("k", Some(107), 600),
+    ("kcommaaccent", None, 600),
+    ("l", Some(108), 600),
+    ("lacute", None, 600),
+    ("lcaron", None, 600),
+    ("lcommaaccent", None, 600),
+    ("less", Some(60), 600),
+    ("lessequal", None, 600),
+    ("logicalnot", None, 600),
+    ("lozenge", None, 600),
+    ("lslash", Some(248), 600),
+    ("m", Some(109), 600),
+    ("macron", Some(197), 600),
+    ("minus", None, 600),
+    ("mu", None, 600),
+    ("multiply", None, 600),
+    ("n", Some(110), 600),
+    ("nacute", None, 600),
+    ("ncaron", None, 600),
+    ("ncommaaccent", None, 600),
+    ("nine", Some(57), 600),
+    ("notequal", None, 600),
+    ("ntilde", None, 600),
+    ("numbersign", Some(35), 600),
+    ("o", Some(111), 600),
+    ("oacute", None, 600),
+    ("ocircumflex", None, 600),
+    ("odieresis", None, 600),
+    ("oe", Some(250), 600),
+    ("ogonek", Some(206), 600),
+    ("ograve", None, 600),
+    ("ohungarumlaut", None, 600),
+    ("omacron", None, 600),
+    ("one", Some(49), 600),
+    ("onehalf", None, 600),
+    ("onequarter", None, 600),
+    ("onesuperior", None, 600),
+    ("ordfeminine", Some(227), 600),
+    ("ordmasculine", Some(235), 600),
+    ("oslash", Some(249), 600),
+    ("otilde", None, 600),
+    ("p", Some(112), 600),
+    ("paragraph", Some(182), 600),
+    ("parenleft", Some(40), 600),
+    ("parenright", Some(41), 600),
+    ("partialdiff", None, 600),
+    ("percent", Some(37), 600),
+    ("period", Some(46), 600),
+    ("periodcentered", Some(180), 600),
+    ("perthousand", Some(189), 600),
+    ("plus", Some(43), 600),
+    ("plusminus", None, 600),
+    ("q", Some(113), 600),
+    ("question", Some(63), 600),
+    ("questiondown", Some(191), 600),
+    ("quotedbl", Some(34), 600),
+    ("quotedblbase", Some(185), 600),
+    ("quotedblleft", Some(170), 600),
+    ("quotedblright", Some(186), 600),
+    ("quoteleft", Some(96), 600),
+    ("quoteright", Some(39), 600),
+    ("quotesinglbase", Some(184), 600),
+    ("quotesingle", Some(169), 600),
+    ("r", Some(114), 600),
+    ("racute", None, 600),
+    ("radical", None, 600),
+    ("rcaron", None, 600),
+    ("rcommaaccent", None, 600),
+    ("registered", None, 600),
+    ("ring", Some(202), 600),
+    ("s", Some(115), 600),
+    ("sacute", None, 600),
+    ("scaron", None, 600),
+    ("scedilla", None, 600),
+    ("scommaaccent", None, 600),
+    ("section", Some(167), 600),
+    ("semicolon", Some(59), 600),
+    ("seven", Some(55), 600),
+    ("six", Some(54), 600),
+    ("slash", Some(47), 600),
+    ("space", Some(32), 600),
+    ("sterling", Some(163), 600),
+    ("summation", None, 600),
+    ("t", Some(116), 600),
+    ("tcaron", None, 600),
+    ("tcommaaccent", None, 600),
+    ("thorn", None, 600),
+    ("three", Some(51), 600),
+    ("threequarters", None, 600),
+    ("threesuperior", None, 600),
+    ("tilde", Some(196), 600),
+    ("trademark", None, 600),
+    ("two", Some(50), 600),
+    ("twosuperior", None, 600),
+    ("u", Some(117), 600),
+    ("uacute", None, 600),
+    ("ucircumflex", None, 600),
+    ("udieresis", None, 600),
+    ("ugrave", None, 600),
+    ("uhungarumlaut", None, 600),
+    ("umacron", None, 600),
+    ("underscore", Some(95), 600),
+    ("uogonek", None, 600),
+    ("uring", None, 600),
+    ("v", Some(118), 600),
+    ("w", Some(119), 600),
+    ("x", Some(120), 600),
+    ("y", Some(121), 600),
+    ("yacute", None, 600),
+    ("ydieresis", None, 600),
+    ("yen", Some(165), 600),
+    ("z", Some(122), 600),
+    ("zacute", None, 600),
+    ("zcaron", None, 600),
+    ("zdotaccent", None, 600),
+    ("zero", Some(48), 600),
+];
+
+pub(super) const HELVETICA: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 667),
+    ("AE", Some(225), 1000),
+    ("Aacute", None, 667),
+    ("Abreve", None, 667),
+    ("Acircumflex", None, 667),
+    ("Adieresis", None, 667),
+    ("Agrave", None, 667),
+    ("Amacron", None, 667),
+    ("Aogonek", None, 667),
+    ("Aring", None, 667),
+    ("Atilde", None, 667),
+    ("B", Some(66), 667),
+    ("C", Some(67), 722),
+    ("Cacute", None, 722),
+    ("Ccaron", None, 722),
+    ("Ccedilla", None, 722),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 667),
+    ("Eacute", None, 667),
+    ("Ecaron", None, 667),
+    ("Ecircumflex", None, 667),
+    ("Edieresis", None, 667),
+    ("Edotaccent", None, 667),
+    ("Egrave", None, 667),
+    ("Emacron", None, 667),
+    ("Eogonek", None, 667),
+    ("Eth", None, 722),
+    ("Euro", None, 556),
+    ("F", Some(70), 611),
+    ("G", Some(71), 778),
+    ("Gbreve", None, 778),
+    ("Gcommaaccent", None, 778),
+    ("H", Some(72), 722),
+    ("I", Some(73), 278),
+    ("Iacute", None, 278),
+    ("Icircumflex", None, 278),
+    ("Idieresis", None, 278),
+    ("Idotaccent", None, 278),
+    ("Igrave", None, 278),
+    ("Imacron", None, 278),
+    ("Iogonek", None, 278),
+    ("J", Some(74), 500),
+    ("K", Some(75), 667),
+    ("Kcommaaccent", None, 667),
+    ("L", Some(76), 556),
+    ("Lacute", None, 556),
+    ("Lcaron", None, 556),
+    ("Lcommaaccent", None, 556),
+    ("Lslash", Some(232), 556),
+    ("M", Some(77), 833),
+    ("N", Some(78), 722),
+    ("Nacute", None, 722),
+    ("Ncaron", None, 722),
+    ("Ncommaaccent", None, 722),
+    ("Ntilde", None, 722),
+    ("O", Some(79), 778),
+    ("OE", Some(234), 1000),
+    ("Oacute", None, 778),
+    ("Ocircumflex", None, 778),
+    ("Odieresis", None, 778),
+    ("Ograve", None, 778),
+    ("Ohungarumlaut", None, 778),
+    ("Omacron", None, 778),
+    ("Oslash", Some(233), 778),
+    ("Otilde", None, 778),
+    ("P", Some(80), 667),
+    ("Q", Some(81), 778),
+    ("R", Some(82), 722),
+    ("Racute", None, 722),
+    ("Rcaron", None, 722),
+    ("Rcommaaccent", None, 722),
+    ("S", Some(83), 667),
+    ("Sacute", None, 667),
+    ("Scaron", None, 667),
+    ("Scedilla", None, 667),
+    ("Scommaaccent", None, 667),
+    ("T", Some(84), 611),
+    ("Tcaron", None, 611),
+    ("Tcommaaccent", None, 611),
+    ("Thorn", None, 667),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 667),
+    ("W", Some(87), 944),
+    ("X", Some(88), 667),
+    ("Y", Some(89), 667),
+    ("Yacute", None, 667),
+    ("Ydieresis", None, 667),
+    ("Z", Some(90), 611),
+    ("Zacute", None, 611),
+    ("Zcaron", None, 611),
+    ("Zdotaccent", None, 611),
+    ("a", Some(97), 556),
+    ("aacute", None, 556),
+    ("abreve", None, 556),
+    ("acircumflex", None, 556),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 556),
+    ("ae", Some(241), 889),
+    ("agrave", None, 556),
+    ("amacron", None, 556),
+    ("ampersand", Some(38), 667),
+    ("aogonek", None, 556),
+    ("aring", None, 556),
+    ("asciicircum", Some(94), 469),
+    ("asciitilde", Some(126), 584),
+    ("asterisk", Some(42), 389),
+    ("at", Some(64), 1015),
+    ("atilde", None, 556),
+    ("b", Some(98), 556),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 260),
+    ("braceleft", Some(123), 334),
+    ("braceright", Some(125), 334),
+    ("bracketleft", Some(91), 278),
+    ("bracketright", Some(93), 278),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 260),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 500),
+    ("cacute", None, 500),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 500),
+    ("ccedilla", None, 500),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 556),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 278),
+    ("comma", Some(44), 278),
+    ("commaaccent", None, 250),
+    ("copyright", None, 737),
+    ("currency", Some(168), 556),
+    ("d", Some(100), 556),
+    ("dagger", Some(178), 556),
+    ("daggerdbl", Some(179), 556),
+    ("dcaron", None, 643),
+    ("dcroat", None, 556),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 584),
+    ("dollar", Some(36), 556),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 556),
+    ("eacute", None, 556),
+    ("ecaron", None, 556),
+    ("ecircumflex", None, 556),
+    ("edieresis", None, 556),
+    ("edotaccent", None, 556),
+    ("egrave", None, 556),
+    ("eight", Some(56), 556),
+    ("ellipsis", Some(188), 1000),
+    ("emacron", None, 556),
+    ("emdash", Some(208), 1000),
+    ("endash", Some(177), 556),
+    ("eogonek", None, 556),
+    ("equal", Some(61), 584),
+    ("eth", None, 556),
+    ("exclam", Some(33), 278),
+    ("exclamdown", Some(161), 333),
+    ("f", Some(102), 278),
+    ("fi", Some(174), 500),
+    ("five", Some(53), 556),
+    ("fl", Some(175), 500),
+    ("florin", Some(166), 556),
+    ("four", Some(52), 556),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 556),
+    ("gbreve", None, 556),
+    ("gcommaaccent", None, 556),
+    ("germandbls", Some(251), 611),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 584),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 556),
+    ("guillemotright", Some(187), 556),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 556),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 222),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 222),
+    ("j", Some(106), 222),
+    ("k", Some(107), 500),
+    ("kcommaaccent", None, 500),
+    ("l", Some(108), 222),
+    ("lacute", None, 222),
+    ("lcaron", None, 299),
+    ("lcommaaccent", None, 222),
+    ("less", Some(60), 584),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 584),
+    ("lozenge", None, 471),
+    ("lslash", Some(248), 222),
+    ("m", Some(109), 833),
+    ("macron", Some(197), 333),
+    ("minus", None, 584),
+    ("mu", None, 556),
+    ("multiply", None, 584),
+    ("n", Some(110), 556),
+    ("nacute", None, 556),
+    ("ncaron", None, 556),
+    ("ncommaaccent", None, 556),
+    ("nine", Some(57), 556),
+    ("notequal", None, 549),
+    ("ntilde", None, 556),
+    ("numbersign", Some(35), 556),
+    ("o", Some(111), 556),
+    ("oacute", None, 556),
+    ("ocircumflex", None, 556),
+    ("odieresis", None, 556),
+    ("oe", Some(250), 944),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 556),
+    ("ohungarumlaut", None, 556),
+    ("omacron", None, 556),
+    ("one", Some(49), 556),
+    ("onehalf", None, 834),
+    ("onequarter", None, 834),
+    ("onesuperior", None, 333),
+    ("ordfeminine", Some(227), 370),
+    ("ordmasculine", Some(235), 365),
+    ("oslash", Some(249), 611),
+    ("otilde", None, 556),
+    ("p", Some(112), 556),
+    ("paragraph", Some(182), 537),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 476),
+    ("percent", Some(37), 889),
+    ("period", Some(46), 278),
+    ("periodcentered", Some(180), 278),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 584),
+    ("plusminus", None, 584),
+    ("q", Some(113), 556),
+    ("question", Some(63), 556),
+    ("questiondown", Some(191), 611),
+    ("quotedbl", Some(34), 355),
+    ("quotedblbase", Some(185), 333),
+    ("quotedblleft", Some(170), 333),
+    ("quotedblright", Some(186), 333),
+    ("quoteleft", Some(96), 222),
+    ("quoteright", Some(39), 222),
+    ("quotesinglbase", Some(184), 222),
+    ("quotesingle", Some(169), 191),
+    ("r", Some(114), 333),
+    ("racute", None, 333),
+    ("radical", None, 453),
+    ("rcaron", None, 333),
+    ("rcommaaccent", None, 333),
+    ("registered", None, 737),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 500),
+    ("sacute", None, 500),
+    ("scaron", None, 500),
+    ("scedilla", None, 500),
+    ("scommaaccent", None, 500),
+    ("section", Some(167), 556),
+    ("semicolon", Some(59), 278),
+    ("seven", Some(55), 556),
+    ("six", Some(54), 556),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 278),
+    ("sterling", Some(163), 556),
+    ("summation", None, 600),
+    ("t", Some(116), 278),
+    ("tcaron", None, 317),
+    ("tcommaaccent", None, 278),
+    ("thorn", None, 556),
+    ("three", Some(51), 556),
+    ("threequarters", None, 834),
+    ("threesuperior", None, 333),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 1000),
+    ("two", Some(50), 556),
+    ("twosuperior", None, 333),
+    ("u", Some(117), 556),
+    ("uacute", None, 556),
+    ("ucircumflex", None, 556),
+    ("udieresis", None, 556),
+    ("ugrave", None, 556),
+    ("uhungarumlaut", None, 556),
+    ("umacron", None, 556),
+    ("underscore", Some(95), 556),
+    ("uogonek", None, 556),
+    ("uring", None, 556),
+    ("v", Some(118), 500),
+    ("w", Some(119), 722),
+    ("x", Some(120), 500),
+    ("y", Some(121), 500),
+    ("yacute", None, 500),
+    ("ydieresis", None, 500),
+    ("yen", Some(165), 556),
+    ("z", Some(122), 500),
+    ("zacute", None, 500),
+    ("zcaron", None, 500),
+    ("zdotaccent", None, 500),
+    ("zero", Some(48), 556),
+];
+
+pub(super) const HELVETICA_BOLD: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 722),
+    ("AE", Some(225), 1000),
+    ("Aacute", None, 722),
+    ("Abreve", None, 722),
+    ("Acircumflex", None, 722),
+    ("Adieresis", None, 722),
+    ("Agrave", None, 722),
+    ("Amacron", None, 722),
+    ("Aogonek", None, 722),
+    ("Aring", None, 722),
+    ("Atilde", None, 722),
+    ("B", Some(66), 722),
+    ("C", Some(67), 722),
+    ("Cacute", None, 722),
+    ("Ccaron", None, 722),
+    ("Ccedilla", None, 722),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 667),
+    ("Eacute", None, 667),
+    ("Ecaron", None, 667),
+    ("Ecircumflex", None, 667),
+    ("Edieresis", None, 667),
+    ("Edotaccent", None, 667),
+    ("Egrave", None, 667),
+    ("Emacron", None, 667),
+    ("Eogonek", None, 667),
+    ("Eth", None, 722),
+    ("Euro", None, 556),
+    ("F", Some(70), 611),
+    ("G", Some(71), 778),
+    ("Gbreve", None, 778),
+    ("Gcommaaccent", None, 778),
+    ("H", Some(72), 722),
+    ("I", Some(73), 278),
+    ("Iacute", None, 278),
+    ("Icircumflex", None, 278),
+    ("Idieresis", None, 278),
+    ("Idotaccent", None, 278),
+    ("Igrave", None, 278),
+    ("Imacron", None, 278),
+    ("Iogonek", None, 278),
+    ("J", Some(74), 556),
+    ("K", Some(75), 722),
+    ("Kcommaaccent", None, 722),
+    ("L", Some(76), 611),
+    ("Lacute", None, 611),
+    ("Lcaron", None, 611),
+    ("Lcommaaccent", None, 611),
+    ("Lslash", Some(232), 611),
+    ("M", Some(77), 833),
+    ("N", Some(78), 722),
+    ("Nacute", None, 722),
+    ("Ncaron", None, 722),
+    ("Ncommaaccent", None, 722),
+    ("Ntilde", None, 722),
+    ("O", Some(79), 778),
+    ("OE", Some(234), 1000),
+    ("Oacute", None, 778),
+    ("Ocircumflex", None, 778),
+    ("Odieresis", None, 778),
+    ("Ograve", None, 778),
+    ("Ohungarumlaut", None, 778),
+    ("Omacron", None, 778),
+    ("Oslash", Some(233), 778),
+    ("Otilde", None, 778),
+    ("P", Some(80), 667),
+    ("Q", Some(81), 778),
+    ("R", Some(82), 722),
+    ("Racute", None, 722),
+    ("Rcaron", None, 722),
+    ("Rcommaaccent", None, 722),
+    ("S", Some(83), 667),
+    ("Sacute", None, 667),
+    ("Scaron", None, 667),
+    ("Scedilla", None, 667),
+    ("Scommaaccent", None, 667),
+    ("T", Some(84), 611),
+    ("Tcaron", None, 611),
+    ("Tcommaaccent", None, 611),
+    ("Thorn", None, 667),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 667),
+    ("W", Some(87), 944),
+    ("X", Some(88), 667),
+    ("Y", Some(89), 667),
+    ("Yacute", None, 667),
+    ("Ydieresis", None, 667),
+    ("Z", Some(90), 611),
+    ("Zacute", None, 611),
+    ("Zcaron", None, 611),
+    ("Zdotaccent", None, 611),
+    ("a", Some(97), 556),
+    ("aacute", None, 556),
+    ("abreve", None, 556),
+    ("acircumflex", None, 556),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 556),
+    ("ae", Some(241), 889),
+    ("agrave", None, 556),
+    ("amacron", None, 556),
+    ("ampersand", Some(38), 722),
+    ("aogonek", None, 556),
+    ("aring", None, 556),
+    ("asciicircum", Some(94), 584),
+    ("asciitilde", Some(126), 584),
+    ("asterisk", Some(42), 389),
+    ("at", Some(64), 975),
+    ("atilde", None, 556),
+    ("b", Some(98), 611),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 280),
+    ("braceleft", Some(123), 389),
+    ("braceright", Some(125), 389),
+    ("bracketleft", Some(91), 333),
+    ("bracketright", Some(93), 333),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 280),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 556),
+    ("cacute", None, 556),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 556),
+    ("ccedilla", None, 556),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 556),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 333),
+    ("comma", Some(44), 278),
+    ("commaaccent", None, 250),
+    ("copyright", None, 737),
+    ("currency", Some(168), 556),
+    ("d", Some(100), 611),
+    ("dagger", Some(178), 556),
+    ("daggerdbl", Some(179), 556),
+    ("dcaron", None, 743),
+    ("dcroat", None, 611),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 584),
+    ("dollar", Some(36), 556),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 556),
+    ("eacute", None, 556),
+    ("ecaron", None, 556),
+    ("ecircumflex", None, 556),
+    ("edieresis", None, 556),
+    ("edotaccent", None, 556),
+    ("egrave", None, 556),
+    ("eight", Some(56), 556),
+    ("ellipsis", Some(188), 1000),
+    ("emacron", None, 556),
+    ("emdash", Some(208), 1000),
+    ("endash", Some(177), 556),
+    ("eogonek", None, 556),
+    ("equal", Some(61), 584),
+    ("eth", None, 611),
+    ("exclam", Some(33), 333),
+    ("exclamdown", Some(161), 333),
+    ("f", Some(102), 333),
+    ("fi", Some(174), 611),
+    ("five", Some(53), 556),
+    ("fl", Some(175), 611),
+    ("florin", Some(166), 556),
+    ("four", Some(52), 556),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 611),
+    ("gbreve", None, 611),
+    ("gcommaaccent", None, 611),
+    ("germandbls", Some(251), 611),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 584),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 556),
+    ("guillemotright", Some(187), 556),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 611),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 278),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 278),
+    ("j", Some(106), 278),
+    ("k", Some(107), 556),
+    ("kcommaaccent", None, 556),
+    ("l", Some(108), 278),
+    ("lacute", None, 278),
+    ("lcaron", None, 400),
+    ("lcommaaccent", None, 278),
+    ("less", Some(60), 584),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 584),
+    ("lozenge", None, 494),
+    ("lslash", Some(248), 278),
+    ("m", Some(109), 889),
+    ("macron", Some(197), 333),
+    ("minus", None, 584),
+    ("mu", None, 611),
+    ("multiply", None, 584),
+    ("n", Some(110), 611),
+    ("nacute", None, 611),
+    ("ncaron", None, 611),
+    ("ncommaaccent", None, 611),
+    ("nine", Some(57), 556),
+    ("notequal", None, 549),
+    ("ntilde", None, 611),
+    ("numbersign", Some(35), 556),
+    ("o", Some(111), 611),
+    ("oacute", None, 611),
+    ("ocircumflex", None, 611),
+    ("odieresis", None, 611),
+    ("oe", Some(250), 944),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 611),
+    ("ohungarumlaut", None, 611),
+    ("omacron", None, 611),
+    ("one", Some(49), 556),
+    ("onehalf", None, 834),
+    ("onequarter", None, 834),
+    ("onesuperior", None, 333),
+    ("ordfeminine", Some(227), 370),
+    ("ordmasculine", Some(235), 365),
+    ("oslash", Some(249), 611),
+    ("otilde", None, 611),
+    ("p", Some(112), 611),
+    ("paragraph", Some(182), 556),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 494),
+    ("percent", Some(37), 889),
+    ("period", Some(46), 278),
+    ("periodcentered", Some(180), 278),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 584),
+    ("plusminus", None, 584),
+    ("q", Some(113), 611),
+    ("question", Some(63), 611),
+    ("questiondown", Some(191), 611),
+    ("quotedbl", Some(34), 474),
+    ("quotedblbase", Some(185), 500),
+    ("quotedblleft", Some(170), 500),
+    ("quotedblright", Some(186), 500),
+    ("quoteleft", Some(96), 278),
+    ("quoteright", Some(39), 278),
+    ("quotesinglbase", Some(184), 278),
+    ("quotesingle", Some(169), 238),
+    ("r", Some(114), 389),
+    ("racute", None, 389),
+    ("radical", None, 549),
+    ("rcaron", None, 389),
+    ("rcommaaccent", None, 389),
+    ("registered", None, 737),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 556),
+    ("sacute", None, 556),
+    ("scaron", None, 556),
+    ("scedilla", None, 556),
+    ("scommaaccent", None, 556),
+    ("section", Some(167), 556),
+    ("semicolon", Some(59), 333),
+    ("seven", Some(55), 556),
+    ("six", Some(54), 556),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 278),
+    ("sterling", Some(163), 556),
+    ("summation", None, 600),
+    ("t", Some(116), 333),
+    ("tcaron", None, 389),
+    ("tcommaaccent", None, 333),
+    ("thorn", None, 611),
+    ("three", Some(51), 556),
+    ("threequarters", None, 834),
+    ("threesuperior", None, 333),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 1000),
+    ("two", Some(50), 556),
+    ("twosuperior", None, 333),
+    ("u", Some(117), 611),
+    ("uacute", None, 611),
+    ("ucircumflex", None, 611),
+    ("udieresis", None, 611),
+    ("ugrave", None, 611),
+    ("uhungarumlaut", None, 611),
+    ("umacron", None, 611),
+    ("underscore", Some(95), 556),
+    ("uogonek", None, 611),
+    ("uring", None, 611),
+    ("v", Some(118), 556),
+    ("w", Some(119), 778),
+    ("x", Some(120), 556),
+    ("y", Some(121), 556),
+    ("yacute", None, 556),
+    ("ydieresis", None, 556),
+    ("yen", Some(165), 556),
+    ("z", Some(122), 500),
+    ("zacute", None, 500),
+    ("zcaron", None, 500),
+    ("zdotaccent", None, 500),
+    ("zero", Some(48), 556),
+];
+
+pub(super) const HELVETICA_OBLIQUE: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 667),
+    ("AE", Some(225), 1000),
+    ("Aacute", None, 667),
+    ("Abreve", None, 667),
+    ("Acircumflex", None, 667),
+    ("Adieresis", None, 667),
+    ("Agrave", None, 667),
+    ("Amacron", None, 667),
+    ("Aogonek", None, 667),
+    ("Aring", None, 667),
+    ("Atilde", None, 667),
+    ("B", Some(66), 667),
+    ("C", Some(67), 722),
+    ("Cacute", None, 722),
+    ("Ccaron", None, 722),
+    ("Ccedilla", None, 722),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 667),
+    ("Eacute", None, 667),
+    ("Ecaron", None, 667),
+    ("Ecircumflex", None, 667),
+    ("Edieresis", None, 667),
+    ("Edotaccent", None, 667),
+    ("Egrave", None, 667),
+    ("Emacron", None, 667),
+    ("Eogonek", None, 667),
+    ("Eth", None, 722),
+    ("Euro", None, 556),
+    ("F", Some(70), 611),
+    ("G", Some(71), 778),
+    ("Gbreve", None, 778),
+    ("Gcommaaccent", None, 778),
+    ("H", Some(72), 722),
+    ("I", Some(73), 278),
+    ("Iacute", None, 278),
+    ("Icircumflex", None, 278),
+    ("Idieresis", None, 278),
+    ("Idotaccent", None, 278),
+    ("Igrave", None, 278),
+    ("Imacron", None, 278),
+    ("Iogonek", None, 278),
+    ("J", Some(74), 500),
+    ("K", Some(75), 667),
+    ("Kcommaaccent", None, 667),
+    ("L", Some(76), 556),
+    ("Lacute", None, 556),
+    ("Lcaron", None, 556),
+    ("Lcommaaccent", None, 556),
+    ("Lslash", Some(232), 556),
+    ("M", Some(77), 833),
+    ("N", Some(78), 722),
+    ("Nacute", None, 722),
+    ("Ncaron", None, 722),
+    ("Ncommaaccent", None, 722),
+    ("Ntilde", None, 722),
+    ("O", Some(79), 778),
+    ("OE", Some(234), 1000),
+    ("Oacute", None, 778),
+    ("Ocircumflex", None, 778),
+    ("Odieresis", None, 778),
+    ("Ograve", None, 778),
+    ("Ohungarumlaut", None, 778),
+    ("Omacron", None, 778),
+    ("Oslash", Some(233), 778),
+    ("Otilde", None, 778),
+    ("P", Some(80), 667),
+    ("Q", Some(81), 778),
+    ("R", Some(82), 722),
+    ("Racute", None, 722),
+    ("Rcaron", None, 722),
+    ("Rcommaaccent", None, 722),
+    ("S", Some(83), 667),
+    ("Sacute", None, 667),
+    ("Scaron", None, 667),
+    ("Scedilla", None, 667),
+    ("Scommaaccent", None, 667),
+    ("T", Some(84), 611),
+    ("Tcaron", None, 611),
+    ("Tcommaaccent", None, 611),
+    ("Thorn", None, 667),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 667),
+    ("W", Some(87), 944),
+    ("X", Some(88), 667),
+    ("Y", Some(89), 667),
+    ("Yacute", None, 667),
+    ("Ydieresis", None, 667),
+    ("Z", Some(90), 611),
+    ("Zacute", None, 611),
+    ("Zcaron", None, 611),
+    ("Zdotaccent", None, 611),
+    ("a", Some(97), 556),
+    ("aacute", None, 556),
+    ("abreve", None, 556),
+    ("acircumflex", None, 556),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 556),
+    ("ae", Some(241), 889),
+    ("agrave", None, 556),
+    ("amacron", None, 556),
+    ("ampersand", Some(38), 667),
+    ("aogonek", None, 556),
+    ("aring", None, 556),
+    ("asciicircum", Some(94), 469),
+    ("asciitilde", Some(126), 584),
+    ("asterisk", Some(42), 389),
+    ("at", Some(64), 1015),
+    ("atilde", None, 556),
+    ("b", Some(98), 556),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 260),
+    ("braceleft", Some(123), 334),
+    ("braceright", Some(125), 334),
+    ("bracketleft", Some(91), 278),
+    ("bracketright", Some(93), 278),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 260),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 500),
+    ("cacute", None, 500),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 500),
+    ("ccedilla", None, 500),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 556),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 278),
+    ("comma", Some(44), 278),
+    ("commaaccent", None, 250),
+    ("copyright", None, 737),
+    ("currency", Some(168), 556),
+    ("d", Some(100), 556),
+    ("dagger", Some(178), 556),
+    ("daggerdbl", Some(179), 556),
+    ("dcaron", None, 643),
+    ("dcroat", None, 556),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 584),
+    ("dollar", Some(36), 556),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 556),
+    ("eacute", None, 556),
+    ("ecaron", None, 556),
+    ("ecircumflex", None, 556),
+    ("edieresis", None, 556),
+    ("edotaccent", None, 556),
+    ("egrave", None, 556),
+    ("eight", Some(56), 556),
+    ("ellipsis", Some(188), 1000),
+    ("emacron", None, 556),
+    ("emdash", Some(208), 1000),
+    ("endash", Some(177), 556),
+    ("eogonek", None, 556),
+    ("equal", Some(61), 584),
+    ("eth", None, 556),
+    ("exclam", Some(33), 278),
+    ("exclamdown", Some(161), 333),
+    ("f", Some(102), 278),
+    ("fi", Some(174), 500),
+    ("five", Some(53), 556),
+    ("fl", Some(175), 500),
+    ("florin", Some(166), 556),
+    ("four", Some(52), 556),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 556),
+    ("gbreve", None, 556),
+    ("gcommaaccent", None, 556),
+    ("germandbls", Some(251), 611),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 584),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 556),
+    ("guillemotright", Some(187), 556),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 556),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 222),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 222),
+    ("j", Some(106), 222),
+    ("k", Some(107), 500),
+    ("kcommaaccent", None, 500),
+    ("l", Some(108), 222),
+    ("lacute", None, 222),
+    ("lcaron", None, 299),
+    ("lcommaaccent", None, 222),
+    ("less", Some(60), 584),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 584),
+    ("lozenge", None, 471),
+    ("lslash", Some(248), 222),
+    ("m", Some(109), 833),
+    ("macron", Some(197), 333),
+    ("minus", None, 584),
+    ("mu", None, 556),
+    ("multiply", None, 584),
+    ("n", Some(110), 556),
+    ("nacute", None, 556),
+    ("ncaron", None, 556),
+    ("ncommaaccent", None, 556),
+    ("nine", Some(57), 556),
+    ("notequal", None, 549),
+    ("ntilde", None, 556),
+    ("numbersign", Some(35), 556),
+    ("o", Some(111), 556),
+    ("oacute", None, 556),
+    ("ocircumflex", None, 556),
+    ("odieresis", None, 556),
+    ("oe", Some(250), 944),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 556),
+    ("ohungarumlaut", None, 556),
+    ("omacron", None, 556),
+    ("one", Some(49), 556),
+    ("onehalf", None, 834),
+    ("onequarter", None, 834),
+    ("onesuperior", None, 333),
+    ("ordfeminine", Some(227), 370),
+    ("ordmasculine", Some(235), 365),
+    ("oslash", Some(249), 611),
+    ("otilde", None, 556),
+    ("p", Some(112), 556),
+    ("paragraph", Some(182), 537),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 476),
+    ("percent", Some(37), 889),
+    ("period", Some(46), 278),
+    ("periodcentered", Some(180), 278),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 584),
+    ("plusminus", None, 584),
+    ("q", Some(113), 556),
+    ("question", Some(63), 556),
+    ("questiondown", Some(191), 611),
+    ("quotedbl", Some(34), 355),
+    ("quotedblbase", Some(185), 333),
+    ("quotedblleft", Some(170), 333),
+    ("quotedblright", Some(186), 333),
+    ("quoteleft", Some(96), 222),
+    ("quoteright", Some(39), 222),
+    ("quotesinglbase", Some(184), 222),
+    ("quotesingle", Some(169), 191),
+    ("r", Some(114), 333),
+    ("racute", None, 333),
+    ("radical", None, 453),
+    ("rcaron", None, 333),
+    ("rcommaaccent", None, 333),
+    ("registered", None, 737),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 500),
+    ("sacute", None, 500),
+    ("scaron", None, 500),
+    ("scedilla", None, 500),
+    ("scommaaccent", None, 500),
+    ("section", Some(167), 556),
+    ("semicolon", Some(59), 278),
+    ("seven", Some(55), 556),
+    ("six", Some(54), 556),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 278),
+    ("sterling", Some(163), 556),
+    ("summation", None, 600),
+    ("t", Some(116), 278),
+    ("tcaron", None, 317),
+    ("tcommaaccent", None, 278),
+    ("thorn", None, 556),
+    ("three", Some(51), 556),
+    ("threequarters", None, 834),
+    ("threesuperior", None, 333),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 1000),
+    ("two", Some(50), 556),
+    ("twosuperior", None, 333),
+    ("u", Some(117), 556),
+    ("uacute", None, 556),
+    ("ucircumflex", None, 556),
+    ("udieresis", None, 556),
+    ("ugrave", None, 556),
+    ("uhungarumlaut", None, 556),
+    ("umacron", None, 556),
+    ("underscore", Some(95), 556),
+    ("uogonek", None, 556),
+    ("uring", None, 556),
+    ("v", Some(118), 500),
+    ("w", Some(119), 722),
+    ("x", Some(120), 500),
+    ("y", Some(121), 500),
+    ("yacute", None, 500),
+    ("ydieresis", None, 500),
+    ("yen", Some(165), 556),
+    ("z", Some(122), 500),
+    ("zacute", None, 500),
+    ("zcaron", None, 500),
+    ("zdotaccent", None, 500),
+    ("zero", Some(48), 556),
+];
+
+pub(super) const HELVETICA_BOLDOBLIQUE: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 722),
+    ("AE", Some(225), 1000),
+    ("Aacute", None, 722),
+    ("Abreve", None, 722),
+    ("Acircumflex", None, 722),
+    ("Adieresis", None, 722),
+    ("Agrave", None, 722),
+    ("Amacron", None, 722),
+    ("Aogonek", None, 722),
+    ("Aring", None, 722),
+    ("Atilde", None, 722),
+    ("B", Some(66), 722),
+    ("C", Some(67), 722),
+    ("Cacute", None, 722),
+    ("Ccaron", None, 722),
+    ("Ccedilla", None, 722),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 667),
+    ("Eacute", None, 667),
+    ("Ecaron", None, 667),
+    ("Ecircumflex", None, 667),
+    ("Edieresis", None, 667),
+    ("Edotaccent", None, 667),
+    ("Egrave", None, 667),
+    ("Emacron", None, 667),
+    ("Eogonek", None, 667),
+    ("Eth", None, 722),
+    ("Euro", None, 556),
+    ("F", Some(70), 611),
+    ("G", Some(71), 778),
+    ("Gbreve", None, 778),
+    ("Gcommaaccent", None, 778),
+    ("H", Some(72), 722),
+    ("I", Some(73), 278),
+    ("Iacute", None, 278),
+    ("Icircumflex", None, 278),
+    ("Idieresis", None, 278),
+    ("Idotaccent", None, 278),
+    ("Igrave", None, 278),
+    ("Imacron", None, 278),
+    ("Iogonek", None, 278),
+    ("J", Some(74), 556),
+    ("K", Some(75), 722),
+    ("Kcommaaccent", None, 722),
+    ("L", Some(76), 611),
+    ("Lacute", None, 611),
+    ("Lcaron", None, 611),
+    ("Lcommaaccent", None, 611),
+    ("Lslash", Some(232), 611),
+    ("M", Some(77), 833),
+    ("N", Some(78), 722),
+    ("Nacute", None, 722),
+    ("Ncaron", None, 722),
+    ("Ncommaaccent", None, 722),
+    ("Ntilde", None, 722),
+    ("O", Some(79), 778),
+    ("OE", Some(234), 1000),
+    ("Oacute", None, 778),
+    ("Ocircumflex", None, 778),
+    ("Odieresis", None, 778),
+    ("Ograve", None, 778),
+    ("Ohungarumlaut", None, 778),
+    ("Omacron", None, 778),
+    ("Oslash", Some(233), 778),
+    ("Otilde", None, 778),
+    ("P", Some(80), 667),
+    ("Q", Some(81), 778),
+    ("R", Some(82), 722),
+    ("Racute", None, 722),
+    ("Rcaron", None, 722),
+    ("Rcommaaccent", None, 722),
+    ("S", Some(83), 667),
+    ("Sacute", None, 667),
+    ("Scaron", None, 667),
+    ("Scedilla", None, 667),
+    ("Scommaaccent", None, 667),
+    ("T", Some(84), 611),
+    ("Tcaron", None, 611),
+    ("Tcommaaccent", None, 611),
+    ("Thorn", None, 667),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 667),
+    ("W", Some(87), 944),
+    ("X", Some(88), 667),
+    ("Y", Some(89), 667),
+    ("Yacute", None, 667),
+    ("Ydieresis", None, 667),
+    ("Z", Some(90), 611),
+    ("Zacute", None, 611),
+    ("Zcaron", None, 611),
+    ("Zdotaccent", None, 611),
+    ("a", Some(97), 556),
+    ("aacute", None, 556),
+    ("abreve", None, 556),
+    ("acircumflex", None, 556),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 556),
+    ("ae", Some(241), 889),
+    ("agrave", None, 556),
+    ("amacron", None, 556),
+    ("ampersand", Some(38), 722),
+    ("aogonek", None, 556),
+    ("aring", None, 556),
+    ("asciicircum", Some(94), 584),
+    ("asciitilde", Some(126), 584),
+    ("asterisk", Some(42), 389),
+    ("at", Some(64), 975),
+    ("atilde", None, 556),
+    ("b", Some(98), 611),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 280),
+    ("braceleft", Some(123), 389),
+    ("braceright", Some(125), 389),
+    ("bracketleft", Some(91), 333),
+    ("bracketright", Some(93), 333),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 280),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 556),
+    ("cacute", None, 556),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 556),
+    ("ccedilla", None, 556),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 556),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 333),
+    ("comma", Some(44), 278),
+    ("commaaccent", None, 250),
+    ("copyright", None, 737),
+    ("currency", Some(168), 556),
+    ("d", Some(100), 611),
+    ("dagger", Some(178), 556),
+    ("daggerdbl", Some(179), 556),
+    ("dcaron", None, 743),
+    ("dcroat", None, 611),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 584),
+    ("dollar", Some(36), 556),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 556),
+    ("eacute", None, 556),
+    ("ecaron", None, 556),
+    ("ecircumflex", None, 556),
+    ("edieresis", None, 556),
+    ("edotaccent", None, 556),
+    ("egrave", None, 556),
+    ("eight", Some(56), 556),
+    ("ellipsis", Some(188), 1000),
+    ("emacron", None, 556),
+    ("emdash", Some(208), 1000),
+    ("endash", Some(177), 556),
+    ("eogonek", None, 556),
+    ("equal", Some(61), 584),
+    ("eth", None, 611),
+    ("exclam", Some(33), 333),
+    ("exclamdown", Some(161), 333),
+    ("f", Some(102), 333),
+    ("fi", Some(174), 611),
+    ("five", Some(53), 556),
+    ("fl", Some(175), 611),
+    ("florin", Some(166), 556),
+    ("four", Some(52), 556),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 611),
+    ("gbreve", None, 611),
+    ("gcommaaccent", None, 611),
+    ("germandbls", Some(251), 611),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 584),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 556),
+    ("guillemotright", Some(187), 556),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 611),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 278),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 278),
+    ("j", Some(106), 278),
+    ("k", Some(107), 556),
+    ("kcommaaccent", None, 556),
+    ("l", Some(108), 278),
+    ("lacute", None, 278),
+    ("lcaron", None, 400),
+    ("lcommaaccent", None, 278),
+    ("less", Some(60), 584),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 584),
+    ("lozenge", None, 494),
+    ("lslash", Some(248), 278),
+    ("m", Some(109), 889),
+    ("macron", Some(197), 333),
+    ("minus", None, 584),
+    ("mu", None, 611),
+    ("multiply", None, 584),
+    ("n", Some(110), 611),
+    ("nacute", None, 611),
+    ("ncaron", None, 611),
+    ("ncommaaccent", None, 611),
+    ("nine", Some(57), 556),
+    ("notequal", None, 549),
+    ("ntilde", None, 611),
+    ("numbersign", Some(35), 556),
+    ("o", Some(111), 611),
+    ("oacute", None, 611),
+    ("ocircumflex", None, 611),
+    ("odieresis", None, 611),
+    ("oe", Some(250), 944),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 611),
+    ("ohungarumlaut", None, 611),
+    ("omacron", None, 611),
+    ("one", Some(49), 556),
+    ("onehalf", None, 834),
+    ("onequarter", None, 834),
+    ("onesuperior", None, 333),
+    ("ordfeminine", Some(227), 370),
+    ("ordmasculine", Some(235), 365),
+    ("oslash", Some(249), 611),
+    ("otilde", None, 611),
+    ("p", Some(112), 611),
+    ("paragraph", Some(182), 556),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 494),
+    ("percent", Some(37), 889),
+    ("period", Some(46), 278),
+    ("periodcentered", Some(180), 278),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 584),
+    ("plusminus", None, 584),
+    ("q", Some(113), 611),
+    ("question", Some(63), 611),
+    ("questiondown", Some(191), 611),
+    ("quotedbl", Some(34), 474),
+    ("quotedblbase", Some(185), 500),
+    ("quotedblleft", Some(170), 500),
+    ("quotedblright", Some(186), 500),
+    ("quoteleft", Some(96), 278),
+    ("quoteright", Some(39), 278),
+    ("quotesinglbase", Some(184), 278),
+    ("quotesingle", Some(169), 238),
+    ("r", Some(114), 389),
+    ("racute", None, 389),
+    ("radical", None, 549),
+    ("rcaron", None, 389),
+    ("rcommaaccent", None, 389),
+    ("registered", None, 737),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 556),
+    ("sacute", None, 556),
+    ("scaron", None, 556),
+    ("scedilla", None, 556),
+    ("scommaaccent", None, 556),
+    ("section", Some(167), 556),
+    ("semicolon", Some(59), 333),
+    ("seven", Some(55), 556),
+    ("six", Some(54), 556),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 278),
+    ("sterling", Some(163), 556),
+    ("summation", None, 600),
+    ("t", Some(116), 333),
+    ("tcaron", None, 389),
+    ("tcommaaccent", None, 333),
+    ("thorn", None, 611),
+    ("three", Some(51), 556),
+    ("threequarters", None, 834),
+    ("threesuperior", None, 333),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 1000),
+    ("two", Some(50), 556),
+    ("twosuperior", None, 333),
+    ("u", Some(117), 611),
+    ("uacute", None, 611),
+    ("ucircumflex", None, 611),
+    ("udieresis", None, 611),
+    ("ugrave", None, 611),
+    ("uhungarumlaut", None, 611),
+    ("umacron", None, 611),
+    ("underscore", Some(95), 556),
+    ("uogonek", None, 611),
+    ("uring", None, 611),
+    ("v", Some(118), 556),
+    ("w", Some(119), 778),
+    ("x", Some(120), 556),
+    ("y", Some(121), 556),
+    ("yacute", None, 556),
+    ("ydieresis", None, 556),
+    ("yen", Some(165), 556),
+    ("z", Some(122), 500),
+    ("zacute", None, 500),
+    ("zcaron", None, 500),
+    ("zdotaccent", None, 500),
+    ("zero", Some(48), 556),
+];
+
+pub(super) const TIMES_ROMAN: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 722),
+    ("AE", Some(225), 889),
+    ("Aacute", None, 722),
+    ("Abreve", None, 722),
+    ("Acircumflex", None, 722),
+    ("Adieresis", None, 722),
+    ("Agrave", None, 722),
+    ("Amacron", None, 722),
+    ("Aogonek", None, 722),
+    ("Aring", None, 722),
+    ("Atilde", None, 722),
+    ("B", Some(66), 667),
+    ("C", Some(67), 667),
+    ("Cacute", None, 667),
+    ("Ccaron", None, 667),
+    ("Ccedilla", None, 667),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 611),
+    ("Eacute", None, 611),
+    ("Ecaron", None, 611),
+    ("Ecircumflex", None, 611),
+    ("Edieresis", None, 611),
+    ("Edotaccent", None, 611),
+    ("Egrave", None, 611),
+    ("Emacron", None, 611),
+    ("Eogonek", None, 611),
+    ("Eth", None, 722),
+    ("Euro", None, 500),
+    ("F", Some(70), 556),
+    ("G", Some(71), 722),
+    ("Gbreve", None, 722),
+    ("Gcommaaccent", None, 722),
+    ("H", Some(72), 722),
+    ("I", Some(73), 333),
+    ("Iacute", None, 333),
+    ("Icircumflex", None, 333),
+    ("Idieresis", None, 333),
+    ("Idotaccent", None, 333),
+    ("Igrave", None, 333),
+    ("Imacron", None, 333),
+    ("Iogonek", None, 333),
+    ("J", Some(74), 389),
+    ("K", Some(75), 722),
+    ("Kcommaaccent", None, 722),
+    ("L", Some(76), 611),
+    ("Lacute", None, 611),
+    ("Lcaron", None, 611),
+    ("Lcommaaccent", None, 611),
+    ("Lslash", Some(232), 611),
+    ("M", Some(77), 889),
+    ("N", Some(78), 722),
+    ("Nacute", None, 722),
+    ("Ncaron", None, 722),
+    ("Ncommaaccent", None, 722),
+    ("Ntilde", None, 722),
+    ("O", Some(79), 722),
+    ("OE", Some(234), 889),
+    ("Oacute", None, 722),
+    ("Ocircumflex", None, 722),
+    ("Odieresis", None, 722),
+    ("Ograve", None, 722),
+    ("Ohungarumlaut", None, 722),
+    ("Omacron", None, 722),
+    ("Oslash", Some(233), 722),
+    ("Otilde", None, 722),
+    ("P", Some(80), 556),
+    ("Q", Some(81), 722),
+    ("R", Some(82), 667),
+    ("Racute", None, 667),
+    ("Rcaron", None, 667),
+    ("Rcommaaccent", None, 667),
+    ("S", Some(83), 556),
+    ("Sacute", None, 556),
+    ("Scaron", None, 556),
+    ("Scedilla", None, 556),
+    ("Scommaaccent", None, 556),
+    ("T", Some(84), 611),
+    ("Tcaron", None, 611),
+    ("Tcommaaccent", None, 611),
+    ("Thorn", None, 556),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 722),
+    ("W", Some(87), 944),
+    ("X", Some(88), 722),
+    ("Y", Some(89), 722),
+    ("Yacute", None, 722),
+    ("Ydieresis", None, 722),
+    ("Z", Some(90), 611),
+    ("Zacute", None, 611),
+    ("Zcaron", None, 611),
+    ("Zdotaccent", None, 611),
+    ("a", Some(97), 444),
+    ("aacute", None, 444),
+    ("abreve", None, 444),
+    ("acircumflex", None, 444),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 444),
+    ("ae", Some(241), 667),
+    ("agrave", None, 444),
+    ("amacron", None, 444),
+    ("ampersand", Some(38), 778),
+    ("aogonek", None, 444),
+    ("aring", None, 444),
+    ("asciicircum", Some(94), 469),
+    ("asciitilde", Some(126), 541),
+    ("asterisk", Some(42), 500),
+    ("at", Some(64), 921),
+    ("atilde", None, 444),
+    ("b", Some(98), 500),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 200),
+    ("braceleft", Some(123), 480),
+    ("braceright", Some(125), 480),
+    ("bracketleft", Some(91), 333),
+    ("bracketright", Some(93), 333),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 200),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 444),
+    ("cacute", None, 444),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 444),
+    ("ccedilla", None, 444),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 500),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 278),
+    ("comma", Some(44), 250),
+    ("commaaccent", None, 250),
+    ("copyright", None, 760),
+    ("currency", Some(168), 500),
+    ("d", Some(100), 500),
+    ("dagger", Some(178), 500),
+    ("daggerdbl", Some(179), 500),
+    ("dcaron", None, 588),
+    ("dcroat", None, 500),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 564),
+    ("dollar", Some(36), 500),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 444),
+    ("eacute", None, 444),
+    ("ecaron", None, 444),
+    ("ecircumflex", None, 444),
+    ("edieresis", None, 444),
+    ("edotaccent", None, 444),
+    ("egrave", None, 444),
+    ("eight", Some(56), 500),
+    ("ellipsis", Some(188), 1000),
+    ("emacron", None, 444),
+    ("emdash", Some(208), 1000),
+    ("endash", Some(177), 500),
+    ("eogonek", None, 444),
+    ("equal", Some(61), 564),
+    ("eth", None, 500),
+    ("exclam", Some(33), 333),
+    ("exclamdown", Some(161), 333),
+    ("f", Some(102), 333),
+    ("fi", Some(174), 556),
+    ("five", Some(53), 500),
+    ("fl", Some(175), 556),
+    ("florin", Some(166), 500),
+    ("four", Some(52), 500),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 500),
+    ("gbreve", None, 500),
+    ("gcommaaccent", None, 500),
+    ("germandbls", Some(251), 500),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 564),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 500),
+    ("guillemotright", Some(187), 500),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 500),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 278),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 278),
+    ("j", Some(106), 278),
+    ("k", Some(107), 500),
+    ("kcommaaccent", None, 500),
+    ("l", Some(108), 278),
+    ("lacute", None, 278),
+    ("lcaron", None, 344),
+    ("lcommaaccent", None, 278),
+    ("less", Some(60), 564),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 564),
+    ("lozenge", None, 471),
+    ("lslash", Some(248), 278),
+    ("m", Some(109), 778),
+    ("macron", Some(197), 333),
+    ("minus", None, 564),
+    ("mu", None, 500),
+    ("multiply", None, 564),
+    ("n", Some(110), 500),
+    ("nacute", None, 500),
+    ("ncaron", None, 500),
+    ("ncommaaccent", None, 500),
+    ("nine", Some(57), 500),
+    ("notequal", None, 549),
+    ("ntilde", None, 500),
+    ("numbersign", Some(35), 500),
+    ("o", Some(111), 500),
+    ("oacute", None, 500),
+    ("ocircumflex", None, 500),
+    ("odieresis", None, 500),
+    ("oe", Some(250), 722),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 500),
+    ("ohungarumlaut", None, 500),
+    ("omacron", None, 500),
+    ("one", Some(49), 500),
+    ("onehalf", None, 750),
+    ("onequarter", None, 750),
+    ("onesuperior", None, 300),
+    ("ordfeminine", Some(227), 276),
+    ("ordmasculine", Some(235), 310),
+    ("oslash", Some(249), 500),
+    ("otilde", None, 500),
+    ("p", Some(112), 500),
+    ("paragraph", Some(182), 453),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 476),
+    ("percent", Some(37), 833),
+    ("period", Some(46), 250),
+    ("periodcentered", Some(180), 250),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 564),
+    ("plusminus", None, 564),
+    ("q", Some(113), 500),
+    ("question", Some(63), 444),
+    ("questiondown", Some(191), 444),
+    ("quotedbl", Some(34), 408),
+    ("quotedblbase", Some(185), 444),
+    ("quotedblleft", Some(170), 444),
+    ("quotedblright", Some(186), 444),
+    ("quoteleft", Some(96), 333),
+    ("quoteright", Some(39), 333),
+    ("quotesinglbase", Some(184), 333),
+    ("quotesingle", Some(169), 180),
+    ("r", Some(114), 333),
+    ("racute", None, 333),
+    ("radical", None, 453),
+    ("rcaron", None, 333),
+    ("rcommaaccent", None, 333),
+    ("registered", None, 760),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 389),
+    ("sacute", None, 389),
+    ("scaron", None, 389),
+    ("scedilla", None, 389),
+    ("scommaaccent", None, 389),
+    ("section", Some(167), 500),
+    ("semicolon", Some(59), 278),
+    ("seven", Some(55), 500),
+    ("six", Some(54), 500),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 250),
+    ("sterling", Some(163), 500),
+    ("summation", None, 600),
+    ("t", Some(116), 278),
+    ("tcaron", None, 326),
+    ("tcommaaccent", None, 278),
+    ("thorn", None, 500),
+    ("three", Some(51), 500),
+    ("threequarters", None, 750),
+    ("threesuperior", None, 300),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 980),
+    ("two", Some(50), 500),
+    ("twosuperior", None, 300),
+    ("u", Some(117), 500),
+    ("uacute", None, 500),
+    ("ucircumflex", None, 500),
+    ("udieresis", None, 500),
+    ("ugrave", None, 500),
+    ("uhungarumlaut", None, 500),
+    ("umacron", None, 500),
+    ("underscore", Some(95), 500),
+    ("uogonek", None, 500),
+    ("uring", None, 500),
+    ("v", Some(118), 500),
+    ("w", Some(119), 722),
+    ("x", Some(120), 500),
+    ("y", Some(121), 500),
+    ("yacute", None, 500),
+    ("ydieresis", None, 500),
+    ("yen", Some(165), 500),
+    ("z", Some(122), 444),
+    ("zacute", None, 444),
+    ("zcaron", None, 444),
+    ("zdotaccent", None, 444),
+    ("zero", Some(48), 500),
+];
+
+pub(super) const TIMES_BOLD: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 722),
+    ("AE", Some(225), 1000),
+    ("Aacute", None, 722),
+    ("Abreve", None, 722),
+    ("Acircumflex", None, 722),
+    ("Adieresis", None, 722),
+    ("Agrave", None, 722),
+    ("Amacron", None, 722),
+    ("Aogonek", None, 722),
+    ("Aring", None, 722),
+    ("Atilde", None, 722),
+    ("B", Some(66), 667),
+    ("C", Some(67), 722),
+    ("Cacute", None, 722),
+    ("Ccaron", None, 722),
+    ("Ccedilla", None, 722),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 667),
+    ("Eacute", None, 667),
+    ("Ecaron", None, 667),
+    ("Ecircumflex", None, 667),
+    ("Edieresis", None, 667),
+    ("Edotaccent", None, 667),
+    ("Egrave", None, 667),
+    ("Emacron", None, 667),
+    ("Eogonek", None, 667),
+    ("Eth", None, 722),
+    ("Euro", None, 500),
+    ("F", Some(70), 611),
+    ("G", Some(71), 778),
+    ("Gbreve", None, 778),
+    ("Gcommaaccent", None, 778),
+    ("H", Some(72), 778),
+    ("I", Some(73), 389),
+    ("Iacute", None, 389),
+    ("Icircumflex", None, 389),
+    ("Idieresis", None, 389),
+    ("Idotaccent", None, 389),
+    ("Igrave", None, 389),
+    ("Imacron", None, 389),
+    ("Iogonek", None, 389),
+    ("J", Some(74), 500),
+    ("K", Some(75), 778),
+    ("Kcommaaccent", None, 778),
+    ("L", Some(76), 667),
+    ("Lacute", None, 667),
+    ("Lcaron", None, 667),
+    ("Lcommaaccent", None, 667),
+    ("Lslash", Some(232), 667),
+    ("M", Some(77), 944),
+    ("N", Some(78), 722),
+    ("Nacute", None, 722),
+    ("Ncaron", None, 722),
+    ("Ncommaaccent", None, 722),
+    ("Ntilde", None, 722),
+    ("O", Some(79), 778),
+    ("OE", Some(234), 1000),
+    ("Oacute", None, 778),
+    ("Ocircumflex", None, 778),
+    ("Odieresis", None, 778),
+    ("Ograve", None, 778),
+    ("Ohungarumlaut", None, 778),
+    ("Omacron", None, 778),
+    ("Oslash", Some(233), 778),
+    ("Otilde", None, 778),
+    ("P", Some(80), 611),
+    ("Q", Some(81), 778),
+    ("R", Some(82), 722),
+    ("Racute", None, 722),
+    ("Rcaron", None, 722),
+    ("Rcommaaccent", None, 722),
+    ("S", Some(83), 556),
+    ("Sacute", None, 556),
+    ("Scaron", None, 556),
+    ("Scedilla", None, 556),
+    ("Scommaaccent", None, 556),
+    ("T", Some(84), 667),
+    ("Tcaron", None, 667),
+    ("Tcommaaccent", None, 667),
+    ("Thorn", None, 611),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 722),
+    ("W", Some(87), 1000),
+    ("X", Some(88), 722),
+    ("Y", Some(89), 722),
+    ("Yacute", None, 722),
+    ("Ydieresis", None, 722),
+    ("Z", Some(90), 667),
+    ("Zacute", None, 667),
+    ("Zcaron", None, 667),
+    ("Zdotaccent", None, 667),
+    ("a", Some(97), 500),
+    ("aacute", None, 500),
+    ("abreve", None, 500),
+    ("acircumflex", None, 500),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 500),
+    ("ae", Some(241), 722),
+    ("agrave", None, 500),
+    ("amacron", None, 500),
+    ("ampersand", Some(38), 833),
+    ("aogonek", None, 500),
+    ("aring", None, 500),
+    ("asciicircum", Some(94), 581),
+    ("asciitilde", Some(126), 520),
+    ("asterisk", Some(42), 500),
+    ("at", Some(64), 930),
+    ("atilde", None, 500),
+    ("b", Some(98), 556),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 220),
+    ("braceleft", Some(123), 394),
+    ("braceright", Some(125), 394),
+    ("bracketleft", Some(91), 333),
+    ("bracketright", Some(93), 333),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 220),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 444),
+    ("cacute", None, 444),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 444),
+    ("ccedilla", None, 444),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 500),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 333),
+    ("comma", Some(44), 250),
+    ("commaaccent", None, 250),
+    ("copyright", None, 747),
+    ("currency", Some(168), 500),
+    ("d", Some(100), 556),
+    ("dagger", Some(178), 500),
+    ("daggerdbl", Some(179), 500),
+    ("dcaron", None, 672),
+    ("dcroat", None, 556),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 570),
+    ("dollar", Some(36), 500),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 444),
+    ("eacute", None, 444),
+    ("ecaron", None, 444),
+    ("ecircumflex", None, 444),
+    ("edieresis", None, 444),
+    ("edotaccent", None, 444),
+    ("egrave", None, 444),
+    ("eight", Some(56), 500),
+    ("ellipsis", Some(188), 1000),
+    ("emacron", None, 444),
+    ("emdash", Some(208), 1000),
+    ("endash", Some(177), 500),
+    ("eogonek", None, 444),
+    ("equal", Some(61), 570),
+    ("eth", None, 500),
+    ("exclam", Some(33), 333),
+    ("exclamdown", Some(161), 333),
+    ("f", Some(102), 333),
+    ("fi", Some(174), 556),
+    ("five", Some(53), 500),
+    ("fl", Some(175), 556),
+    ("florin", Some(166), 500),
+    ("four", Some(52), 500),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 500),
+    ("gbreve", None, 500),
+    ("gcommaaccent", None, 500),
+    ("germandbls", Some(251), 556),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 570),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 500),
+    ("guillemotright", Some(187), 500),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 556),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 278),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 278),
+    ("j", Some(106), 333),
+    ("k", Some(107), 556),
+    ("kcommaaccent", None, 556),
+    ("l", Some(108), 278),
+    ("lacute", None, 278),
+    ("lcaron", None, 394),
+    ("lcommaaccent", None, 278),
+    ("less", Some(60), 570),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 570),
+    ("lozenge", None, 494),
+    ("lslash", Some(248), 278),
+    ("m", Some(109), 833),
+    ("macron", Some(197), 333),
+    ("minus", None, 570),
+    ("mu", None, 556),
+    ("multiply", None, 570),
+    ("n", Some(110), 556),
+    ("nacute", None, 556),
+    ("ncaron", None, 556),
+    ("ncommaaccent", None, 556),
+    ("nine", Some(57), 500),
+    ("notequal", None, 549),
+    ("ntilde", None, 556),
+    ("numbersign", Some(35), 500),
+    ("o", Some(111), 500),
+    ("oacute", None, 500),
+    ("ocircumflex", None, 500),
+    ("odieresis", None, 500),
+    ("oe", Some(250), 722),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 500),
+    ("ohungarumlaut", None, 500),
+    ("omacron", None, 500),
+    ("one", Some(49), 500),
+    ("onehalf", None, 750),
+    ("onequarter", None, 750),
+    ("onesuperior", None, 300),
+    ("ordfeminine", Some(227), 300),
+    ("ordmasculine", Some(235), 330),
+    ("oslash", Some(249), 500),
+    ("otilde", None, 500),
+    ("p", Some(112), 556),
+    ("paragraph", Some(182), 540),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 494),
+    ("percent", Some(37), 1000),
+    ("period", Some(46), 250),
+    ("periodcentered", Some(180), 250),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 570),
+    ("plusminus", None, 570),
+    ("q", Some(113), 556),
+    ("question", Some(63), 500),
+    ("questiondown", Some(191), 500),
+    ("quotedbl", Some(34), 555),
+    ("quotedblbase", Some(185), 500),
+    ("quotedblleft", Some(170), 500),
+    ("quotedblright", Some(186), 500),
+    ("quoteleft", Some(96), 333),
+    ("quoteright", Some(39), 333),
+    ("quotesinglbase", Some(184), 333),
+    ("quotesingle", Some(169), 278),
+    ("r", Some(114), 444),
+    ("racute", None, 444),
+    ("radical", None, 549),
+    ("rcaron", None, 444),
+    ("rcommaaccent", None, 444),
+    ("registered", None, 747),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 389),
+    ("sacute", None, 389),
+    ("scaron", None, 389),
+    ("scedilla", None, 389),
+    ("scommaaccent", None, 389),
+    ("section", Some(167), 500),
+    ("semicolon", Some(59), 333),
+    ("seven", Some(55), 500),
+    ("six", Some(54), 500),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 250),
+    ("sterling", Some(163), 500),
+    ("summation", None, 600),
+    ("t", Some(116), 333),
+    ("tcaron", None, 416),
+    ("tcommaaccent", None, 333),
+    ("thorn", None, 556),
+    ("three", Some(51), 500),
+    ("threequarters", None, 750),
+    ("threesuperior", None, 300),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 1000),
+    ("two", Some(50), 500),
+    ("twosuperior", None, 300),
+    ("u", Some(117), 556),
+    ("uacute", None, 556),
+    ("ucircumflex", None, 556),
+    ("udieresis", None, 556),
+    ("ugrave", None, 556),
+    ("uhungarumlaut", None, 556),
+    ("umacron", None, 556),
+    ("underscore", Some(95), 500),
+    ("uogonek", None, 556),
+    ("uring", None, 556),
+    ("v", Some(118), 500),
+    ("w", Some(119), 722),
+    ("x", Some(120), 500),
+    ("y", Some(121), 500),
+    ("yacute", None, 500),
+    ("ydieresis", None, 500),
+    ("yen", Some(165), 500),
+    ("z", Some(122), 444),
+    ("zacute", None, 444),
+    ("zcaron", None, 444),
+    ("zdotaccent", None, 444),
+    ("zero", Some(48), 500),
+];
+
+pub(super) const TIMES_ITALIC: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 611),
+    ("AE", Some(225), 889),
+    ("Aacute", None, 611),
+    ("Abreve", None, 611),
+    ("Acircumflex", None, 611),
+    ("Adieresis", None, 611),
+    ("Agrave", None, 611),
+    ("Amacron", None, 611),
+    ("Aogonek", None, 611),
+    ("Aring", None, 611),
+    ("Atilde", None, 611),
+    ("B", Some(66), 611),
+    ("C", Some(67), 667),
+    ("Cacute", None, 667),
+    ("Ccaron", None, 667),
+    ("Ccedilla", None, 667),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 611),
+    ("Eacute", None, 611),
+    ("Ecaron", None, 611),
+    ("Ecircumflex", None, 611),
+    ("Edieresis", None, 611),
+    ("Edotaccent", None, 611),
+    ("Egrave", None, 611),
+    ("Emacron", None, 611),
+    ("Eogonek", None, 611),
+    ("Eth", None, 722),
+    ("Euro", None, 500),
+    ("F", Some(70), 611),
+    ("G", Some(71), 722),
+    ("Gbreve", None, 722),
+    ("Gcommaaccent", None, 722),
+    ("H", Some(72), 722),
+    ("I", Some(73), 333),
+    ("Iacute", None, 333),
+    ("Icircumflex", None, 333),
+    ("Idieresis", None, 333),
+    ("Idotaccent", None, 333),
+    ("Igrave", None, 333),
+    ("Imacron", None, 333),
+    ("Iogonek", None, 333),
+    ("J", Some(74), 444),
+    ("K", Some(75), 667),
+    ("Kcommaaccent", None, 667),
+    ("L", Some(76), 556),
+    ("Lacute", None, 556),
+    ("Lcaron", None, 611),
+    ("Lcommaaccent", None, 556),
+    ("Lslash", Some(232), 556),
+    ("M", Some(77), 833),
+    ("N", Some(78), 667),
+    ("Nacute", None, 667),
+    ("Ncaron", None, 667),
+    ("Ncommaaccent", None, 667),
+    ("Ntilde", None, 667),
+    ("O", Some(79), 722),
+    ("OE", Some(234), 944),
+    ("Oacute", None, 722),
+    ("Ocircumflex", None, 722),
+    ("Odieresis", None, 722),
+    ("Ograve", None, 722),
+    ("Ohungarumlaut", None, 722),
+    ("Omacron", None, 722),
+    ("Oslash", Some(233), 722),
+    ("Otilde", None, 722),
+    ("P", Some(80), 611),
+    ("Q", Some(81), 722),
+    ("R", Some(82), 611),
+    ("Racute", None, 611),
+    ("Rcaron", None, 611),
+    ("Rcommaaccent", None, 611),
+    ("S", Some(83), 500),
+    ("Sacute", None, 500),
+    ("Scaron", None, 500),
+    ("Scedilla", None, 500),
+    ("Scommaaccent", None, 500),
+    ("T", Some(84), 556),
+    ("Tcaron", None, 556),
+    ("Tcommaaccent", None, 556),
+    ("Thorn", None, 611),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 611),
+    ("W", Some(87), 833),
+    ("X", Some(88), 611),
+    ("Y", Some(89), 556),
+    ("Yacute", None, 556),
+    ("Ydieresis", None, 556),
+    ("Z", Some(90), 556),
+    ("Zacute", None, 556),
+    ("Zcaron", None, 556),
+    ("Zdotaccent", None, 556),
+    ("a", Some(97), 500),
+    ("aacute", None, 500),
+    ("abreve", None, 500),
+    ("acircumflex", None, 500),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 500),
+    ("ae", Some(241), 667),
+    ("agrave", None, 500),
+    ("amacron", None, 500),
+    ("ampersand", Some(38), 778),
+    ("aogonek", None, 500),
+    ("aring", None, 500),
+    ("asciicircum", Some(94), 422),
+    ("asciitilde", Some(126), 541),
+    ("asterisk", Some(42), 500),
+    ("at", Some(64), 920),
+    ("atilde", None, 500),
+    ("b", Some(98), 500),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 275),
+    ("braceleft", Some(123), 400),
+    ("braceright", Some(125), 400),
+    ("bracketleft", Some(91), 389),
+    ("bracketright", Some(93), 389),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 275),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 444),
+    ("cacute", None, 444),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 444),
+    ("ccedilla", None, 444),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 500),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 333),
+    ("comma", Some(44), 250),
+    ("commaaccent", None, 250),
+    ("copyright", None, 760),
+    ("currency", Some(168), 500),
+    ("d", Some(100), 500),
+    ("dagger", Some(178), 500),
+    ("daggerdbl", Some(179), 500),
+    ("dcaron", None, 544),
+    ("dcroat", None, 500),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 675),
+    ("dollar", Some(36), 500),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 444),
+    ("eacute", None, 444),
+    ("ecaron", None, 444),
+    ("ecircumflex", None, 444),
+    ("edieresis", None, 444),
+    ("edotaccent", None, 444),
+    ("egrave", None, 444),
+    ("eight", Some(56), 500),
+    ("ellipsis", Some(188), 889),
+    ("emacron", None, 444),
+    ("emdash", Some(208), 889),
+    ("endash", Some(177), 500),
+    ("eogonek", None, 444),
+    ("equal", Some(61), 675),
+    ("eth", None, 500),
+    ("exclam", Some(33), 333),
+    ("exclamdown", Some(161), 389),
+    ("f", Some(102), 278),
+    ("fi", Some(174), 500),
+    ("five", Some(53), 500),
+    ("fl", Some(175), 500),
+    ("florin", Some(166), 500),
+    ("four", Some(52), 500),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 500),
+    ("gbreve", None, 500),
+    ("gcommaaccent", None, 500),
+    ("germandbls", Some(251), 500),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 675),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 500),
+    ("guillemotright", Some(187), 500),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 500),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 278),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 278),
+    ("j", Some(106), 278),
+    ("k", Some(107), 444),
+    ("kcommaaccent", None, 444),
+    ("l", Some(108), 278),
+    ("lacute", None, 278),
+    ("lcaron", None, 300),
+    ("lcommaaccent", None, 278),
+    ("less", Some(60), 675),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 675),
+    ("lozenge", None, 471),
+    ("lslash", Some(248), 278),
+    ("m", Some(109), 722),
+    ("macron", Some(197), 333),
+    ("minus", None, 675),
+    ("mu", None, 500),
+    ("multiply", None, 675),
+    ("n", Some(110), 500),
+    ("nacute", None, 500),
+    ("ncaron", None, 500),
+    ("ncommaaccent", None, 500),
+    ("nine", Some(57), 500),
+    ("notequal", None, 549),
+    ("ntilde", None, 500),
+    ("numbersign", Some(35), 500),
+    ("o", Some(111), 500),
+    ("oacute", None, 500),
+    ("ocircumflex", None, 500),
+    ("odieresis", None, 500),
+    ("oe", Some(250), 667),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 500),
+    ("ohungarumlaut", None, 500),
+    ("omacron", None, 500),
+    ("one", Some(49), 500),
+    ("onehalf", None, 750),
+    ("onequarter", None, 750),
+    ("onesuperior", None, 300),
+    ("ordfeminine", Some(227), 276),
+    ("ordmasculine", Some(235), 310),
+    ("oslash", Some(249), 500),
+    ("otilde", None, 500),
+    ("p", Some(112), 500),
+    ("paragraph", Some(182), 523),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 476),
+    ("percent", Some(37), 833),
+    ("period", Some(46), 250),
+    ("periodcentered", Some(180), 250),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 675),
+    ("plusminus", None, 675),
+    ("q", Some(113), 500),
+    ("question", Some(63), 500),
+    ("questiondown", Some(191), 500),
+    ("quotedbl", Some(34), 420),
+    ("quotedblbase", Some(185), 556),
+    ("quotedblleft", Some(170), 556),
+    ("quotedblright", Some(186), 556),
+    ("quoteleft", Some(96), 333),
+    ("quoteright", Some(39), 333),
+    ("quotesinglbase", Some(184), 333),
+    ("quotesingle", Some(169), 214),
+    ("r", Some(114), 389),
+    ("racute", None, 389),
+    ("radical", None, 453),
+    ("rcaron", None, 389),
+    ("rcommaaccent", None, 389),
+    ("registered", None, 760),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 389),
+    ("sacute", None, 389),
+    ("scaron", None, 389),
+    ("scedilla", None, 389),
+    ("scommaaccent", None, 389),
+    ("section", Some(167), 500),
+    ("semicolon", Some(59), 333),
+    ("seven", Some(55), 500),
+    ("six", Some(54), 500),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 250),
+    ("sterling", Some(163), 500),
+    ("summation", None, 600),
+    ("t", Some(116), 278),
+    ("tcaron", None, 300),
+    ("tcommaaccent", None, 278),
+    ("thorn", None, 500),
+    ("three", Some(51), 500),
+    ("threequarters", None, 750),
+    ("threesuperior", None, 300),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 980),
+    ("two", Some(50), 500),
+    ("twosuperior", None, 300),
+    ("u", Some(117), 500),
+    ("uacute", None, 500),
+    ("ucircumflex", None, 500),
+    ("udieresis", None, 500),
+    ("ugrave", None, 500),
+    ("uhungarumlaut", None, 500),
+    ("umacron", None, 500),
+    ("underscore", Some(95), 500),
+    ("uogonek", None, 500),
+    ("uring", None, 500),
+    ("v", Some(118), 444),
+    ("w", Some(119), 667),
+    ("x", Some(120), 444),
+    ("y", Some(121), 444),
+    ("yacute", None, 444),
+    ("ydieresis", None, 444),
+    ("yen", Some(165), 500),
+    ("z", Some(122), 389),
+    ("zacute", None, 389),
+    ("zcaron", None, 389),
+    ("zdotaccent", None, 389),
+    ("zero", Some(48), 500),
+];
+
+pub(super) const TIMES_BOLDITALIC: &[(&str, Option<u8>, u16)] = &[
+    ("A", Some(65), 667),
+    ("AE", Some(225), 944),
+    ("Aacute", None, 667),
+    ("Abreve", None, 667),
+    ("Acircumflex", None, 667),
+    ("Adieresis", None, 667),
+    ("Agrave", None, 667),
+    ("Amacron", None, 667),
+    ("Aogonek", None, 667),
+    ("Aring", None, 667),
+    ("Atilde", None, 667),
+    ("B", Some(66), 667),
+    ("C", Some(67), 667),
+    ("Cacute", None, 667),
+    ("Ccaron", None, 667),
+    ("Ccedilla", None, 667),
+    ("D", Some(68), 722),
+    ("Dcaron", None, 722),
+    ("Dcroat", None, 722),
+    ("Delta", None, 612),
+    ("E", Some(69), 667),
+    ("Eacute", None, 667),
+    ("Ecaron", None, 667),
+    ("Ecircumflex", None, 667),
+    ("Edieresis", None, 667),
+    ("Edotaccent", None, 667),
+    ("Egrave", None, 667),
+    ("Emacron", None, 667),
+    ("Eogonek", None, 667),
+    ("Eth", None, 722),
+    ("Euro", None, 500),
+    ("F", Some(70), 667),
+    ("G", Some(71), 722),
+    ("Gbreve", None, 722),
+    ("Gcommaaccent", None, 722),
+    ("H", Some(72), 778),
+    ("I", Some(73), 389),
+    ("Iacute", None, 389),
+    ("Icircumflex", None, 389),
+    ("Idieresis", None, 389),
+    ("Idotaccent", None, 389),
+    ("Igrave", None, 389),
+    ("Imacron", None, 389),
+    ("Iogonek", None, 389),
+    ("J", Some(74), 500),
+    ("K", Some(75), 667),
+    ("Kcommaaccent", None, 667),
+    ("L", Some(76), 611),
+    ("Lacute", None, 611),
+    ("Lcaron", None, 611),
+    ("Lcommaaccent", None, 611),
+    ("Lslash", Some(232), 611),
+    ("M", Some(77), 889),
+    ("N", Some(78), 722),
+    ("Nacute", None, 722),
+    ("Ncaron", None, 722),
+    ("Ncommaaccent", None, 722),
+    ("Ntilde", None, 722),
+    ("O", Some(79), 722),
+    ("OE", Some(234), 944),
+    ("Oacute", None, 722),
+    ("Ocircumflex", None, 722),
+    ("Odieresis", None, 722),
+    ("Ograve", None, 722),
+    ("Ohungarumlaut", None, 722),
+    ("Omacron", None, 722),
+    ("Oslash", Some(233), 722),
+    ("Otilde", None, 722),
+    ("P", Some(80), 611),
+    ("Q", Some(81), 722),
+    ("R", Some(82), 667),
+    ("Racute", None, 667),
+    ("Rcaron", None, 667),
+    ("Rcommaaccent", None, 667),
+    ("S", Some(83), 556),
+    ("Sacute", None, 556),
+    ("Scaron", None, 556),
+    ("Scedilla", None, 556),
+    ("Scommaaccent", None, 556),
+    ("T", Some(84), 611),
+    ("Tcaron", None, 611),
+    ("Tcommaaccent", None, 611),
+    ("Thorn", None, 611),
+    ("U", Some(85), 722),
+    ("Uacute", None, 722),
+    ("Ucircumflex", None, 722),
+    ("Udieresis", None, 722),
+    ("Ugrave", None, 722),
+    ("Uhungarumlaut", None, 722),
+    ("Umacron", None, 722),
+    ("Uogonek", None, 722),
+    ("Uring", None, 722),
+    ("V", Some(86), 667),
+    ("W", Some(87), 889),
+    ("X", Some(88), 667),
+    ("Y", Some(89), 611),
+    ("Yacute", None, 611),
+    ("Ydieresis", None, 611),
+    ("Z", Some(90), 611),
+    ("Zacute", None, 611),
+    ("Zcaron", None, 611),
+    ("Zdotaccent", None, 611),
+    ("a", Some(97), 500),
+    ("aacute", None, 500),
+    ("abreve", None, 500),
+    ("acircumflex", None, 500),
+    ("acute", Some(194), 333),
+    ("adieresis", None, 500),
+    ("ae", Some(241), 722),
+    ("agrave", None, 500),
+    ("amacron", None, 500),
+    ("ampersand", Some(38), 778),
+    ("aogonek", None, 500),
+    ("aring", None, 500),
+    ("asciicircum", Some(94), 570),
+    ("asciitilde", Some(126), 570),
+    ("asterisk", Some(42), 500),
+    ("at", Some(64), 832),
+    ("atilde", None, 500),
+    ("b", Some(98), 500),
+    ("backslash", Some(92), 278),
+    ("bar", Some(124), 220),
+    ("braceleft", Some(123), 348),
+    ("braceright", Some(125), 348),
+    ("bracketleft", Some(91), 333),
+    ("bracketright", Some(93), 333),
+    ("breve", Some(198), 333),
+    ("brokenbar", None, 220),
+    ("bullet", Some(183), 350),
+    ("c", Some(99), 444),
+    ("cacute", None, 444),
+    ("caron", Some(207), 333),
+    ("ccaron", None, 444),
+    ("ccedilla", None, 444),
+    ("cedilla", Some(203), 333),
+    ("cent", Some(162), 500),
+    ("circumflex", Some(195), 333),
+    ("colon", Some(58), 333),
+    ("comma", Some(44), 250),
+    ("commaaccent", None, 250),
+    ("copyright", None, 747),
+    ("currency", Some(168), 500),
+    ("d", Some(100), 500),
+    ("dagger", Some(178), 500),
+    ("daggerdbl", Some(179), 500),
+    ("dcaron", None, 608),
+    ("dcroat", None, 500),
+    ("degree", None, 400),
+    ("dieresis", Some(200), 333),
+    ("divide", None, 570),
+    ("dollar", Some(36), 500),
+    ("dotaccent", Some(199), 333),
+    ("dotlessi", Some(245), 278),
+    ("e", Some(101), 444),
+    ("eacute", None, 444),
+    ("ecaron", None, 444),
+    ("ecircumflex", None, 444),
+    ("edieresis", None, 444),
+    ("edotaccent", None, 444),
+    ("egrave", None, 444),
+    ("eight", Some(56), 500),
+    ("ellipsis", Some(188), 1000),
+    ("emacron", None, 444),
+    ("emdash", Some(208), 1000),
+    ("endash", Some(177), 500),
+    ("eogonek", None, 444),
+    ("equal", Some(61), 570),
+    ("eth", None, 500),
+    ("exclam", Some(33), 389),
+    ("exclamdown", Some(161), 389),
+    ("f", Some(102), 333),
+    ("fi", Some(174), 556),
+    ("five", Some(53), 500),
+    ("fl", Some(175), 556),
+    ("florin", Some(166), 500),
+    ("four", Some(52), 500),
+    ("fraction", Some(164), 167),
+    ("g", Some(103), 500),
+    ("gbreve", None, 500),
+    ("gcommaaccent", None, 500),
+    ("germandbls", Some(251), 500),
+    ("grave", Some(193), 333),
+    ("greater", Some(62), 570),
+    ("greaterequal", None, 549),
+    ("guillemotleft", Some(171), 500),
+    ("guillemotright", Some(187), 500),
+    ("guilsinglleft", Some(172), 333),
+    ("guilsinglright", Some(173), 333),
+    ("h", Some(104), 556),
+    ("hungarumlaut", Some(205), 333),
+    ("hyphen", Some(45), 333),
+    ("i", Some(105), 278),
+    ("iacute", None, 278),
+    ("icircumflex", None, 278),
+    ("idieresis", None, 278),
+    ("igrave", None, 278),
+    ("imacron", None, 278),
+    ("iogonek", None, 278),
+    ("j", Some(106), 278),
+    ("k", Some(107), 500),
+    ("kcommaaccent", None, 500),
+    ("l", Some(108), 278),
+    ("lacute", None, 278),
+    ("lcaron", None, 382),
+    ("lcommaaccent", None, 278),
+    ("less", Some(60), 570),
+    ("lessequal", None, 549),
+    ("logicalnot", None, 606),
+    ("lozenge", None, 494),
+    ("lslash", Some(248), 278),
+    ("m", Some(109), 778),
+    ("macron", Some(197), 333),
+    ("minus", None, 606),
+    ("mu", None, 576),
+    ("multiply", None, 570),
+    ("n", Some(110), 556),
+    ("nacute", None, 556),
+    ("ncaron", None, 556),
+    ("ncommaaccent", None, 556),
+    ("nine", Some(57), 500),
+    ("notequal", None, 549),
+    ("ntilde", None, 556),
+    ("numbersign", Some(35), 500),
+    ("o", Some(111), 500),
+    ("oacute", None, 500),
+    ("ocircumflex", None, 500),
+    ("odieresis", None, 500),
+    ("oe", Some(250), 722),
+    ("ogonek", Some(206), 333),
+    ("ograve", None, 500),
+    ("ohungarumlaut", None, 500),
+    ("omacron", None, 500),
+    ("one", Some(49), 500),
+    ("onehalf", None, 750),
+    ("onequarter", None, 750),
+    ("onesuperior", None, 300),
+    ("ordfeminine", Some(227), 266),
+    ("ordmasculine", Some(235), 300),
+    ("oslash", Some(249), 500),
+    ("otilde", None, 500),
+    ("p", Some(112), 500),
+    ("paragraph", Some(182), 500),
+    ("parenleft", Some(40), 333),
+    ("parenright", Some(41), 333),
+    ("partialdiff", None, 494),
+    ("percent", Some(37), 833),
+    ("period", Some(46), 250),
+    ("periodcentered", Some(180), 250),
+    ("perthousand", Some(189), 1000),
+    ("plus", Some(43), 570),
+    ("plusminus", None, 570),
+    ("q", Some(113), 500),
+    ("question", Some(63), 500),
+    ("questiondown", Some(191), 500),
+    ("quotedbl", Some(34), 555),
+    ("quotedblbase", Some(185), 500),
+    ("quotedblleft", Some(170), 500),
+    ("quotedblright", Some(186), 500),
+    ("quoteleft", Some(96), 333),
+    ("quoteright", Some(39), 333),
+    ("quotesinglbase", Some(184), 333),
+    ("quotesingle", Some(169), 278),
+    ("r", Some(114), 389),
+    ("racute", None, 389),
+    ("radical", None, 549),
+    ("rcaron", None, 389),
+    ("rcommaaccent", None, 389),
+    ("registered", None, 747),
+    ("ring", Some(202), 333),
+    ("s", Some(115), 389),
+    ("sacute", None, 389),
+    ("scaron", None, 389),
+    ("scedilla", None, 389),
+    ("scommaaccent", None, 389),
+    ("section", Some(167), 500),
+    ("semicolon", Some(59), 333),
+    ("seven", Some(55), 500),
+    ("six", Some(54), 500),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 250),
+    ("sterling", Some(163), 500),
+    ("summation", None, 600),
+    ("t", Some(116), 278),
+    ("tcaron", None, 366),
+    ("tcommaaccent", None, 278),
+    ("thorn", None, 500),
+    ("three", Some(51), 500),
+    ("threequarters", None, 750),
+    ("threesuperior", None, 300),
+    ("tilde", Some(196), 333),
+    ("trademark", None, 1000),
+    ("two", Some(50), 500),
+    ("twosuperior", None, 300),
+    ("u", Some(117), 556),
+    ("uacute", None, 556),
+    ("ucircumflex", None, 556),
+    ("udieresis", None, 556),
+    ("ugrave", None, 556),
+    ("uhungarumlaut", None, 556),
+    ("umacron", None, 556),
+    ("underscore", Some(95), 500),
+    ("uogonek", None, 556),
+    ("uring", None, 556),
+    ("v", Some(118), 444),
+    ("w", Some(119), 667),
+    ("x", Some(120), 500),
+    ("y", Some(121), 444),
+    ("yacute", None, 444),
+    ("ydieresis", None, 444),
+    ("yen", Some(165), 500),
+    ("z", Some(122), 389),
+    ("zacute", None, 389),
+    ("zcaron", None, 389),
+    ("zdotaccent", None, 389),
+    ("zero", Some(48), 500),
+];
+
+pub(super) const SYMBOL: &[(&str, Option<u8>, u16)] = &[
+    ("Alpha", Some(65), 722),
+    ("Beta", Some(66), 667),
+    ("Chi", Some(67), 722),
+    ("Delta", Some(68), 612),
+    ("Epsilon", Some(69), 611),
+    ("Eta", Some(72), 722),
+    ("Euro", Some(160), 750),
+    ("Gamma", Some(71), 603),
+    ("Ifraktur", Some(193), 686),
+    ("Iota", Some(73), 333),
+    ("Kappa", Some(75), 722),
+    ("Lambda", Some(76), 686),
+    ("Mu", Some(77), 889),
+    ("Nu", Some(78), 722),
+    ("Omega", Some(87), 768),
+    ("Omicron", Some(79), 722),
+    ("Phi", Some(70), 763),
+    ("Pi", Some(80), 768),
+    ("Psi", Some(89), 795),
+    ("Rfraktur", Some(194), 795),
+    ("Rho", Some(82), 556),
+    ("Sigma", Some(83), 592),
+    ("Tau", Some(84), 611),
+    ("Theta", Some(81), 741),
+    ("Upsilon", Some(85), 690),
+    ("Upsilon1", Some(161), 620),
+    ("Xi", Some(88), 645),
+    ("Zeta", Some(90), 611),
+    ("aleph", Some(192), 823),
+    ("alpha", Some(97), 631),
+    ("ampersand", Some(38), 778),
+    ("angle", Some(208), 768),
+    ("angleleft", Some(225), 329),
+    ("angleright", Some(241), 329),
+    ("apple", None, 790),
+    ("approxequal", Some(187), 549),
+    ("arrowboth", Some(171), 1042),
+    ("arrowdblboth", Some(219), 1042),
+    ("arrowdbldown", Some(223), 603),
+    ("arrowdblleft", Some(220), 987),
+    ("arrowdblright", Some(222), 987),
+    ("arrowdblup", Some(221), 603),
+    ("arrowdown", Some(175), 603),
+    ("arrowhorizex", Some(190), 1000),
+    ("arrowleft", Some(172), 987),
+    ("arrowright", Some(174), 987),
+    ("arrowup", Some(173), 603),
+    ("arrowvertex", Some(189), 603),
+    ("asteriskmath", Some(42), 500),
+    ("bar", Some(124), 200),
+    ("beta", Some(98), 549),
+    ("braceex", Some(239), 494),
+    ("braceleft", Some(123), 480),
+    ("braceleftbt", Some(238), 494),
+    ("braceleftmid", Some(237), 494),
+    ("bracelefttp", Some(236), 494),
+    ("braceright", Some(125), 480),
+    ("bracerightbt", Some(254), 494),
+    ("bracerightmid", Some(253), 494),
+    ("bracerighttp", Some(252), 494),
+    ("bracketleft", Some(91), 333),
+    ("bracketleftbt", Some(235), 384),
+    ("bracketleftex", Some(234), 384),
+    ("bracketlefttp", Some(233), 384),
+    ("bracketright", Some(93), 333),
+    ("bracketrightbt", Some(251), 384),
+    ("bracketrightex", Some(250), 384),
+    ("bracketrighttp", Some(249), 384),
+    ("bullet", Some(183), 460),
+    ("carriagereturn", Some(191), 658),
+    ("chi", Some(99), 549),
+    ("circlemultiply", Some(196), 768),
+    ("circleplus", Some(197), 768),
+    ("club", Some(167), 753),
+    ("colon", Some(58), 278),
+    ("comma", Some(44), 250),
+    ("congruent", Some(64), 549),
+    ("copyrightsans", Some(227), 790),
+    ("copyrightserif", Some(211), 790),
+    ("degree", Some(176), 400),
+    ("delta", Some(100), 494),
+    ("diamond", Some(168), 753),
+    ("divide", Some(184), 549),
+    ("dotmath", Some(215), 250),
+    ("eight", Some(56), 500),
+    ("element", Some(206), 713),
+    ("ellipsis", Some(188), 1000),
+    ("emptyset", Some(198), 823),
+    ("epsilon", Some(101), 439),
+    ("equal", Some(61), 549),
+    ("equivalence", Some(186), 549),
+    ("eta", Some(104), 603),
+    ("exclam", Some(33), 333),
+    ("existential", Some(36), 549),
+    ("five", Some(53), 500),
+    ("florin", Some(166), 500),
+    ("four", Some(52), 500),
+    ("fraction", Some(164), 167),
+    ("gamma", Some(103), 411),
+    ("gradient", Some(209), 713),
+    ("greater", Some(62), 549),
+    ("greaterequal", Some(179), 549),
+    ("heart", Some(169), 753),
+    ("infinity", Some(165), 713),
+    ("integral", Some(242), 274),
+    ("integralbt", Some(245), 686),
+    ("integralex", Some(244), 686),
+    ("integraltp", Some(243), 686),
+    ("intersection", Some(199), 768),
+    ("iota", Some(105), 329),
+    ("kappa", Some(107), 549),
+    ("lambda", Some(108), 549),
+    ("less", Some(60), 549),
+    ("lessequal", Some(163), 549),
+    ("logicaland", Some(217), 603),
+    ("logicalnot", Some(216), 713),
+    ("logicalor", Some(218), 603),
+    ("lozenge", Some(224), 494),
+    ("minus", Some(45), 549),
+    ("minute", Some(162), 247),
+    ("mu", Some(109), 576),
+    ("multiply", Some(180), 549),
+    ("nine", Some(57), 500),
+    ("notelement", Some(207), 713),
+    ("notequal", Some(185), 549),
+    ("notsubset", Some(203), 713),
+    ("nu", Some(110), 521),
+    ("numbersign", Some(35), 500),
+    ("omega", Some(119), 686),
+    ("omega1", Some(118), 713),
+    ("omicron", Some(111), 549),
+    ("one", Some(49), 500),
+    ("parenleft", Some(40), 333),
+    ("parenleftbt", Some(232), 384),
+    ("parenleftex", Some(231), 384),
+    ("parenlefttp", Some(230), 384),
+    ("parenright", Some(41), 333),
+    ("parenrightbt", Some(248), 384),
+    ("parenrightex", Some(247), 384),
+    ("parenrighttp", Some(246), 384),
+    ("partialdiff", Some(182), 494),
+    ("percent", Some(37), 833),
+    ("period", Some(46), 250),
+    ("perpendicular", Some(94), 658),
+    ("phi", Some(102), 521),
+    ("phi1", Some(106), 603),
+    ("pi", Some(112), 549),
+    ("plus", Some(43), 549),
+    ("plusminus", Some(177), 549),
+    ("product", Some(213), 823),
+    ("propersubset", Some(204), 713),
+    ("propersuperset", Some(201), 713),
+    ("proportional", Some(181), 713),
+    ("psi", Some(121), 686),
+    ("question", Some(63), 444),
+    ("radical", Some(214), 549),
+    ("radicalex", Some(96), 500),
+    ("reflexsubset", Some(205), 713),
+    ("reflexsuperset", Some(202), 713),
+    ("registersans", Some(226), 790),
+    ("registerserif", Some(210), 790),
+    ("rho", Some(114), 549),
+    ("second", Some(178), 411),
+    ("semicolon", Some(59), 278),
+    ("seven", Some(55), 500),
+    ("sigma", Some(115), 603),
+    ("sigma1", Some(86), 439),
+    ("similar", Some(126), 549),
+    ("six", Some(54), 500),
+    ("slash", Some(47), 278),
+    ("space", Some(32), 250),
+    ("spade", Some(170), 753),
+    ("suchthat", Some(39), 439),
+    ("summation", Some(229), 713),
+    ("tau", Some(116), 439),
+    ("therefore", Some(92), 863),
+    ("theta", Some(113), 521),
+    ("theta1", Some(74), 631),
+    ("three", Some(51), 500),
+    ("trademarksans", Some(228), 786),
+    ("trademarkserif", Some(212), 890),
+    ("two", Some(50), 500),
+    ("underscore", Some(95), 500),
+    ("union", Some(200), 768),
+    ("universal", Some(34), 713),
+    ("upsilon", Some(117), 576),
+    ("weierstrass", Some(195), 987),
+    ("xi", Some(120), 493),
+    ("zero", Some(48), 500),
+    ("zeta", Some(122), 494),
+];
+
+pub(super) const ZAPFDINGBATS: &[(&str, Option<u8>, u16)] = &[
+    ("a1", Some(33), 974),
+    ("a10", Some(65), 692),
+    ("a100", Some(126), 668),
+    ("a101", Some(161), 732),
+    ("a102", Some(162), 544),
+    ("a103", Some(163), 544),
+    ("a104", Some(164), 910),
+    ("a105", Some(48), 911),
+    ("a106", Some(165), 667),
+    ("a107", Some(166), 760),
+    ("a108", Some(167), 760),
+    ("a109", Some(171), 626),
+    ("a11", Some(42), 960),
+    ("a110", Some(170), 694),
+    ("a111", Some(169), 595),
+    ("a112", Some(168), 776),
+    ("a117", Some(41), 690),
+    ("a118", Some(40), 791),
+    ("a119", Some(39), 790),
+    ("a12", Some(43), 939),
+    ("a120", Some(172), 788),
+    ("a121", Some(173), 788),
+    ("a122", Some(174), 788),
+    ("a123", Some(175), 788),
+    ("a124", Some(176), 788),
+    ("a125", Some(177), 788),
+    ("a126", Some(178), 788),
+    ("a127", Some(179), 788),
+    ("a128", Some(180), 788),
+    ("a129", Some(181), 788),
+    ("a13", Some(44), 549),
+    ("a130", Some(182), 788),
+    ("a131", Some(183), 788),
+    ("a132", Some(184), 788),
+    ("a133", Some(185), 788),
+    ("a134", Some(186), 788),
+    ("a135", Some(187), 788),
+    ("a136", Some(188), 788),
+    ("a137", Some(189), 788),
+    ("a138", Some(190), 788),
+    ("a139", Some(191), 788),
+    ("a14", Some(45), 855),
+    ("a140", Some(192), 788),
+    ("a141", Some(193), 788),
+    ("a142", Some(194), 788),
+    ("a143", Some(195), 788),
+    ("a144", Some(196), 788),
+    ("a145", Some(197), 788),
+    ("a146", Some(198), 788),
+    ("a147", Some(199), 788),
+    ("a148", Some(200), 788),
+    ("a149", Some(201), 788),
+    ("a15", Some(46), 911),
+    ("a150", Some(202), 788),
+    ("a151", Some(203), 788),
+    ("a152", Some(204), 788),
+    ("a153", Some(205), 788),
+    ("a154", Some(206), 788),
+    ("a155", Some(207), 788),
+    ("a156", Some(208), 788),
+    ("a157", Some(209), 788),
+    ("a158", Some(210), 788),
+    ("a159", Some(211), 788),
+    ("a16", Some(47), 933),
+    ("a160", Some(212), 894),
+    ("a161", Some(213), 838),
+    ("a162", Some(227), 924),
+    ("a163", Some(214), 1016),
+    ("a164", Some(215), 458),
+    ("a165", Some(217), 924),
+    ("a166", Some(219), 918),
+    ("a167", Some(220), 927),
+    ("a168", Some(221), 928),
+    ("a169", Some(222), 928),
+    ("a17", Some(49), 945),
+    ("a170", Some(223), 834),
+    ("a171", Some(224), 873),
+    ("a172", Some(225), 828),
+    ("a173", Some(226), 924),
+    ("a174", Some(228), 917),
+    ("a175", Some(229), 930),
+    ("a176", Some(230), 931),
+    ("a177", Some(231), 463),
+    ("a178", Some(232), 883),
+    ("a179", Some(233), 836),
+    ("a18", Some(50), 974),
+    ("a180", Some(235), 867),
+    ("a181", Some(237), 696),
+    ("a182", Some(239), 874),
+    ("a183", Some(242), 760),
+    ("a184", Some(243), 946),
+    ("a185", Some(245), 865),
+    ("a186", Some(248), 967),
+    ("a187", Some(250), 831),
+    ("a188", Some(251), 873),
+    ("a189", Some(252), 927),
+    ("a19", Some(51), 755),
+    ("a190", Some(253), 970),
+    ("a191", Some(254), 918),
+    ("a192", Some(218), 748),
+    ("a193", Some(234), 836),
+    ("a194", Some(246), 771),
+    ("a195", Some(249), 888),
+    ("a196", Some(216), 748),
+    ("a197", Some(244), 771),
+    ("a198", Some(247), 888),
+    ("a199", Some(236), 867),
+    ("a2", Some(34), 961),
+    ("a20", Some(52), 846),
+    ("a200", Some(238), 696),
+    ("a201", Some(241), 874),
+    ("a202", Some(35), 974),
+    ("a203", Some(112), 762),
+    ("a204", Some(114), 759),
+    ("a205", Some(134), 509),
+    ("a206", Some(136), 410),
+    ("a21", Some(53), 762),
+    ("a22", Some(54), 761),
+    ("a23", Some(55), 571),
+    ("a24", Some(56), 677),
+    ("a25", Some(57), 763),
+    ("a26", Some(58), 760),
+    ("a27", Some(59), 759),
+    ("a28", Some(60), 754),
+    ("a29", Some(66), 786),
+    ("a3", Some(36), 980),
+    ("a30", Some(67), 788),
+    ("a31", Some(68), 788),
+    ("a32", Some(69), 790),
+    ("a33", Some(70), 793),
+    ("a34", Some(71), 794),
+    ("a35", Some(72), 816),
+    ("a36", Some(73), 823),
+    ("a37", Some(74), 789),
+    ("a38", Some(75), 841),
+    ("a39", Some(76), 823),
+    ("a4", Some(37), 719),
+    ("a40", Some(77), 833),
+    ("a41", Some(78), 816),
+    ("a42", Some(79), 831),
+    ("a43", Some(80), 923),
+    ("a44", Some(81), 744),
+    ("a45", Some(82), 723),
+    ("a46", Some(83), 749),
+    ("a47", Some(84), 790),
+    ("a48", Some(85), 792),
+    ("a49", Some(86), 695),
+    ("a5", Some(38), 789),
+    ("a50", Some(87), 776),
+    ("a51", Some(88), 768),
+    ("a52", Some(89), 792),
+    ("a53", Some(90), 759),
+    ("a54", Some(91), 707),
+    ("a55", Some(92), 708),
+    ("a56", Some(93), 682),
+    ("a57", Some(94), 701),
+    ("a58", Some(95), 826),
+    ("a59", Some(96), 815),
+    ("a6", Some(61), 494),
+    ("a60", Some(97), 789),
+    ("a61", Some(98), 789),
+    ("a62", Some(99), 707),
+    ("a63", Some(100), 687),
+    ("a64", Some(101), 696),
+    ("a65", Some(102), 689),
+    ("a66", Some(103), 786),
+    ("a67", Some(104), 787),
+    ("a68", Some(105), 713),
+    ("a69", Some(106), 791),
+    ("a7", Some(62), 552),
+    ("a70", Some(107), 785),
+    ("a71", Some(108), 791),
+    ("a72", Some(109), 873),
+    ("a73", Some(110), 761),
+    ("a74", Some(111), 762),
+    ("a75", Some(113), 759),
+    ("a76", Some(115), 892),
+    ("a77", Some(116), 892),
+    ("a78", Some(117), 788),
+    ("a79", Some(118), 784),
+    ("a8", Some(63), 537),
+    ("a81", Some(119), 438),
+    ("a82", Some(120), 138),
+    ("a83", Some(121), 277),
+    ("a84", Some(122), 415),
+    ("a85", Some(135), 509),
+    ("a86", Some(137), 410),
+    ("a87", Some(138), 234),
+    ("a88", Some(139), 234),
+    ("a89", Some(128), 390),
+    ("a9", Some(64), 577),
+    ("a90", Some(129), 390),
+    ("a91", Some(132), 276),
+    ("a92", Some(133), 276),
+    ("a93", Some(130), 317),
+    ("a94", Some(131), 317),
+    ("a95", Some(140), 334),
+    ("a96", Some(141), 334),
+    ("a97", Some(123), 392),
+    ("a98", Some(124), 392),
+    ("a99", Some(125), 668),
+    ("space", Some(32), 278),
+];
+
+pub(super) const COURIER_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const COURIER_BOLD_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const COURIER_OBLIQUE_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const COURIER_BOLDOBLIQUE_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const HELVETICA_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const HELVETICA_BOLD_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const HELVETICA_OBLIQUE_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const HELVETICA_BOLDOBLIQUE_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const TIMES_ROMAN_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const TIMES_BOLD_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const TIMES_ITALIC_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const TIMES_BOLDITALIC_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const SYMBOL_KERNING: &[(&str, &str, i16)] = &[];
+pub(super) const ZAPFDINGBATS_KERNING: &[(&str, &str, i16)] = &[];
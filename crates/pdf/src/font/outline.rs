@@ -0,0 +1,80 @@
+use lyon::math::{Point, Transform};
+use lyon::path::PathEvent;
+
+/// Collects the path events of a glyph as a charstring draws it. Points are
+/// given in the font's own units and mapped to glyph space, and moving to a
+/// new point closes the contour before.
+#[derive(Debug)]
+pub(super) struct Outline {
+    events: Vec<PathEvent>,
+    transform: Transform,
+    first: Point,
+    current: Point,
+    open: bool,
+}
+
+impl Outline {
+    pub fn new(transform: Transform) -> Self {
+        Outline {
+            events: vec![],
+            transform,
+            first: Point::zero(),
+            current: Point::zero(),
+            open: false,
+        }
+    }
+
+    pub fn move_to(&mut self, to: Point) {
+        self.close();
+        self.current = self.transform.transform_point(to);
+    }
+
+    pub fn line_to(&mut self, to: Point) {
+        self.begin();
+        let to = self.transform.transform_point(to);
+        self.events.push(PathEvent::Line {
+            from: self.current,
+            to,
+        });
+        self.current = to;
+    }
+
+    pub fn curve_to(&mut self, ctrl1: Point, ctrl2: Point, to: Point) {
+        self.begin();
+        let to = self.transform.transform_point(to);
+        self.events.push(PathEvent::Cubic {
+            from: self.current,
+            ctrl1: self.transform.transform_point(ctrl1),
+            ctrl2: self.transform.transform_point(ctrl2),
+            to,
+        });
+        self.current = to;
+    }
+
+    pub fn close(&mut self) {
+        if self.open {
+            self.events.push(PathEvent::End {
+                first: self.first,
+                last: self.current,
+                close: true,
+            });
+            self.current = self.first;
+            self.open = false;
+        }
+    }
+
+    pub fn build(mut self) -> Vec<PathEvent> {
+        self.close();
+        self.events
+    }
+
+    /// Contours only begin once something is drawn, so moving twice in a
+    /// row doesn't leave an empty one
+    fn begin(&mut self) {
+        if !self.open {
+            self.events.push(PathEvent::Begin { at: self.current });
+            self.first = self.current;
+            self.open = true;
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use super::{metrics, FontDescriptor, FIXED_PITCH, ITALIC, NONSYMBOLIC, SERIF, SYMBOLIC};
+use crate::encodings::EncodingTable;
+
+/// The metrics of one of the standard 14 fonts, which every reader has, so
+/// documents can use them without embedding them or giving their widths
+#[derive(Debug, PartialEq)]
+pub struct StandardFont {
+    pub name: &'static str,
+    /// Flags as in a font descriptor's `/Flags`
+    pub flags: u32,
+    /// `[x_min y_min x_max y_max]` bounds of all the glyphs, in glyph space
+    pub font_bbox: [f32; 4],
+    pub italic_angle: f32,
+    pub ascent: f32,
+    pub descent: f32,
+    pub cap_height: f32,
+    glyphs: &'static [(&'static str, Option<u8>, u16)],
+    kerning: &'static [(&'static str, &'static str, i16)],
+}
+
+// Symbol and ZapfDingbats have no ascender, descender or cap height in their
+// AFM files, so the top and bottom of their bounding boxes stand in
+static STANDARD_FONTS: [StandardFont; 14] = [
+    StandardFont {
+        name: "Courier",
+        flags: FIXED_PITCH | SERIF | NONSYMBOLIC,
+        font_bbox: [-23.0, -250.0, 715.0, 805.0],
+        italic_angle: 0.0,
+        ascent: 629.0,
+        descent: -157.0,
+        cap_height: 562.0,
+        glyphs: metrics::COURIER,
+        kerning: metrics::COURIER_KERNING,
+    },
+    StandardFont {
+        name: "Courier-Bold",
+        flags: FIXED_PITCH | SERIF | NONSYMBOLIC,
+        font_bbox: [-113.0, -250.0, 749.0, 801.0],
+        italic_angle: 0.0,
+        ascent: 629.0,
+        descent: -157.0,
+        cap_height: 562.0,
+        glyphs: metrics::COURIER_BOLD,
+        kerning: metrics::COURIER_BOLD_KERNING,
+    },
+    StandardFont {
+        name: "Courier-Oblique",
+        flags: FIXED_PITCH | SERIF | NONSYMBOLIC | ITALIC,
+        font_bbox: [-27.0, -250.0, 849.0, 805.0],
+        italic_angle: -12.0,
+        ascent: 629.0,
+        descent: -157.0,
+        cap_height: 562.0,
+        glyphs: metrics::COURIER_OBLIQUE,
+        kerning: metrics::COURIER_OBLIQUE_KERNING,
+    },
+    StandardFont {
+        name: "Courier-BoldOblique",
+        flags: FIXED_PITCH | SERIF | NONSYMBOLIC | ITALIC,
+        font_bbox: [-57.0, -250.0, 869.0, 801.0],
+        italic_angle: -12.0,
+        ascent: 629.0,
+        descent: -157.0,
+        cap_height: 562.0,
+        glyphs: metrics::COURIER_BOLDOBLIQUE,
+        kerning: metrics::COURIER_BOLDOBLIQUE_KERNING,
+    },
+    StandardFont {
+        name: "Helvetica",
+        flags: NONSYMBOLIC,
+        font_bbox: [-166.0, -225.0, 1000.0, 931.0],
+        italic_angle: 0.0,
+        ascent: 718.0,
+        descent: -207.0,
+        cap_height: 718.0,
+        glyphs: metrics::HELVETICA,
+        kerning: metrics::HELVETICA_KERNING,
+    },
+    StandardFont {
+        name: "Helvetica-Bold",
+        flags: NONSYMBOLIC,
+        font_bbox: [-170.0, -228.0, 1003.0, 962.0],
+        italic_angle: 0.0,
+        ascent: 718.0,
+        descent: -207.0,
+        cap_height: 718.0,
+        glyphs: metrics::HELVETICA_BOLD,
+        kerning: metrics::HELVETICA_BOLD_KERNING,
+    },
+    StandardFont {
+        name: "Helvetica-Oblique",
+        flags: NONSYMBOLIC | ITALIC,
+        font_bbox: [-170.0, -225.0, 1116.0, 931.0],
+        italic_angle: -12.0,
+        ascent: 718.0,
+        descent: -207.0,
+        cap_height: 718.0,
+        glyphs: metrics::HELVETICA_OBLIQUE,
+        kerning: metrics::HELVETICA_OBLIQUE_KERNING,
+    },
+    StandardFont {
+        name: "Helvetica-BoldOblique",
+        flags: NONSYMBOLIC | ITALIC,
+        font_bbox: [-174.0, -228.0, 1114.0, 962.0],
+        italic_angle: -12.0,
+        ascent: 718.0,
+        descent: -207.0,
+        cap_height: 718.0,
+        glyphs: metrics::HELVETICA_BOLDOBLIQUE,
+        kerning: metrics::HELVETICA_BOLDOBLIQUE_KERNING,
+    },
+    StandardFont {
+        name: "Times-Roman",
+        flags: SERIF | NONSYMBOLIC,
+        font_bbox: [-168.0, -218.0, 1000.0, 898.0],
+        italic_angle: 0.0,
+        ascent: 683.0,
+        descent: -217.0,
+        cap_height: 662.0,
+        glyphs: metrics::TIMES_ROMAN,
+        kerning: metrics::TIMES_ROMAN_KERNING,
+    },
+    StandardFont {
+        name: "Times-Bold",
+        flags: SERIF | NONSYMBOLIC,
+        font_bbox: [-168.0, -218.0, 1000.0, 935.0],
+        italic_angle: 0.0,
+        ascent: 683.0,
+        descent: -217.0,
+        cap_height: 676.0,
+        glyphs: metrics::TIMES_BOLD,
+        kerning: metrics::TIMES_BOLD_KERNING,
+    },
+    StandardFont {
+        name: "Times-Italic",
+        flags: SERIF | NONSYMBOLIC | ITALIC,
+        font_bbox: [-169.0, -217.0, 1010.0, 883.0],
+        italic_angle: -15.5,
+        ascent: 683.0,
+        descent: -217.0,
+        cap_height: 653.0,
+        glyphs: metrics::TIMES_ITALIC,
+        kerning: metrics::TIMES_ITALIC_KERNING,
+    },
+    StandardFont {
+        name: "Times-BoldItalic",
+        flags: SERIF | NONSYMBOLIC | ITALIC,
+        font_bbox: [-200.0, -218.0, 996.0, 921.0],
+        italic_angle: -15.0,
+        ascent: 683.0,
+        descent: -217.0,
+        cap_height: 669.0,
+        glyphs: metrics::TIMES_BOLDITALIC,
+        kerning: metrics::TIMES_BOLDITALIC_KERNING,
+    },
+    StandardFont {
+        name: "Symbol",
+        flags: SYMBOLIC,
+        font_bbox: [-180.0, -293.0, 1090.0, 1010.0],
+        italic_angle: 0.0,
+        ascent: 1010.0,
+        descent: -293.0,
+        cap_height: 1010.0,
+        glyphs: metrics::SYMBOL,
+        kerning: metrics::SYMBOL_KERNING,
+    },
+    StandardFont {
+        name: "ZapfDingbats",
+        flags: SYMBOLIC,
+        font_bbox: [-1.0, -143.0, 981.0, 820.0],
+        italic_angle: 0.0,
+        ascent: 820.0,
+        descent: -143.0,
+        cap_height: 820.0,
+        glyphs: metrics::ZAPFDINGBATS,
+        kerning: metrics::ZAPFDINGBATS_KERNING,
+    },
+];
+
+/// Other names that documents commonly use for the standard fonts
+const ALIASES: [(&str, &str); 26] = [
+    ("Arial", "Helvetica"),
+    ("Arial,Bold", "Helvetica-Bold"),
+    ("Arial,BoldItalic", "Helvetica-BoldOblique"),
+    ("Arial,Italic", "Helvetica-Oblique"),
+    ("Arial-Bold", "Helvetica-Bold"),
+    ("Arial-BoldItalic", "Helvetica-BoldOblique"),
+    ("Arial-BoldItalicMT", "Helvetica-BoldOblique"),
+    ("Arial-BoldMT", "Helvetica-Bold"),
+    ("Arial-Italic", "Helvetica-Oblique"),
+    ("Arial-ItalicMT", "Helvetica-Oblique"),
+    ("ArialMT", "Helvetica"),
+    ("CourierNew", "Courier"),
+    ("CourierNew,Bold", "Courier-Bold"),
+    ("CourierNew,BoldItalic", "Courier-BoldOblique"),
+    ("CourierNew,Italic", "Courier-Oblique"),
+    ("CourierNewPS-BoldItalicMT", "Courier-BoldOblique"),
+    ("CourierNewPS-BoldMT", "Courier-Bold"),
+    ("CourierNewPS-ItalicMT", "Courier-Oblique"),
+    ("CourierNewPSMT", "Courier"),
+    ("Times", "Times-Roman"),
+    ("TimesNewRoman", "Times-Roman"),
+    ("TimesNewRoman,Bold", "Times-Bold"),
+    ("TimesNewRoman,BoldItalic", "Times-BoldItalic"),
+    ("TimesNewRoman,Italic", "Times-Italic"),
+    ("TimesNewRomanPS-BoldMT", "Times-Bold"),
+    ("TimesNewRomanPSMT", "Times-Roman"),
+];
+
+impl StandardFont {
+    /// Finds the standard font a `/BaseFont` names, if any, ignoring the
+    /// tag that marks a subset, e.g. `ABCDEF+Helvetica`
+    pub fn find(base_font: &str) -> Option<&'static StandardFont> {
+        let name = match base_font.split_once('+') {
+            Some((tag, name)) if tag.len() == 6 && tag.bytes().all(|b| b.is_ascii_uppercase()) => {
+                name
+            }
+            _ => base_font,
+        };
+        let name = ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == name)
+            .map_or(name, |(_, standard)| standard);
+        STANDARD_FONTS.iter().find(|font| font.name == name)
+    }
+
+    /// The width of a glyph, in glyph space
+    pub fn width(&self, glyph_name: &str) -> Option<f32> {
+        self.glyphs
+            .binary_search_by(|(name, _, _)| (*name).cmp(glyph_name))
+            .ok()
+            .map(|i| f32::from(self.glyphs[i].2))
+    }
+
+    /// How much the space between two glyphs changes when `right` follows
+    /// `left`, in glyph space. Showing text never applies this, as any
+    /// kerning is already in `TJ`, but laying out new text does.
+    pub fn kerning(&self, left: &str, right: &str) -> Option<f32> {
+        self.kerning
+            .binary_search_by(|(l, r, _)| (*l, *r).cmp(&(left, right)))
+            .ok()
+            .map(|i| f32::from(self.kerning[i].2))
+    }
+
+    /// The name of the glyph for a code in the font's built-in encoding
+    pub fn glyph_name(&self, code: u8) -> Option<&'static str> {
+        self.glyphs
+            .iter()
+            .find(|(_, glyph_code, _)| *glyph_code == Some(code))
+            .map(|(name, _, _)| *name)
+    }
+
+    /// The font's built-in encoding, which fonts that refer to it use for
+    /// codes that their `/Encoding` doesn't map
+    pub fn built_in_encoding(&self) -> EncodingTable {
+        let mut names = [None; 256];
+        for (name, code, _) in self.glyphs {
+            if let Some(code) = code {
+                names[*code as usize] = Some(*name);
+            }
+        }
+        EncodingTable::from_glyph_names(&names)
+    }
+
+    /// A descriptor for fonts that refer to a standard font without one
+    pub(crate) fn descriptor(&self) -> FontDescriptor {
+        FontDescriptor {
+            flags: self.flags,
+            font_bbox: self.font_bbox,
+            italic_angle: self.italic_angle,
+            ascent: self.ascent,
+            descent: self.descent,
+            cap_height: self.cap_height,
+            stem_v: 0.0,
+            missing_width: 0.0,
+            font_file: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StandardFont;
+
+    #[test]
+    fn test_standard_font() {
+        let helvetica = StandardFont::find("Helvetica").unwrap();
+        assert_eq!(helvetica.width("A"), Some(667.0));
+        assert_eq!(helvetica.width("space"), Some(278.0));
+        assert_eq!(helvetica.width("Aacute"), Some(667.0));
+        assert_eq!(helvetica.width("a1"), None);
+        assert_eq!(helvetica.glyph_name(b'A'), Some("A"));
+        assert_eq!(helvetica.glyph_name(0xe9), Some("Oslash"));
+        assert_eq!(StandardFont::find("ABCDEF+ArialMT"), Some(helvetica));
+        assert_eq!(
+            StandardFont::find("Times-Bold").unwrap().width("W"),
+            Some(1000.0)
+        );
+        let courier = StandardFont::find("CourierNew,Bold").unwrap();
+        assert_eq!(courier.name, "Courier-Bold");
+        assert_eq!(courier.width("ampersand"), Some(600.0));
+        let dingbats = StandardFont::find("ZapfDingbats").unwrap();
+        assert_eq!(dingbats.glyph_name(0x21), Some("a1"));
+        assert_eq!(dingbats.width("a1"), Some(974.0));
+        assert_eq!(dingbats.built_in_encoding().glyph_name(0x21), Some("a1"));
+        assert_eq!(StandardFont::find("Garamond"), None);
+    }
+
+    #[test]
+    fn test_kerning() {
+        // Pairs are looked up by both glyph names, in order
+        let font = StandardFont {
+            kerning: &[("A", "T", -10), ("A", "V", -20), ("L", "T", -30)],
+            ..*StandardFont::find("Helvetica").unwrap()
+        };
+        assert_eq!(font.kerning("A", "V"), Some(-20.0));
+        assert_eq!(font.kerning("L", "T"), Some(-30.0));
+        assert_eq!(font.kerning("T", "A"), None);
+        assert_eq!(font.kerning("A", "A"), None);
+    }
+}
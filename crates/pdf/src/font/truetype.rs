@@ -0,0 +1,678 @@
+use anyhow::Result;
+use lyon::math::{point, vector, Point, Transform};
+use lyon::path::PathEvent;
+use nom::number::complete::{be_i16, be_i8, be_u16, be_u32, be_u8};
+use std::convert::TryFrom;
+
+use crate::{error::FontError, NomResult};
+
+/// `ttcf`, the version of a collection of fonts rather than of a single one
+const COLLECTION: u32 = 0x7474_6366;
+
+/// Limit on how deeply composite glyphs are followed, which protects
+/// against cycles in malformed fonts
+const MAX_COMPONENT_DEPTH: usize = 8;
+
+// Bits of the flags of a point of a simple glyph
+const ON_CURVE: u8 = 1;
+const X_SHORT: u8 = 1 << 1;
+const Y_SHORT: u8 = 1 << 2;
+const REPEAT: u8 = 1 << 3;
+const X_SAME_OR_POSITIVE: u8 = 1 << 4;
+const Y_SAME_OR_POSITIVE: u8 = 1 << 5;
+
+// Bits of the flags of a component of a composite glyph
+const ARGS_ARE_WORDS: u16 = 1;
+const ARGS_ARE_XY_VALUES: u16 = 1 << 1;
+const HAS_SCALE: u16 = 1 << 3;
+const MORE_COMPONENTS: u16 = 1 << 5;
+const HAS_X_AND_Y_SCALE: u16 = 1 << 6;
+const HAS_TWO_BY_TWO: u16 = 1 << 7;
+
+/// A TrueType font program, as embedded by `/FontFile2`, or an OpenType one
+/// with TrueType outlines. Only what's needed to draw glyphs is read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrueType {
+    /// How many units of the font's coordinates make up the em
+    pub units_per_em: u16,
+    /// Where each glyph starts in `glyf`, followed by where the last ends
+    locations: Vec<u32>,
+    glyf: Vec<u8>,
+    cmaps: Vec<CmapSubtable>,
+}
+
+/// A subtable of `cmap`, which maps the codes of an encoding on a platform
+/// to glyphs
+#[derive(Debug, Clone, PartialEq)]
+struct CmapSubtable {
+    platform: u16,
+    encoding: u16,
+    mapping: Mapping,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Mapping {
+    /// Format 0, a glyph for each one-byte code
+    Bytes(Vec<u8>),
+    /// Format 4, ranges of two-byte codes
+    Segments {
+        segments: Vec<Segment>,
+        glyph_ids: Vec<u16>,
+    },
+    /// Format 6, a glyph for each of a range of two-byte codes
+    Trimmed { first: u32, glyph_ids: Vec<u16> },
+    /// Format 12, `(first, last, glyph)` for ranges of codes that map to
+    /// consecutive glyphs
+    Groups(Vec<(u32, u32, u32)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    start: u16,
+    end: u16,
+    delta: u16,
+    /// Where the glyphs of the segment are in the glyph id array, in bytes
+    /// from where this is in the subtable, or 0 if they're found by `delta`
+    range_offset: u16,
+}
+
+/// A point of an outline, and whether it's on the curve rather than a
+/// control point
+type OutlinePoint = (Point, bool);
+
+impl TrueType {
+    pub fn parse(data: &[u8]) -> Result<TrueType> {
+        let tables = tables(data).ok_or(FontError::InvalidTable("table directory"))?;
+        let table = |tag: &'static str| {
+            tables
+                .iter()
+                .find(|(name, _)| name == tag.as_bytes())
+                .map(|(_, table)| *table)
+                .ok_or(FontError::InvalidTable(tag))
+        };
+        let head = table("head")?;
+        let units_per_em = read(head, 18, be_u16).ok_or(FontError::InvalidTable("head"))?;
+        let long_locations = read(head, 50, be_i16).ok_or(FontError::InvalidTable("head"))? != 0;
+        let num_glyphs = read(table("maxp")?, 4, be_u16).ok_or(FontError::InvalidTable("maxp"))?;
+        let loca = table("loca")?;
+        let locations = (0..=usize::from(num_glyphs))
+            .map(|i| match long_locations {
+                true => read(loca, i * 4, be_u32),
+                // Short locations are halved
+                false => read(loca, i * 2, be_u16).map(|location| u32::from(location) * 2),
+            })
+            .collect::<Option<Vec<u32>>>()
+            .ok_or(FontError::InvalidTable("loca"))?;
+        let cmaps = cmap_subtables(table("cmap")?).ok_or(FontError::InvalidTable("cmap"))?;
+        Ok(TrueType {
+            units_per_em,
+            locations,
+            glyf: table("glyf")?.to_vec(),
+            cmaps,
+        })
+    }
+
+    /// Whether the font has a `cmap` subtable for the platform and encoding,
+    /// e.g. (3, 1) for Unicode on Windows
+    pub fn has_cmap(&self, platform: u16, encoding: u16) -> bool {
+        self.cmap(platform, encoding).is_some()
+    }
+
+    /// The glyph that the `cmap` subtable for a platform and encoding maps a
+    /// code to. Codes mapped to `.notdef` have none.
+    pub fn glyph_id(&self, platform: u16, encoding: u16, code: u32) -> Option<u16> {
+        let glyph_id = match &self.cmap(platform, encoding)?.mapping {
+            Mapping::Bytes(glyph_ids) => u16::from(*glyph_ids.get(code as usize)?),
+            Mapping::Segments {
+                segments,
+                glyph_ids,
+            } => {
+                let code = u16::try_from(code).ok()?;
+                let i = segments.iter().position(|segment| segment.end >= code)?;
+                let segment = &segments[i];
+                if segment.start > code {
+                    return None;
+                }
+                if segment.range_offset == 0 {
+                    code.wrapping_add(segment.delta)
+                } else {
+                    // The offset is from the segment's entry of the range
+                    // offsets, which are followed by the glyph ids
+                    let index = usize::from(segment.range_offset / 2)
+                        + usize::from(code - segment.start)
+                        + i;
+                    let glyph_id = *glyph_ids.get(index.checked_sub(segments.len())?)?;
+                    match glyph_id {
+                        0 => 0,
+                        glyph_id => glyph_id.wrapping_add(segment.delta),
+                    }
+                }
+            }
+            Mapping::Trimmed { first, glyph_ids } => {
+                *glyph_ids.get(code.checked_sub(*first)? as usize)?
+            }
+            Mapping::Groups(groups) => {
+                let (first, _, glyph_id) = groups
+                    .iter()
+                    .find(|(first, last, _)| (*first..=*last).contains(&code))?;
+                u16::try_from(glyph_id + (code - first)).ok()?
+            }
+        };
+        Some(glyph_id).filter(|glyph_id| *glyph_id != 0)
+    }
+
+    /// The character that a Unicode `cmap` subtable, (3, 10) or (3, 1), maps
+    /// to a glyph, which is how text is found for glyphs selected by CID
+    pub fn unicode(&self, glyph_id: u16) -> Option<char> {
+        let (encoding, subtable) = [10, 1]
+            .iter()
+            .find_map(|&encoding| Some((encoding, self.cmap(3, encoding)?)))?;
+        let code = match &subtable.mapping {
+            Mapping::Groups(groups) => groups.iter().find_map(|&(first, last, first_glyph)| {
+                let offset = u32::from(glyph_id).checked_sub(first_glyph)?;
+                Some(first + offset).filter(|&code| code <= last)
+            }),
+            Mapping::Bytes(_) => {
+                (0..256).find(|&code| self.glyph_id(3, encoding, code) == Some(glyph_id))
+            }
+            Mapping::Segments { segments, .. } => segments.iter().find_map(|segment| {
+                (u32::from(segment.start)..=u32::from(segment.end))
+                    .find(|&code| self.glyph_id(3, encoding, code) == Some(glyph_id))
+            }),
+            Mapping::Trimmed { first, glyph_ids } => (*first..*first + glyph_ids.len() as u32)
+                .find(|&code| self.glyph_id(3, encoding, code) == Some(glyph_id)),
+        };
+        char::from_u32(code?)
+    }
+
+    /// The outline of a glyph, in glyph space, i.e. scaled to 1000 units to
+    /// the em. Contours are closed, and made of lines and quadratic curves.
+    pub fn outline(&self, glyph_id: u16) -> Result<Vec<PathEvent>> {
+        let scale = 1000.0 / f32::from(self.units_per_em.max(1));
+        let contours = self.contours(glyph_id, &Transform::scale(scale, scale), 0)?;
+        Ok(contours
+            .iter()
+            .filter(|contour| !contour.is_empty())
+            .flat_map(|contour| contour_events(contour))
+            .collect())
+    }
+
+    fn cmap(&self, platform: u16, encoding: u16) -> Option<&CmapSubtable> {
+        self.cmaps
+            .iter()
+            .find(|cmap| cmap.platform == platform && cmap.encoding == encoding)
+    }
+
+    /// The contours of a glyph, with the points of those of composite glyphs
+    /// transformed into place
+    fn contours(
+        &self,
+        glyph_id: u16,
+        transform: &Transform,
+        depth: usize,
+    ) -> Result<Vec<Vec<OutlinePoint>>> {
+        let invalid = || FontError::InvalidTable("glyf");
+        let i = usize::from(glyph_id);
+        let (start, end) = match self.locations.get(i..i + 2) {
+            Some(&[start, end]) => (start as usize, end as usize),
+            _ => return Err(invalid().into()),
+        };
+        // Glyphs without outlines, like spaces, take no room
+        if start >= end {
+            return Ok(vec![]);
+        }
+        let glyph = self.glyf.get(start..end).ok_or_else(invalid)?;
+        let num_contours = read(glyph, 0, be_i16).ok_or_else(invalid)?;
+        if num_contours >= 0 {
+            let contours = simple_glyph(glyph, num_contours as usize).ok_or_else(invalid)?;
+            return Ok(contours
+                .into_iter()
+                .map(|contour| {
+                    contour
+                        .into_iter()
+                        .map(|(p, on_curve)| (transform.transform_point(p), on_curve))
+                        .collect()
+                })
+                .collect());
+        }
+        if depth >= MAX_COMPONENT_DEPTH {
+            return Err(invalid().into());
+        }
+        let mut contours: Vec<Vec<OutlinePoint>> = vec![];
+        let mut offset = 10;
+        loop {
+            let flags = read(glyph, offset, be_u16).ok_or_else(invalid)?;
+            let component = read(glyph, offset + 2, be_u16).ok_or_else(invalid)?;
+            offset += 4;
+            let (arg1, arg2) = match flags & ARGS_ARE_WORDS != 0 {
+                true => {
+                    offset += 4;
+                    (
+                        read(glyph, offset - 4, be_i16),
+                        read(glyph, offset - 2, be_i16),
+                    )
+                }
+                false => {
+                    offset += 2;
+                    let byte = |offset| match flags & ARGS_ARE_XY_VALUES != 0 {
+                        true => read(glyph, offset, be_i8).map(i16::from),
+                        false => read(glyph, offset, be_u8).map(i16::from),
+                    };
+                    (byte(offset - 2), byte(offset - 1))
+                }
+            };
+            let (arg1, arg2) = (arg1.ok_or_else(invalid)?, arg2.ok_or_else(invalid)?);
+            // Scales are 2.14 fixed point numbers
+            let mut scales = vec![];
+            let count = match flags {
+                f if f & HAS_SCALE != 0 => 1,
+                f if f & HAS_X_AND_Y_SCALE != 0 => 2,
+                f if f & HAS_TWO_BY_TWO != 0 => 4,
+                _ => 0,
+            };
+            for _ in 0..count {
+                let scale = read(glyph, offset, be_i16).ok_or_else(invalid)?;
+                scales.push(f32::from(scale) / 16384.0);
+                offset += 2;
+            }
+            let (a, b, c, d) = match scales[..] {
+                [scale] => (scale, 0.0, 0.0, scale),
+                [x, y] => (x, 0.0, 0.0, y),
+                [a, b, c, d] => (a, b, c, d),
+                _ => (1.0, 0.0, 0.0, 1.0),
+            };
+            let matrix = Transform::new(a, b, c, d, 0.0, 0.0);
+            let component_contours = if flags & ARGS_ARE_XY_VALUES != 0 {
+                let matrix = matrix.then_translate(vector(f32::from(arg1), f32::from(arg2)));
+                self.contours(component, &matrix.then(transform), depth + 1)?
+            } else {
+                // The component is moved so that one of its points lands on
+                // one of the points of the glyph so far
+                let placed = self.contours(component, &matrix.then(transform), depth + 1)?;
+                let point = |contours: &[Vec<OutlinePoint>], i: i16| {
+                    contours.iter().flatten().nth(i as usize).map(|(p, _)| *p)
+                };
+                let offset = match (point(&contours, arg1), point(&placed, arg2)) {
+                    (Some(to), Some(from)) => to - from,
+                    _ => return Err(invalid().into()),
+                };
+                placed
+                    .into_iter()
+                    .map(|contour| {
+                        contour
+                            .into_iter()
+                            .map(|(p, on_curve)| (p + offset, on_curve))
+                            .collect()
+                    })
+                    .collect()
+            };
+            contours.extend(component_contours);
+            if flags & MORE_COMPONENTS == 0 {
+                return Ok(contours);
+            }
+        }
+    }
+}
+
+/// Reads a number at an offset, if the data is long enough
+pub(super) fn read<'a, T>(
+    data: &'a [u8],
+    offset: usize,
+    parser: impl Fn(&'a [u8]) -> NomResult<'a, T>,
+) -> Option<T> {
+    parser(data.get(offset..)?).ok().map(|(_, value)| value)
+}
+
+/// The tables of a font by their tags. Only the first font of a collection
+/// is read.
+pub(super) fn tables(data: &[u8]) -> Option<Vec<([u8; 4], &[u8])>> {
+    let start = match read(data, 0, be_u32)? {
+        COLLECTION => read(data, 12, be_u32)? as usize,
+        _ => 0,
+    };
+    let num_tables = read(data, start + 4, be_u16)?;
+    (0..usize::from(num_tables))
+        .map(|i| {
+            let record = start + 12 + i * 16;
+            let tag = data.get(record..record + 4)?;
+            let offset = read(data, record + 8, be_u32)? as usize;
+            let length = read(data, record + 12, be_u32)? as usize;
+            let table = data.get(offset..offset.checked_add(length)?)?;
+            Some(([tag[0], tag[1], tag[2], tag[3]], table))
+        })
+        .collect()
+}
+
+/// Reads the subtables of `cmap` that are in formats that are understood
+fn cmap_subtables(cmap: &[u8]) -> Option<Vec<CmapSubtable>> {
+    let num_tables = read(cmap, 2, be_u16)?;
+    let mut subtables = vec![];
+    for i in 0..usize::from(num_tables) {
+        let record = 4 + i * 8;
+        let platform = read(cmap, record, be_u16)?;
+        let encoding = read(cmap, record + 2, be_u16)?;
+        let subtable = cmap.get(read(cmap, record + 4, be_u32)? as usize..)?;
+        if let Some(mapping) = mapping(subtable) {
+            subtables.push(CmapSubtable {
+                platform,
+                encoding,
+                mapping,
+            });
+        }
+    }
+    Some(subtables)
+}
+
+fn mapping(subtable: &[u8]) -> Option<Mapping> {
+    let u16_array = |offset: usize, count: usize| -> Option<Vec<u16>> {
+        (0..count)
+            .map(|i| read(subtable, offset + i * 2, be_u16))
+            .collect()
+    };
+    match read(subtable, 0, be_u16)? {
+        0 => Some(Mapping::Bytes(subtable.get(6..6 + 256)?.to_vec())),
+        4 => {
+            let length = usize::from(read(subtable, 2, be_u16)?);
+            let seg_count = usize::from(read(subtable, 6, be_u16)? / 2);
+            let ends = u16_array(14, seg_count)?;
+            let starts = u16_array(16 + seg_count * 2, seg_count)?;
+            let deltas = u16_array(16 + seg_count * 4, seg_count)?;
+            let range_offsets = u16_array(16 + seg_count * 6, seg_count)?;
+            let glyph_ids_start = 16 + seg_count * 8;
+            let glyph_ids = u16_array(
+                glyph_ids_start,
+                length.min(subtable.len()).saturating_sub(glyph_ids_start) / 2,
+            )?;
+            let segments = (0..seg_count)
+                .map(|i| Segment {
+                    start: starts[i],
+                    end: ends[i],
+                    delta: deltas[i],
+                    range_offset: range_offsets[i],
+                })
+                .collect();
+            Some(Mapping::Segments {
+                segments,
+                glyph_ids,
+            })
+        }
+        6 => {
+            let first = read(subtable, 6, be_u16)?;
+            let count = read(subtable, 8, be_u16)?;
+            Some(Mapping::Trimmed {
+                first: u32::from(first),
+                glyph_ids: u16_array(10, usize::from(count))?,
+            })
+        }
+        12 => {
+            let count = read(subtable, 12, be_u32)? as usize;
+            let groups = (0..count)
+                .map(|i| {
+                    let group = 16 + i * 12;
+                    Some((
+                        read(subtable, group, be_u32)?,
+                        read(subtable, group + 4, be_u32)?,
+                        read(subtable, group + 8, be_u32)?,
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(Mapping::Groups(groups))
+        }
+        _ => None,
+    }
+}
+
+/// Reads the contours of a glyph that isn't made of other glyphs
+fn simple_glyph(glyph: &[u8], num_contours: usize) -> Option<Vec<Vec<OutlinePoint>>> {
+    let ends = (0..num_contours)
+        .map(|i| read(glyph, 10 + i * 2, be_u16).map(usize::from))
+        .collect::<Option<Vec<usize>>>()?;
+    let num_points = ends.last().map_or(0, |last| last + 1);
+    let instructions = usize::from(read(glyph, 10 + num_contours * 2, be_u16)?);
+    let mut offset = 12 + num_contours * 2 + instructions;
+    let mut flags = Vec::with_capacity(num_points);
+    while flags.len() < num_points {
+        let flag = *glyph.get(offset)?;
+        offset += 1;
+        let repeats = match flag & REPEAT != 0 {
+            true => {
+                offset += 1;
+                usize::from(*glyph.get(offset - 1)?)
+            }
+            false => 0,
+        };
+        flags.extend(std::iter::repeat_n(flag, repeats + 1));
+    }
+    flags.truncate(num_points);
+    // Coordinates are relative to those of the point before, and are either
+    // a byte with the sign in the flags or a word unless they're unchanged
+    let mut coordinates = |short: u8, same_or_positive: u8| -> Option<Vec<f32>> {
+        let mut value = 0;
+        let mut values = Vec::with_capacity(num_points);
+        for flag in &flags {
+            if flag & short != 0 {
+                let delta = i32::from(*glyph.get(offset)?);
+                offset += 1;
+                value += match flag & same_or_positive != 0 {
+                    true => delta,
+                    false => -delta,
+                };
+            } else if flag & same_or_positive == 0 {
+                value += i32::from(read(glyph, offset, be_i16)?);
+                offset += 2;
+            }
+            values.push(value as f32);
+        }
+        Some(values)
+    };
+    let xs = coordinates(X_SHORT, X_SAME_OR_POSITIVE)?;
+    let ys = coordinates(Y_SHORT, Y_SAME_OR_POSITIVE)?;
+    let mut contours = vec![];
+    let mut start = 0;
+    for end in ends {
+        let contour = (start..=end)
+            .map(|i| Some((point(*xs.get(i)?, *ys.get(i)?), flags[i] & ON_CURVE != 0)))
+            .collect::<Option<Vec<OutlinePoint>>>()?;
+        contours.push(contour);
+        start = end + 1;
+    }
+    Some(contours)
+}
+
+/// Converts a contour to path events. Between two control points in a row
+/// is an implied point on the curve, halfway between them.
+fn contour_events(contour: &[OutlinePoint]) -> Vec<PathEvent> {
+    let (first, rest) = match contour.iter().position(|(_, on_curve)| *on_curve) {
+        Some(i) => (
+            contour[i].0,
+            contour[i + 1..]
+                .iter()
+                .chain(&contour[..i])
+                .copied()
+                .collect(),
+        ),
+        // Without any points on the curve, it starts between the last
+        // control point and the first
+        None => (
+            contour[contour.len() - 1].0.lerp(contour[0].0, 0.5),
+            contour.to_vec(),
+        ),
+    };
+    let mut events = vec![PathEvent::Begin { at: first }];
+    let mut current = first;
+    let mut control: Option<Point> = None;
+    let points: Vec<OutlinePoint> = rest;
+    for (to, on_curve) in points.into_iter().chain(std::iter::once((first, true))) {
+        match (control, on_curve) {
+            (None, true) => {
+                if to != current {
+                    events.push(PathEvent::Line { from: current, to });
+                }
+                current = to;
+            }
+            (None, false) => control = Some(to),
+            (Some(ctrl), true) => {
+                events.push(PathEvent::Quadratic {
+                    from: current,
+                    ctrl,
+                    to,
+                });
+                current = to;
+                control = None;
+            }
+            (Some(ctrl), false) => {
+                let mid = ctrl.lerp(to, 0.5);
+                events.push(PathEvent::Quadratic {
+                    from: current,
+                    ctrl,
+                    to: mid,
+                });
+                current = mid;
+                control = Some(to);
+            }
+        }
+    }
+    events.push(PathEvent::End {
+        first,
+        last: current,
+        close: true,
+    });
+    events
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use lyon::math::point;
+    use lyon::path::PathEvent;
+
+    use super::TrueType;
+
+    fn u16s(values: &[u16]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect()
+    }
+
+    /// A font whose glyph 1 is a triangle with a curved side, and whose
+    /// glyph 2 is glyph 1 at half the size, moved right. `A` maps to glyph 1
+    /// and `B` to glyph 2 on every platform, as does `a` through the glyph
+    /// id array of a format 4 subtable.
+    pub(in crate::font) fn font() -> Vec<u8> {
+        let mut head = vec![0; 54];
+        head[18..20].copy_from_slice(&500u16.to_be_bytes());
+        let maxp = u16s(&[0, 0x5000, 3]);
+        // Three points, the second of which is off the curve. x moves by 0,
+        // then by +100 as a byte, then by -50 as a word; y stays at 0 until
+        // it moves by +100 as a byte.
+        let mut triangle = u16s(&[1, 0, 0, 100, 100, 2, 0]);
+        triangle.extend([0x31, 0x32, 0x25, 100]);
+        triangle.extend((-50i16).to_be_bytes());
+        // Padded, since short locations are halved
+        triangle.extend([100, 0]);
+        // Glyph 1 scaled by 0.5 in 2.14 fixed point, and moved by (100, 0)
+        let mut composite = u16s(&[0xffff, 0, 0, 0, 0, 0x000a, 1]);
+        composite.extend([100, 0, 0x20, 0]);
+        let glyf: Vec<u8> = triangle.iter().chain(&composite).copied().collect();
+        let loca = u16s(&[0, 0, triangle.len() as u16 / 2, glyf.len() as u16 / 2]);
+        let mut format0 = u16s(&[0, 262, 0]);
+        let mut bytes = [0u8; 256];
+        bytes[0x41] = 1;
+        bytes[0x42] = 2;
+        format0.extend(bytes);
+        let format12 = u16s(&[12, 0, 0, 28, 0, 0, 0, 1, 0, 0xf041, 0, 0xf042, 0, 1]);
+        // `A` and `B` by delta, then `a` through the glyph id array
+        let format4 = u16s(&[
+            4, 42, 0, 6, 0, 0, 0, 0x42, 0x61, 0xffff, 0, 0x41, 0x61, 0xffff, 0xffc0, 0, 1, 0, 4, 0,
+            2,
+        ]);
+        let cmap_header = u16s(&[0, 3, 1, 0, 0, 28, 3, 0, 0, 290, 3, 1, 0, 318]);
+        let cmap: Vec<u8> = cmap_header
+            .into_iter()
+            .chain(format0)
+            .chain(format12)
+            .chain(format4)
+            .collect();
+        let tables: [(&[u8; 4], Vec<u8>); 5] = [
+            (b"cmap", cmap),
+            (b"glyf", glyf),
+            (b"head", head),
+            (b"loca", loca),
+            (b"maxp", maxp),
+        ];
+        let mut data = u16s(&[1, 0, tables.len() as u16, 0, 0, 0]);
+        let mut offset = 12 + tables.len() * 16;
+        for (tag, table) in &tables {
+            data.extend(tag.iter());
+            data.extend([0; 4]);
+            data.extend((offset as u32).to_be_bytes());
+            data.extend((table.len() as u32).to_be_bytes());
+            offset += table.len();
+        }
+        for (_, table) in tables {
+            data.extend(table);
+        }
+        data
+    }
+
+    #[test]
+    fn test_cmap() {
+        let font = TrueType::parse(&font()).unwrap();
+        assert_eq!(font.units_per_em, 500);
+        for (platform, encoding, code) in [(1, 0, 0x41), (3, 1, 0x41), (3, 0, 0xf041)] {
+            assert!(font.has_cmap(platform, encoding));
+            assert_eq!(font.glyph_id(platform, encoding, code), Some(1));
+            assert_eq!(font.glyph_id(platform, encoding, code + 1), Some(2));
+            assert_eq!(font.glyph_id(platform, encoding, code + 2), None);
+        }
+        assert_eq!(font.glyph_id(3, 1, 0x61), Some(2));
+        assert_eq!(font.glyph_id(3, 1, 0x62), None);
+        assert!(!font.has_cmap(3, 10));
+        assert_eq!(font.unicode(1), Some('A'));
+        assert_eq!(font.unicode(2), Some('B'));
+        assert_eq!(font.unicode(3), None);
+        assert!(TrueType::parse(b"OTTO").is_err());
+    }
+
+    #[test]
+    fn test_outline() {
+        let font = TrueType::parse(&font()).unwrap();
+        // Scaled from 500 units to the em to 1000
+        let (a, b, c) = (point(0.0, 0.0), point(200.0, 0.0), point(100.0, 200.0));
+        assert_eq!(
+            font.outline(1).unwrap(),
+            [
+                PathEvent::Begin { at: a },
+                PathEvent::Quadratic {
+                    from: a,
+                    ctrl: b,
+                    to: c,
+                },
+                PathEvent::Line { from: c, to: a },
+                PathEvent::End {
+                    first: a,
+                    last: a,
+                    close: true,
+                },
+            ]
+        );
+        // Half the size, and moved right by 100 units
+        let composite = font.outline(2).unwrap();
+        assert_eq!(
+            composite[0],
+            PathEvent::Begin {
+                at: point(200.0, 0.0)
+            }
+        );
+        assert_eq!(
+            composite[1],
+            PathEvent::Quadratic {
+                from: point(200.0, 0.0),
+                ctrl: point(300.0, 0.0),
+                to: point(250.0, 100.0),
+            }
+        );
+        assert_eq!(font.outline(0).unwrap(), []);
+        assert!(font.outline(3).is_err());
+    }
+}
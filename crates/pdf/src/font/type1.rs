@@ -0,0 +1,710 @@
+use anyhow::Result;
+use lyon::math::{point, vector, Point, Transform, Vector};
+use lyon::path::PathEvent;
+use std::convert::TryFrom;
+
+use super::outline::Outline;
+use crate::{
+    cmap::parsers::{tokens, Token},
+    encodings::{EncodingTable, STANDARD_GLYPH_NAMES},
+    error::FontError,
+};
+
+/// The key that the private part of a font program is encrypted with
+const EEXEC_KEY: u16 = 55665;
+/// The key that charstrings and subroutines are encrypted with
+const CHARSTRING_KEY: u16 = 4330;
+/// How many random bytes start encrypted charstrings, unless `/lenIV` says
+const DEFAULT_LEN_IV: usize = 4;
+
+/// Limit on how deeply subroutines may call each other, as in the spec
+const MAX_SUBR_DEPTH: usize = 10;
+
+/// A Type 1 font program, as embedded by `/FontFile`. Only what's needed to
+/// draw glyphs is read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Type1 {
+    /// `/FontMatrix`, which maps the font's units to text space
+    pub font_matrix: [f32; 6],
+    /// `/Encoding`, the font's built-in encoding
+    pub encoding: EncodingTable,
+    /// The decrypted charstrings of the glyphs, by name
+    char_strings: Vec<(String, Vec<u8>)>,
+    /// The decrypted `/Subrs`
+    subrs: Vec<Vec<u8>>,
+}
+
+impl Type1 {
+    pub fn parse(data: &[u8]) -> Result<Type1> {
+        let data = strip_segment_headers(data);
+        let eexec = find(&data, b"eexec", 0).ok_or(FontError::InvalidProgram("eexec"))?;
+        let (font_matrix, encoding) = clear_text(&data[..eexec]);
+        let mut encrypted = &data[eexec + b"eexec".len()..];
+        while let [b' ' | b'\t' | b'\r' | b'\n', rest @ ..] = encrypted {
+            encrypted = rest;
+        }
+        // The encrypted part may be in hexadecimal, which its first four
+        // bytes tell, since they can't all be hex digits otherwise
+        let private = match encrypted.get(..4) {
+            Some(start) if start.iter().all(u8::is_ascii_hexdigit) => {
+                decrypt(&from_hex(encrypted), EEXEC_KEY, 4)
+            }
+            _ => decrypt(encrypted, EEXEC_KEY, 4),
+        };
+        let len_iv = find(&private, b"/lenIV", 0)
+            .and_then(|i| Scanner::new(&private, i + 6).integer())
+            .map_or(DEFAULT_LEN_IV as i64, |len_iv| len_iv);
+        let charstring = |encrypted: &[u8]| match usize::try_from(len_iv) {
+            Ok(skip) => decrypt(encrypted, CHARSTRING_KEY, skip),
+            // A negative `/lenIV` means charstrings aren't encrypted
+            Err(_) => encrypted.to_vec(),
+        };
+        let mut subrs = vec![];
+        let mut end = 0;
+        if let Some(i) = find(&private, b"/Subrs", 0) {
+            let mut scanner = Scanner::new(&private, i + 6);
+            // Each subroutine takes some bytes, which bounds how many there
+            // can be whatever the count says
+            let count = usize::try_from(scanner.integer().unwrap_or(0)).unwrap_or(0);
+            let count = count.min(private.len().saturating_sub(scanner.position));
+            // `dup index length RD binary NP`, where `NP` may be spelled
+            // `noaccess put` or `|`
+            for _ in 0..count {
+                if !(0..3).any(|_| scanner.token() == Some(&b"dup"[..])) {
+                    break;
+                }
+                let subr = (|| {
+                    let index = usize::try_from(scanner.integer()?).ok()?;
+                    Some((index, scanner.binary()?))
+                })();
+                match subr {
+                    Some((index, binary)) if index < count => {
+                        if index >= subrs.len() {
+                            subrs.resize(index + 1, vec![]);
+                        }
+                        subrs[index] = charstring(binary);
+                    }
+                    _ => break,
+                }
+            }
+            end = scanner.position;
+        }
+        let mut char_strings = vec![];
+        let i =
+            find(&private, b"/CharStrings", end).ok_or(FontError::InvalidProgram("CharStrings"))?;
+        let mut scanner = Scanner::new(&private, i + 12);
+        // `/name length RD binary ND`, after `count dict dup begin`
+        while let Some(token) = scanner.token() {
+            if token == b"end" {
+                break;
+            }
+            if let [b'/', name @ ..] = token {
+                let name = String::from_utf8_lossy(name).into_owned();
+                let binary = scanner
+                    .binary()
+                    .ok_or(FontError::InvalidProgram("CharStrings"))?;
+                char_strings.push((name, charstring(binary)));
+            }
+        }
+        // `.notdef` is glyph 0, as in other font programs
+        if let Some(i) = char_strings.iter().position(|(name, _)| name == ".notdef") {
+            let notdef = char_strings.remove(i);
+            char_strings.insert(0, notdef);
+        }
+        Ok(Type1 {
+            font_matrix,
+            encoding,
+            char_strings,
+            subrs,
+        })
+    }
+
+    /// The index of the glyph with a name, which `outline` takes
+    pub fn glyph_id(&self, name: &str) -> Option<u16> {
+        let i = self.char_strings.iter().position(|(n, _)| n == name)?;
+        u16::try_from(i).ok()
+    }
+
+    /// The outline of a glyph, in glyph space, i.e. scaled to 1000 units to
+    /// the em. Contours are closed, and made of lines and cubic curves.
+    pub fn outline(&self, glyph_id: u16) -> Result<Vec<PathEvent>> {
+        let (_, charstring) = self
+            .char_strings
+            .get(usize::from(glyph_id))
+            .ok_or(FontError::InvalidProgram("CharStrings"))?;
+        let [a, b, c, d, e, f] = self.font_matrix.map(|value| value * 1000.0);
+        let mut outline = Outline::new(Transform::new(a, b, c, d, e, f));
+        Interpreter::new(self, &mut outline, vector(0.0, 0.0)).run(charstring, 0)?;
+        Ok(outline.build())
+    }
+
+    fn char_string(&self, name: &str) -> Option<&[u8]> {
+        let glyph_id = self.glyph_id(name)?;
+        Some(&self.char_strings[usize::from(glyph_id)].1)
+    }
+}
+
+/// Joins the segments of a font in the PFB format, which some documents
+/// embed as is
+fn strip_segment_headers(data: &[u8]) -> Vec<u8> {
+    let mut stripped = vec![];
+    let mut rest = data;
+    // Each segment is 0x80, its type and its length in little-endian
+    while let [0x80, 1 | 2, a, b, c, d, segment @ ..] = rest {
+        let length = u32::from_le_bytes([*a, *b, *c, *d]) as usize;
+        let length = length.min(segment.len());
+        stripped.extend_from_slice(&segment[..length]);
+        rest = &segment[length..];
+    }
+    match stripped.is_empty() {
+        true => data.to_vec(),
+        false => stripped,
+    }
+}
+
+fn find(data: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    data.get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|i| i + from)
+}
+
+fn from_hex(data: &[u8]) -> Vec<u8> {
+    let digits: Vec<u8> = data
+        .iter()
+        .filter_map(|&c| (c as char).to_digit(16))
+        .map(|digit| digit as u8)
+        .collect();
+    digits
+        .chunks_exact(2)
+        .map(|pair| pair[0] << 4 | pair[1])
+        .collect()
+}
+
+/// Decrypts data encrypted for `eexec` or a charstring, leaving out the
+/// random bytes it starts with
+fn decrypt(data: &[u8], key: u16, skip: usize) -> Vec<u8> {
+    let mut r = key;
+    let decrypted = data.iter().map(|&cipher| {
+        let plain = cipher ^ (r >> 8) as u8;
+        r = (u16::from(cipher).wrapping_add(r))
+            .wrapping_mul(52845)
+            .wrapping_add(22719);
+        plain
+    });
+    decrypted.skip(skip).collect()
+}
+
+/// Reads `/FontMatrix` and `/Encoding` from the part of a font program
+/// that isn't encrypted
+fn clear_text(data: &[u8]) -> ([f32; 6], EncodingTable) {
+    let mut font_matrix = [0.001, 0.0, 0.0, 0.001, 0.0, 0.0];
+    let mut encoding = EncodingTable::from_glyph_names(&STANDARD_GLYPH_NAMES);
+    // Whatever can't be read, like the procedures that the program defines
+    // for itself, ends what's read
+    let tokens = match tokens(data) {
+        Ok((_, tokens)) => tokens,
+        Err(_) => return (font_matrix, encoding),
+    };
+    let number = |token: &Token| match token {
+        Token::Integer(value) => Some(*value as f32),
+        Token::Real(value) => Some(*value as f32),
+        _ => None,
+    };
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Name(name) if name == b"FontMatrix" => {
+                let values: Option<Vec<f32>> = tokens
+                    .get(i + 2..i + 8)
+                    .and_then(|values| values.iter().map(number).collect());
+                if let Some(&[a, b, c, d, e, f]) = values.as_deref() {
+                    font_matrix = [a, b, c, d, e, f];
+                }
+            }
+            // `StandardEncoding`, or an array that `put` fills in
+            Token::Name(name) if name == b"Encoding" => {
+                if let Some(Token::Integer(_)) = tokens.get(i + 1) {
+                    encoding = EncodingTable::from_glyph_names(&[]);
+                }
+            }
+            // `dup code /name put`
+            Token::Operator(operator) if operator == b"put" && i >= 3 => {
+                if let [Token::Operator(dup), Token::Integer(code), Token::Name(name)] =
+                    &tokens[i - 3..i]
+                {
+                    if let (b"dup", Ok(code)) = (&dup[..], u8::try_from(*code)) {
+                        encoding.set(code, &String::from_utf8_lossy(name));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    (font_matrix, encoding)
+}
+
+/// Reads the tokens of the private part of a font program, which has
+/// binary charstrings among them
+struct Scanner<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(data: &'a [u8], position: usize) -> Self {
+        Scanner { data, position }
+    }
+
+    fn token(&mut self) -> Option<&'a [u8]> {
+        let is_space = |c: &u8| b" \t\r\n\x0C\0".contains(c);
+        let rest = self.data.get(self.position..)?;
+        let start = rest.iter().position(|c| !is_space(c))?;
+        // A name's slash starts it, rather than ending the token before it
+        let length = rest[start + 1..]
+            .iter()
+            .position(|c| is_space(c) || b"/[]{}()<>".contains(c))
+            .map_or(rest.len() - start, |length| length + 1);
+        self.position += start + length;
+        Some(&rest[start..start + length])
+    }
+
+    fn integer(&mut self) -> Option<i64> {
+        std::str::from_utf8(self.token()?).ok()?.parse().ok()
+    }
+
+    /// A length, the `RD` or `-|` operator that reads that many bytes after
+    /// a single space, and the bytes
+    fn binary(&mut self) -> Option<&'a [u8]> {
+        let length = usize::try_from(self.integer()?).ok()?;
+        self.token()?;
+        let start = self.position + 1;
+        let binary = self.data.get(start..start + length)?;
+        self.position = start + length;
+        Some(binary)
+    }
+}
+
+/// Runs Type 1 charstrings, drawing what they draw onto an outline
+struct Interpreter<'f, 'o> {
+    font: &'f Type1,
+    outline: &'o mut Outline,
+    /// Where the glyph's origin is, which only isn't 0 for the accent of an
+    /// accented glyph
+    origin: Vector,
+    stack: Vec<f32>,
+    /// What `callothersubr` leaves for `pop`
+    results: Vec<f32>,
+    current: Point,
+    /// The points of a flex, which are moved to rather than drawn until the
+    /// flex ends, and where it starts
+    flex: Option<(Point, Vec<Point>)>,
+}
+
+impl<'f, 'o> Interpreter<'f, 'o> {
+    fn new(font: &'f Type1, outline: &'o mut Outline, origin: Vector) -> Self {
+        Interpreter {
+            font,
+            outline,
+            origin,
+            stack: vec![],
+            results: vec![],
+            current: point(0.0, 0.0),
+            flex: None,
+        }
+    }
+
+    /// Runs a charstring, returning whether it ended the glyph
+    fn run(&mut self, charstring: &[u8], depth: usize) -> Result<bool> {
+        let invalid = || FontError::InvalidProgram("charstring");
+        let mut i = 0;
+        while let Some(&byte) = charstring.get(i) {
+            i += 1;
+            let mut next = || {
+                i += 1;
+                charstring
+                    .get(i - 1)
+                    .map(|&b| i32::from(b))
+                    .ok_or_else(invalid)
+            };
+            match byte {
+                32..=246 => self.stack.push(f32::from(byte) - 139.0),
+                247..=250 => {
+                    let value = (i32::from(byte) - 247) * 256 + next()? + 108;
+                    self.stack.push(value as f32);
+                }
+                251..=254 => {
+                    let value = -(i32::from(byte) - 251) * 256 - next()? - 108;
+                    self.stack.push(value as f32);
+                }
+                255 => {
+                    let bytes = [next()?, next()?, next()?, next()?].map(|b| b as u8);
+                    self.stack.push(i32::from_be_bytes(bytes) as f32);
+                }
+                // hstem, vstem
+                1 | 3 => self.stack.clear(),
+                // vmoveto
+                4 => {
+                    let [dy] = self.args()?;
+                    self.move_by(0.0, dy);
+                }
+                // rlineto
+                5 => {
+                    let [dx, dy] = self.args()?;
+                    self.line_by(dx, dy);
+                }
+                // hlineto
+                6 => {
+                    let [dx] = self.args()?;
+                    self.line_by(dx, 0.0);
+                }
+                // vlineto
+                7 => {
+                    let [dy] = self.args()?;
+                    self.line_by(0.0, dy);
+                }
+                // rrcurveto
+                8 => {
+                    let [dx1, dy1, dx2, dy2, dx3, dy3] = self.args()?;
+                    self.curve_by(dx1, dy1, dx2, dy2, dx3, dy3);
+                }
+                // closepath
+                9 => {
+                    self.stack.clear();
+                    self.outline.close();
+                }
+                // callsubr
+                10 => {
+                    let subr = self.stack.pop().ok_or_else(invalid)?;
+                    let subr = self.font.subrs.get(subr as usize).ok_or_else(invalid)?;
+                    if depth >= MAX_SUBR_DEPTH {
+                        return Err(invalid().into());
+                    }
+                    if self.run(subr, depth + 1)? {
+                        return Ok(true);
+                    }
+                }
+                // return
+                11 => return Ok(false),
+                12 => {
+                    let operator = next()?;
+                    if self.escape(operator)? {
+                        return Ok(true);
+                    }
+                }
+                // hsbw
+                13 => {
+                    let [sbx, _] = self.args()?;
+                    self.current = point(sbx, 0.0) + self.origin;
+                }
+                // endchar
+                14 => {
+                    self.outline.close();
+                    return Ok(true);
+                }
+                // rmoveto
+                21 => {
+                    let [dx, dy] = self.args()?;
+                    self.move_by(dx, dy);
+                }
+                // hmoveto
+                22 => {
+                    let [dx] = self.args()?;
+                    self.move_by(dx, 0.0);
+                }
+                // vhcurveto
+                30 => {
+                    let [dy1, dx2, dy2, dx3] = self.args()?;
+                    self.curve_by(0.0, dy1, dx2, dy2, dx3, 0.0);
+                }
+                // hvcurveto
+                31 => {
+                    let [dx1, dx2, dy2, dy3] = self.args()?;
+                    self.curve_by(dx1, 0.0, dx2, dy2, 0.0, dy3);
+                }
+                _ => self.stack.clear(),
+            }
+        }
+        Ok(false)
+    }
+
+    /// Runs an operator that follows the escape byte, returning whether it
+    /// ended the glyph
+    fn escape(&mut self, operator: i32) -> Result<bool> {
+        let invalid = || FontError::InvalidProgram("charstring");
+        match operator {
+            // seac
+            6 => {
+                let [asb, adx, ady, base, accent] = self.args()?;
+                let font = self.font;
+                let glyph = |code: f32| {
+                    let code = usize::try_from(code as i64).ok()?;
+                    font.char_string((*STANDARD_GLYPH_NAMES.get(code)?)?)
+                };
+                let base = glyph(base).ok_or_else(invalid)?;
+                let accent = glyph(accent).ok_or_else(invalid)?;
+                Interpreter::new(font, self.outline, self.origin).run(base, 0)?;
+                // The accent's own sidebearing puts it at `asb` already
+                let origin = self.origin + vector(adx - asb, ady);
+                Interpreter::new(font, self.outline, origin).run(accent, 0)?;
+                return Ok(true);
+            }
+            // sbw
+            7 => {
+                let [sbx, sby, _, _] = self.args()?;
+                self.current = point(sbx, sby) + self.origin;
+            }
+            // div
+            12 => {
+                let [a, b] = [self.stack.pop(), self.stack.pop()];
+                match (b, a) {
+                    (Some(a), Some(b)) => self.stack.push(a / b),
+                    _ => return Err(invalid().into()),
+                }
+            }
+            // callothersubr
+            16 => {
+                let other = self.stack.pop().ok_or_else(invalid)? as i32;
+                let count = self.stack.pop().ok_or_else(invalid)? as usize;
+                let args = self
+                    .stack
+                    .split_off(self.stack.len().checked_sub(count).ok_or_else(invalid)?);
+                self.other_subr(other, args);
+            }
+            // pop
+            17 => {
+                let result = self.results.pop().ok_or_else(invalid)?;
+                self.stack.push(result);
+            }
+            // setcurrentpoint
+            33 => {
+                let [x, y] = self.args()?;
+                self.current = point(x, y) + self.origin;
+            }
+            // dotsection, vstem3, hstem3, and anything else
+            _ => self.stack.clear(),
+        }
+        Ok(false)
+    }
+
+    /// Runs one of the PostScript procedures that every Type 1 font has. The
+    /// ones for flex are drawn as curves, and hints are left out.
+    fn other_subr(&mut self, other: i32, args: Vec<f32>) {
+        match other {
+            // End a flex, which is two curves through the points that were
+            // moved to after the reference point
+            0 => {
+                if let Some((start, points)) = self.flex.take() {
+                    if let [_, p1, p2, p3, p4, p5, p6] = points[..] {
+                        self.outline.move_to(start);
+                        self.outline.curve_to(p1, p2, p3);
+                        self.outline.curve_to(p4, p5, p6);
+                    }
+                }
+                // `pop pop setcurrentpoint` gets the end of the flex back
+                if let [_, x, y] = args[..] {
+                    self.results.extend([y, x]);
+                }
+            }
+            1 => self.flex = Some((self.current, vec![])),
+            2 => {}
+            // Hint replacement, which calls subroutine 3, which only
+            // returns, since hints are left out
+            3 => self.results.push(3.0),
+            _ => self.results.extend(args.into_iter().rev()),
+        }
+    }
+
+    /// Pops the operands of an operator, clearing the stack
+    fn args<const N: usize>(&mut self) -> Result<[f32; N]> {
+        let start = self
+            .stack
+            .len()
+            .checked_sub(N)
+            .ok_or(FontError::InvalidProgram("charstring"))?;
+        let mut args = [0.0; N];
+        args.copy_from_slice(&self.stack[start..]);
+        self.stack.clear();
+        Ok(args)
+    }
+
+    fn move_by(&mut self, dx: f32, dy: f32) {
+        self.current += vector(dx, dy);
+        match &mut self.flex {
+            Some((_, points)) => points.push(self.current),
+            None => self.outline.move_to(self.current),
+        }
+    }
+
+    fn line_by(&mut self, dx: f32, dy: f32) {
+        self.current += vector(dx, dy);
+        self.outline.line_to(self.current);
+    }
+
+    fn curve_by(&mut self, dx1: f32, dy1: f32, dx2: f32, dy2: f32, dx3: f32, dy3: f32) {
+        let ctrl1 = self.current + vector(dx1, dy1);
+        let ctrl2 = ctrl1 + vector(dx2, dy2);
+        self.current = ctrl2 + vector(dx3, dy3);
+        self.outline.curve_to(ctrl1, ctrl2, self.current);
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use lyon::math::point;
+    use lyon::path::PathEvent;
+
+    use super::{Type1, CHARSTRING_KEY, EEXEC_KEY};
+
+    fn encrypt(data: &[u8], key: u16) -> Vec<u8> {
+        let mut r = key;
+        [0, 0, 0, 0]
+            .iter()
+            .chain(data)
+            .map(|&plain| {
+                let cipher = plain ^ (r >> 8) as u8;
+                r = (u16::from(cipher).wrapping_add(r))
+                    .wrapping_mul(52845)
+                    .wrapping_add(22719);
+                cipher
+            })
+            .collect()
+    }
+
+    /// Encodes the numbers of a charstring, which are between -107 and 107
+    fn charstring(numbers_and_operators: &[i32]) -> Vec<u8> {
+        numbers_and_operators
+            .iter()
+            .map(|&n| match n {
+                // Operators are given as their negated codes
+                -32..=-1 => (-n) as u8,
+                _ => (n + 139) as u8,
+            })
+            .collect()
+    }
+
+    fn binary(data: &mut Vec<u8>, prefix: &str, charstring: &[u8], suffix: &str) {
+        let encrypted = encrypt(charstring, CHARSTRING_KEY);
+        data.extend(format!("{} {} RD ", prefix, encrypted.len()).bytes());
+        data.extend(encrypted);
+        data.extend(format!(" {}\n", suffix).bytes());
+    }
+
+    /// A font whose `A` is a square drawn partly by a subroutine, whose
+    /// `acute` is a line, and whose `Aacute` is both through `seac`
+    pub(in crate::font) fn font() -> Vec<u8> {
+        program(1)
+    }
+
+    /// The font, with `/Subrs` claiming a number of subroutines
+    fn program(subrs: u32) -> Vec<u8> {
+        let mut private = format!(
+            "dup /Private 8 dict dup begin\n/lenIV 4 def\n/Subrs {} array\n",
+            subrs
+        )
+        .into_bytes();
+        // rlineto, return
+        binary(&mut private, "dup 0", &charstring(&[0, 100, -5, -11]), "NP");
+        private.extend(b"2 index /CharStrings 4 dict dup begin\n");
+        // hsbw, endchar
+        binary(
+            &mut private,
+            "/.notdef",
+            &charstring(&[0, 50, -13, -14]),
+            "ND",
+        );
+        // hsbw, rmoveto, hlineto, callsubr, hlineto, closepath, endchar
+        let a = charstring(&[10, 100, -13, 0, 0, -21, 100, -6, 0, -10, -100, -6, -9, -14]);
+        binary(&mut private, "/A", &a, "ND");
+        // hsbw, rmoveto, rlineto, closepath, endchar
+        let acute = charstring(&[20, 50, -13, 0, 100, -21, 10, 10, -5, -9, -14]);
+        binary(&mut private, "/acute", &acute, "ND");
+        // asb adx ady bchar achar seac: `A` is 65, `acute` is 194
+        let mut aacute = charstring(&[20, 30, 20, 65]);
+        aacute.extend([247, 86, 12, 6]);
+        binary(&mut private, "/Aacute", &aacute, "ND");
+        private.extend(b"end\nend\n");
+        let mut data = b"%!PS-AdobeFont-1.0: Test 001.000\n\
+            /FontMatrix [0.002 0 0 0.002 0 0] readonly def\n\
+            /Encoding 256 array\n\
+            0 1 255 {1 index exch /.notdef put} for\n\
+            dup 65 /A put\n\
+            dup 193 /Aacute put\n\
+            readonly def\n\
+            currentfile eexec\n"
+            .to_vec();
+        data.extend(encrypt(&private, EEXEC_KEY));
+        data.extend(b"\n0000000000000000\ncleartomark\n");
+        data
+    }
+
+    #[test]
+    fn test_type1() {
+        let font = Type1::parse(&font()).unwrap();
+        assert_eq!(font.font_matrix, [0.002, 0.0, 0.0, 0.002, 0.0, 0.0]);
+        assert_eq!(font.encoding.glyph_name(65), Some("A"));
+        assert_eq!(font.encoding.glyph_name(193), Some("Aacute"));
+        assert_eq!(font.encoding.glyph_name(66), None);
+        assert_eq!(font.glyph_id(".notdef"), Some(0));
+        assert_eq!(font.glyph_id("Aacute"), Some(3));
+        assert_eq!(font.glyph_id("B"), None);
+        assert_eq!(font.outline(0).unwrap(), []);
+
+        // Scaled from 500 units to the em to 1000
+        let square = [
+            point(20.0, 0.0),
+            point(220.0, 0.0),
+            point(220.0, 200.0),
+            point(20.0, 200.0),
+        ];
+        let a = font.outline(1).unwrap();
+        assert_eq!(a[0], PathEvent::Begin { at: square[0] });
+        assert_eq!(
+            a[1..4],
+            [
+                PathEvent::Line {
+                    from: square[0],
+                    to: square[1]
+                },
+                PathEvent::Line {
+                    from: square[1],
+                    to: square[2]
+                },
+                PathEvent::Line {
+                    from: square[2],
+                    to: square[3]
+                },
+            ]
+        );
+        assert_eq!(
+            a[4],
+            PathEvent::End {
+                first: square[0],
+                last: square[3],
+                close: true
+            }
+        );
+
+        // The accent is moved by adx - asb, from where its own sidebearing
+        // put it
+        let aacute = font.outline(3).unwrap();
+        assert_eq!(aacute[..5], a[..]);
+        assert_eq!(
+            aacute[5..7],
+            [
+                PathEvent::Begin {
+                    at: point(60.0, 240.0)
+                },
+                PathEvent::Line {
+                    from: point(60.0, 240.0),
+                    to: point(80.0, 260.0)
+                },
+            ]
+        );
+        assert!(Type1::parse(b"%!PS-AdobeFont-1.0").is_err());
+
+        // A count of subroutines that the program doesn't have room for
+        let font = Type1::parse(&program(u32::MAX)).unwrap();
+        assert_eq!(font.subrs.len(), 1);
+        assert_eq!(font.outline(1).unwrap(), a);
+    }
+}
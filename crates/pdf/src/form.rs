@@ -0,0 +1,174 @@
+use anyhow::Result;
+use shared::ColorSpace;
+
+use crate::{
+    color_space,
+    document::Document,
+    error::XObjectError,
+    object::Object,
+    resources::Resources,
+    stream::{parse_content, parse_text_content, StreamObject},
+};
+
+/// A form XObject, a self-contained drawing painted by `Do`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Form<'d, 'a> {
+    /// `[x_min y_min x_max y_max]` bounds the form is clipped to, in form space
+    pub bbox: [f32; 4],
+    /// Maps form space to the user space the form is painted in
+    pub matrix: [f32; 6],
+    /// What the form's content refers to by name
+    pub resources: Resources<'d, 'a>,
+    /// Present if the form is a transparency group
+    pub group: Option<TransparencyGroup>,
+    content: Vec<u8>,
+}
+
+/// The `/Group` of a form whose `/S` is `/Transparency`, whose content is
+/// composited together before being painted onto the page
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransparencyGroup {
+    /// `/CS`, the space that the content is blended in
+    pub color_space: Option<ColorSpace>,
+    /// `/I`, whether the content is blended onto a transparent backdrop
+    /// rather than onto what's under the group
+    pub isolated: bool,
+    /// `/K`, whether each element is composited with the group's initial
+    /// backdrop rather than with the elements before it
+    pub knockout: bool,
+}
+
+impl<'d, 'a> Form<'d, 'a> {
+    /// Reads a form XObject. A form without `/Resources` of its own uses
+    /// `outer`, the resources that it was found in.
+    pub(crate) fn from_object(
+        document: &'d Document<'a>,
+        object: &'d Object<'a>,
+        outer: Resources<'d, 'a>,
+    ) -> Result<Form<'d, 'a>> {
+        let stream = document.dereference(object)?.as_stream()?;
+        let dict = &stream.dict;
+        match document.dereference(dict.get(b"Subtype")?)?.as_name_str()? {
+            "Form" => {}
+            other => return Err(XObjectError::UnsupportedSubtype(other.to_owned()).into()),
+        }
+        let bbox = match document.get_floats(dict.get(b"BBox")?)?[..] {
+            [x0, y0, x1, y1] => [x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)],
+            _ => return Err(XObjectError::InvalidEntry("BBox").into()),
+        };
+        let matrix = match dict.get(b"Matrix") {
+            Ok(matrix) => match document.get_floats(matrix)?[..] {
+                [a, b, c, d, e, f] => [a, b, c, d, e, f],
+                _ => return Err(XObjectError::InvalidEntry("Matrix").into()),
+            },
+            Err(_) => shared::IDENTITY,
+        };
+        let resources = match dict.get(b"Resources") {
+            Ok(resources) => {
+                Resources::new(document, Some(document.dereference(resources)?.as_dict()?))
+            }
+            Err(_) => outer,
+        };
+        let group = match dict.get(b"Group") {
+            Ok(group) => transparency_group(document, group)?,
+            Err(_) => None,
+        };
+        Ok(Form {
+            bbox,
+            matrix,
+            resources,
+            group,
+            content: stream.decoded_content()?.into_owned(),
+        })
+    }
+
+    /// The operators that draw the form, in form space
+    pub fn content(&self) -> Result<Vec<StreamObject>> {
+        parse_content(&self.content)
+    }
+
+    /// Like `content`, but for finding text, which skips operators that
+    /// aren't understood
+    pub(crate) fn text_content(&self) -> Vec<StreamObject> {
+        parse_text_content(&self.content)
+    }
+}
+
+/// Reads a group attributes dictionary. Only transparency groups are
+/// defined, so other kinds are ignored.
+fn transparency_group<'a>(
+    document: &Document<'a>,
+    object: &Object<'a>,
+) -> Result<Option<TransparencyGroup>> {
+    let dict = document.dereference(object)?.as_dict()?;
+    if document.dereference(dict.get(b"S")?)?.as_name()? != b"Transparency" {
+        return Ok(None);
+    }
+    let flag = |key: &[u8]| -> Result<bool> {
+        match dict.get(key) {
+            Ok(flag) => document.dereference(flag)?.as_bool(),
+            Err(_) => Ok(false),
+        }
+    };
+    let color_space = match dict.get(b"CS") {
+        Ok(color_space) => Some(color_space::resolve(document, color_space)?),
+        Err(_) => None,
+    };
+    Ok(Some(TransparencyGroup {
+        color_space,
+        isolated: flag(b"I")?,
+        knockout: flag(b"K")?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::ColorSpace;
+
+    use super::Form;
+    use crate::{
+        array, bool, dict, dictionary::Dictionary, dictionary_struct, document::Document, int,
+        name, object::Object, resources::Resources, stream, stream::Stream, StreamObject,
+    };
+
+    #[test]
+    fn test_form() {
+        let document = Document::new();
+        let object = stream!(Stream {
+            dict: dictionary_struct! {
+                "Type" => name!("XObject"),
+                "Subtype" => name!("Form"),
+                "BBox" => array!(int!(0), int!(0), int!(100), int!(50)),
+                "Group" => dict!(dictionary_struct! {
+                    "S" => name!("Transparency"),
+                    "CS" => name!("DeviceRGB"),
+                    "I" => bool!(true),
+                }),
+            },
+            content: b"0 0 10 10 re f",
+            allows_compression: true,
+            start_position: None,
+        });
+        let outer = Resources::new(&document, None);
+        let form = Form::from_object(&document, &object, outer).unwrap();
+        assert_eq!(form.bbox, [0.0, 0.0, 100.0, 50.0]);
+        assert_eq!(form.matrix, shared::IDENTITY);
+        assert_eq!(form.resources, outer);
+        let group = form.group.as_ref().unwrap();
+        assert_eq!(group.color_space, Some(ColorSpace::DeviceRGB));
+        assert!(group.isolated);
+        assert!(!group.knockout);
+        assert_eq!(form.content().unwrap().last(), Some(&StreamObject::Fill));
+
+        let image = stream!(Stream {
+            dict: dictionary_struct! {
+                "Subtype" => name!("Image"),
+                "BBox" => array!(int!(0), int!(0), int!(1), int!(1)),
+            },
+            content: b"",
+            allows_compression: true,
+            start_position: None,
+        });
+        assert!(Form::from_object(&document, &image, outer).is_err());
+    }
+}
@@ -0,0 +1,536 @@
+use anyhow::Result;
+use shared::TintTransform;
+
+mod postscript;
+
+use self::postscript::{Op, Value};
+use crate::{
+    document::Document,
+    error::{FunctionError, HandlingError},
+    object::Object,
+};
+
+/// Limit on how deeply stitching functions may be nested, which protects
+/// against cycles in malformed files
+const MAX_NESTING: usize = 16;
+
+/// Limit on how many inputs a sampled function may have, since evaluating
+/// one interpolates between `2^m` samples
+const MAX_SAMPLED_INPUTS: usize = 16;
+
+/// A PDF function object, which maps `m` input values to `n` output values
+#[derive(Debug, Clone, PartialEq)]
+pub enum Function {
+    /// Type 0, interpolating between samples on an `m`-dimensional grid
+    Sampled {
+        domain: Vec<f32>,
+        range: Vec<f32>,
+        size: Vec<usize>,
+        encode: Vec<f32>,
+        /// Every output of every grid point, with `/Decode` already applied
+        samples: Vec<f32>,
+    },
+    /// Type 2, interpolating between `c0` and `c1` with exponent `n`
+    Exponential {
+        domain: Vec<f32>,
+        range: Option<Vec<f32>>,
+        c0: Vec<f32>,
+        c1: Vec<f32>,
+        n: f32,
+    },
+    /// Type 3, which splits its domain between other 1-input functions
+    Stitching {
+        domain: Vec<f32>,
+        range: Option<Vec<f32>>,
+        functions: Vec<Function>,
+        bounds: Vec<f32>,
+        encode: Vec<f32>,
+    },
+    /// Type 4, a program in a small subset of PostScript
+    PostScript {
+        domain: Vec<f32>,
+        range: Vec<f32>,
+        program: Vec<Op>,
+    },
+}
+
+impl Function {
+    pub fn from_object<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<Function> {
+        Function::parse(document, object, 0)
+    }
+
+    fn parse<'a>(document: &Document<'a>, object: &Object<'a>, depth: usize) -> Result<Function> {
+        if depth > MAX_NESTING {
+            return Err(FunctionError::InvalidEntry("Functions").into());
+        }
+        let object = document.dereference(object)?;
+        let (dict, stream) = match object {
+            Object::Dictionary(dict) => (dict, None),
+            Object::Stream(stream) => (&stream.dict, Some(stream)),
+            _ => return Err(HandlingError::ObjectCast.into()),
+        };
+        let floats = |key: &str| -> Result<Option<Vec<f32>>> {
+            dict.get(key.as_bytes())
+                .ok()
+                .map(|value| document.get_floats(value))
+                .transpose()
+        };
+        // A `/Domain` or `/Range`, made up of `[min max]` pairs
+        let pairs = |key: &'static str| -> Result<Option<Vec<f32>>> {
+            match floats(key)? {
+                Some(values)
+                    if values.is_empty()
+                        || values.len() & 1 != 0
+                        || values.chunks(2).any(|pair| pair[0] > pair[1]) =>
+                {
+                    Err(FunctionError::InvalidEntry(key).into())
+                }
+                values => Ok(values),
+            }
+        };
+        let required = |key: &'static str| -> Result<Vec<f32>> {
+            Ok(pairs(key)?.ok_or(FunctionError::MissingEntry(key))?)
+        };
+        let stream = || stream.ok_or(HandlingError::ObjectCast);
+        let domain = required("Domain")?;
+        match document.dereference(dict.get(b"FunctionType")?)?.as_i64()? {
+            0 => {
+                let range = required("Range")?;
+                let size = document
+                    .dereference(dict.get(b"Size")?)?
+                    .as_array()?
+                    .iter()
+                    .map(|v| Ok(document.dereference(v)?.as_i64()?.max(1) as usize))
+                    .collect::<Result<Vec<_>>>()?;
+                if size.len() != domain.len() / 2 || size.len() > MAX_SAMPLED_INPUTS {
+                    return Err(FunctionError::InvalidEntry("Size").into());
+                }
+                let bits = document
+                    .dereference(dict.get(b"BitsPerSample")?)?
+                    .as_i64()?;
+                if ![1, 2, 4, 8, 12, 16, 24, 32].contains(&bits) {
+                    return Err(FunctionError::InvalidEntry("BitsPerSample").into());
+                }
+                let default_encode = size
+                    .iter()
+                    .flat_map(|&s| vec![0.0, (s - 1) as f32])
+                    .collect();
+                let encode = floats("Encode")?.unwrap_or(default_encode);
+                let decode = floats("Decode")?.unwrap_or_else(|| range.clone());
+                if encode.len() != domain.len() || decode.len() != range.len() {
+                    return Err(FunctionError::InvalidEntry("Encode").into());
+                }
+                let data = stream()?.decoded_content()?;
+                let samples = read_samples(&data, bits as u32, &size, &decode)?;
+                Ok(Function::Sampled {
+                    domain,
+                    range,
+                    size,
+                    encode,
+                    samples,
+                })
+            }
+            2 => Ok(Function::Exponential {
+                domain,
+                range: pairs("Range")?,
+                c0: floats("C0")?.unwrap_or_else(|| vec![0.0]),
+                c1: floats("C1")?.unwrap_or_else(|| vec![1.0]),
+                n: document.dereference(dict.get(b"N")?)?.as_float()? as f32,
+            }),
+            3 => {
+                let functions = document
+                    .dereference(dict.get(b"Functions")?)?
+                    .as_array()?
+                    .iter()
+                    .map(|f| Function::parse(document, f, depth + 1))
+                    .collect::<Result<Vec<_>>>()?;
+                let bounds = floats("Bounds")?.ok_or(FunctionError::MissingEntry("Bounds"))?;
+                let encode = floats("Encode")?.ok_or(FunctionError::MissingEntry("Encode"))?;
+                if functions.is_empty()
+                    || bounds.len() + 1 != functions.len()
+                    || encode.len() != 2 * functions.len()
+                {
+                    return Err(FunctionError::InvalidEntry("Functions").into());
+                }
+                Ok(Function::Stitching {
+                    domain,
+                    range: pairs("Range")?,
+                    functions,
+                    bounds,
+                    encode,
+                })
+            }
+            4 => Ok(Function::PostScript {
+                domain,
+                range: required("Range")?,
+                program: postscript::parse(&stream()?.decoded_content()?)?,
+            }),
+            other => Err(FunctionError::UnsupportedType(other).into()),
+        }
+    }
+
+    pub fn evaluate(&self, input: &[f32]) -> Vec<f32> {
+        match self {
+            Function::Sampled {
+                domain,
+                range,
+                size,
+                encode,
+                samples,
+            } => {
+                let n = range.len() / 2;
+                let mut encoded = Vec::with_capacity(size.len());
+                for (i, &s) in size.iter().enumerate() {
+                    let x = input
+                        .get(i)
+                        .copied()
+                        .unwrap_or(0.0)
+                        .clamp(domain[2 * i], domain[2 * i + 1]);
+                    let e = interpolate(
+                        x,
+                        domain[2 * i],
+                        domain[2 * i + 1],
+                        encode[2 * i],
+                        encode[2 * i + 1],
+                    );
+                    encoded.push(e.clamp(0.0, (s - 1) as f32));
+                }
+                let output = multilinear(samples, size, n, &encoded);
+                clip(&output, range)
+            }
+            Function::Exponential {
+                domain,
+                range,
+                c0,
+                c1,
+                n,
+            } => {
+                let x = clip(input, domain).first().copied().unwrap_or(domain[0]);
+                let output = c0
+                    .iter()
+                    .zip(c1)
+                    .map(|(c0, c1)| c0 + x.powf(*n) * (c1 - c0))
+                    .collect::<Vec<_>>();
+                match range {
+                    Some(range) => clip(&output, range),
+                    None => output,
+                }
+            }
+            Function::Stitching {
+                domain,
+                range,
+                functions,
+                bounds,
+                encode,
+            } => {
+                let x = clip(input, domain).first().copied().unwrap_or(domain[0]);
+                // The last subdomain also includes the end of the domain
+                let i = bounds.iter().take_while(|&&b| x >= b).count();
+                let low = if i == 0 { domain[0] } else { bounds[i - 1] };
+                let high = bounds.get(i).copied().unwrap_or(domain[1]);
+                let x = interpolate(x, low, high, encode[2 * i], encode[2 * i + 1]);
+                let output = functions[i].evaluate(&[x]);
+                match range {
+                    Some(range) => clip(&output, range),
+                    None => output,
+                }
+            }
+            Function::PostScript {
+                domain,
+                range,
+                program,
+            } => {
+                let mut stack = clip(input, domain)
+                    .into_iter()
+                    .map(|v| Value::Real(v as f64))
+                    .collect();
+                let n = range.len() / 2;
+                // An error in the program leaves every output at its minimum
+                let output = postscript::run(program, &mut stack)
+                    .and_then(|_| postscript::outputs(&stack, n))
+                    .unwrap_or_else(|| vec![f32::MIN; n]);
+                clip(&output, range)
+            }
+        }
+    }
+}
+
+impl TintTransform for Function {
+    fn transform(&self, input: &[f32]) -> Vec<f32> {
+        self.evaluate(input)
+    }
+}
+
+/// Clips each value to its `[min max]` pair of `bounds`
+fn clip(values: &[f32], bounds: &[f32]) -> Vec<f32> {
+    values
+        .iter()
+        .zip(bounds.chunks_exact(2))
+        .map(|(v, b)| v.clamp(b[0], b[1]))
+        .collect()
+}
+
+/// Maps `x` from `[x_min, x_max]` to `[y_min, y_max]`
+fn interpolate(x: f32, x_min: f32, x_max: f32, y_min: f32, y_max: f32) -> f32 {
+    if x_max == x_min {
+        return y_min;
+    }
+    y_min + (x - x_min) * (y_max - y_min) / (x_max - x_min)
+}
+
+/// Unpacks the big-endian samples of a sampled function and applies `decode`
+fn read_samples(data: &[u8], bits: u32, size: &[usize], decode: &[f32]) -> Result<Vec<f32>> {
+    let n = decode.len() / 2;
+    let count = size
+        .iter()
+        .try_fold(n, |count, &s| count.checked_mul(s))
+        .ok_or(FunctionError::InvalidEntry("Size"))?;
+    let needed = count
+        .checked_mul(bits as usize)
+        .ok_or(FunctionError::InvalidEntry("Size"))?;
+    if data.len().saturating_mul(8) < needed {
+        return Err(FunctionError::NotEnoughSamples.into());
+    }
+    let max = ((1u64 << bits) - 1) as f32;
+    let mut samples = Vec::with_capacity(count);
+    let mut accumulator = 0u64;
+    let mut available = 0;
+    let mut bytes = data.iter();
+    for i in 0..count {
+        while available < bits {
+            accumulator = (accumulator << 8) | *bytes.next().unwrap_or(&0) as u64;
+            available += 8;
+        }
+        available -= bits;
+        let sample = (accumulator >> available) & ((1u64 << bits) - 1);
+        let j = i % n;
+        samples.push(interpolate(
+            sample as f32,
+            0.0,
+            max,
+            decode[2 * j],
+            decode[2 * j + 1],
+        ));
+    }
+    Ok(samples)
+}
+
+/// Interpolates the `n` outputs at the fractional grid position `e` from
+/// the `2^m` surrounding samples. The first dimension varies fastest.
+fn multilinear(samples: &[f32], size: &[usize], n: usize, e: &[f32]) -> Vec<f32> {
+    let mut output = vec![0.0; n];
+    for corner in 0..1usize << size.len() {
+        let mut weight = 1.0;
+        let mut index = 0;
+        let mut stride = 1;
+        for (i, (&s, &e)) in size.iter().zip(e).enumerate() {
+            let floor = e.floor();
+            let fraction = e - floor;
+            let upper = corner >> i & 1 == 1;
+            let position = if upper {
+                weight *= fraction;
+                (floor as usize + 1).min(s - 1)
+            } else {
+                weight *= 1.0 - fraction;
+                floor as usize
+            };
+            index += position * stride;
+            stride *= s;
+        }
+        if weight == 0.0 {
+            continue;
+        }
+        for (o, sample) in output.iter_mut().zip(&samples[index * n..]) {
+            *o += weight * sample;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Function;
+    use crate::{
+        array, dict, dictionary::Dictionary, dictionary_struct, document::Document, int,
+        object::Object, real, stream, stream::Stream,
+    };
+
+    fn function_stream(dict: Dictionary<'static>, content: &'static [u8]) -> Object<'static> {
+        stream!(Stream {
+            dict,
+            content,
+            allows_compression: true,
+            start_position: None,
+        })
+    }
+
+    fn assert_outputs(actual: Vec<f32>, expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            approx::assert_relative_eq!(a, e, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn test_sampled() {
+        let document = Document::new();
+        // A 2x2 grid of 8 bit samples with a single output
+        let object = function_stream(
+            dictionary_struct! {
+                "FunctionType" => int!(0),
+                "Domain" => array!(int!(0), int!(1), int!(0), int!(1)),
+                "Range" => array!(int!(0), int!(1)),
+                "Size" => array!(int!(2), int!(2)),
+                "BitsPerSample" => int!(8),
+            },
+            &[0, 255, 255, 0],
+        );
+        let function = Function::from_object(&document, &object).unwrap();
+        assert_outputs(function.evaluate(&[0.0, 0.0]), &[0.0]);
+        assert_outputs(function.evaluate(&[1.0, 0.0]), &[1.0]);
+        assert_outputs(function.evaluate(&[0.5, 0.5]), &[0.5]);
+        assert_outputs(function.evaluate(&[0.25, 0.0]), &[0.25]);
+        // Inputs are clipped to the domain
+        assert_outputs(function.evaluate(&[-1.0, 2.0]), &[1.0]);
+    }
+
+    #[test]
+    fn test_sampled_bits_and_decode() {
+        let document = Document::new();
+        // Four 4 bit samples with two outputs each: (0, 15) (15, 0)
+        let object = function_stream(
+            dictionary_struct! {
+                "FunctionType" => int!(0),
+                "Domain" => array!(int!(0), int!(1)),
+                "Range" => array!(int!(0), int!(1), int!(0), int!(10)),
+                "Decode" => array!(int!(0), int!(1), int!(0), int!(10)),
+                "Size" => array!(int!(2)),
+                "BitsPerSample" => int!(4),
+            },
+            &[0x0f, 0xf0],
+        );
+        let function = Function::from_object(&document, &object).unwrap();
+        assert_outputs(function.evaluate(&[0.0]), &[0.0, 10.0]);
+        assert_outputs(function.evaluate(&[0.5]), &[0.5, 5.0]);
+
+        let object = function_stream(
+            dictionary_struct! {
+                "FunctionType" => int!(0),
+                "Domain" => array!(int!(0), int!(1)),
+                "Range" => array!(int!(0), int!(1)),
+                "Size" => array!(int!(3)),
+                "BitsPerSample" => int!(16),
+            },
+            &[0, 0, 0xff, 0xff],
+        );
+        assert!(Function::from_object(&document, &object).is_err());
+
+        // A size whose samples can't be counted
+        let object = function_stream(
+            dictionary_struct! {
+                "FunctionType" => int!(0),
+                "Domain" => array!(int!(0), int!(1), int!(0), int!(1)),
+                "Range" => array!(int!(0), int!(1)),
+                "Size" => array!(int!(i64::MAX), int!(i64::MAX)),
+                "BitsPerSample" => int!(32),
+            },
+            &[0, 0, 0xff, 0xff],
+        );
+        assert!(Function::from_object(&document, &object).is_err());
+    }
+
+    #[test]
+    fn test_exponential() {
+        let document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "FunctionType" => int!(2),
+            "Domain" => array!(int!(0), int!(1)),
+            "C0" => array!(int!(1), int!(0), int!(0)),
+            "C1" => array!(int!(0), int!(0), int!(1)),
+            "N" => int!(1),
+        });
+        let function = Function::from_object(&document, &object).unwrap();
+        assert_eq!(function.evaluate(&[0.25]), vec![0.75, 0.0, 0.25]);
+        // Inputs are clipped to the domain
+        assert_eq!(function.evaluate(&[2.0]), vec![0.0, 0.0, 1.0]);
+
+        let object = dict!(dictionary_struct! {
+            "FunctionType" => int!(2),
+            "Domain" => array!(int!(0), int!(1)),
+            "N" => real!(2.0),
+        });
+        let function = Function::from_object(&document, &object).unwrap();
+        assert_eq!(function.evaluate(&[0.5]), vec![0.25]);
+    }
+
+    #[test]
+    fn test_stitching() {
+        let document = Document::new();
+        let linear = || {
+            dict!(dictionary_struct! {
+                "FunctionType" => int!(2),
+                "Domain" => array!(int!(0), int!(1)),
+                "N" => int!(1),
+            })
+        };
+        let object = dict!(dictionary_struct! {
+            "FunctionType" => int!(3),
+            "Domain" => array!(int!(0), int!(2)),
+            "Functions" => array!(linear(), linear()),
+            "Bounds" => array!(int!(1)),
+            // The second function runs backwards
+            "Encode" => array!(int!(0), int!(1), int!(1), int!(0)),
+        });
+        let function = Function::from_object(&document, &object).unwrap();
+        assert_outputs(function.evaluate(&[0.5]), &[0.5]);
+        assert_outputs(function.evaluate(&[1.0]), &[1.0]);
+        assert_outputs(function.evaluate(&[1.5]), &[0.5]);
+        assert_outputs(function.evaluate(&[2.0]), &[0.0]);
+    }
+
+    #[test]
+    fn test_postscript() {
+        let document = Document::new();
+        let object = function_stream(
+            dictionary_struct! {
+                "FunctionType" => int!(4),
+                "Domain" => array!(int!(0), int!(1), int!(0), int!(1)),
+                "Range" => array!(int!(0), int!(1), int!(0), int!(1)),
+            },
+            b"{ 2 copy add 2 div 3 1 roll exch pop 1 index exch gt { 1 } { 0 } ifelse }",
+        );
+        let function = Function::from_object(&document, &object).unwrap();
+        assert_outputs(function.evaluate(&[0.2, 0.6]), &[0.4, 0.0]);
+        assert_outputs(function.evaluate(&[0.8, 0.6]), &[0.7, 1.0]);
+        // Outputs are clipped to the range
+        let object = function_stream(
+            dictionary_struct! {
+                "FunctionType" => int!(4),
+                "Domain" => array!(int!(0), int!(1)),
+                "Range" => array!(int!(0), int!(1)),
+            },
+            b"{ 10 mul }",
+        );
+        let function = Function::from_object(&document, &object).unwrap();
+        assert_outputs(function.evaluate(&[0.5]), &[1.0]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "FunctionType" => int!(1),
+            "Domain" => array!(int!(0), int!(1)),
+        });
+        assert!(Function::from_object(&document, &object).is_err());
+        let object = dict!(dictionary_struct! { "FunctionType" => int!(2) });
+        assert!(Function::from_object(&document, &object).is_err());
+        // Types 0 and 4 must be streams
+        let object = dict!(dictionary_struct! {
+            "FunctionType" => int!(4),
+            "Domain" => array!(int!(0), int!(1)),
+            "Range" => array!(int!(0), int!(1)),
+        });
+        assert!(Function::from_object(&document, &object).is_err());
+    }
+}
@@ -0,0 +1,464 @@
+//! The subset of PostScript used by Type 4 functions: numbers, booleans,
+//! the arithmetic, relational, bitwise and stack operators, and `if` and
+//! `ifelse`.
+
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::char,
+    combinator::{all_consuming, map, map_res, verify},
+    multi::many0,
+    sequence::{delimited, terminated},
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+use strum_macros::EnumString;
+
+use crate::{
+    error::FunctionError,
+    utils::{_real, int1, ws},
+    NomResult,
+};
+
+/// The operand stack may hold at most 100 values
+const MAX_STACK: usize = 100;
+
+/// Limit on how deeply procedures may be nested, since they're parsed and
+/// run recursively
+const MAX_NESTING: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Real(f64),
+    Bool(bool),
+}
+
+impl Value {
+    fn real(self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(i as f64),
+            Value::Real(r) => Some(r),
+            Value::Bool(_) => None,
+        }
+    }
+
+    fn int(self) -> Option<i64> {
+        match self {
+            Value::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum Operator {
+    Abs,
+    Add,
+    Atan,
+    Ceiling,
+    Cos,
+    Cvi,
+    Cvr,
+    Div,
+    Exp,
+    Floor,
+    Idiv,
+    Ln,
+    Log,
+    Mod,
+    Mul,
+    Neg,
+    Round,
+    Sin,
+    Sqrt,
+    Sub,
+    Truncate,
+    And,
+    Bitshift,
+    Eq,
+    False,
+    Ge,
+    Gt,
+    Le,
+    Lt,
+    Ne,
+    Not,
+    Or,
+    True,
+    Xor,
+    Copy,
+    Dup,
+    Exch,
+    Index,
+    Pop,
+    Roll,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Op {
+    Push(Value),
+    Operator(Operator),
+    If(Vec<Op>),
+    IfElse(Vec<Op>, Vec<Op>),
+}
+
+pub fn parse(input: &[u8]) -> Result<Vec<Op>> {
+    let mut depth = 0usize;
+    for &c in input {
+        match c {
+            b'{' => depth += 1,
+            b'}' => depth = depth.saturating_sub(1),
+            _ => continue,
+        }
+        if depth > MAX_NESTING {
+            return Err(FunctionError::InvalidPostScript.into());
+        }
+    }
+    all_consuming(ws(block))(input)
+        .map(|(_, program)| program)
+        .map_err(|_| FunctionError::InvalidPostScript.into())
+}
+
+fn block(input: &[u8]) -> NomResult<Vec<Op>> {
+    delimited(ws(char('{')), many0(op), ws(char('}')))(input)
+}
+
+/// `if` or `ifelse`. The first procedure is only parsed once, however it
+/// turns out to be used, so that nested procedures take linear time.
+fn conditional(input: &[u8]) -> NomResult<Op> {
+    let (rest, then) = block(input)?;
+    if let Ok((rest, _)) = ws(keyword("if"))(rest) {
+        return Ok((rest, Op::If(then)));
+    }
+    let (rest, otherwise) = terminated(block, ws(keyword("ifelse")))(rest)?;
+    Ok((rest, Op::IfElse(then, otherwise)))
+}
+
+fn keyword(name: &'static str) -> impl Fn(&[u8]) -> NomResult<&[u8]> {
+    move |input| {
+        verify(
+            take_while1(|c: u8| c.is_ascii_alphabetic()),
+            |word: &[u8]| word == name.as_bytes(),
+        )(input)
+    }
+}
+
+fn op(input: &[u8]) -> NomResult<Op> {
+    alt((
+        conditional,
+        map(ws(_real), |r| Op::Push(Value::Real(r))),
+        map(ws(int1), |i| Op::Push(Value::Int(i))),
+        map(ws(operator), Op::Operator),
+    ))(input)
+}
+
+fn operator(input: &[u8]) -> NomResult<Operator> {
+    map_res(take_while1(|c: u8| c.is_ascii_alphabetic()), |name| {
+        Operator::from_str(std::str::from_utf8(name).unwrap())
+    })(input)
+}
+
+/// Runs `program` on `stack`, or returns `None` if it fails with an error
+/// such as a stack underflow or an operand of the wrong type
+pub fn run(program: &[Op], stack: &mut Vec<Value>) -> Option<()> {
+    for op in program {
+        match op {
+            Op::Push(value) => stack.push(*value),
+            Op::Operator(operator) => apply(*operator, stack)?,
+            Op::If(then) => match stack.pop()? {
+                Value::Bool(true) => run(then, stack)?,
+                Value::Bool(false) => {}
+                _ => return None,
+            },
+            Op::IfElse(then, otherwise) => match stack.pop()? {
+                Value::Bool(true) => run(then, stack)?,
+                Value::Bool(false) => run(otherwise, stack)?,
+                _ => return None,
+            },
+        }
+        if stack.len() > MAX_STACK {
+            return None;
+        }
+    }
+    Some(())
+}
+
+/// The top `n` values on the stack, as the outputs of the function
+pub fn outputs(stack: &[Value], n: usize) -> Option<Vec<f32>> {
+    let start = stack.len().checked_sub(n)?;
+    stack[start..]
+        .iter()
+        .map(|v| v.real().map(|r| r as f32))
+        .collect()
+}
+
+fn apply(operator: Operator, stack: &mut Vec<Value>) -> Option<()> {
+    use Operator::*;
+
+    let value = match operator {
+        Abs | Neg | Ceiling | Floor | Round | Truncate => {
+            let v = stack.pop()?;
+            match v {
+                Value::Int(i) => match operator {
+                    Abs => Value::Int(i.checked_abs()?),
+                    Neg => Value::Int(i.checked_neg()?),
+                    _ => v,
+                },
+                Value::Real(r) => Value::Real(match operator {
+                    Abs => r.abs(),
+                    Neg => -r,
+                    Ceiling => r.ceil(),
+                    Floor => r.floor(),
+                    // PostScript rounds halves up, even when negative
+                    Round => (r + 0.5).floor(),
+                    _ => r.trunc(),
+                }),
+                Value::Bool(_) => return None,
+            }
+        }
+        Add | Sub | Mul => {
+            let b = stack.pop()?;
+            let a = stack.pop()?;
+            let int = match (a, b) {
+                (Value::Int(a), Value::Int(b)) => match operator {
+                    Add => a.checked_add(b),
+                    Sub => a.checked_sub(b),
+                    _ => a.checked_mul(b),
+                },
+                _ => None,
+            };
+            match int {
+                Some(i) => Value::Int(i),
+                None => {
+                    let (a, b) = (a.real()?, b.real()?);
+                    Value::Real(match operator {
+                        Add => a + b,
+                        Sub => a - b,
+                        _ => a * b,
+                    })
+                }
+            }
+        }
+        Div => {
+            let b = stack.pop()?.real()?;
+            let a = stack.pop()?.real()?;
+            if b == 0.0 {
+                return None;
+            }
+            Value::Real(a / b)
+        }
+        Idiv | Mod => {
+            let b = stack.pop()?.int()?;
+            let a = stack.pop()?.int()?;
+            Value::Int(match operator {
+                Idiv => a.checked_div(b)?,
+                _ => a.checked_rem(b)?,
+            })
+        }
+        Atan => {
+            let den = stack.pop()?.real()?;
+            let num = stack.pop()?.real()?;
+            if num == 0.0 && den == 0.0 {
+                return None;
+            }
+            Value::Real(num.atan2(den).to_degrees().rem_euclid(360.0))
+        }
+        Cos | Sin => {
+            let angle = stack.pop()?.real()?.to_radians();
+            Value::Real(match operator {
+                Cos => angle.cos(),
+                _ => angle.sin(),
+            })
+        }
+        Exp => {
+            let exponent = stack.pop()?.real()?;
+            let base = stack.pop()?.real()?;
+            Value::Real(base.powf(exponent))
+        }
+        Ln | Log | Sqrt => {
+            let v = stack.pop()?.real()?;
+            if v < 0.0 || (v == 0.0 && operator != Sqrt) {
+                return None;
+            }
+            Value::Real(match operator {
+                Ln => v.ln(),
+                Log => v.log10(),
+                _ => v.sqrt(),
+            })
+        }
+        Cvi => Value::Int(stack.pop()?.real()?.trunc() as i64),
+        Cvr => Value::Real(stack.pop()?.real()?),
+        Eq | Ne => {
+            let b = stack.pop()?;
+            let a = stack.pop()?;
+            let equal = match (a, b) {
+                (Value::Bool(a), Value::Bool(b)) => a == b,
+                (a, b) => a.real()? == b.real()?,
+            };
+            Value::Bool(equal == (operator == Eq))
+        }
+        Ge | Gt | Le | Lt => {
+            let b = stack.pop()?.real()?;
+            let a = stack.pop()?.real()?;
+            Value::Bool(match operator {
+                Ge => a >= b,
+                Gt => a > b,
+                Le => a <= b,
+                _ => a < b,
+            })
+        }
+        And | Or | Xor => {
+            let b = stack.pop()?;
+            let a = stack.pop()?;
+            match (a, b) {
+                (Value::Bool(a), Value::Bool(b)) => Value::Bool(match operator {
+                    And => a && b,
+                    Or => a || b,
+                    _ => a ^ b,
+                }),
+                (Value::Int(a), Value::Int(b)) => Value::Int(match operator {
+                    And => a & b,
+                    Or => a | b,
+                    _ => a ^ b,
+                }),
+                _ => return None,
+            }
+        }
+        Not => match stack.pop()? {
+            Value::Bool(b) => Value::Bool(!b),
+            Value::Int(i) => Value::Int(!i),
+            Value::Real(_) => return None,
+        },
+        Bitshift => {
+            let shift = stack.pop()?.int()?;
+            let i = stack.pop()?.int()?;
+            let shift = shift.clamp(-63, 63);
+            Value::Int(if shift >= 0 { i << shift } else { i >> -shift })
+        }
+        True => Value::Bool(true),
+        False => Value::Bool(false),
+        Copy => {
+            let n = usize::try_from(stack.pop()?.int()?).ok()?;
+            let start = stack.len().checked_sub(n)?;
+            stack.extend_from_within(start..);
+            return Some(());
+        }
+        Dup => *stack.last()?,
+        Exch => {
+            let len = stack.len();
+            if len < 2 {
+                return None;
+            }
+            stack.swap(len - 1, len - 2);
+            return Some(());
+        }
+        Index => {
+            let n = usize::try_from(stack.pop()?.int()?).ok()?;
+            let i = stack.len().checked_sub(n + 1)?;
+            stack[i]
+        }
+        Pop => {
+            stack.pop()?;
+            return Some(());
+        }
+        Roll => {
+            let j = stack.pop()?.int()?;
+            let n = usize::try_from(stack.pop()?.int()?).ok()?;
+            let start = stack.len().checked_sub(n)?;
+            if n > 0 {
+                let j = j.rem_euclid(n as i64) as usize;
+                stack[start..].rotate_right(j);
+            }
+            return Some(());
+        }
+    };
+    stack.push(value);
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, run, Op, Operator, Value};
+
+    fn eval(program: &str, inputs: &[Value]) -> Option<Vec<Value>> {
+        let program = parse(program.as_bytes()).unwrap();
+        let mut stack = inputs.to_vec();
+        run(&program, &mut stack).map(|_| stack)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(b"{ 1 -2.5 add dup 0 gt { neg } if }").unwrap(),
+            vec![
+                Op::Push(Value::Int(1)),
+                Op::Push(Value::Real(-2.5)),
+                Op::Operator(Operator::Add),
+                Op::Operator(Operator::Dup),
+                Op::Push(Value::Int(0)),
+                Op::Operator(Operator::Gt),
+                Op::If(vec![Op::Operator(Operator::Neg)]),
+            ]
+        );
+        assert!(parse(b"{ 1 2 frobnicate }").is_err());
+        assert!(parse(b"{ 1 2 add").is_err());
+        assert!(parse(b"{ { 1 } }").is_err());
+        assert!(parse(b"{ true { 1 } ifelse }").is_err());
+        assert!(parse(b"{ true { 1 } { 2 } if }").is_err());
+        // Nested procedures are parsed in one pass
+        let nested = format!("{{ {} 1 {} }}", "true { ".repeat(40), "} if ".repeat(40));
+        assert!(parse(nested.as_bytes()).is_ok());
+        let too_deep = format!("{{ {} }}", "true { ".repeat(1000));
+        assert!(parse(too_deep.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(
+            eval("{ 7 2 idiv 7 2 mod }", &[]),
+            Some(vec![Value::Int(3), Value::Int(1)])
+        );
+        assert_eq!(eval("{ 1 2 div }", &[]), Some(vec![Value::Real(0.5)]));
+        assert_eq!(
+            eval("{ -1.5 round 2.5 round }", &[]),
+            Some(vec![Value::Real(-1.0), Value::Real(3.0)])
+        );
+        assert_eq!(
+            eval("{ 1 0 atan 0 -1 atan }", &[]),
+            Some(vec![Value::Real(90.0), Value::Real(180.0)])
+        );
+        assert_eq!(
+            eval("{ 1 8 bitshift 256 -4 bitshift }", &[]),
+            Some(vec![Value::Int(256), Value::Int(16)])
+        );
+        assert_eq!(eval("{ 1 0 div }", &[]), None);
+        assert_eq!(eval("{ true 1 add }", &[]), None);
+    }
+
+    #[test]
+    fn test_stack() {
+        let ints = |v: &[i64]| Some(v.iter().map(|&i| Value::Int(i)).collect::<Vec<_>>());
+        assert_eq!(eval("{ 1 2 3 3 1 roll }", &[]), ints(&[3, 1, 2]));
+        assert_eq!(eval("{ 1 2 3 3 -1 roll }", &[]), ints(&[2, 3, 1]));
+        assert_eq!(eval("{ 1 2 3 2 index }", &[]), ints(&[1, 2, 3, 1]));
+        assert_eq!(eval("{ 1 2 2 copy exch }", &[]), ints(&[1, 2, 2, 1]));
+        assert_eq!(eval("{ pop }", &[]), None);
+    }
+
+    #[test]
+    fn test_conditionals() {
+        let program = "{ 0.5 gt { 1 } { 0 } ifelse }";
+        assert_eq!(
+            eval(program, &[Value::Real(0.7)]),
+            Some(vec![Value::Int(1)])
+        );
+        assert_eq!(
+            eval(program, &[Value::Real(0.3)]),
+            Some(vec![Value::Int(0)])
+        );
+        assert_eq!(eval("{ 1 { 2 } if }", &[]), None);
+    }
+}
@@ -3,14 +3,25 @@
 
 use nom::{error::VerboseError, IResult};
 
+mod cmap;
+mod color_space;
 mod dictionary;
 mod document;
 mod encodings;
 mod error;
+mod ext_g_state;
+mod font;
+mod form;
+mod function;
 mod macros;
 mod object;
+mod page;
+mod pattern;
 mod pdf;
+mod resources;
+mod shading;
 mod stream;
+mod text;
 // public for the window binary
 pub mod utils;
 mod xref;
@@ -21,8 +32,18 @@ mod known;
 #[macro_use]
 extern crate maplit;
 
+pub use crate::cmap::CMap;
+pub use crate::font::{
+    Cff, CidToGidMap, Font, FontDescriptor, FontEncoding, FontFile, FontProgram, FontSubtype,
+    StandardFont, TrueType, Type1,
+};
+pub use crate::form::{Form, TransparencyGroup};
+pub use crate::page::Page;
+pub use crate::pattern::{PaintType, Pattern, Tiling};
 pub use crate::pdf::Pdf;
-pub use crate::stream::StreamObject;
+pub use crate::resources::Resources;
+pub use crate::stream::{StreamObject, TextElement};
+pub use crate::text::{PageText, TextSpan};
 
 type NomError = ();
 type NomResult<'a, O, E = NomError> = IResult<&'a [u8], O, E>;
@@ -0,0 +1,201 @@
+use anyhow::Result;
+use shared::{ColorSpace, ExtGState, Shading};
+
+use crate::{
+    dictionary::Dictionary,
+    document::Document,
+    error::HandlingError,
+    font::Font,
+    form::Form,
+    object::Object,
+    pattern::Pattern,
+    resources::Resources,
+    stream::{parse_content, parse_text_content, StreamObject},
+    text::{self, PageText},
+    ObjectId,
+};
+use std::rc::Rc;
+
+/// Limit on how many `/Parent`s are followed looking for an inherited
+/// attribute, which protects against cycles in malformed files
+const MAX_TREE_DEPTH: usize = 32;
+
+/// A single page of a document
+#[derive(Debug)]
+pub struct Page<'d, 'a> {
+    document: &'d Document<'a>,
+    pub id: ObjectId,
+    pub dict: &'d Dictionary<'a>,
+}
+
+impl<'d, 'a> Page<'d, 'a> {
+    pub(crate) fn new(document: &'d Document<'a>, id: ObjectId) -> Result<Page<'d, 'a>> {
+        let dict = document.get_object(id)?.as_dict()?;
+        Ok(Page { document, id, dict })
+    }
+
+    /// Looks up an attribute that may be inherited from an ancestor in the
+    /// page tree, such as `/Resources` or `/MediaBox`
+    pub fn inherited(&self, key: &[u8]) -> Result<&'d Object<'a>> {
+        let mut dict = self.dict;
+        for _ in 0..MAX_TREE_DEPTH {
+            if let Ok(value) = dict.get(key) {
+                return self.document.dereference(value);
+            }
+            let parent = dict.get(b"Parent").and_then(Object::as_reference)?;
+            dict = self.document.get_object(parent)?.as_dict()?;
+        }
+        Err(HandlingError::ObjectNotFound.into())
+    }
+
+    pub fn resources(&self) -> Result<&'d Dictionary<'a>> {
+        self.inherited(b"Resources")?.as_dict()
+    }
+
+    /// The resources that the page's content stream refers to by name
+    pub fn named_resources(&self) -> Resources<'d, 'a> {
+        Resources::new(self.document, self.resources().ok())
+    }
+
+    /// The operators that draw the page. `/Contents` may be split across
+    /// several streams, which are joined as if they were one.
+    pub fn content(&self) -> Result<Vec<StreamObject>> {
+        parse_content(&self.content_bytes()?)
+    }
+
+    /// The joined `/Contents` streams, with their filters applied
+    fn content_bytes(&self) -> Result<Vec<u8>> {
+        let contents = match self.dict.get(b"Contents") {
+            Ok(contents) => self.document.dereference(contents)?,
+            Err(_) => return Ok(vec![]),
+        };
+        let streams = match contents {
+            Object::Array(streams) => streams.iter().collect(),
+            stream => vec![stream],
+        };
+        let mut content = vec![];
+        for stream in streams {
+            let stream = self.document.dereference(stream)?.as_stream()?;
+            content.extend_from_slice(&stream.decoded_content()?);
+            // Streams may only be split between tokens
+            content.push(b'\n');
+        }
+        Ok(content)
+    }
+
+    /// Finds the text shown on the page, both as a whole and as the strings
+    /// it was shown in, with where they are. Operators that fail, e.g. ones
+    /// showing text in a font that can't be read, are logged and skipped.
+    pub fn extract_text(&self) -> Result<PageText> {
+        let content = parse_text_content(&self.content_bytes()?);
+        Ok(text::extract(content, self.named_resources()))
+    }
+
+    /// Looks up a named resource of the given category, e.g. `/ColorSpace /CS0`
+    pub fn resource(&self, category: &[u8], name: &[u8]) -> Result<&'d Object<'a>> {
+        self.named_resources().resource(category, name)
+    }
+
+    /// Resolves the operand of `cs` or `CS`, which is either the name of a
+    /// `/ColorSpace` resource or a family that takes no parameters
+    pub fn color_space(&self, name: &[u8]) -> Result<ColorSpace> {
+        self.named_resources().color_space(name)
+    }
+
+    /// Resolves the operand of `sh`, a `/Shading` resource
+    pub fn shading(&self, name: &[u8]) -> Result<Shading> {
+        self.named_resources().shading(name)
+    }
+
+    /// Resolves the operand of `gs`, an `/ExtGState` resource
+    pub fn ext_g_state(&self, name: &[u8]) -> Result<ExtGState> {
+        self.named_resources().ext_g_state(name)
+    }
+
+    /// Resolves the operand of `Do`, an `/XObject` resource
+    pub fn form(&self, name: &[u8]) -> Result<Form<'d, 'a>> {
+        self.named_resources().form(name)
+    }
+
+    /// Resolves the pattern selected by `scn` or `SCN`, a `/Pattern` resource
+    pub fn pattern(&self, name: &[u8]) -> Result<Pattern<'d, 'a>> {
+        self.named_resources().pattern(name)
+    }
+
+    /// Resolves the operand of `Tf` on this page, a `/Font` resource
+    pub fn font(&self, name: &[u8]) -> Result<Rc<Font>> {
+        self.named_resources().font(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::ColorSpace;
+
+    use crate::{
+        array, dict, dictionary::Dictionary, dictionary_struct, document::Document, name,
+        object::Object, reference,
+    };
+
+    /// A page tree whose only page inherits its resources from the root
+    fn document() -> Document<'static> {
+        let mut document = Document::new();
+        document.trailer = dictionary_struct! { "Root" => reference!(1, 0) };
+        document.objects.insert(
+            (1, 0),
+            dict!(dictionary_struct! {
+                "Type" => name!("Catalog"),
+                "Pages" => reference!(2, 0),
+            }),
+        );
+        document.objects.insert(
+            (2, 0),
+            dict!(dictionary_struct! {
+                "Type" => name!("Pages"),
+                "Kids" => array!(reference!(3, 0)),
+                "Resources" => reference!(4, 0),
+            }),
+        );
+        document.objects.insert(
+            (3, 0),
+            dict!(dictionary_struct! {
+                "Type" => name!("Page"),
+                "Parent" => reference!(2, 0),
+            }),
+        );
+        document.objects.insert(
+            (4, 0),
+            dict!(dictionary_struct! {
+                "ColorSpace" => dict!(dictionary_struct! {
+                    "CS0" => name!("DeviceCMYK"),
+                    "CS1" => array!(name!("Unknown")),
+                }),
+            }),
+        );
+        document
+    }
+
+    #[test]
+    fn test_inherited_resources() {
+        let document = document();
+        let pages = document.get_pages().unwrap();
+        assert_eq!(pages.len(), 1);
+        let page = &pages[0];
+        assert_eq!(page.id, (3, 0));
+        assert!(page.resources().unwrap().get(b"ColorSpace").is_ok());
+        assert!(page.inherited(b"MediaBox").is_err());
+    }
+
+    #[test]
+    fn test_color_space() {
+        let document = document();
+        let page = document.get_page((3, 0)).unwrap();
+        assert_eq!(page.color_space(b"CS0").unwrap(), ColorSpace::DeviceCMYK);
+        assert_eq!(
+            page.color_space(b"DeviceRGB").unwrap(),
+            ColorSpace::DeviceRGB
+        );
+        assert!(page.color_space(b"CS1").is_err());
+        assert!(page.color_space(b"CS2").is_err());
+    }
+}
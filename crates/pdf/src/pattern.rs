@@ -0,0 +1,199 @@
+use anyhow::Result;
+use shared::Shading;
+
+use crate::{
+    document::Document,
+    error::{HandlingError, ShadingError},
+    object::Object,
+    resources::Resources,
+    shading,
+    stream::{parse_content, StreamObject},
+};
+
+/// A pattern selected by `scn` or `SCN` in the `/Pattern` color space
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern<'d, 'a> {
+    /// `/PatternType 1`, which repeats a small drawing
+    Tiling(Tiling<'d, 'a>),
+    /// `/PatternType 2`, which fills with a shading
+    Shading(Shading),
+}
+
+/// Whether the cell of a tiling pattern has colors of its own
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaintType {
+    /// `/PaintType 1`, where the cell sets its own colors
+    Colored,
+    /// `/PaintType 2`, where the cell is a stencil painted in the color
+    /// given to `scn` along with the pattern's name
+    Uncolored,
+}
+
+/// A tiling pattern, whose cell is drawn by a content stream and repeated
+/// every `x_step` and `y_step`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tiling<'d, 'a> {
+    pub paint_type: PaintType,
+    /// `[x_min y_min x_max y_max]` bounds of the cell, in pattern space
+    pub bbox: [f32; 4],
+    pub x_step: f32,
+    pub y_step: f32,
+    /// Maps pattern space to the default user space
+    pub matrix: [f32; 6],
+    /// What the cell's content refers to by name
+    pub resources: Resources<'d, 'a>,
+    content: Vec<u8>,
+}
+
+impl<'d, 'a> Tiling<'d, 'a> {
+    /// The operators that draw the cell, in pattern space
+    pub fn content(&self) -> Result<Vec<StreamObject>> {
+        parse_content(&self.content)
+    }
+}
+
+impl<'d, 'a> Pattern<'d, 'a> {
+    /// Reads a pattern. A tiling pattern without `/Resources` of its own
+    /// uses `outer`, the resources that it was found in.
+    pub(crate) fn from_object(
+        document: &'d Document<'a>,
+        object: &'d Object<'a>,
+        outer: Resources<'d, 'a>,
+    ) -> Result<Pattern<'d, 'a>> {
+        let dict = match document.dereference(object)? {
+            Object::Dictionary(dict) => dict,
+            Object::Stream(stream) => &stream.dict,
+            _ => return Err(HandlingError::ObjectCast.into()),
+        };
+        let matrix = match dict.get(b"Matrix") {
+            Ok(matrix) => match document.get_floats(matrix)?[..] {
+                [a, b, c, d, e, f] => [a, b, c, d, e, f],
+                _ => return Err(ShadingError::InvalidEntry("Matrix").into()),
+            },
+            Err(_) => shared::IDENTITY,
+        };
+        match document.dereference(dict.get(b"PatternType")?)?.as_i64()? {
+            1 => {
+                let stream = document.dereference(object)?.as_stream()?;
+                let paint_type = match document.dereference(dict.get(b"PaintType")?)?.as_i64()? {
+                    1 => PaintType::Colored,
+                    2 => PaintType::Uncolored,
+                    _ => return Err(ShadingError::InvalidEntry("PaintType").into()),
+                };
+                let bbox = match document.get_floats(dict.get(b"BBox")?)?[..] {
+                    [x0, y0, x1, y1] => [x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)],
+                    _ => return Err(ShadingError::InvalidEntry("BBox").into()),
+                };
+                let step = |key: &'static str| -> Result<f32> {
+                    let step = document
+                        .dereference(dict.get(key.as_bytes())?)?
+                        .as_float()? as f32;
+                    if step == 0.0 {
+                        return Err(ShadingError::InvalidEntry(key).into());
+                    }
+                    Ok(step)
+                };
+                let resources = match dict.get(b"Resources") {
+                    Ok(resources) => {
+                        Resources::new(document, Some(document.dereference(resources)?.as_dict()?))
+                    }
+                    Err(_) => outer,
+                };
+                Ok(Pattern::Tiling(Tiling {
+                    paint_type,
+                    bbox,
+                    x_step: step("XStep")?,
+                    y_step: step("YStep")?,
+                    matrix,
+                    resources,
+                    content: stream.decoded_content()?.into_owned(),
+                }))
+            }
+            2 => {
+                let shading = shading::shading(document, dict.get(b"Shading")?)?;
+                Ok(Pattern::Shading(shading.with_matrix(matrix)))
+            }
+            other => Err(ShadingError::UnsupportedPatternType(other).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::{GradientKind, Shading};
+
+    use super::{PaintType, Pattern};
+    use crate::{
+        array, dict, dictionary::Dictionary, dictionary_struct, document::Document, int, name,
+        object::Object, reference, resources::Resources, stream, stream::Stream, StreamObject,
+    };
+
+    #[test]
+    fn test_shading_pattern() {
+        let mut document = Document::new();
+        document.objects.insert(
+            (8, 0),
+            dict!(dictionary_struct! {
+                "ShadingType" => int!(2),
+                "ColorSpace" => name!("DeviceGray"),
+                "Coords" => array!(int!(0), int!(0), int!(1), int!(0)),
+                "Function" => dict!(dictionary_struct! {
+                    "FunctionType" => int!(2),
+                    "Domain" => array!(int!(0), int!(1)),
+                    "N" => int!(1),
+                }),
+            }),
+        );
+        let object = dict!(dictionary_struct! {
+            "PatternType" => int!(2),
+            "Shading" => reference!(8, 0),
+            "Matrix" => array!(int!(100), int!(0), int!(0), int!(100), int!(10), int!(20)),
+        });
+        let outer = Resources::new(&document, None);
+        match Pattern::from_object(&document, &object, outer).unwrap() {
+            Pattern::Shading(Shading::Gradient(gradient)) => {
+                assert!(matches!(gradient.kind, GradientKind::Axial { .. }));
+                assert_eq!(gradient.transform([1.0, 0.0]), [110.0, 20.0]);
+            }
+            other => panic!("expected a gradient, got {:?}", other),
+        }
+        let object = dict!(dictionary_struct! { "PatternType" => int!(3) });
+        assert!(Pattern::from_object(&document, &object, outer).is_err());
+    }
+
+    #[test]
+    fn test_tiling_pattern() {
+        let document = Document::new();
+        let tiling = |x_step: i64| {
+            stream!(Stream {
+                dict: dictionary_struct! {
+                    "PatternType" => int!(1),
+                    "PaintType" => int!(2),
+                    "TilingType" => int!(1),
+                    "BBox" => array!(int!(0), int!(0), int!(10), int!(10)),
+                    "XStep" => int!(x_step),
+                    "YStep" => int!(10),
+                },
+                content: b"0 0 5 5 re f",
+                allows_compression: true,
+                start_position: None,
+            })
+        };
+        let outer = Resources::new(&document, None);
+        let object = tiling(20);
+        let tiling_pattern = match Pattern::from_object(&document, &object, outer).unwrap() {
+            Pattern::Tiling(tiling) => tiling,
+            other => panic!("expected a tiling pattern, got {:?}", other),
+        };
+        assert_eq!(tiling_pattern.paint_type, PaintType::Uncolored);
+        assert_eq!(tiling_pattern.bbox, [0.0, 0.0, 10.0, 10.0]);
+        assert_eq!((tiling_pattern.x_step, tiling_pattern.y_step), (20.0, 10.0));
+        assert_eq!(tiling_pattern.resources, outer);
+        let content = tiling_pattern.content().unwrap();
+        assert_eq!(content.len(), 2);
+        assert_eq!(content[1], StreamObject::Fill);
+
+        let object = tiling(0);
+        assert!(Pattern::from_object(&document, &object, outer).is_err());
+    }
+}
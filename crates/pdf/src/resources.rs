@@ -0,0 +1,80 @@
+use anyhow::Result;
+use shared::{ColorSpace, ExtGState, ObjectRef, Shading, SoftMask};
+use std::rc::Rc;
+
+use crate::{
+    color_space, dictionary::Dictionary, document::Document, error::HandlingError, ext_g_state,
+    font::Font, form::Form, object::Object, pattern::Pattern, shading,
+};
+
+/// The named resources a content stream's operators refer to, i.e. its
+/// `/Resources` dictionary. Pages and tiling patterns each have their own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resources<'d, 'a> {
+    document: &'d Document<'a>,
+    dict: Option<&'d Dictionary<'a>>,
+}
+
+impl<'d, 'a> Resources<'d, 'a> {
+    pub(crate) fn new(document: &'d Document<'a>, dict: Option<&'d Dictionary<'a>>) -> Self {
+        Resources { document, dict }
+    }
+
+    /// Looks up a named resource of the given category, e.g. `/ColorSpace /CS0`
+    pub fn resource(&self, category: &[u8], name: &[u8]) -> Result<&'d Object<'a>> {
+        let resources = self.dict.ok_or(HandlingError::ObjectNotFound)?;
+        let category = self.document.dereference(resources.get(category)?)?;
+        self.document.dereference(category.as_dict()?.get(name)?)
+    }
+
+    /// Resolves the operand of `cs` or `CS`, which is either the name of a
+    /// `/ColorSpace` resource or a family that takes no parameters
+    pub fn color_space(&self, name: &[u8]) -> Result<ColorSpace> {
+        match self.resource(b"ColorSpace", name) {
+            Ok(object) => color_space::resolve(self.document, object),
+            Err(_) => color_space::resolve(self.document, &Object::Name(name.to_vec())),
+        }
+    }
+
+    /// Resolves the operand of `sh`, a `/Shading` resource
+    pub fn shading(&self, name: &[u8]) -> Result<Shading> {
+        shading::shading(self.document, self.resource(b"Shading", name)?)
+    }
+
+    /// Resolves the operand of `gs`, an `/ExtGState` resource
+    pub fn ext_g_state(&self, name: &[u8]) -> Result<ExtGState> {
+        ext_g_state::ext_g_state(self.document, self.resource(b"ExtGState", name)?)
+    }
+
+    /// Resolves the font selected by `Tf`, a `/Font` resource. Fonts that
+    /// are indirect objects are only read once per document.
+    pub fn font(&self, name: &[u8]) -> Result<Rc<Font>> {
+        let resources = self.dict.ok_or(HandlingError::ObjectNotFound)?;
+        let fonts = self.document.dereference(resources.get(b"Font")?)?;
+        match fonts.as_dict()?.get(name)? {
+            Object::Reference(id) => self.document.get_font(*id),
+            font => Ok(Rc::new(Font::from_object(self.document, font)?)),
+        }
+    }
+
+    /// Resolves the font selected by an ExtGState's `/Font`
+    pub fn indirect_font(&self, font: ObjectRef) -> Result<Rc<Font>> {
+        self.document.get_font(font)
+    }
+
+    /// Resolves the operand of `Do`, an `/XObject` resource. Only forms are
+    /// supported, not images.
+    pub fn form(&self, name: &[u8]) -> Result<Form<'d, 'a>> {
+        Form::from_object(self.document, self.resource(b"XObject", name)?, *self)
+    }
+
+    /// Resolves the transparency group that a soft mask is rendered from
+    pub fn soft_mask_group(&self, mask: &SoftMask) -> Result<Form<'d, 'a>> {
+        Form::from_object(self.document, self.document.get_object(mask.group)?, *self)
+    }
+
+    /// Resolves the pattern selected by `scn` or `SCN`, a `/Pattern` resource
+    pub fn pattern(&self, name: &[u8]) -> Result<Pattern<'d, 'a>> {
+        Pattern::from_object(self.document, self.resource(b"Pattern", name)?, *self)
+    }
+}
@@ -0,0 +1,478 @@
+use anyhow::Result;
+use shared::{ColorSpace, Gradient, GradientKind, Mesh, MeshVertex, Rgb, Shading, IDENTITY};
+
+use crate::{
+    color_space, dictionary::Dictionary, document::Document, error::HandlingError,
+    error::ShadingError, function::Function, object::Object,
+};
+
+mod mesh;
+
+/// Number of colors sampled from the function of an axial or radial shading
+const STOPS: usize = 64;
+
+/// Number of cells along each side of the grid a function-based shading is
+/// sampled on
+const GRID: usize = 16;
+
+/// Reads a shading dictionary, or a stream for the mesh types
+pub(crate) fn shading<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<Shading> {
+    let dict = dictionary(document, object)?;
+    let shading_type = document.dereference(dict.get(b"ShadingType")?)?.as_i64()?;
+    let color_space = color_space::resolve(document, dict.get(b"ColorSpace")?)?;
+    let triangles = match shading_type {
+        2 | 3 => return Ok(Shading::Gradient(gradient(document, object)?)),
+        1 => function_based(document, dict, &color_space)?,
+        4..=7 => {
+            let stream = match document.dereference(object)? {
+                Object::Stream(stream) => stream,
+                _ => return Err(HandlingError::ObjectCast.into()),
+            };
+            // The function is optional for meshes
+            let functions = match dict.get(b"Function") {
+                Ok(function) => functions(document, function)?,
+                Err(_) => vec![],
+            };
+            let data = stream.decoded_content()?;
+            mesh::triangles(
+                document,
+                dict,
+                &data,
+                shading_type,
+                &color_space,
+                &functions,
+            )?
+        }
+        other => return Err(ShadingError::UnsupportedShadingType(other).into()),
+    };
+    Ok(Shading::Mesh(Mesh {
+        triangles,
+        background: background(document, dict, &color_space)?,
+        bbox: bbox(document, dict)?,
+        matrix: IDENTITY,
+    }))
+}
+
+/// Reads an axial (type 2) or radial (type 3) shading dictionary
+fn gradient<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<Gradient> {
+    let dict = dictionary(document, object)?;
+    let color_space = color_space::resolve(document, dict.get(b"ColorSpace")?)?;
+    let coords = floats(document, dict, b"Coords")?.ok_or(ShadingError::InvalidEntry("Coords"))?;
+    let kind = match (
+        document.dereference(dict.get(b"ShadingType")?)?.as_i64()?,
+        &coords[..],
+    ) {
+        (2, &[x0, y0, x1, y1]) => GradientKind::Axial {
+            start: [x0, y0],
+            end: [x1, y1],
+        },
+        (3, &[x0, y0, r0, x1, y1, r1]) if r0 >= 0.0 && r1 >= 0.0 => GradientKind::Radial {
+            start: [x0, y0],
+            start_radius: r0,
+            end: [x1, y1],
+            end_radius: r1,
+        },
+        (2, _) | (3, _) => return Err(ShadingError::InvalidEntry("Coords").into()),
+        (other, _) => return Err(ShadingError::UnsupportedShadingType(other).into()),
+    };
+    let domain = match floats(document, dict, b"Domain")?.as_deref() {
+        None => [0.0, 1.0],
+        Some(&[t0, t1]) => [t0, t1],
+        Some(_) => return Err(ShadingError::InvalidEntry("Domain").into()),
+    };
+    let functions = functions(document, dict.get(b"Function")?)?;
+    let stops = (0..STOPS)
+        .map(|i| {
+            let s = i as f32 / (STOPS - 1) as f32;
+            let t = domain[0] + (domain[1] - domain[0]) * s;
+            Ok((s, color(&color_space, &functions, &[t])?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let extend = match dict.get(b"Extend") {
+        Ok(extend) => match document.dereference(extend)?.as_array()?.as_slice() {
+            [start, end] => [
+                document.dereference(start)?.as_bool()?,
+                document.dereference(end)?.as_bool()?,
+            ],
+            _ => return Err(ShadingError::InvalidEntry("Extend").into()),
+        },
+        Err(_) => [false, false],
+    };
+    Ok(Gradient {
+        kind,
+        stops,
+        extend,
+        background: background(document, dict, &color_space)?,
+        bbox: bbox(document, dict)?,
+        matrix: IDENTITY,
+    })
+}
+
+/// Samples a function-based (type 1) shading on a grid over its domain.
+/// The colors are only exact at the corners of the grid.
+fn function_based<'a>(
+    document: &Document<'a>,
+    dict: &Dictionary<'a>,
+    color_space: &ColorSpace,
+) -> Result<Vec<[MeshVertex; 3]>> {
+    let domain = match floats(document, dict, b"Domain")?.as_deref() {
+        None => [0.0, 1.0, 0.0, 1.0],
+        Some(&[x0, x1, y0, y1]) => [x0, x1, y0, y1],
+        Some(_) => return Err(ShadingError::InvalidEntry("Domain").into()),
+    };
+    // Maps the domain to shading space
+    let [a, b, c, d, e, f] = match floats(document, dict, b"Matrix")?.as_deref() {
+        None => IDENTITY,
+        Some(&[a, b, c, d, e, f]) => [a, b, c, d, e, f],
+        Some(_) => return Err(ShadingError::InvalidEntry("Matrix").into()),
+    };
+    let functions = functions(document, dict.get(b"Function")?)?;
+    let rows = (0..=GRID)
+        .map(|j| {
+            let y = domain[2] + (domain[3] - domain[2]) * j as f32 / GRID as f32;
+            (0..=GRID)
+                .map(|i| {
+                    let x = domain[0] + (domain[1] - domain[0]) * i as f32 / GRID as f32;
+                    let position = [a * x + c * y + e, b * x + d * y + f];
+                    Ok(MeshVertex::new(
+                        position,
+                        color(color_space, &functions, &[x, y])?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(mesh::lattice(&rows))
+}
+
+fn dictionary<'b, 'a>(
+    document: &'b Document<'a>,
+    object: &'b Object<'a>,
+) -> Result<&'b Dictionary<'a>> {
+    match document.dereference(object)? {
+        Object::Dictionary(dict) => Ok(dict),
+        Object::Stream(stream) => Ok(&stream.dict),
+        _ => Err(HandlingError::ObjectCast.into()),
+    }
+}
+
+fn floats<'a>(
+    document: &Document<'a>,
+    dict: &Dictionary<'a>,
+    key: &[u8],
+) -> Result<Option<Vec<f32>>> {
+    dict.get(key)
+        .ok()
+        .map(|value| document.get_floats(value))
+        .transpose()
+}
+
+fn background<'a>(
+    document: &Document<'a>,
+    dict: &Dictionary<'a>,
+    color_space: &ColorSpace,
+) -> Result<Option<Rgb>> {
+    match floats(document, dict, b"Background")? {
+        Some(c) => Ok(Some(color_space.with_components(c)?.to_rgb())),
+        None => Ok(None),
+    }
+}
+
+fn bbox<'a>(document: &Document<'a>, dict: &Dictionary<'a>) -> Result<Option<[f32; 4]>> {
+    match floats(document, dict, b"BBox")?.as_deref() {
+        None => Ok(None),
+        Some(&[x0, y0, x1, y1]) => Ok(Some([x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)])),
+        Some(_) => Err(ShadingError::InvalidEntry("BBox").into()),
+    }
+}
+
+/// A shading's `/Function`, which is either a single function with an
+/// output per color component or an array with a function per component
+fn functions<'a>(document: &Document<'a>, object: &Object<'a>) -> Result<Vec<Function>> {
+    match document.dereference(object)? {
+        Object::Array(array) => array
+            .iter()
+            .map(|f| Function::from_object(document, f))
+            .collect(),
+        function => Ok(vec![Function::from_object(document, function)?]),
+    }
+}
+
+/// The color for `inputs`, which are the color components themselves when
+/// there are no functions
+fn color(color_space: &ColorSpace, functions: &[Function], inputs: &[f32]) -> Result<Rgb> {
+    let components = match functions {
+        [] => inputs.to_vec(),
+        [function] => function.evaluate(inputs),
+        functions => functions
+            .iter()
+            .map(|f| f.evaluate(inputs).first().copied().unwrap_or(0.0))
+            .collect(),
+    };
+    Ok(color_space.with_components(components)?.to_rgb())
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::{GradientKind, Mesh, MeshVertex, Rgb, Shading};
+
+    use super::{gradient, shading};
+    use crate::{
+        array, bool, dict, dictionary::Dictionary, dictionary_struct, document::Document, int,
+        name, object::Object, stream, stream::Stream,
+    };
+
+    fn mesh_stream(dict: Dictionary<'static>, content: &'static [u8]) -> Object<'static> {
+        stream!(Stream {
+            dict,
+            content,
+            allows_compression: true,
+            start_position: None,
+        })
+    }
+
+    fn mesh(document: &Document<'static>, object: &Object<'static>) -> Mesh {
+        match shading(document, object).unwrap() {
+            Shading::Mesh(mesh) => mesh,
+            other => panic!("expected a mesh, got {:?}", other),
+        }
+    }
+
+    /// The color of the first vertex at `position`
+    fn color_at(mesh: &Mesh, position: [f32; 2]) -> Rgb {
+        mesh.triangles
+            .iter()
+            .flatten()
+            .find(|v| {
+                (v.position[0] - position[0]).abs() < 0.001
+                    && (v.position[1] - position[1]).abs() < 0.001
+            })
+            .map(|v| v.color)
+            .expect("no vertex at position")
+    }
+
+    /// Exponential interpolation from `c0` to `c1`
+    fn linear(c0: Object<'static>, c1: Object<'static>) -> Object<'static> {
+        dict!(dictionary_struct! {
+            "FunctionType" => int!(2),
+            "Domain" => array!(int!(0), int!(1)),
+            "C0" => c0,
+            "C1" => c1,
+            "N" => int!(1),
+        })
+    }
+
+    #[test]
+    fn test_axial() {
+        let document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "ShadingType" => int!(2),
+            "ColorSpace" => name!("DeviceRGB"),
+            "Coords" => array!(int!(0), int!(0), int!(100), int!(0)),
+            "Function" => linear(
+                array!(int!(1), int!(0), int!(0)),
+                array!(int!(0), int!(0), int!(1))
+            ),
+            "Extend" => array!(bool!(true), bool!(false)),
+        });
+        let gradient = gradient(&document, &object).unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Axial {
+                start: [0.0, 0.0],
+                end: [100.0, 0.0],
+            }
+        );
+        assert_eq!(gradient.extend, [true, false]);
+        assert_eq!(
+            gradient.stops.first(),
+            Some(&(0.0, Rgb::new(1.0, 0.0, 0.0)))
+        );
+        assert_eq!(gradient.stops.last(), Some(&(1.0, Rgb::new(0.0, 0.0, 1.0))));
+    }
+
+    #[test]
+    fn test_radial() {
+        let document = Document::new();
+        // A function per component, over a domain other than [0 1]
+        let object = dict!(dictionary_struct! {
+            "ShadingType" => int!(3),
+            "ColorSpace" => name!("DeviceGray"),
+            "Coords" => array!(int!(50), int!(50), int!(0), int!(50), int!(50), int!(25)),
+            "Domain" => array!(int!(0), int!(2)),
+            "Function" => array!(linear(array!(int!(0)), array!(int!(1)))),
+            "Background" => array!(int!(1)),
+        });
+        let gradient = gradient(&document, &object).unwrap();
+        assert!(
+            matches!(gradient.kind, GradientKind::Radial { end_radius, .. } if end_radius == 25.0)
+        );
+        assert_eq!(gradient.background, Some(Rgb::new(1.0, 1.0, 1.0)));
+        // The function's domain ends at 1, halfway through the shading's
+        assert_eq!(gradient.color_at(0.75), Rgb::new(1.0, 1.0, 1.0));
+        approx::assert_relative_eq!(gradient.color_at(0.25).red(), 0.5, epsilon = 0.001);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let document = Document::new();
+        let object = dict!(dictionary_struct! {
+            "ShadingType" => int!(2),
+            "ColorSpace" => name!("DeviceRGB"),
+            "Coords" => array!(int!(0), int!(0), int!(100)),
+            "Function" => linear(array!(int!(0)), array!(int!(1))),
+        });
+        assert!(gradient(&document, &object).is_err());
+        let object = dict!(dictionary_struct! {
+            "ShadingType" => int!(1),
+            "ColorSpace" => name!("DeviceRGB"),
+            "Coords" => array!(int!(0), int!(0), int!(100), int!(0)),
+            "Function" => linear(array!(int!(0)), array!(int!(1))),
+        });
+        assert!(gradient(&document, &object).is_err());
+    }
+
+    #[test]
+    fn test_function_based() {
+        let document = Document::new();
+        let object = mesh_stream(
+            dictionary_struct! {
+                "ShadingType" => int!(1),
+                "ColorSpace" => name!("DeviceGray"),
+                "Matrix" => array!(int!(10), int!(0), int!(0), int!(10), int!(0), int!(0)),
+                "Function" => mesh_stream(
+                    dictionary_struct! {
+                        "FunctionType" => int!(4),
+                        "Domain" => array!(int!(0), int!(1), int!(0), int!(1)),
+                        "Range" => array!(int!(0), int!(1)),
+                    },
+                    b"{ add 2 div }",
+                ),
+            },
+            b"",
+        );
+        let mesh = mesh(&document, &object);
+        assert_eq!(mesh.triangles.len(), 2 * 16 * 16);
+        assert_eq!(color_at(&mesh, [0.0, 0.0]), Rgb::new(0.0, 0.0, 0.0));
+        approx::assert_relative_eq!(color_at(&mesh, [5.0, 0.0]).red(), 0.25, epsilon = 0.001);
+        assert_eq!(color_at(&mesh, [10.0, 10.0]), Rgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_free_form() {
+        let document = Document::new();
+        let dict = || {
+            dictionary_struct! {
+                "ShadingType" => int!(4),
+                "ColorSpace" => name!("DeviceGray"),
+                "BitsPerCoordinate" => int!(8),
+                "BitsPerComponent" => int!(8),
+                "BitsPerFlag" => int!(8),
+                "Decode" => array!(int!(0), int!(255), int!(0), int!(255), int!(0), int!(1)),
+            }
+        };
+        // A triangle, one that shares the last two vertices, then one that
+        // shares the first and the last
+        #[rustfmt::skip]
+        let object = mesh_stream(dict(), &[
+            0, 0, 0, 0,
+            0, 100, 0, 255,
+            0, 0, 100, 0,
+            1, 100, 100, 255,
+            2, 50, 200, 255,
+        ]);
+        let mesh = mesh(&document, &object);
+        let positions = mesh
+            .triangles
+            .iter()
+            .map(|t| [t[0].position, t[1].position, t[2].position])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![
+                [[0.0, 0.0], [100.0, 0.0], [0.0, 100.0]],
+                [[100.0, 0.0], [0.0, 100.0], [100.0, 100.0]],
+                [[100.0, 0.0], [100.0, 100.0], [50.0, 200.0]],
+            ]
+        );
+        assert_eq!(
+            mesh.triangles[1][2],
+            MeshVertex::new([100.0, 100.0], Rgb::new(1.0, 1.0, 1.0))
+        );
+        // Nothing to share with yet
+        let object = mesh_stream(dict(), &[1, 0, 0, 0]);
+        assert!(shading(&document, &object).is_err());
+    }
+
+    #[test]
+    fn test_lattice() {
+        let document = Document::new();
+        // The vertices have a parametric value for the function instead of
+        // color components. The trailing byte isn't a whole vertex.
+        #[rustfmt::skip]
+        let object = mesh_stream(
+            dictionary_struct! {
+                "ShadingType" => int!(5),
+                "ColorSpace" => name!("DeviceRGB"),
+                "BitsPerCoordinate" => int!(8),
+                "BitsPerComponent" => int!(8),
+                "VerticesPerRow" => int!(2),
+                "Decode" => array!(int!(0), int!(255), int!(0), int!(255), int!(0), int!(1)),
+                "Function" => linear(
+                    array!(int!(0), int!(0), int!(0)),
+                    array!(int!(1), int!(0), int!(0))
+                ),
+            },
+            &[
+                0, 0, 0,
+                10, 0, 255,
+                0, 10, 0,
+                10, 10, 255,
+                7,
+            ],
+        );
+        let mesh = mesh(&document, &object);
+        assert_eq!(mesh.triangles.len(), 2);
+        assert_eq!(color_at(&mesh, [10.0, 10.0]), Rgb::new(1.0, 0.0, 0.0));
+        assert_eq!(color_at(&mesh, [0.0, 10.0]), Rgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_coons_patches() {
+        let document = Document::new();
+        // A square with straight edges, then another to its right that
+        // shares its right edge
+        #[rustfmt::skip]
+        let object = mesh_stream(
+            dictionary_struct! {
+                "ShadingType" => int!(6),
+                "ColorSpace" => name!("DeviceGray"),
+                "BitsPerCoordinate" => int!(8),
+                "BitsPerComponent" => int!(8),
+                "BitsPerFlag" => int!(8),
+                "Decode" => array!(int!(0), int!(255), int!(0), int!(255), int!(0), int!(1)),
+            },
+            &[
+                0,
+                0, 0, 0, 10, 0, 20, 0, 30,
+                10, 30, 20, 30, 30, 30,
+                30, 20, 30, 10, 30, 0,
+                20, 0, 10, 0,
+                0, 255, 255, 0,
+                2,
+                40, 0, 50, 0, 60, 0,
+                60, 10, 60, 20, 60, 30,
+                50, 30, 40, 30,
+                0, 255,
+            ],
+        );
+        let mesh = mesh(&document, &object);
+        assert_eq!(mesh.triangles.len(), 2 * 2 * 8 * 8);
+        approx::assert_relative_eq!(color_at(&mesh, [15.0, 15.0]).red(), 0.5, epsilon = 0.001);
+        assert_eq!(color_at(&mesh, [0.0, 30.0]), Rgb::new(1.0, 1.0, 1.0));
+        // The shared corners keep their colors
+        assert_eq!(color_at(&mesh, [60.0, 0.0]), Rgb::new(0.0, 0.0, 0.0));
+        assert_eq!(color_at(&mesh, [60.0, 30.0]), Rgb::new(1.0, 1.0, 1.0));
+        approx::assert_relative_eq!(color_at(&mesh, [45.0, 15.0]).red(), 0.5, epsilon = 0.001);
+    }
+}
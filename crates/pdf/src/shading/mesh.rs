@@ -0,0 +1,352 @@
+//! Triangle meshes (types 4 and 5) and patch meshes (types 6 and 7), whose
+//! vertices are packed into the bits of a stream
+
+use anyhow::Result;
+use shared::{ColorSpace, MeshVertex};
+
+use super::color;
+use crate::{dictionary::Dictionary, document::Document, error::ShadingError, function::Function};
+
+/// Number of steps along each side of the grid a patch is evaluated on
+const PATCH_STEPS: usize = 8;
+
+/// Where the control points of a patch appear in the stream, as `(i, j)`
+/// indices into its 4 by 4 grid. Coons patches only have the first 12.
+const PATCH_ORDER: [(usize, usize); 16] = [
+    (0, 0),
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (1, 3),
+    (2, 3),
+    (3, 3),
+    (3, 2),
+    (3, 1),
+    (3, 0),
+    (2, 0),
+    (1, 0),
+    (1, 1),
+    (1, 2),
+    (2, 2),
+    (2, 1),
+];
+
+/// A vertex as stored in the stream. `values` are either the color
+/// components or the input to the shading's function.
+#[derive(Debug, Clone)]
+struct Vertex {
+    position: [f32; 2],
+    values: Vec<f32>,
+}
+
+/// The control points of a patch in stream order, and the colors at its
+/// `p00`, `p03`, `p33` and `p30` corners
+#[derive(Debug, Clone)]
+struct Patch {
+    points: Vec<[f32; 2]>,
+    colors: Vec<Vec<f32>>,
+}
+
+/// How the numbers in the stream are packed
+struct Layout {
+    bits_per_coordinate: u32,
+    bits_per_component: u32,
+    bits_per_flag: u32,
+    decode: Vec<f32>,
+}
+
+/// Reads big-endian numbers of any width up to 32 bits
+struct BitReader<'d> {
+    data: &'d [u8],
+    position: usize,
+}
+
+impl<'d> BitReader<'d> {
+    fn new(data: &'d [u8]) -> Self {
+        BitReader { data, position: 0 }
+    }
+
+    fn read(&mut self, bits: u32) -> Option<u32> {
+        if self.position + bits as usize > self.data.len() * 8 {
+            return None;
+        }
+        let mut value = 0u32;
+        for _ in 0..bits {
+            let byte = self.data[self.position / 8];
+            let bit = byte >> (7 - self.position % 8) & 1;
+            value = value << 1 | bit as u32;
+            self.position += 1;
+        }
+        Some(value)
+    }
+
+    /// Skips to the start of the next byte
+    fn align(&mut self) {
+        self.position = self.position.div_ceil(8) * 8;
+    }
+
+    fn read_decoded(&mut self, bits: u32, min: f32, max: f32) -> Option<f32> {
+        let value = self.read(bits)? as f64;
+        let top = ((1u64 << bits) - 1) as f64;
+        Some((min as f64 + value * (max - min) as f64 / top) as f32)
+    }
+
+    fn read_flag(&mut self, layout: &Layout) -> Option<u32> {
+        self.read(layout.bits_per_flag)
+    }
+
+    fn read_point(&mut self, layout: &Layout) -> Option<[f32; 2]> {
+        let bits = layout.bits_per_coordinate;
+        let decode = &layout.decode;
+        Some([
+            self.read_decoded(bits, decode[0], decode[1])?,
+            self.read_decoded(bits, decode[2], decode[3])?,
+        ])
+    }
+
+    fn read_values(&mut self, layout: &Layout) -> Option<Vec<f32>> {
+        layout.decode[4..]
+            .chunks(2)
+            .map(|range| self.read_decoded(layout.bits_per_component, range[0], range[1]))
+            .collect()
+    }
+
+    fn read_vertex(&mut self, layout: &Layout) -> Option<Vertex> {
+        Some(Vertex {
+            position: self.read_point(layout)?,
+            values: self.read_values(layout)?,
+        })
+    }
+}
+
+/// Decodes the triangles of a mesh shading from its stream `data`. A
+/// vertex or patch cut short by the end of the data is ignored.
+pub(super) fn triangles<'a>(
+    document: &Document<'a>,
+    dict: &Dictionary<'a>,
+    data: &[u8],
+    shading_type: i64,
+    color_space: &ColorSpace,
+    functions: &[Function],
+) -> Result<Vec<[MeshVertex; 3]>> {
+    let int = |key: &'static str| -> Result<i64> {
+        document.dereference(dict.get(key.as_bytes())?)?.as_i64()
+    };
+    let bits = |key: &'static str, allowed: &[i64]| -> Result<u32> {
+        match int(key)? {
+            n if allowed.contains(&n) => Ok(n as u32),
+            _ => Err(ShadingError::InvalidEntry(key).into()),
+        }
+    };
+    let components = if functions.is_empty() {
+        color_space.components()
+    } else {
+        1
+    };
+    let decode = document.get_floats(dict.get(b"Decode")?)?;
+    if decode.len() != 4 + 2 * components {
+        return Err(ShadingError::InvalidEntry("Decode").into());
+    }
+    let layout = Layout {
+        bits_per_coordinate: bits("BitsPerCoordinate", &[1, 2, 4, 8, 12, 16, 24, 32])?,
+        bits_per_component: bits("BitsPerComponent", &[1, 2, 4, 8, 12, 16])?,
+        bits_per_flag: match shading_type {
+            5 => 0,
+            _ => bits("BitsPerFlag", &[2, 4, 8])?,
+        },
+        decode,
+    };
+    let mut reader = BitReader::new(data);
+    let triangles = match shading_type {
+        4 => free_form(&mut reader, &layout)?,
+        5 => {
+            let per_row = match int("VerticesPerRow")? {
+                n if n >= 2 => n as usize,
+                _ => return Err(ShadingError::InvalidEntry("VerticesPerRow").into()),
+            };
+            let mut vertices = vec![];
+            while let Some(vertex) = reader.read_vertex(&layout) {
+                vertices.push(vertex);
+            }
+            let rows = vertices
+                .chunks_exact(per_row)
+                .map(|row| row.to_vec())
+                .collect::<Vec<_>>();
+            lattice(&rows)
+        }
+        _ => patches(&mut reader, &layout, shading_type == 7)?,
+    };
+    triangles
+        .into_iter()
+        .map(|triangle| {
+            let mut corners = vec![];
+            for vertex in triangle.iter() {
+                let rgb = color(color_space, functions, &vertex.values)?;
+                corners.push(MeshVertex::new(vertex.position, rgb));
+            }
+            Ok([corners[0], corners[1], corners[2]])
+        })
+        .collect()
+}
+
+/// Splits each cell between consecutive rows of vertices into two triangles
+pub(super) fn lattice<T: Clone>(rows: &[Vec<T>]) -> Vec<[T; 3]> {
+    let mut triangles = vec![];
+    for pair in rows.windows(2) {
+        let (top, bottom) = (&pair[0], &pair[1]);
+        for i in 0..top.len().min(bottom.len()).saturating_sub(1) {
+            triangles.push([top[i].clone(), top[i + 1].clone(), bottom[i].clone()]);
+            triangles.push([top[i + 1].clone(), bottom[i + 1].clone(), bottom[i].clone()]);
+        }
+    }
+    triangles
+}
+
+/// Type 4, where each vertex has a flag saying whether it starts a new
+/// triangle or continues from two vertices of the previous one. Every
+/// vertex starts on a byte boundary.
+fn free_form(reader: &mut BitReader, layout: &Layout) -> Result<Vec<[Vertex; 3]>> {
+    let mut triangles: Vec<[Vertex; 3]> = vec![];
+    let next = |reader: &mut BitReader| -> Option<(u32, Vertex)> {
+        let flag = reader.read_flag(layout)?;
+        let vertex = reader.read_vertex(layout)?;
+        reader.align();
+        Some((flag, vertex))
+    };
+    while let Some((flag, a)) = next(reader) {
+        let triangle = match (flag, triangles.last()) {
+            // The flags of the other two vertices are ignored
+            (0, _) => match (next(reader), next(reader)) {
+                (Some((_, b)), Some((_, c))) => [a, b, c],
+                _ => break,
+            },
+            (1, Some([_, vb, vc])) => [vb.clone(), vc.clone(), a],
+            (2, Some([va, _, vc])) => [va.clone(), vc.clone(), a],
+            _ => return Err(ShadingError::InvalidEntry("Flag").into()),
+        };
+        triangles.push(triangle);
+    }
+    Ok(triangles)
+}
+
+/// Types 6 and 7, where each patch has a flag saying whether it shares an
+/// edge with the previous one. Every patch starts on a byte boundary.
+fn patches(reader: &mut BitReader, layout: &Layout, tensor: bool) -> Result<Vec<[Vertex; 3]>> {
+    let count = if tensor { 16 } else { 12 };
+    let mut triangles = vec![];
+    let mut previous: Option<Patch> = None;
+    while let Some(flag) = reader.read_flag(layout) {
+        let (mut points, mut colors) = (vec![], vec![]);
+        if flag != 0 {
+            // The stream indices of the shared edge and its corner colors
+            let (edge, corners) = match flag {
+                1 => ([3, 4, 5, 6], [1, 2]),
+                2 => ([6, 7, 8, 9], [2, 3]),
+                3 => ([9, 10, 11, 0], [3, 0]),
+                _ => return Err(ShadingError::InvalidEntry("Flag").into()),
+            };
+            let previous = previous
+                .as_ref()
+                .ok_or(ShadingError::InvalidEntry("Flag"))?;
+            points.extend(edge.iter().map(|&i| previous.points[i]));
+            colors.extend(corners.iter().map(|&i| previous.colors[i].clone()));
+        }
+        let rest = (|| {
+            while points.len() < count {
+                points.push(reader.read_point(layout)?);
+            }
+            while colors.len() < 4 {
+                colors.push(reader.read_values(layout)?);
+            }
+            Some(())
+        })();
+        if rest.is_none() {
+            break;
+        }
+        reader.align();
+        let patch = Patch { points, colors };
+        triangles.extend(tessellate(&patch));
+        previous = Some(patch);
+    }
+    Ok(triangles)
+}
+
+/// Evaluates a patch on a grid, interpolating the corner colors between
+fn tessellate(patch: &Patch) -> Vec<[Vertex; 3]> {
+    let mut p = [[[0.0; 2]; 4]; 4];
+    for (&point, &(i, j)) in patch.points.iter().zip(PATCH_ORDER.iter()) {
+        p[i][j] = point;
+    }
+    if patch.points.len() == 12 {
+        coons_interior(&mut p);
+    }
+    let rows = (0..=PATCH_STEPS)
+        .map(|step_u| {
+            let u = step_u as f32 / PATCH_STEPS as f32;
+            (0..=PATCH_STEPS)
+                .map(|step_v| {
+                    let v = step_v as f32 / PATCH_STEPS as f32;
+                    let (bu, bv) = (bernstein(u), bernstein(v));
+                    let mut position = [0.0; 2];
+                    for i in 0..4 {
+                        for j in 0..4 {
+                            position[0] += bu[i] * bv[j] * p[i][j][0];
+                            position[1] += bu[i] * bv[j] * p[i][j][1];
+                        }
+                    }
+                    let [c00, c03, c33, c30] = [
+                        &patch.colors[0],
+                        &patch.colors[1],
+                        &patch.colors[2],
+                        &patch.colors[3],
+                    ];
+                    let values = (0..c00.len())
+                        .map(|k| {
+                            (1.0 - u) * (1.0 - v) * c00[k]
+                                + (1.0 - u) * v * c03[k]
+                                + u * v * c33[k]
+                                + u * (1.0 - v) * c30[k]
+                        })
+                        .collect();
+                    Vertex { position, values }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    lattice(&rows)
+}
+
+/// Fills in the four interior control points that turn a Coons patch into
+/// the equivalent tensor-product patch
+fn coons_interior(p: &mut [[[f32; 2]; 4]; 4]) {
+    let [x11, x12, x22, x21] = interior(|i, j| p[i][j][0]);
+    let [y11, y12, y22, y21] = interior(|i, j| p[i][j][1]);
+    p[1][1] = [x11, y11];
+    p[1][2] = [x12, y12];
+    p[2][2] = [x22, y22];
+    p[2][1] = [x21, y21];
+}
+
+/// One coordinate of `p11`, `p12`, `p22` and `p21`, from the same coordinate
+/// `q(i, j)` of the boundary points
+fn interior(q: impl Fn(usize, usize) -> f32) -> [f32; 4] {
+    let p11 = -4.0 * q(0, 0) + 6.0 * (q(0, 1) + q(1, 0)) - 2.0 * (q(0, 3) + q(3, 0))
+        + 3.0 * (q(3, 1) + q(1, 3))
+        - q(3, 3);
+    let p12 = -4.0 * q(0, 3) + 6.0 * (q(0, 2) + q(1, 3)) - 2.0 * (q(0, 0) + q(3, 3))
+        + 3.0 * (q(3, 2) + q(1, 0))
+        - q(3, 0);
+    let p22 = -4.0 * q(3, 3) + 6.0 * (q(3, 2) + q(2, 3)) - 2.0 * (q(3, 0) + q(0, 3))
+        + 3.0 * (q(2, 0) + q(0, 2))
+        - q(0, 0);
+    let p21 = -4.0 * q(3, 0) + 6.0 * (q(3, 1) + q(2, 0)) - 2.0 * (q(3, 3) + q(0, 0))
+        + 3.0 * (q(0, 1) + q(2, 3))
+        - q(0, 3);
+    [p11 / 9.0, p12 / 9.0, p22 / 9.0, p21 / 9.0]
+}
+
+/// The cubic Bernstein polynomials at `t`
+fn bernstein(t: f32) -> [f32; 4] {
+    let s = 1.0 - t;
+    [s * s * s, 3.0 * t * s * s, 3.0 * t * t * s, t * t * t]
+}
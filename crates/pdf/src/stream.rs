@@ -2,11 +2,16 @@ use crate::error::ParseError;
 use crate::{dictionary::Dictionary, utils::strip_nom, ObjectId};
 use anyhow::Result;
 use lyon::geom::Vector;
-use lyon::path::LineCap;
-use shared::{ColorSpace, DashPattern, Height, LineWidth, Rgb, Width};
+use lyon::path::{FillRule, LineCap, LineJoin};
+use shared::{
+    ColorSpace, ColorSpaceWithColor, DashPattern, Flatness, Height, LineWidth, MiterLimit,
+    RenderingIntent, TextRenderingMode, Width,
+};
+use std::borrow::Cow;
 
-use self::parsers::stream_objects;
+use self::parsers::{stream_objects, text_stream_objects};
 
+mod filters;
 mod parsers;
 
 /// Stream object
@@ -25,19 +30,55 @@ pub struct Stream<'a> {
     pub start_position: Option<usize>,
 }
 
+/// An operand of `TJ`
 #[derive(Debug, PartialEq)]
-pub struct TextContent<'a> {
-    pub font_family: Vec<u8>,
-    pub rgb: Option<Rgb>,
-    pub font_size: u32,
-    pub l_r: (f32, f32),
-    pub contents: &'a [u8],
+pub enum TextElement {
+    /// A string of character codes to show
+    Text(Vec<u8>),
+    /// How far to move back before the next string, in thousandths of the
+    /// font size
+    Adjustment(f32),
 }
 
 #[derive(Debug, PartialEq)]
-pub enum StreamObject<'a> {
-    Text(TextContent<'a>),
+pub enum StreamObject {
+    BeginText,
+    EndText,
+    /// `Tc`
+    CharSpacing(f32),
+    /// `Tw`
+    WordSpacing(f32),
+    /// `Tz`, as a percentage
+    HorizontalScaling(f32),
+    /// `TL`
+    Leading(f32),
+    /// `Tf`, with a `/Font` resource name and a size
+    Font(Vec<u8>, f32),
+    /// `Tr`
+    TextRenderingMode(TextRenderingMode),
+    /// `Ts`
+    TextRise(f32),
+    /// `Td`
+    TextMove(Vector<f32>),
+    /// `TD`, which also sets the leading
+    TextMoveSetLeading(Vector<f32>),
+    /// `Tm`
+    TextMatrix([f32; 6]),
+    /// `T*`
+    NextLine,
+    /// `Tj`
+    ShowText(Vec<u8>),
+    /// `TJ`
+    ShowTextAdjusted(Vec<TextElement>),
+    /// `'`
+    NextLineShowText(Vec<u8>),
+    /// `"`, with the word and character spacing to set first
+    NextLineShowTextSpaced(f32, f32, Vec<u8>),
     CapStyle(LineCap),
+    JoinStyle(LineJoin),
+    MiterLimit(MiterLimit),
+    Flatness(Flatness),
+    RenderingIntent(RenderingIntent),
     MoveTo(Vector<f32>),
     LineTo(Vector<f32>),
     Rect(Vector<f32>, Width, Height),
@@ -48,20 +89,62 @@ pub enum StreamObject<'a> {
     StrokeColor(Vec<f32>),
     StrokeColorSpace(ColorSpace),
     NonStrokeColorSpace(ColorSpace),
+    /// `CS` with a `/ColorSpace` resource name, resolved through the page
+    NamedStrokeColorSpace(Vec<u8>),
+    /// `cs` with a `/ColorSpace` resource name, resolved through the page
+    NamedNonStrokeColorSpace(Vec<u8>),
+    /// `g`, `rg` and `k`, which also set the non-stroking color space
+    NonStrokeDeviceColor(ColorSpaceWithColor),
+    /// `G`, `RG` and `K`, which also set the stroking color space
+    StrokeDeviceColor(ColorSpaceWithColor),
+    /// `scn`, with an optional pattern name
+    NonStrokeColorN(Vec<f32>, Option<Vec<u8>>),
+    /// `SCN`, with an optional pattern name
+    StrokeColorN(Vec<f32>, Option<Vec<u8>>),
     DashPattern(DashPattern),
+    Clip(FillRule),
+    /// `sh`, painting a `/Shading` resource
+    Shade(Vec<u8>),
+    /// `gs`, applying an `/ExtGState` resource
+    ExtGState(Vec<u8>),
+    /// `Do`, painting an `/XObject` resource
+    XObject(Vec<u8>),
+    EndPath,
+    SaveState,
+    RestoreState,
+    /// `cm`, which is only read when finding text, since drawing doesn't
+    /// apply the current transformation matrix yet
+    ConcatMatrix([f32; 6]),
 }
 
 impl<'a> Stream<'a> {
-    pub fn get_content(&self) -> Result<Vec<StreamObject<'a>>> {
-        let (rest, content) = stream_objects(self.content)?;
-        if !rest.is_empty() {
-            return Err(ParseError::FailedToParseAllStreamContent(
-                String::from_utf8_lossy(rest).to_string(),
-            )
-            .into());
-        }
-        Ok(content)
+    pub fn get_content(&self) -> Result<Vec<StreamObject>> {
+        parse_content(self.content)
     }
+
+    /// The content of the stream with its filters applied
+    pub fn decoded_content(&self) -> Result<Cow<'static, [u8]>> {
+        filters::decode(self.content, &self.dict)
+    }
+}
+
+/// Parses the operators of a content stream, all of which must be understood
+pub(crate) fn parse_content(input: &[u8]) -> Result<Vec<StreamObject>> {
+    let (rest, content) = stream_objects(input)?;
+    if !rest.is_empty() {
+        return Err(ParseError::FailedToParseAllStreamContent(
+            String::from_utf8_lossy(rest).to_string(),
+        )
+        .into());
+    }
+    Ok(content)
+}
+
+/// Parses the operators of a content stream that matter for finding text.
+/// Operators that aren't understood are skipped, so that text can be found
+/// on pages that can't be drawn.
+pub(crate) fn parse_text_content(input: &[u8]) -> Vec<StreamObject> {
+    text_stream_objects(input).map_or_else(|_| vec![], |(_, content)| content)
 }
 
 #[cfg(test)]
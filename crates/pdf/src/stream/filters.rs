@@ -0,0 +1,75 @@
+use anyhow::Result;
+use flate2::read::ZlibDecoder;
+use std::borrow::Cow;
+use std::io::Read;
+
+use crate::{dictionary::Dictionary, error::StreamError, object::Object};
+
+/// Applies the stream's `/Filter`s, in order, to its content
+pub(crate) fn decode<'a>(content: &'a [u8], dict: &Dictionary<'_>) -> Result<Cow<'a, [u8]>> {
+    let filters = match dict.get(b"Filter") {
+        Ok(Object::Array(filters)) => filters.iter().collect(),
+        Ok(filter) => vec![filter],
+        Err(_) => return Ok(Cow::Borrowed(content)),
+    };
+    if let Ok(params) = dict.get(b"DecodeParms") {
+        if let Ok(predictor) = params.as_dict().and_then(|p| p.get(b"Predictor")) {
+            let predictor = predictor.as_i64()?;
+            if predictor > 1 {
+                return Err(StreamError::UnsupportedPredictor(predictor).into());
+            }
+        }
+    }
+    let mut data = Cow::Borrowed(content);
+    for filter in filters {
+        data = match filter.as_name()? {
+            b"FlateDecode" | b"Fl" => Cow::Owned(flate(&data)?),
+            other => {
+                let name = String::from_utf8_lossy(other).to_string();
+                return Err(StreamError::UnsupportedFilter(name).into());
+            }
+        };
+    }
+    Ok(data)
+}
+
+fn flate(data: &[u8]) -> Result<Vec<u8>> {
+    let mut decoded = vec![];
+    ZlibDecoder::new(data).read_to_end(&mut decoded)?;
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use crate::{array, dictionary::Dictionary, dictionary_struct, name, object::Object};
+
+    #[test]
+    fn test_unfiltered() {
+        let content = b"0 0 m 10 10 l S";
+        let decoded = decode(content, &Dictionary::new()).unwrap();
+        assert_eq!(&*decoded, content);
+    }
+
+    #[test]
+    fn test_flate() {
+        // "0 0 m 10 10 l S", compressed with zlib
+        let content = [
+            0x78, 0x9c, 0x33, 0x50, 0x30, 0x50, 0xc8, 0x55, 0x30, 0x34, 0x00, 0xa1, 0x1c, 0x85,
+            0x60, 0x00, 0x17, 0x04, 0x03, 0x0f,
+        ];
+        let dict = dictionary_struct! {
+            "Filter" => name!("FlateDecode"),
+        };
+        let decoded = decode(&content, &dict).unwrap();
+        assert_eq!(&*decoded, b"0 0 m 10 10 l S");
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let dict = dictionary_struct! {
+            "Filter" => array!(name!("FlateDecode"), name!("DCTDecode")),
+        };
+        assert!(decode(b"", &dict).is_err());
+    }
+}
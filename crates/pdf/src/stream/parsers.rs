@@ -1,28 +1,31 @@
 use anyhow::Error;
 use lyon::geom::{vector, Vector};
-use lyon::path::LineCap;
+use lyon::path::{FillRule, LineCap, LineJoin};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    bytes::complete::take_until,
+    bytes::complete::{take_until, take_while1},
     character::complete::{char, digit0, digit1, one_of},
-    character::streaming::multispace0,
-    combinator::{map, map_opt, map_res, opt},
+    combinator::{map, map_opt, map_res, opt, verify},
     error::{ErrorKind, ParseError},
     multi::{many0, many1},
     sequence::{delimited, pair, preceded, terminated, tuple},
 };
 use num::ToPrimitive;
-use shared::{ColorSpace, DashPattern, Height, LineWidth, NumberError, Rgb, Width};
+use shared::{
+    Cmyk, ColorSpace, ColorSpaceWithColor, DashPattern, Flatness, Gray, Height, LineWidth,
+    MiterLimit, NumberError, RenderingIntent, Rgb, TextRenderingMode, Width,
+};
 use std::str::FromStr;
 
 use crate::{
+    document::parsers::_string,
     error::ParseError as PdfParseError,
-    utils::{_name, _real, int1, take_until_unmatched, ws},
+    utils::{_name, _real, int1, is_regular, ws},
     NomError, NomResult,
 };
 
-use super::{StreamObject, TextContent};
+use super::{StreamObject, TextElement};
 
 #[inline]
 fn convert_result<O, E>(result: Result<O, E>, input: &[u8], error_kind: ErrorKind) -> NomResult<O> {
@@ -32,7 +35,7 @@ fn convert_result<O, E>(result: Result<O, E>, input: &[u8], error_kind: ErrorKin
     })
 }
 
-fn rg(input: &[u8]) -> NomResult<Rgb> {
+fn rgb_with_operator<'a>(operator: &'static str, input: &'a [u8]) -> NomResult<'a, Rgb> {
     map(
         terminated(
             tuple((
@@ -40,48 +43,272 @@ fn rg(input: &[u8]) -> NomResult<Rgb> {
                 ws(number_forced_to_f32),
                 ws(number_forced_to_f32),
             )),
-            ws(tag("rg")),
+            ws(tag(operator)),
         ),
         |(r, g, b)| Rgb::new(r, g, b),
     )(input)
 }
 
-fn font_family_and_size(input: &[u8]) -> NomResult<(Vec<u8>, u32)> {
-    terminated(tuple((ws(_name), ws(int1::<u32>))), tag("Tf"))(input)
+fn gray_with_operator(operator: char, input: &[u8]) -> NomResult<Gray> {
+    map(
+        terminated(ws(number_forced_to_f32), ws(char(operator))),
+        Gray::new,
+    )(input)
 }
 
-fn location(input: &[u8]) -> NomResult<(f32, f32)> {
-    terminated(tuple((ws(_real::<f32>), ws(_real::<f32>))), tag("Td"))(input)
+fn cmyk_with_operator(operator: char, input: &[u8]) -> NomResult<Cmyk> {
+    map(
+        terminated(
+            tuple((
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+            )),
+            ws(char(operator)),
+        ),
+        |(c, m, y, k)| Cmyk::new(c, m, y, k),
+    )(input)
 }
 
-fn text_content(input: &[u8]) -> NomResult<&[u8]> {
-    terminated(
-        delimited(
-            tuple((multispace0, char('('))),
-            take_until_unmatched(b"(", b")"),
-            tuple((char(')'), multispace0)),
+/// `g`, `rg` and `k`
+fn set_non_stroke_device_color(input: &[u8]) -> NomResult<ColorSpaceWithColor> {
+    alt((
+        map(
+            |i| gray_with_operator('g', i),
+            ColorSpaceWithColor::DeviceGray,
+        ),
+        map(
+            |i| rgb_with_operator("rg", i),
+            ColorSpaceWithColor::DeviceRGB,
+        ),
+        map(
+            |i| cmyk_with_operator('k', i),
+            ColorSpaceWithColor::DeviceCMYK,
+        ),
+    ))(input)
+}
+
+/// `G`, `RG` and `K`
+fn set_stroke_device_color(input: &[u8]) -> NomResult<ColorSpaceWithColor> {
+    alt((
+        map(
+            |i| gray_with_operator('G', i),
+            ColorSpaceWithColor::DeviceGray,
+        ),
+        map(
+            |i| rgb_with_operator("RG", i),
+            ColorSpaceWithColor::DeviceRGB,
+        ),
+        map(
+            |i| cmyk_with_operator('K', i),
+            ColorSpaceWithColor::DeviceCMYK,
+        ),
+    ))(input)
+}
+
+#[test]
+fn test_device_color() {
+    assert_eq!(
+        set_non_stroke_device_color("0.5 g".as_bytes()).unwrap().1,
+        ColorSpaceWithColor::DeviceGray(Gray::new(0.5))
+    );
+    assert_eq!(
+        set_non_stroke_device_color(" 1 0 0.5 rg ".as_bytes())
+            .unwrap()
+            .1,
+        ColorSpaceWithColor::DeviceRGB(Rgb::new(1.0, 0.0, 0.5))
+    );
+    assert_eq!(
+        set_stroke_device_color("0 0.1 0.2 1 K".as_bytes())
+            .unwrap()
+            .1,
+        ColorSpaceWithColor::DeviceCMYK(Cmyk::new(0.0, 0.1, 0.2, 1.0))
+    );
+    assert!(set_stroke_device_color("0.5 g".as_bytes()).is_err());
+}
+
+fn color_n_with_operator<'a>(
+    operator: &'static str,
+    input: &'a [u8],
+) -> NomResult<'a, (Vec<f32>, Option<Vec<u8>>)> {
+    verify(
+        terminated(
+            tuple((many0(ws(number_forced_to_f32)), opt(_name))),
+            ws(tag(operator)),
+        ),
+        |(components, pattern): &(Vec<f32>, Option<Vec<u8>>)| {
+            !components.is_empty() || pattern.is_some()
+        },
+    )(input)
+}
+
+/// `scn`, which is like `sc` but also accepts a pattern name
+fn set_non_stroke_color_n(input: &[u8]) -> NomResult<(Vec<f32>, Option<Vec<u8>>)> {
+    color_n_with_operator("scn", input)
+}
+
+/// `SCN`, which is like `SC` but also accepts a pattern name
+fn set_stroke_color_n(input: &[u8]) -> NomResult<(Vec<f32>, Option<Vec<u8>>)> {
+    color_n_with_operator("SCN", input)
+}
+
+#[test]
+fn test_color_n() {
+    assert_eq!(
+        set_non_stroke_color_n("0.1 0.2 0.3 scn".as_bytes())
+            .unwrap()
+            .1,
+        (vec![0.1, 0.2, 0.3], None)
+    );
+    assert_eq!(
+        set_non_stroke_color_n("/P0 scn".as_bytes()).unwrap().1,
+        (vec![], Some(b"P0".to_vec()))
+    );
+    assert_eq!(
+        set_stroke_color_n(" 0.5 /P1 SCN ".as_bytes()).unwrap().1,
+        (vec![0.5], Some(b"P1".to_vec()))
+    );
+    assert!(set_stroke_color_n("SCN".as_bytes()).is_err());
+}
+
+/// A literal or hexadecimal string, e.g. an operand of `Tj`
+fn text_string(input: &[u8]) -> NomResult<Vec<u8>> {
+    map(_string, |(string, _)| string)(input)
+}
+
+fn begin_text(input: &[u8]) -> NomResult<()> {
+    map(ws(tag("BT")), |_| ())(input)
+}
+
+fn end_text(input: &[u8]) -> NomResult<()> {
+    map(ws(tag("ET")), |_| ())(input)
+}
+
+fn text_state_with_operator<'a>(operator: &'static str, input: &'a [u8]) -> NomResult<'a, f32> {
+    terminated(ws(number_forced_to_f32), ws(tag(operator)))(input)
+}
+
+fn font(input: &[u8]) -> NomResult<(Vec<u8>, f32)> {
+    terminated(tuple((ws(_name), ws(number_forced_to_f32))), ws(tag("Tf")))(input)
+}
+
+fn text_rendering_mode(input: &[u8]) -> NomResult<TextRenderingMode> {
+    map_opt(
+        terminated(ws(int1::<i64>), ws(tag("Tr"))),
+        TextRenderingMode::from_number,
+    )(input)
+}
+
+fn text_move_with_operator<'a>(
+    operator: &'static str,
+    input: &'a [u8],
+) -> NomResult<'a, Vector<f32>> {
+    map(
+        terminated(
+            tuple((ws(number_forced_to_f32), ws(number_forced_to_f32))),
+            ws(tag(operator)),
         ),
-        tag("Tj"),
+        |(x, y)| vector(x, y),
     )(input)
 }
 
-fn text(input: &[u8]) -> NomResult<TextContent<'_>> {
+fn matrix_with_operator<'a>(operator: &'static str, input: &'a [u8]) -> NomResult<'a, [f32; 6]> {
     map(
+        terminated(
+            tuple((
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+                ws(number_forced_to_f32),
+            )),
+            ws(tag(operator)),
+        ),
+        |(a, b, c, d, e, f)| [a, b, c, d, e, f],
+    )(input)
+}
+
+fn text_matrix(input: &[u8]) -> NomResult<[f32; 6]> {
+    matrix_with_operator("Tm", input)
+}
+
+fn next_line(input: &[u8]) -> NomResult<()> {
+    map(ws(tag("T*")), |_| ())(input)
+}
+
+fn show_text(input: &[u8]) -> NomResult<Vec<u8>> {
+    terminated(text_string, ws(tag("Tj")))(input)
+}
+
+fn show_text_adjusted(input: &[u8]) -> NomResult<Vec<TextElement>> {
+    terminated(
         delimited(
-            ws(tag("BT")),
-            tuple((opt(rg), font_family_and_size, location, text_content)),
-            ws(tag("ET")),
+            ws(char('[')),
+            many0(alt((
+                map(text_string, TextElement::Text),
+                map(ws(number_forced_to_f32), TextElement::Adjustment),
+            ))),
+            ws(char(']')),
         ),
-        |(rgb, (font_family, font_size), l_r, contents)| TextContent {
-            font_family,
-            rgb,
-            font_size,
-            l_r,
-            contents,
-        },
+        ws(tag("TJ")),
+    )(input)
+}
+
+fn next_line_show_text(input: &[u8]) -> NomResult<Vec<u8>> {
+    terminated(text_string, ws(char('\'')))(input)
+}
+
+fn next_line_show_text_spaced(input: &[u8]) -> NomResult<(f32, f32, Vec<u8>)> {
+    terminated(
+        tuple((
+            ws(number_forced_to_f32),
+            ws(number_forced_to_f32),
+            text_string,
+        )),
+        ws(char('"')),
     )(input)
 }
 
+#[test]
+fn test_text_operators() {
+    assert_eq!(
+        font(" /F1 9.5 Tf".as_bytes()).unwrap().1,
+        (b"F1".to_vec(), 9.5)
+    );
+    assert_eq!(
+        text_rendering_mode("7 Tr".as_bytes()).unwrap().1,
+        TextRenderingMode::Clip
+    );
+    assert!(text_rendering_mode("8 Tr".as_bytes()).is_err());
+    assert_eq!(
+        text_matrix("1 0 0 1 72 -.5 Tm".as_bytes()).unwrap().1,
+        [1.0, 0.0, 0.0, 1.0, 72.0, -0.5]
+    );
+    assert_eq!(
+        show_text(r"(a\) \(b\101) Tj".as_bytes()).unwrap().1,
+        b"a) (bA".to_vec()
+    );
+    assert_eq!(
+        show_text_adjusted("[<0041> -250 (B)120.5]TJ".as_bytes())
+            .unwrap()
+            .1,
+        vec![
+            TextElement::Text(vec![0x00, 0x41]),
+            TextElement::Adjustment(-250.0),
+            TextElement::Text(b"B".to_vec()),
+            TextElement::Adjustment(120.5),
+        ]
+    );
+    assert_eq!(
+        next_line_show_text_spaced("1 0.5 (x) \"".as_bytes())
+            .unwrap()
+            .1,
+        (1.0, 0.5, b"x".to_vec())
+    );
+}
+
 fn cap_style(input: &[u8]) -> NomResult<LineCap> {
     map_res(terminated(ws(int1::<u8>), ws(char('J'))), |cap| match cap {
         0 => Ok(LineCap::Butt),
@@ -95,6 +322,69 @@ fn cap_style(input: &[u8]) -> NomResult<LineCap> {
     })(input)
 }
 
+fn join_style(input: &[u8]) -> NomResult<LineJoin> {
+    map_res(
+        terminated(ws(int1::<u8>), ws(char('j'))),
+        |join| match join {
+            0 => Ok(LineJoin::Miter),
+            1 => Ok(LineJoin::Round),
+            2 => Ok(LineJoin::Bevel),
+            _ => Err(PdfParseError::InvalidJoinStyle(join.into())),
+        },
+    )(input)
+}
+
+#[test]
+fn test_join_style() {
+    assert_eq!(join_style("0 j".as_bytes()).unwrap().1, LineJoin::Miter);
+    assert_eq!(join_style(" 1 j ".as_bytes()).unwrap().1, LineJoin::Round);
+    assert_eq!(join_style("2 j".as_bytes()).unwrap().1, LineJoin::Bevel);
+    assert!(join_style("3 j".as_bytes()).is_err());
+}
+
+fn miter_limit(input: &[u8]) -> NomResult<MiterLimit> {
+    map(terminated(ws(number_forced_to_f32), ws(char('M'))), |m| {
+        MiterLimit::new(m)
+    })(input)
+}
+
+#[test]
+fn test_miter_limit() {
+    assert_eq!(
+        miter_limit("4.5 M".as_bytes()).unwrap().1,
+        MiterLimit::new(4.5)
+    );
+    assert_eq!(
+        miter_limit("0 M".as_bytes()).unwrap().1,
+        MiterLimit::new(1.0)
+    );
+}
+
+fn flatness(input: &[u8]) -> NomResult<Flatness> {
+    map(terminated(ws(number_forced_to_f32), ws(char('i'))), |f| {
+        Flatness::new(f)
+    })(input)
+}
+
+#[test]
+fn test_flatness() {
+    assert_eq!(flatness("50 i".as_bytes()).unwrap().1, Flatness::new(50.0));
+}
+
+fn rendering_intent(input: &[u8]) -> NomResult<RenderingIntent> {
+    map(terminated(_name, ws(tag("ri"))), |name| {
+        RenderingIntent::from(String::from_utf8_lossy(&name).as_ref())
+    })(input)
+}
+
+#[test]
+fn test_rendering_intent() {
+    assert_eq!(
+        rendering_intent("/Perceptual ri".as_bytes()).unwrap().1,
+        RenderingIntent::Perceptual
+    );
+}
+
 fn move_to(input: &[u8]) -> NomResult<Vector<f32>> {
     map(
         terminated(
@@ -254,6 +544,80 @@ fn fill(input: &[u8]) -> NomResult<()> {
     map(ws(char('f')), |_| ())(input)
 }
 
+fn end_path(input: &[u8]) -> NomResult<()> {
+    map(ws(char('n')), |_| ())(input)
+}
+
+fn clip(input: &[u8]) -> NomResult<FillRule> {
+    alt((
+        map(ws(tag("W*")), |_| FillRule::EvenOdd),
+        map(ws(char('W')), |_| FillRule::NonZero),
+    ))(input)
+}
+
+#[test]
+fn test_clip() {
+    assert_eq!(clip(" W ".as_bytes()).unwrap().1, FillRule::NonZero);
+    assert_eq!(
+        clip("W* n".as_bytes()).unwrap(),
+        ("n".as_bytes(), FillRule::EvenOdd)
+    );
+}
+
+fn shade(input: &[u8]) -> NomResult<Vec<u8>> {
+    terminated(_name, ws(tag("sh")))(input)
+}
+
+#[test]
+fn test_shade() {
+    assert_eq!(shade(" /Sh0 sh ".as_bytes()).unwrap().1, b"Sh0".to_vec());
+    assert!(shade("/Sh0 cs".as_bytes()).is_err());
+}
+
+fn ext_g_state(input: &[u8]) -> NomResult<Vec<u8>> {
+    terminated(_name, ws(tag("gs")))(input)
+}
+
+#[test]
+fn test_ext_g_state() {
+    assert_eq!(
+        ext_g_state(" /GS0 gs ".as_bytes()).unwrap().1,
+        b"GS0".to_vec()
+    );
+    assert!(ext_g_state("/GS0 cs".as_bytes()).is_err());
+}
+
+fn x_object(input: &[u8]) -> NomResult<Vec<u8>> {
+    terminated(_name, ws(tag("Do")))(input)
+}
+
+#[test]
+fn test_x_object() {
+    assert_eq!(x_object(" /Fm0 Do ".as_bytes()).unwrap().1, b"Fm0".to_vec());
+    assert!(x_object("/Fm0 gs".as_bytes()).is_err());
+}
+
+fn concat_matrix(input: &[u8]) -> NomResult<[f32; 6]> {
+    matrix_with_operator("cm", input)
+}
+
+#[test]
+fn test_concat_matrix() {
+    assert_eq!(
+        concat_matrix("2 0 0 2 10 -20 cm".as_bytes()).unwrap().1,
+        [2.0, 0.0, 0.0, 2.0, 10.0, -20.0]
+    );
+    assert!(concat_matrix("2 0 0 2 10 -20 Tm".as_bytes()).is_err());
+}
+
+fn save_state(input: &[u8]) -> NomResult<()> {
+    map(ws(char('q')), |_| ())(input)
+}
+
+fn restore_state(input: &[u8]) -> NomResult<()> {
+    map(ws(char('Q')), |_| ())(input)
+}
+
 fn line_width(input: &[u8]) -> NomResult<LineWidth> {
     map(terminated(ws(number_forced_to_f32), ws(char('w'))), |w| {
         LineWidth::new(w)
@@ -312,6 +676,31 @@ fn test_color_space() {
     );
 }
 
+fn set_named_non_stroke_color_space(input: &[u8]) -> NomResult<Vec<u8>> {
+    terminated(_name, ws(tag("cs")))(input)
+}
+
+fn set_named_stroke_color_space(input: &[u8]) -> NomResult<Vec<u8>> {
+    terminated(_name, ws(tag("CS")))(input)
+}
+
+#[test]
+fn test_named_color_space() {
+    assert_eq!(
+        set_named_non_stroke_color_space("/CS0 cs".as_bytes())
+            .unwrap()
+            .1,
+        b"CS0".to_vec()
+    );
+    assert_eq!(
+        set_named_stroke_color_space(" /Pattern CS ".as_bytes())
+            .unwrap()
+            .1,
+        b"Pattern".to_vec()
+    );
+    assert!(set_named_stroke_color_space("/CS0 cs".as_bytes()).is_err());
+}
+
 fn color_space(input: &[u8]) -> NomResult<&[u8]> {
     alt((tag("DeviceRGB"), tag("DeviceGray"), tag("DeviceCMYK")))(input)
 }
@@ -343,10 +732,8 @@ fn test_dash_pattern() {
     assert_eq!(dash, DashPattern::new(vec![10.0, 7.333, 9.0], 0.0));
 }
 
-pub fn stream_objects(input: &[u8]) -> NomResult<Vec<StreamObject<'_>>> {
-    many0(alt((
-        map(text, StreamObject::Text),
-        map(cap_style, StreamObject::CapStyle),
+fn path_objects(input: &[u8]) -> NomResult<StreamObject> {
+    alt((
         map(move_to, StreamObject::MoveTo),
         map(line_to, StreamObject::LineTo),
         map(rect, |(low_left, width, height)| {
@@ -354,7 +741,37 @@ pub fn stream_objects(input: &[u8]) -> NomResult<Vec<StreamObject<'_>>> {
         }),
         map(stroke, StreamObject::Stroke),
         map(fill, |_| StreamObject::Fill),
+        map(clip, StreamObject::Clip),
+        map(end_path, |_| StreamObject::EndPath),
+        map(shade, StreamObject::Shade),
+        map(x_object, StreamObject::XObject),
+    ))(input)
+}
+
+fn graphics_state_objects(input: &[u8]) -> NomResult<StreamObject> {
+    alt((
+        map(cap_style, StreamObject::CapStyle),
+        map(join_style, StreamObject::JoinStyle),
+        map(miter_limit, StreamObject::MiterLimit),
+        map(flatness, StreamObject::Flatness),
+        map(rendering_intent, StreamObject::RenderingIntent),
         map(line_width, StreamObject::LineWidth),
+        map(dash_pattern, StreamObject::DashPattern),
+        map(ext_g_state, StreamObject::ExtGState),
+        map(save_state, |_| StreamObject::SaveState),
+        map(restore_state, |_| StreamObject::RestoreState),
+    ))(input)
+}
+
+fn color_objects(input: &[u8]) -> NomResult<StreamObject> {
+    alt((
+        // Must come before `sc` and `SC`, which are prefixes of them
+        map(set_non_stroke_color_n, |(c, pattern)| {
+            StreamObject::NonStrokeColorN(c, pattern)
+        }),
+        map(set_stroke_color_n, |(c, pattern)| {
+            StreamObject::StrokeColorN(c, pattern)
+        }),
         map(set_non_stroke, StreamObject::NonStrokeColor),
         map(set_stroke_color, StreamObject::StrokeColor),
         map(set_stroke_color_space, StreamObject::StrokeColorSpace),
@@ -362,16 +779,131 @@ pub fn stream_objects(input: &[u8]) -> NomResult<Vec<StreamObject<'_>>> {
             set_non_stroke_color_space,
             StreamObject::NonStrokeColorSpace,
         ),
-        map(dash_pattern, StreamObject::DashPattern),
+        map(
+            set_non_stroke_device_color,
+            StreamObject::NonStrokeDeviceColor,
+        ),
+        map(set_stroke_device_color, StreamObject::StrokeDeviceColor),
+        // Must come after the device color spaces, which are also names
+        map(
+            set_named_non_stroke_color_space,
+            StreamObject::NamedNonStrokeColorSpace,
+        ),
+        map(
+            set_named_stroke_color_space,
+            StreamObject::NamedStrokeColorSpace,
+        ),
+    ))(input)
+}
+
+fn text_objects(input: &[u8]) -> NomResult<StreamObject> {
+    alt((
+        map(begin_text, |_| StreamObject::BeginText),
+        map(end_text, |_| StreamObject::EndText),
+        map(
+            |i| text_state_with_operator("Tc", i),
+            StreamObject::CharSpacing,
+        ),
+        map(
+            |i| text_state_with_operator("Tw", i),
+            StreamObject::WordSpacing,
+        ),
+        map(
+            |i| text_state_with_operator("Tz", i),
+            StreamObject::HorizontalScaling,
+        ),
+        map(|i| text_state_with_operator("TL", i), StreamObject::Leading),
+        map(
+            |i| text_state_with_operator("Ts", i),
+            StreamObject::TextRise,
+        ),
+        map(font, |(name, size)| StreamObject::Font(name, size)),
+        map(text_rendering_mode, StreamObject::TextRenderingMode),
+        map(|i| text_move_with_operator("Td", i), StreamObject::TextMove),
+        map(
+            |i| text_move_with_operator("TD", i),
+            StreamObject::TextMoveSetLeading,
+        ),
+        map(text_matrix, StreamObject::TextMatrix),
+        map(next_line, |_| StreamObject::NextLine),
+        map(show_text, StreamObject::ShowText),
+        map(show_text_adjusted, StreamObject::ShowTextAdjusted),
+        map(next_line_show_text, StreamObject::NextLineShowText),
+        map(next_line_show_text_spaced, |(word, char, string)| {
+            StreamObject::NextLineShowTextSpaced(word, char, string)
+        }),
+    ))(input)
+}
+
+pub fn stream_objects(input: &[u8]) -> NomResult<Vec<StreamObject>> {
+    many0(alt((
+        text_objects,
+        path_objects,
+        graphics_state_objects,
+        color_objects,
     )))(input)
 }
 
+/// Any operand, which is skipped over without being read
+fn operand(input: &[u8]) -> NomResult<()> {
+    alt((
+        map(ws(number_forced_to_f32), |_| ()),
+        map(ws(_name), |_| ()),
+        map(
+            delimited(ws(tag("<<")), many0(operand), ws(tag(">>"))),
+            |_| (),
+        ),
+        map(text_string, |_| ()),
+        map(
+            delimited(ws(char('[')), many0(operand), ws(char(']'))),
+            |_| (),
+        ),
+    ))(input)
+}
+
+/// An operator that isn't understood, along with its operands. Inline
+/// images are skipped whole, since their data isn't made of tokens.
+fn unknown_object(input: &[u8]) -> NomResult<()> {
+    alt((
+        map(
+            tuple((ws(tag("BI")), take_until("EI"), ws(tag("EI")))),
+            |_| (),
+        ),
+        map(pair(many0(operand), ws(take_while1(is_regular))), |_| ()),
+    ))(input)
+}
+
+/// Like `stream_objects`, but skips operators that aren't understood rather
+/// than stopping at them, and reads `cm`
+pub fn text_stream_objects(input: &[u8]) -> NomResult<Vec<StreamObject>> {
+    map(
+        many0(alt((
+            map(
+                alt((
+                    text_objects,
+                    path_objects,
+                    graphics_state_objects,
+                    color_objects,
+                    map(concat_matrix, StreamObject::ConcatMatrix),
+                )),
+                Some,
+            ),
+            map(unknown_object, |_| None),
+        ))),
+        |objects| objects.into_iter().flatten().collect(),
+    )(input)
+}
+
 #[cfg(test)]
 mod test {
     use super::stream_objects;
-    use crate::stream::{Rgb, StreamObject, TextContent};
+    use crate::stream::{StreamObject, TextElement};
     use lyon::geom::vector;
-    use lyon::path::LineCap;
+    use lyon::path::{FillRule, LineCap, LineJoin};
+    use shared::{
+        Cmyk, ColorSpaceWithColor, Flatness, Gray, Height, LineWidth, MiterLimit, RenderingIntent,
+        Rgb, TextRenderingMode, Width,
+    };
 
     #[test]
     fn test_text_stream() {
@@ -391,21 +923,58 @@ ET";
             stream_objects(input).unwrap().1,
             vec![
                 StreamObject::CapStyle(LineCap::Square),
-                StreamObject::Text(TextContent {
-                    font_family: b"F1".to_vec(),
-                    rgb: Some(Rgb::new(0.0, 0.0, 0.0)),
-                    font_size: 27,
-                    l_r: (57.375, 722.28),
-                    contents: b" Simple PDF File 2 ",
-                },),
-                StreamObject::Text(TextContent {
-                    font_family: b"F1".to_vec(),
-                    rgb: None,
-                    font_size: 10,
-                    l_r: (69.25, 688.608),
-                    contents:
-                        b" ...continued from page 1. Yet more text. And more text. And more text. "
-                })
+                StreamObject::BeginText,
+                StreamObject::NonStrokeDeviceColor(ColorSpaceWithColor::DeviceRGB(Rgb::new(
+                    0.0, 0.0, 0.0
+                ))),
+                StreamObject::Font(b"F1".to_vec(), 27.0),
+                StreamObject::TextMove(vector(57.375, 722.28)),
+                StreamObject::ShowText(b" Simple PDF File 2 ".to_vec()),
+                StreamObject::EndText,
+                StreamObject::BeginText,
+                StreamObject::Font(b"F1".to_vec(), 10.0),
+                StreamObject::TextMove(vector(69.25, 688.608)),
+                StreamObject::ShowText(
+                    b" ...continued from page 1. Yet more text. And more text. And more text. "
+                        .to_vec()
+                ),
+                StreamObject::EndText,
+            ]
+        )
+    }
+
+    #[test]
+    fn test_text_object_stream() {
+        let input = b"BT
+/F2 12 Tf 14.4 TL 2 Tr 1.5 Tc 3 Tw 90 Tz -2 Ts
+1 0 0 1 72 700 Tm
+0 -20 TD
+[(W) 80 (orld)] TJ
+T* (next) '
+1 2 (spaced) \"
+ET";
+        assert_eq!(
+            stream_objects(input).unwrap().1,
+            vec![
+                StreamObject::BeginText,
+                StreamObject::Font(b"F2".to_vec(), 12.0),
+                StreamObject::Leading(14.4),
+                StreamObject::TextRenderingMode(TextRenderingMode::FillStroke),
+                StreamObject::CharSpacing(1.5),
+                StreamObject::WordSpacing(3.0),
+                StreamObject::HorizontalScaling(90.0),
+                StreamObject::TextRise(-2.0),
+                StreamObject::TextMatrix([1.0, 0.0, 0.0, 1.0, 72.0, 700.0]),
+                StreamObject::TextMoveSetLeading(vector(0.0, -20.0)),
+                StreamObject::ShowTextAdjusted(vec![
+                    TextElement::Text(b"W".to_vec()),
+                    TextElement::Adjustment(80.0),
+                    TextElement::Text(b"orld".to_vec()),
+                ]),
+                StreamObject::NextLine,
+                StreamObject::NextLineShowText(b"next".to_vec()),
+                StreamObject::NextLineShowTextSpaced(1.0, 2.0, b"spaced".to_vec()),
+                StreamObject::EndText,
             ]
         )
     }
@@ -425,4 +994,81 @@ f";
             ]
         )
     }
+
+    #[test]
+    fn test_clipping_stream() {
+        let input = b"q
+0 0 100 100 re
+W* n
+50 50 m
+60 60 l
+S
+Q";
+
+        assert_eq!(
+            stream_objects(input).unwrap().1,
+            vec![
+                StreamObject::SaveState,
+                StreamObject::Rect(vector(0.0, 0.0), Width::new(100.0), Height::new(100.0)),
+                StreamObject::Clip(FillRule::EvenOdd),
+                StreamObject::EndPath,
+                StreamObject::MoveTo(vector(50.0, 50.0)),
+                StreamObject::LineTo(vector(60.0, 60.0)),
+                StreamObject::Stroke(false),
+                StreamObject::RestoreState,
+            ]
+        )
+    }
+
+    #[test]
+    fn test_stroke_properties_stream() {
+        let input = b"1 j 2 J 5 M 10 i /Saturation ri
+10 w
+85 15 m
+105 30 l
+115 17 l
+S";
+
+        assert_eq!(
+            stream_objects(input).unwrap().1,
+            vec![
+                StreamObject::JoinStyle(LineJoin::Round),
+                StreamObject::CapStyle(LineCap::Square),
+                StreamObject::MiterLimit(MiterLimit::new(5.0)),
+                StreamObject::Flatness(Flatness::new(10.0)),
+                StreamObject::RenderingIntent(RenderingIntent::Saturation),
+                StreamObject::LineWidth(LineWidth::new(10.0)),
+                StreamObject::MoveTo(vector(85.0, 15.0)),
+                StreamObject::LineTo(vector(105.0, 30.0)),
+                StreamObject::LineTo(vector(115.0, 17.0)),
+                StreamObject::Stroke(false),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_shorthand_color_stream() {
+        let input = b"0.5 g
+1 0 0 RG
+0 0 0 1 k
+/P0 scn
+0.2 0.4 0.6 sc
+0.2 0.4 0.6 SCN
+";
+        assert_eq!(
+            stream_objects(input).unwrap().1,
+            vec![
+                StreamObject::NonStrokeDeviceColor(ColorSpaceWithColor::DeviceGray(Gray::new(0.5))),
+                StreamObject::StrokeDeviceColor(ColorSpaceWithColor::DeviceRGB(Rgb::new(
+                    1.0, 0.0, 0.0
+                ))),
+                StreamObject::NonStrokeDeviceColor(ColorSpaceWithColor::DeviceCMYK(Cmyk::new(
+                    0.0, 0.0, 0.0, 1.0
+                ))),
+                StreamObject::NonStrokeColorN(vec![], Some(b"P0".to_vec())),
+                StreamObject::NonStrokeColor(vec![0.2, 0.4, 0.6]),
+                StreamObject::StrokeColorN(vec![0.2, 0.4, 0.6], None),
+            ]
+        )
+    }
 }
@@ -0,0 +1,555 @@
+use anyhow::Result;
+use graphics_state::{FontRef, GraphicsState};
+use lyon::math::{point, vector, Point, Transform};
+use shared::{Height, Width};
+use std::rc::Rc;
+
+use crate::{
+    error::FontError,
+    font::Font,
+    resources::Resources,
+    stream::{StreamObject, TextElement},
+};
+
+/// How far apart glyphs on a line must be for a space to be put between
+/// them, as a fraction of the font size. Spaces are usually about a quarter
+/// of it, and kerning much less.
+const WORD_GAP: f32 = 0.15;
+
+/// How far a glyph must be off the baseline of the one before it to start a
+/// new line, as a fraction of the font size. This leaves room for
+/// superscripts and subscripts.
+const LINE_GAP: f32 = 0.5;
+
+/// Limit on how deeply forms painted by forms are followed, which protects
+/// against cycles in malformed files
+const MAX_FORM_DEPTH: usize = 16;
+
+/// The text of a page, as found by `Page::extract_text`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageText {
+    /// All of the text in the order it's shown in, which is usually the
+    /// order it's read in. Words are separated by spaces and lines by
+    /// newlines, judging by where the glyphs are.
+    pub text: String,
+    /// The runs of text on a line that were shown by the same operator
+    pub spans: Vec<TextSpan>,
+}
+
+/// A run of text with where it's shown
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSpan {
+    /// `/BaseFont` of the font the text is shown in
+    pub font: Option<String>,
+    /// How tall the font appears, i.e. its size scaled by the text matrix
+    pub size: f32,
+    /// `[x_min y_min x_max y_max]` bounds of the glyphs, in user space, from
+    /// the font's descent to its ascent
+    pub bbox: [f32; 4],
+    pub text: String,
+}
+
+/// A glyph that was shown, in user space
+#[derive(Debug)]
+struct Glyph {
+    text: String,
+    font: Option<String>,
+    size: f32,
+    bbox: [f32; 4],
+    /// Where the glyph starts on the baseline
+    origin: Point,
+    /// Where the glyph ends on the baseline, before any spacing
+    end: Point,
+    /// Which operator showed the glyph
+    operator: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum Break {
+    None,
+    Word,
+    Line,
+}
+
+/// Runs page content through the text state and finds the glyphs it shows
+struct Extractor<'d, 'a> {
+    state: GraphicsState,
+    resources: Resources<'d, 'a>,
+    /// The current transformation matrix, which maps user space to that of
+    /// the page
+    matrix: Transform,
+    /// The matrices that `q` saved, for `Q` to restore
+    matrices: Vec<Transform>,
+    glyphs: Vec<Glyph>,
+    operators: usize,
+    depth: usize,
+}
+
+pub(crate) fn extract(content: Vec<StreamObject>, resources: Resources) -> PageText {
+    let mut extractor = Extractor {
+        // Nothing is placed relative to the page, so its size doesn't matter
+        state: GraphicsState::new(Width::new(0.0), Height::new(0.0)),
+        resources,
+        matrix: Transform::identity(),
+        matrices: vec![],
+        glyphs: vec![],
+        operators: 0,
+        depth: 0,
+    };
+    extractor.run(content);
+    assemble(&extractor.glyphs)
+}
+
+/// Logs why an operator failed, for it to be skipped. One broken operator
+/// or font shouldn't lose the rest of the text of a page.
+fn or_skip(result: Result<()>, operator: &str) {
+    if let Err(error) = result {
+        log::warn!("Skipping {}: {}", operator, error);
+    }
+}
+
+impl<'d, 'a> Extractor<'d, 'a> {
+    fn run(&mut self, content: Vec<StreamObject>) {
+        // How many states this content has saved, since a `Q` without a
+        // matching `q` mustn't restore one saved outside of it
+        let mut saved = 0;
+        for inst in content {
+            match inst {
+                StreamObject::BeginText => or_skip(self.state.begin_text(), "BT"),
+                StreamObject::EndText => or_skip(self.state.end_text(), "ET"),
+                StreamObject::CharSpacing(spacing) => {
+                    or_skip(self.state.set_char_spacing(spacing), "Tc")
+                }
+                StreamObject::WordSpacing(spacing) => {
+                    or_skip(self.state.set_word_spacing(spacing), "Tw")
+                }
+                StreamObject::HorizontalScaling(scaling) => {
+                    or_skip(self.state.set_horizontal_scaling(scaling), "Tz")
+                }
+                StreamObject::Leading(leading) => or_skip(self.state.set_leading(leading), "TL"),
+                StreamObject::Font(name, size) => or_skip(self.state.set_font(name, size), "Tf"),
+                StreamObject::TextRenderingMode(mode) => {
+                    or_skip(self.state.set_text_rendering_mode(mode), "Tr")
+                }
+                StreamObject::TextRise(rise) => or_skip(self.state.set_text_rise(rise), "Ts"),
+                StreamObject::TextMove(offset) => or_skip(self.state.move_text(offset), "Td"),
+                StreamObject::TextMoveSetLeading(offset) => {
+                    or_skip(self.state.move_text_set_leading(offset), "TD")
+                }
+                StreamObject::TextMatrix(matrix) => {
+                    or_skip(self.state.set_text_matrix(matrix), "Tm")
+                }
+                StreamObject::NextLine => or_skip(self.state.next_line(), "T*"),
+                StreamObject::ShowText(text) => {
+                    self.operators += 1;
+                    or_skip(self.show(&text), "Tj");
+                }
+                StreamObject::ShowTextAdjusted(elements) => {
+                    self.operators += 1;
+                    or_skip(self.show_adjusted(elements), "TJ");
+                }
+                StreamObject::NextLineShowText(text) => {
+                    self.operators += 1;
+                    let result = self.state.next_line().and_then(|()| self.show(&text));
+                    or_skip(result, "'");
+                }
+                StreamObject::NextLineShowTextSpaced(word_spacing, char_spacing, text) => {
+                    self.operators += 1;
+                    let result = self
+                        .state
+                        .set_word_spacing(word_spacing)
+                        .and_then(|()| self.state.set_char_spacing(char_spacing))
+                        .and_then(|()| self.state.next_line())
+                        .and_then(|()| self.show(&text));
+                    or_skip(result, "\"");
+                }
+                StreamObject::ExtGState(name) => {
+                    let result = self
+                        .resources
+                        .ext_g_state(&name)
+                        .and_then(|ext_g_state| self.state.set_ext_g_state(ext_g_state));
+                    or_skip(result, "gs");
+                }
+                StreamObject::XObject(name) => {
+                    // Images and other XObjects have no text
+                    if let Ok(form) = self.resources.form(&name) {
+                        if self.depth < MAX_FORM_DEPTH {
+                            let result =
+                                self.form(form.text_content(), form.resources, form.matrix);
+                            or_skip(result, "Do");
+                        }
+                    }
+                }
+                StreamObject::ConcatMatrix([a, b, c, d, e, f]) => {
+                    self.matrix = Transform::new(a, b, c, d, e, f).then(&self.matrix);
+                }
+                StreamObject::SaveState => {
+                    let result = self.state.save();
+                    if result.is_ok() {
+                        self.matrices.push(self.matrix);
+                        saved += 1;
+                    }
+                    or_skip(result, "q");
+                }
+                StreamObject::RestoreState if saved == 0 => {
+                    log::warn!("Skipping Q: no state was saved by q");
+                }
+                StreamObject::RestoreState => or_skip(self.restore(&mut saved), "Q"),
+                _ => {}
+            }
+        }
+        // States the content left saved are restored, so that they don't
+        // leak into what's shown after it
+        while saved > 0 {
+            or_skip(self.restore(&mut saved), "a q without a matching Q");
+        }
+    }
+
+    /// Restores the state and matrix that the last `q` saved
+    fn restore(&mut self, saved: &mut usize) -> Result<()> {
+        // Counted even if the state can't be restored, for the matrices to
+        // stay in step with the states
+        *saved -= 1;
+        if let Some(matrix) = self.matrices.pop() {
+            self.matrix = matrix;
+        }
+        self.state.restore()
+    }
+
+    /// Runs the content of a form, which is painted as if the graphics state
+    /// were saved before and restored after
+    fn form(
+        &mut self,
+        content: Vec<StreamObject>,
+        resources: Resources<'d, 'a>,
+        matrix: [f32; 6],
+    ) -> Result<()> {
+        self.state.save()?;
+        let [a, b, c, d, e, f] = matrix;
+        let outer = (self.resources, self.matrix);
+        self.resources = resources;
+        self.matrix = Transform::new(a, b, c, d, e, f).then(&self.matrix);
+        self.depth += 1;
+        self.run(content);
+        self.depth -= 1;
+        let (resources, matrix) = outer;
+        self.resources = resources;
+        self.matrix = matrix;
+        self.state.restore()
+    }
+
+    fn font(&self) -> Result<Rc<Font>> {
+        match &self.state.properties.text.font {
+            Some(FontRef::Named(name)) => self.resources.font(name),
+            Some(FontRef::Indirect(font)) => self.resources.indirect_font(*font),
+            None => Err(FontError::NotSelected.into()),
+        }
+    }
+
+    /// Shows the strings of `TJ` and moves by the numbers between them
+    fn show_adjusted(&mut self, elements: Vec<TextElement>) -> Result<()> {
+        let vertical = self.font()?.is_vertical();
+        for element in elements {
+            match element {
+                TextElement::Text(text) => self.show(&text)?,
+                TextElement::Adjustment(adjustment) if vertical => {
+                    self.state.adjust_text_vertically(adjustment)?
+                }
+                TextElement::Adjustment(adjustment) => self.state.adjust_text(adjustment)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the glyphs that a string shows, moving past each of them.
+    /// Glyphs of vertical fonts are placed below their vertical origin, and
+    /// move down.
+    fn show(&mut self, bytes: &[u8]) -> Result<()> {
+        let font = self.font()?;
+        let (descent, ascent) = vertical_extent(&font);
+        let mut offset = 0;
+        for (code, len) in font.codes(bytes) {
+            let [a, b, c, d, e, f] = self.state.user_text_rendering_matrix()?;
+            let matrix = Transform::new(a, b, c, d, e, f).then(&self.matrix);
+            // In text space, rather than in glyph space
+            let width = font.width(code) * font.font_matrix[0];
+            let vertical = Some(code)
+                .filter(|_| font.is_vertical())
+                .map(|code| font.vertical_metrics(code));
+            // Where the glyph is drawn from, and where it ends along the line
+            let (shift, end) = match vertical {
+                Some((height, [v_x, v_y])) => (
+                    vector(-v_x * font.font_matrix[0], -v_y * font.font_matrix[3]),
+                    point(0.0, height * font.font_matrix[3]),
+                ),
+                None => (vector(0.0, 0.0), point(width, 0.0)),
+            };
+            let corners = [
+                point(0.0, descent),
+                point(width, descent),
+                point(0.0, ascent),
+                point(width, ascent),
+            ]
+            .map(|corner| matrix.transform_point(corner + shift));
+            let bbox = corners.iter().fold(
+                [f32::MAX, f32::MAX, f32::MIN, f32::MIN],
+                |[x0, y0, x1, y1], p| [x0.min(p.x), y0.min(p.y), x1.max(p.x), y1.max(p.y)],
+            );
+            let text = bytes
+                .get(offset..offset + len)
+                .map_or_else(String::new, |code| font.decode(code));
+            self.glyphs.push(Glyph {
+                text,
+                font: font.base_font.clone(),
+                size: matrix.transform_vector(vector(0.0, 1.0)).length(),
+                bbox,
+                origin: matrix.transform_point(point(0.0, 0.0)),
+                end: matrix.transform_point(end),
+                operator: self.operators,
+            });
+            let is_word_space = len == 1 && code == 32;
+            match vertical {
+                Some(_) => self
+                    .state
+                    .advance_glyph_vertically(end.y * 1000.0, is_word_space)?,
+                None => self.state.advance_glyph(width * 1000.0, is_word_space)?,
+            }
+            offset += len;
+        }
+        Ok(())
+    }
+}
+
+/// How far below and above the baseline a font's glyphs reach, in text
+/// space. Fonts without a descriptor are assumed to be like most others.
+fn vertical_extent(font: &Font) -> (f32, f32) {
+    let font = font.descendant.as_deref().unwrap_or(font);
+    let scale = font.font_matrix[3];
+    match &font.descriptor {
+        Some(descriptor) if descriptor.ascent > descriptor.descent => {
+            (descriptor.descent * scale, descriptor.ascent * scale)
+        }
+        Some(descriptor) if descriptor.font_bbox[3] > descriptor.font_bbox[1] => (
+            descriptor.font_bbox[1] * scale,
+            descriptor.font_bbox[3] * scale,
+        ),
+        _ => (-0.2, 0.8),
+    }
+}
+
+/// Judges whether a glyph starts a new word or line, by how far it is from
+/// where the one before it ended along and across the baseline
+fn between(previous: &Glyph, glyph: &Glyph) -> Break {
+    let size = previous.size.max(glyph.size);
+    let direction = previous.end - previous.origin;
+    let direction = match direction.length() {
+        length if length > 0.0 => direction / length,
+        _ => vector(1.0, 0.0),
+    };
+    let offset = glyph.origin - previous.end;
+    let along = offset.dot(direction);
+    let across = offset.cross(direction);
+    // Text that jumps back to the left is taken to start a new line even on
+    // the same baseline, e.g. in another column
+    if across.abs() > size * LINE_GAP || along < -size {
+        Break::Line
+    } else if along > size * WORD_GAP {
+        Break::Word
+    } else {
+        Break::None
+    }
+}
+
+/// Joins glyphs into the text of a page and its spans
+fn assemble(glyphs: &[Glyph]) -> PageText {
+    let mut text = String::new();
+    let mut spans: Vec<TextSpan> = vec![];
+    let mut previous: Option<&Glyph> = None;
+    for glyph in glyphs {
+        let space = glyph.text.starts_with(char::is_whitespace);
+        let split = match previous.map(|previous| (previous, between(previous, glyph))) {
+            None => true,
+            Some((_, Break::Line)) => {
+                text.truncate(text.trim_end_matches(' ').len());
+                text.push('\n');
+                true
+            }
+            Some((previous, Break::Word)) => {
+                let ends_in_space = previous.text.ends_with(char::is_whitespace);
+                let split = previous.operator != glyph.operator;
+                if !space && !ends_in_space {
+                    text.push(' ');
+                    if let Some(span) = spans.last_mut().filter(|_| !split) {
+                        span.text.push(' ');
+                    }
+                }
+                split
+            }
+            Some((previous, Break::None)) => previous.operator != glyph.operator,
+        };
+        if glyph.text.is_empty() {
+            previous = Some(glyph);
+            continue;
+        }
+        text.push_str(&glyph.text);
+        match spans.last_mut() {
+            Some(span) if !split => {
+                span.text.push_str(&glyph.text);
+                let [x0, y0, x1, y1] = glyph.bbox;
+                span.bbox = [
+                    span.bbox[0].min(x0),
+                    span.bbox[1].min(y0),
+                    span.bbox[2].max(x1),
+                    span.bbox[3].max(y1),
+                ];
+            }
+            _ => spans.push(TextSpan {
+                font: glyph.font.clone(),
+                size: glyph.size,
+                bbox: glyph.bbox,
+                text: glyph.text.clone(),
+            }),
+        }
+        previous = Some(glyph);
+    }
+    PageText { text, spans }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::{extract, PageText};
+    use crate::{
+        array, dict,
+        dictionary::Dictionary,
+        dictionary_struct,
+        document::Document,
+        name,
+        object::Object,
+        reference,
+        resources::Resources,
+        stream,
+        stream::{parse_text_content, Stream},
+    };
+
+    fn extract_text(content: &[u8]) -> PageText {
+        let mut document = Document::new();
+        document.objects.insert(
+            (3, 0),
+            dict!(dictionary_struct! {
+                "Subtype" => name!("Type1"),
+                "BaseFont" => name!("Helvetica"),
+            }),
+        );
+        let dict = dictionary_struct! {
+            "Font" => dict!(dictionary_struct! {
+                "F1" => reference!(3, 0),
+            }),
+        };
+        let resources = Resources::new(&document, Some(&dict));
+        extract(parse_text_content(content), resources)
+    }
+
+    fn assert_bbox_eq(bbox: [f32; 4], expected: [f32; 4]) {
+        for (value, expected) in bbox.iter().zip(expected) {
+            assert_relative_eq!(*value, expected, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn test_extract_text() {
+        let text = extract_text(
+            b"BT /F1 10 Tf 14 TL 100 700 Td (Hello) Tj [(W) 80 (orld) -300 (again)] TJ \
+              T* (Next) Tj 0 -100 Td (Far) Tj 30 0 Td (x) Tj ET",
+        );
+        assert_eq!(text.text, "HelloWorld again\nNext\nFar x");
+        let spans: Vec<&str> = text.spans.iter().map(|span| span.text.as_str()).collect();
+        assert_eq!(spans, ["Hello", "World again", "Next", "Far", "x"]);
+        let hello = &text.spans[0];
+        assert_eq!(hello.font.as_deref(), Some("Helvetica"));
+        assert_eq!(hello.size, 10.0);
+        // From Helvetica's descent to its ascent, and across the widths of
+        // H, e, l, l and o
+        assert_bbox_eq(hello.bbox, [100.0, 697.93, 122.78, 707.18]);
+        assert_bbox_eq(text.spans[2].bbox, [100.0, 683.93, 120.56, 693.18]);
+    }
+
+    #[test]
+    fn test_extract_scaled_text() {
+        // The text matrix scales the size, and the space is shown rather than
+        // found from the gap
+        let text = extract_text(b"BT /F1 1 Tf 12 0 0 12 0 0 Tm (a b) Tj 0 -2 Td (c) Tj ET");
+        assert_eq!(text.text, "a b\nc");
+        assert_eq!(text.spans[0].size, 12.0);
+        assert_eq!(text.spans[1].bbox[0], 0.0);
+    }
+
+    #[test]
+    fn test_extract_transformed_text() {
+        // Operators that aren't understood are skipped, and the current
+        // transformation matrix places the text until it's restored
+        let text = extract_text(
+            b"/Artifact BMC EMC 0 0 10 10 re W n 1 2 3 4 5 6 c \
+              q 2 0 0 2 100 200 cm BT /F1 10 Tf /P <</MCID 0>> BDC (a) Tj EMC ET Q \
+              BT /F1 10 Tf (b) Tj ET",
+        );
+        assert_eq!(text.text, "a\nb");
+        assert_eq!(text.spans[0].size, 20.0);
+        assert_eq!(text.spans[0].bbox[0], 100.0);
+        assert_eq!(text.spans[1].size, 10.0);
+        assert_eq!(text.spans[1].bbox[0], 0.0);
+    }
+
+    #[test]
+    fn test_extract_skips_failing_operators() {
+        // Text shown before a font is selected or in one that isn't there,
+        // and ExtGStates that aren't there, are skipped along with a Q that
+        // has no q
+        let text = extract_text(
+            b"BT (a) Tj /F9 10 Tf (b) Tj [(c)] TJ ET /GS9 gs Q q Q Q \
+              BT /F1 10 Tf (d) Tj ET",
+        );
+        assert_eq!(text.text, "d");
+        assert_eq!(text.spans.len(), 1);
+    }
+
+    #[test]
+    fn test_extract_vertical_text() {
+        let mut document = Document::new();
+        document.objects.insert(
+            (4, 0),
+            dict!(dictionary_struct! {
+                "Subtype" => name!("Type0"),
+                "Encoding" => name!("Identity-V"),
+                "DescendantFonts" => array!(dict!(dictionary_struct! {
+                    "Subtype" => name!("CIDFontType2"),
+                })),
+                "ToUnicode" => stream!(Stream {
+                    dict: dictionary_struct! {},
+                    content: b"begincmap 1 begincodespacerange <0000> <FFFF> endcodespacerange \
+                               2 beginbfchar <0001> <4E00> <0002> <4E8C> endbfchar endcmap",
+                    allows_compression: true,
+                    start_position: None,
+                }),
+            }),
+        );
+        let dict = dictionary_struct! {
+            "Font" => dict!(dictionary_struct! {
+                "F2" => reference!(4, 0),
+            }),
+        };
+        let resources = Resources::new(&document, Some(&dict));
+        // Glyphs move down by `/DW2`, and so does the TJ adjustment, which
+        // leaves a gap between words. The next column starts a new line.
+        let content = parse_text_content(
+            b"BT /F2 10 Tf 100 700 Td [<0001> 500 <0002>] TJ 20 0 Td <0001> Tj ET",
+        );
+        let text = extract(content, resources);
+        assert_eq!(text.text, "\u{4e00} \u{4e8c}\n\u{4e00}");
+        assert_eq!(text.spans.len(), 2);
+        // Centered on the vertical origin, and from 880 units below it
+        assert_bbox_eq(text.spans[0].bbox, [95.0, 674.2, 105.0, 699.2]);
+        assert_bbox_eq(text.spans[1].bbox, [115.0, 689.2, 125.0, 699.2]);
+    }
+}
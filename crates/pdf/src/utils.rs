@@ -79,7 +79,7 @@ fn is_delimiter(c: u8) -> bool {
 }
 
 #[inline]
-fn is_regular(c: u8) -> bool {
+pub(crate) fn is_regular(c: u8) -> bool {
     !is_whitespace(c) && !is_delimiter(c)
 }
 
@@ -0,0 +1,22 @@
+use derive_more::{Deref, Display, From, Into};
+
+/// A constant opacity, set by `/CA` for stroking and `/ca` for everything
+/// else
+#[derive(Debug, Copy, Clone, Display, From, Into, PartialEq, Deref)]
+pub struct Alpha(f32);
+
+impl Alpha {
+    pub fn new(v: f32) -> Self {
+        Alpha(v.clamp(0.0, 1.0))
+    }
+
+    pub fn set(&mut self, v: Alpha) {
+        self.0 = *v;
+    }
+}
+
+impl Default for Alpha {
+    fn default() -> Self {
+        Alpha::new(1.0)
+    }
+}
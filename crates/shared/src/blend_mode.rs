@@ -0,0 +1,250 @@
+use crate::Rgb;
+
+/// How a painted color is combined with the color already on the page.
+/// The discriminants are what the playground's blend shader switches on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Reads a blend mode name. `Compatible` is a deprecated alias for
+    /// `Normal`.
+    pub fn from_name(input: &str) -> Option<Self> {
+        Some(match input {
+            "Normal" | "Compatible" => BlendMode::Normal,
+            "Multiply" => BlendMode::Multiply,
+            "Screen" => BlendMode::Screen,
+            "Overlay" => BlendMode::Overlay,
+            "Darken" => BlendMode::Darken,
+            "Lighten" => BlendMode::Lighten,
+            "ColorDodge" => BlendMode::ColorDodge,
+            "ColorBurn" => BlendMode::ColorBurn,
+            "HardLight" => BlendMode::HardLight,
+            "SoftLight" => BlendMode::SoftLight,
+            "Difference" => BlendMode::Difference,
+            "Exclusion" => BlendMode::Exclusion,
+            "Hue" => BlendMode::Hue,
+            "Saturation" => BlendMode::Saturation,
+            "Color" => BlendMode::Color,
+            "Luminosity" => BlendMode::Luminosity,
+            _ => return None,
+        })
+    }
+
+    /// Whether the mode applies to each component separately, rather than
+    /// to hue, saturation and luminosity
+    pub fn is_separable(&self) -> bool {
+        !matches!(
+            self,
+            BlendMode::Hue | BlendMode::Saturation | BlendMode::Color | BlendMode::Luminosity
+        )
+    }
+
+    /// The blend function B(cb, cs) of the backdrop and source colors
+    pub fn blend(&self, backdrop: Rgb, source: Rgb) -> Rgb {
+        let cb = [backdrop.red(), backdrop.green(), backdrop.blue()];
+        let cs = [source.red(), source.green(), source.blue()];
+        let [r, g, b] = match self {
+            BlendMode::Hue => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+            BlendMode::Saturation => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+            BlendMode::Color => set_lum(cs, lum(cb)),
+            BlendMode::Luminosity => set_lum(cb, lum(cs)),
+            separable => [0, 1, 2].map(|i| separable.blend_component(cb[i], cs[i])),
+        };
+        Rgb::new(r, g, b)
+    }
+
+    /// Paints `source` over an opaque backdrop with the given alpha
+    pub fn composite(&self, backdrop: Rgb, source: Rgb, alpha: f32) -> Rgb {
+        let blended = self.blend(backdrop, source);
+        let mix = |b: f32, s: f32| (1.0 - alpha) * b + alpha * s;
+        Rgb::new(
+            mix(backdrop.red(), blended.red()),
+            mix(backdrop.green(), blended.green()),
+            mix(backdrop.blue(), blended.blue()),
+        )
+    }
+
+    fn blend_component(&self, cb: f32, cs: f32) -> f32 {
+        match self {
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => cb + cs - cb * cs,
+            BlendMode::Overlay => BlendMode::HardLight.blend_component(cs, cb),
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::ColorDodge => {
+                if cb <= 0.0 {
+                    0.0
+                } else if cb >= 1.0 - cs {
+                    1.0
+                } else {
+                    cb / (1.0 - cs)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if 1.0 - cb >= cs {
+                    0.0
+                } else {
+                    1.0 - (1.0 - cb) / cs
+                }
+            }
+            BlendMode::HardLight => {
+                if cs <= 0.5 {
+                    BlendMode::Multiply.blend_component(cb, 2.0 * cs)
+                } else {
+                    BlendMode::Screen.blend_component(cb, 2.0 * cs - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        cb.sqrt()
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::Exclusion => cb + cs - 2.0 * cb * cs,
+            _ => cs,
+        }
+    }
+}
+
+fn lum([r, g, b]: [f32; 3]) -> f32 {
+    0.3 * r + 0.59 * g + 0.11 * b
+}
+
+fn clip_color(c: [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    c.map(|c| {
+        let c = if n < 0.0 {
+            l + (c - l) * l / (l - n)
+        } else {
+            c
+        };
+        if x > 1.0 {
+            l + (c - l) * (1.0 - l) / (x - l)
+        } else {
+            c
+        }
+    })
+}
+
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|c| c + d))
+}
+
+fn sat(c: [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+/// Scales the color so its saturation is `s`, which moves the smallest
+/// component to 0 and the largest to `s`
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    let min = c[0].min(c[1]).min(c[2]);
+    let max = c[0].max(c[1]).max(c[2]);
+    if max > min {
+        c.map(|c| (c - min) * s / (max - min))
+    } else {
+        [0.0; 3]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BlendMode;
+    use crate::rgb::test_utils::assert_relative_eq_rgb_epsilon;
+    use crate::Rgb;
+
+    #[test]
+    fn from_name() {
+        assert_eq!(
+            BlendMode::from_name("ColorDodge"),
+            Some(BlendMode::ColorDodge)
+        );
+        assert_eq!(BlendMode::from_name("Compatible"), Some(BlendMode::Normal));
+        assert_eq!(BlendMode::from_name("Dissolve"), None);
+    }
+
+    #[test]
+    fn separable() {
+        let backdrop = Rgb::new(0.2, 0.5, 0.8);
+        let source = Rgb::new(0.6, 0.6, 0.1);
+        let reference = [
+            (BlendMode::Normal, Rgb::new(0.6, 0.6, 0.1)),
+            (BlendMode::Multiply, Rgb::new(0.12, 0.3, 0.08)),
+            (BlendMode::Screen, Rgb::new(0.68, 0.8, 0.82)),
+            (BlendMode::Overlay, Rgb::new(0.24, 0.6, 0.64)),
+            (BlendMode::Darken, Rgb::new(0.2, 0.5, 0.1)),
+            (BlendMode::Lighten, Rgb::new(0.6, 0.6, 0.8)),
+            (BlendMode::ColorDodge, Rgb::new(0.5, 1.0, 0.888_889)),
+            (BlendMode::ColorBurn, Rgb::new(0.0, 0.166_667, 0.0)),
+            (BlendMode::HardLight, Rgb::new(0.36, 0.6, 0.16)),
+            (BlendMode::SoftLight, Rgb::new(0.2496, 0.541_421, 0.672)),
+            (BlendMode::Difference, Rgb::new(0.4, 0.1, 0.7)),
+            (BlendMode::Exclusion, Rgb::new(0.56, 0.5, 0.74)),
+        ];
+        for (mode, expected) in reference {
+            assert!(mode.is_separable());
+            assert_relative_eq_rgb_epsilon(mode.blend(backdrop, source), expected, 1e-5);
+        }
+    }
+
+    #[test]
+    fn non_separable() {
+        let backdrop = Rgb::new(0.2, 0.5, 0.8);
+        let source = Rgb::new(1.0, 0.0, 0.0);
+        let reference = [
+            (BlendMode::Hue, Rgb::new(0.863, 0.263, 0.263)),
+            (BlendMode::Saturation, Rgb::new(0.063_866, 0.531_933, 1.0)),
+            (BlendMode::Color, Rgb::new(1.0, 0.204_286, 0.204_286)),
+            (BlendMode::Luminosity, Rgb::new(0.057, 0.357, 0.657)),
+        ];
+        for (mode, expected) in reference {
+            assert!(!mode.is_separable());
+            assert_relative_eq_rgb_epsilon(mode.blend(backdrop, source), expected, 1e-5);
+        }
+    }
+
+    #[test]
+    fn composite() {
+        let white = Rgb::new(1.0, 1.0, 1.0);
+        let red = Rgb::new(1.0, 0.0, 0.0);
+        assert_relative_eq_rgb_epsilon(
+            BlendMode::Normal.composite(white, red, 0.25),
+            Rgb::new(1.0, 0.75, 0.75),
+            1e-6,
+        );
+        assert_relative_eq_rgb_epsilon(
+            BlendMode::Multiply.composite(Rgb::new(0.5, 0.5, 0.5), red, 0.5),
+            Rgb::new(0.5, 0.25, 0.25),
+            1e-6,
+        );
+    }
+}
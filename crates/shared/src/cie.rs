@@ -0,0 +1,231 @@
+//! Conversions between CIE color spaces and sRGB, shared by the ICC and
+//! CIE-based color spaces.
+
+use crate::Rgb;
+
+/// A `/CalGray` color space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalGray {
+    pub white_point: [f32; 3],
+    pub gamma: f32,
+}
+
+impl CalGray {
+    pub fn to_srgb(&self, a: f32) -> Rgb {
+        let y = a.clamp(0.0, 1.0).powf(self.gamma);
+        let xyz = [
+            self.white_point[0] * y,
+            self.white_point[1] * y,
+            self.white_point[2] * y,
+        ];
+        xyz_to_srgb(xyz, self.white_point)
+    }
+}
+
+/// A `/CalRGB` color space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalRgb {
+    pub white_point: [f32; 3],
+    pub gamma: [f32; 3],
+    /// The XYZ of each of A, B and C, i.e. the `/Matrix` entry in row-major order
+    pub matrix: [[f32; 3]; 3],
+}
+
+impl CalRgb {
+    pub fn to_srgb(&self, abc: [f32; 3]) -> Rgb {
+        let mut xyz = [0.0; 3];
+        for (i, row) in self.matrix.iter().enumerate() {
+            let v = abc[i].clamp(0.0, 1.0).powf(self.gamma[i]);
+            for (c, m) in xyz.iter_mut().zip(row) {
+                *c += m * v;
+            }
+        }
+        xyz_to_srgb(xyz, self.white_point)
+    }
+}
+
+/// A `/Lab` color space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    pub white_point: [f32; 3],
+    /// The `[amin amax bmin bmax]` bounds of a* and b*
+    pub range: [f32; 4],
+}
+
+impl Lab {
+    /// Clamps L* to 0-100 and a* and b* to the range of the color space
+    pub fn clamp(&self, lab: [f32; 3]) -> [f32; 3] {
+        [
+            lab[0].clamp(0.0, 100.0),
+            lab[1].clamp(self.range[0], self.range[1]),
+            lab[2].clamp(self.range[2], self.range[3]),
+        ]
+    }
+
+    pub fn to_srgb(&self, lab: [f32; 3]) -> Rgb {
+        let xyz = lab_to_xyz(self.clamp(lab), self.white_point);
+        xyz_to_srgb(xyz, self.white_point)
+    }
+}
+
+/// The D50 white point used by the ICC profile connection space
+pub const D50: [f32; 3] = [0.9642, 1.0, 0.8249];
+
+/// The D65 white point of sRGB
+pub const D65: [f32; 3] = [0.95047, 1.0, 1.08883];
+
+type Matrix = [[f32; 3]; 3];
+
+const BRADFORD: Matrix = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+const BRADFORD_INVERSE: Matrix = [
+    [0.986_993, -0.147_054_3, 0.159_962_7],
+    [0.432_305_3, 0.518_360_3, 0.049_291_2],
+    [-0.008_528_7, 0.040_042_8, 0.968_486_7],
+];
+
+/// XYZ (D65) to linear sRGB
+const XYZ_TO_SRGB: Matrix = [
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266, 1.876_010_8, 0.041_556],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+];
+
+pub fn multiply(m: &Matrix, v: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Adapts `xyz` from one white point to another with the Bradford transform
+pub fn adapt(xyz: [f32; 3], from: [f32; 3], to: [f32; 3]) -> [f32; 3] {
+    if from == to {
+        return xyz;
+    }
+    let source = multiply(&BRADFORD, from);
+    let destination = multiply(&BRADFORD, to);
+    let cone = multiply(&BRADFORD, xyz);
+    let scaled = [
+        cone[0] * destination[0] / source[0],
+        cone[1] * destination[1] / source[1],
+        cone[2] * destination[2] / source[2],
+    ];
+    multiply(&BRADFORD_INVERSE, scaled)
+}
+
+/// Converts CIE L*a*b* to XYZ relative to `white`
+pub fn lab_to_xyz(lab: [f32; 3], white: [f32; 3]) -> [f32; 3] {
+    let [l, a, b] = lab;
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let inverse = |t: f32| {
+        if t > 6.0 / 29.0 {
+            t.powi(3)
+        } else {
+            3.0 * (6.0_f32 / 29.0).powi(2) * (t - 4.0 / 29.0)
+        }
+    };
+    [
+        white[0] * inverse(fx),
+        white[1] * inverse(fy),
+        white[2] * inverse(fz),
+    ]
+}
+
+/// Converts XYZ relative to `white` to sRGB
+pub fn xyz_to_srgb(xyz: [f32; 3], white: [f32; 3]) -> Rgb {
+    let [r, g, b] = multiply(&XYZ_TO_SRGB, adapt(xyz, white, D65));
+    Rgb::new(gamma(r), gamma(g), gamma(b))
+}
+
+/// The sRGB transfer function
+fn gamma(v: f32) -> f32 {
+    if v <= 0.003_130_8 {
+        12.92 * v
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lab_to_xyz, xyz_to_srgb, CalGray, CalRgb, Lab, D50, D65};
+    use crate::rgb::test_utils::assert_relative_eq_rgb_epsilon;
+    use crate::Rgb;
+
+    #[test]
+    fn white() {
+        let white = Rgb::new(1.0, 1.0, 1.0);
+        assert_relative_eq_rgb_epsilon(xyz_to_srgb(D65, D65), white, 0.001);
+        assert_relative_eq_rgb_epsilon(xyz_to_srgb(D50, D50), white, 0.001);
+        let xyz = lab_to_xyz([100.0, 0.0, 0.0], D50);
+        assert_relative_eq_rgb_epsilon(xyz_to_srgb(xyz, D50), white, 0.001);
+    }
+
+    #[test]
+    fn primaries() {
+        // sRGB red in XYZ (D65)
+        let red = [0.412_456_4, 0.212_672_9, 0.019_333_9];
+        assert_relative_eq_rgb_epsilon(xyz_to_srgb(red, D65), Rgb::new(1.0, 0.0, 0.0), 0.001);
+        // 50% gray in L*a*b*
+        let xyz = lab_to_xyz([53.389, 0.0, 0.0], D50);
+        assert_relative_eq_rgb_epsilon(xyz_to_srgb(xyz, D50), Rgb::new(0.5, 0.5, 0.5), 0.002);
+    }
+
+    #[test]
+    fn cal_gray() {
+        let linear = CalGray {
+            white_point: D65,
+            gamma: 1.0,
+        };
+        let mid = Rgb::new(0.5, 0.5, 0.5);
+        assert_relative_eq_rgb_epsilon(linear.to_srgb(0.214), mid, 0.002);
+        let gamma = CalGray {
+            white_point: D50,
+            gamma: 2.2,
+        };
+        let expected = Rgb::new(0.5038, 0.5038, 0.5038);
+        assert_relative_eq_rgb_epsilon(gamma.to_srgb(0.5), expected, 0.002);
+    }
+
+    #[test]
+    fn cal_rgb() {
+        // Linear sRGB expressed as CalRGB
+        let srgb = CalRgb {
+            white_point: D65,
+            gamma: [1.0; 3],
+            matrix: [
+                [0.4124, 0.2126, 0.0193],
+                [0.3576, 0.7152, 0.1192],
+                [0.1805, 0.0722, 0.9505],
+            ],
+        };
+        let white = Rgb::new(1.0, 1.0, 1.0);
+        assert_relative_eq_rgb_epsilon(srgb.to_srgb([1.0; 3]), white, 0.002);
+        let red = Rgb::new(1.0, 0.0, 0.0);
+        assert_relative_eq_rgb_epsilon(srgb.to_srgb([1.0, 0.0, 0.0]), red, 0.002);
+        let mid = Rgb::new(0.5, 0.5, 0.5);
+        assert_relative_eq_rgb_epsilon(srgb.to_srgb([0.214; 3]), mid, 0.002);
+    }
+
+    #[test]
+    fn lab() {
+        let lab = Lab {
+            white_point: D50,
+            range: [-100.0, 100.0, -100.0, 100.0],
+        };
+        // sRGB red in L*a*b* (D50)
+        let red = Rgb::new(1.0, 0.0, 0.0);
+        assert_relative_eq_rgb_epsilon(lab.to_srgb([54.29, 80.80, 69.89]), red, 0.01);
+        let black = Rgb::new(0.0, 0.0, 0.0);
+        assert_relative_eq_rgb_epsilon(lab.to_srgb([0.0, 0.0, 0.0]), black, 0.001);
+        assert_eq!(lab.clamp([120.0, -150.0, 50.0]), [100.0, -100.0, 50.0]);
+    }
+}
@@ -1,3 +1,6 @@
+use crate::Rgb;
+
+#[cfg(test)]
 mod test_utils;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -22,15 +25,36 @@ impl Cmyk {
         *self = c;
     }
 
-    // TODO: What range is CMYK? Should RGB and CMYK be the same scale IE 0 to 1?
+    pub fn cyan(&self) -> f32 {
+        self.0
+    }
+
+    pub fn magenta(&self) -> f32 {
+        self.1
+    }
+
+    pub fn yellow(&self) -> f32 {
+        self.2
+    }
+
+    pub fn black(&self) -> f32 {
+        self.3
+    }
+
     fn clamp(v: f32) -> f32 {
-        if v > 255.0 {
-            255.0
-        } else if v < 0.0 {
-            0.0
-        } else {
-            v
-        }
+        v.clamp(0.0, 1.0)
+    }
+}
+
+impl From<Cmyk> for Rgb {
+    /// The naive conversion from the PDF spec (10.4.2.4), used when no
+    /// color profile is available
+    fn from(c: Cmyk) -> Self {
+        Rgb::new(
+            1.0 - (c.0 + c.3).min(1.0),
+            1.0 - (c.1 + c.3).min(1.0),
+            1.0 - (c.2 + c.3).min(1.0),
+        )
     }
 }
 
@@ -38,10 +62,28 @@ impl Cmyk {
 mod test {
     use super::test_utils::assert_relative_eq_cmyk;
     use super::Cmyk;
+    use crate::rgb::test_utils::assert_relative_eq_rgb;
+    use crate::Rgb;
 
     #[test]
     fn min_max() {
-        let rgb = Cmyk::new(256.0, -1.0, 130.0, 0.0);
-        assert_relative_eq_cmyk(rgb, Cmyk::new(255.0, 0.0, 130.0, 0.0))
+        let rgb = Cmyk::new(1.5, -1.0, 0.5, 0.0);
+        assert_relative_eq_cmyk(rgb, Cmyk::new(1.0, 0.0, 0.5, 0.0))
+    }
+
+    #[test]
+    fn to_rgb() {
+        assert_relative_eq_rgb(
+            Rgb::from(Cmyk::new(0.0, 0.0, 0.0, 0.0)),
+            Rgb::new(1.0, 1.0, 1.0),
+        );
+        assert_relative_eq_rgb(
+            Rgb::from(Cmyk::new(0.0, 0.0, 0.0, 1.0)),
+            Rgb::new(0.0, 0.0, 0.0),
+        );
+        assert_relative_eq_rgb(
+            Rgb::from(Cmyk::new(1.0, 0.5, 0.0, 0.25)),
+            Rgb::new(0.0, 0.25, 0.75),
+        );
     }
 }
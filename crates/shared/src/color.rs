@@ -1,27 +1,167 @@
 use anyhow::{Error, Result};
 use std::str::FromStr;
+use std::sync::Arc;
 use thiserror::Error;
 
-use crate::{Cmyk, Gray, Rgb};
+use crate::{CalGray, CalRgb, Cmyk, DeviceN, Gray, IccProfile, Indexed, Lab, Rgb, Separation};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ColorSpace {
     DeviceRGB,
     DeviceGray,
     DeviceCMYK,
+    CalGray(CalGray),
+    CalRGB(CalRgb),
+    Lab(Lab),
+    ICCBased(Arc<IccProfile>),
+    Indexed(Arc<Indexed>),
+    Separation(Arc<Separation>),
+    DeviceN(Arc<DeviceN>),
+    /// Paints with the pattern selected by `scn`/`SCN` rather than a color.
+    /// Uncolored tiling patterns are painted in a color from the base space.
+    Pattern(Option<Arc<ColorSpace>>),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ColorSpaceWithColor {
     DeviceRGB(Rgb),
     DeviceGray(Gray),
     DeviceCMYK(Cmyk),
+    CalGray(CalGray, f32),
+    CalRGB(CalRgb, [f32; 3]),
+    Lab(Lab, [f32; 3]),
+    ICCBased(Arc<IccProfile>, Vec<f32>),
+    /// An index into the lookup table
+    Indexed(Arc<Indexed>, f32),
+    /// The tint, where 0.0 applies none of the colorant and 1.0 all of it
+    Separation(Arc<Separation>, f32),
+    DeviceN(Arc<DeviceN>, Vec<f32>),
+    /// The color to paint uncolored tiling patterns in, if there's a base
+    Pattern(Option<Arc<ColorSpaceWithColor>>),
+}
+
+impl ColorSpace {
+    /// The number of color components, i.e. operands to `sc` and `SC`
+    pub fn components(&self) -> usize {
+        match self {
+            ColorSpace::DeviceGray | ColorSpace::CalGray(_) => 1,
+            ColorSpace::DeviceRGB | ColorSpace::CalRGB(_) | ColorSpace::Lab(_) => 3,
+            ColorSpace::DeviceCMYK => 4,
+            ColorSpace::ICCBased(profile) => profile.components(),
+            ColorSpace::Indexed(_) | ColorSpace::Separation(_) => 1,
+            ColorSpace::DeviceN(device_n) => device_n.names.len(),
+            ColorSpace::Pattern(base) => base.as_ref().map_or(0, |base| base.components()),
+        }
+    }
+
+    /// The `[min max]` range of each component. Only `Lab` has components
+    /// outside of 0.0 to 1.0.
+    pub fn component_ranges(&self) -> Vec<[f32; 2]> {
+        match self {
+            ColorSpace::Lab(lab) => vec![
+                [0.0, 100.0],
+                [lab.range[0], lab.range[1]],
+                [lab.range[2], lab.range[3]],
+            ],
+            _ => vec![[0.0, 1.0]; self.components()],
+        }
+    }
+
+    /// The components of the color selected along with the color space by
+    /// `cs` and `CS`
+    pub fn initial_components(&self) -> Vec<f32> {
+        match self {
+            // Every colorant is applied fully
+            ColorSpace::Separation(_) | ColorSpace::DeviceN(_) => vec![1.0; self.components()],
+            // 0.0 may be outside the range of a* and b*
+            ColorSpace::Lab(lab) => lab.clamp([0.0; 3]).to_vec(),
+            // Black, rather than no ink at all
+            ColorSpace::DeviceCMYK => vec![0.0, 0.0, 0.0, 1.0],
+            _ => vec![0.0; self.components()],
+        }
+    }
+
+    pub fn with_components(&self, c: Vec<f32>) -> Result<ColorSpaceWithColor> {
+        match c.len() {
+            n if n == self.components() => {}
+            0 => return Err(ColorError::TooFewParams.into()),
+            n => return Err(ColorError::WrongNumberOfParams(self.clone(), n).into()),
+        }
+        Ok(match self {
+            ColorSpace::DeviceGray => ColorSpaceWithColor::DeviceGray(Gray::new(c[0])),
+            ColorSpace::DeviceRGB => ColorSpaceWithColor::DeviceRGB(Rgb::new(c[0], c[1], c[2])),
+            ColorSpace::DeviceCMYK => {
+                ColorSpaceWithColor::DeviceCMYK(Cmyk::new(c[0], c[1], c[2], c[3]))
+            }
+            ColorSpace::CalGray(cal) => ColorSpaceWithColor::CalGray(*cal, c[0]),
+            ColorSpace::CalRGB(cal) => ColorSpaceWithColor::CalRGB(*cal, [c[0], c[1], c[2]]),
+            ColorSpace::Lab(lab) => ColorSpaceWithColor::Lab(*lab, lab.clamp([c[0], c[1], c[2]])),
+            ColorSpace::ICCBased(profile) => ColorSpaceWithColor::ICCBased(profile.clone(), c),
+            ColorSpace::Indexed(indexed) => ColorSpaceWithColor::Indexed(indexed.clone(), c[0]),
+            ColorSpace::Separation(separation) => {
+                ColorSpaceWithColor::Separation(separation.clone(), c[0])
+            }
+            ColorSpace::DeviceN(device_n) => ColorSpaceWithColor::DeviceN(device_n.clone(), c),
+            ColorSpace::Pattern(None) => ColorSpaceWithColor::Pattern(None),
+            ColorSpace::Pattern(Some(base)) => {
+                ColorSpaceWithColor::Pattern(Some(Arc::new(base.with_components(c)?)))
+            }
+        })
+    }
+}
+
+impl ColorSpaceWithColor {
+    /// Whether painting in the color marks the page at all, which it
+    /// doesn't for the `None` colorant of `Separation` and `DeviceN`
+    pub fn paints(&self) -> bool {
+        match self {
+            ColorSpaceWithColor::Separation(separation, _) => separation.name != b"None",
+            ColorSpaceWithColor::DeviceN(device_n, _) => {
+                device_n.names.iter().any(|name| name != b"None")
+            }
+            _ => true,
+        }
+    }
+
+    /// Converts a color in one of the special color spaces to its base or
+    /// alternate space. Other colors are returned as they are.
+    pub fn to_base(&self) -> ColorSpaceWithColor {
+        match self {
+            // The base of an indexed space may itself be special
+            ColorSpaceWithColor::Indexed(indexed, index) => indexed.lookup(*index).to_base(),
+            ColorSpaceWithColor::Separation(separation, tint) => separation.convert(*tint),
+            ColorSpaceWithColor::DeviceN(device_n, tints) => device_n.convert(tints),
+            ColorSpaceWithColor::Pattern(Some(color)) => {
+                ColorSpaceWithColor::Pattern(Some(Arc::new(color.to_base())))
+            }
+            other => other.clone(),
+        }
+    }
+
+    /// Converts the color to RGB for rendering
+    pub fn to_rgb(&self) -> Rgb {
+        match self {
+            ColorSpaceWithColor::DeviceRGB(rgb) => *rgb,
+            ColorSpaceWithColor::DeviceGray(gray) => (*gray).into(),
+            ColorSpaceWithColor::DeviceCMYK(cmyk) => (*cmyk).into(),
+            ColorSpaceWithColor::CalGray(cal, a) => cal.to_srgb(*a),
+            ColorSpaceWithColor::CalRGB(cal, abc) => cal.to_srgb(*abc),
+            ColorSpaceWithColor::Lab(lab, c) => lab.to_srgb(*c),
+            ColorSpaceWithColor::ICCBased(profile, c) => profile.to_srgb(c),
+            // Only uncolored patterns can be represented by a single color
+            ColorSpaceWithColor::Pattern(Some(color)) => color.to_rgb(),
+            ColorSpaceWithColor::Pattern(None) => Rgb::default(),
+            special => special.to_base().to_rgb(),
+        }
+    }
 }
 
 #[derive(Error, Debug)]
 pub enum ColorSpaceError {
     #[error("Unrecognized color space: {0}")]
     UnrecognizedColorSpace(String),
+    #[error("Color space is nested too deeply")]
+    TooDeeplyNested,
 }
 
 impl FromStr for ColorSpace {
@@ -32,6 +172,7 @@ impl FromStr for ColorSpace {
             "DeviceRGB" => Ok(ColorSpace::DeviceRGB),
             "DeviceGray" => Ok(ColorSpace::DeviceGray),
             "DeviceCMYK" => Ok(ColorSpace::DeviceCMYK),
+            "Pattern" => Ok(ColorSpace::Pattern(None)),
             s => Err(ColorSpaceError::UnrecognizedColorSpace(s.to_owned()).into()),
         }
     }
@@ -43,6 +184,8 @@ pub enum ColorError {
     TooManyParams(usize),
     #[error("Received 0 params for color. At least one is required.")]
     TooFewParams,
+    #[error("Received {1} params for color in {0:?}, which needs {}", .0.components())]
+    WrongNumberOfParams(ColorSpace, usize),
 }
 
 pub trait Color {
@@ -50,5 +193,92 @@ pub trait Color {
 
     fn set_color(&mut self, c: Vec<f32>) -> Result<()>;
 
+    /// Sets the color space and color at once, as `g`, `rg` and `k` do.
+    fn set_device_color(&mut self, c: ColorSpaceWithColor);
+
+    /// Selects a named pattern from the page's resources, as `scn` does.
+    fn set_pattern(&mut self, name: Vec<u8>);
+
     fn get_current_color(&self) -> ColorSpaceWithColor;
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::{Color, ColorSpace, ColorSpaceWithColor};
+    use crate::{Cmyk, Gray, Indexed, NonStrokeColor, Rgb, Separation, StrokeColor, TintTransform};
+
+    #[test]
+    fn with_components() {
+        assert_eq!(
+            ColorSpace::DeviceGray.with_components(vec![0.5]).unwrap(),
+            ColorSpaceWithColor::DeviceGray(Gray::new(0.5))
+        );
+        assert_eq!(
+            ColorSpace::DeviceCMYK
+                .with_components(vec![0.0, 0.1, 0.2, 0.3])
+                .unwrap(),
+            ColorSpaceWithColor::DeviceCMYK(Cmyk::new(0.0, 0.1, 0.2, 0.3))
+        );
+        assert!(ColorSpace::DeviceRGB.with_components(vec![0.5]).is_err());
+        assert!(ColorSpace::DeviceGray.with_components(vec![]).is_err());
+    }
+
+    #[derive(Debug)]
+    struct Invert;
+
+    impl TintTransform for Invert {
+        fn transform(&self, input: &[f32]) -> Vec<f32> {
+            vec![1.0 - input[0]]
+        }
+    }
+
+    #[test]
+    fn special() {
+        let separation = ColorSpace::Separation(Arc::new(Separation {
+            name: b"Spot".to_vec(),
+            alternate: ColorSpace::DeviceGray,
+            tint_transform: Arc::new(Invert),
+        }));
+        assert_eq!(separation.initial_components(), vec![1.0]);
+        let indexed = Indexed::new(separation, 1, vec![0, 255]).unwrap();
+        let color = ColorSpace::Indexed(Arc::new(indexed))
+            .with_components(vec![1.0])
+            .unwrap();
+        assert_eq!(
+            color.to_base(),
+            ColorSpaceWithColor::DeviceGray(Gray::new(0.0))
+        );
+        assert_eq!(color.to_rgb(), Rgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn initial_color() {
+        // `cs` starts each device space over at black
+        let mut color = NonStrokeColor::default();
+        color.set_device_color(ColorSpaceWithColor::DeviceRGB(Rgb::new(1.0, 0.0, 0.0)));
+        color.set_color_space(ColorSpace::DeviceRGB);
+        assert_eq!(color.get_current_color().to_rgb(), Rgb::new(0.0, 0.0, 0.0));
+
+        let mut color = StrokeColor::default();
+        color.set_color_space(ColorSpace::DeviceCMYK);
+        assert_eq!(
+            color.get_current_color(),
+            ColorSpaceWithColor::DeviceCMYK(Cmyk::new(0.0, 0.0, 0.0, 1.0))
+        );
+        assert_eq!(color.get_current_color().to_rgb(), Rgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn uncolored_pattern() {
+        let pattern = ColorSpace::Pattern(Some(Arc::new(ColorSpace::DeviceRGB)));
+        assert_eq!(pattern.components(), 3);
+        let color = pattern.with_components(vec![1.0, 0.0, 0.0]).unwrap();
+        assert_eq!(color.to_rgb(), Rgb::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            ColorSpace::Pattern(None).with_components(vec![]).unwrap(),
+            ColorSpaceWithColor::Pattern(None)
+        );
+    }
+}
@@ -17,4 +17,17 @@ impl DashPattern {
     pub fn new(array: Vec<f32>, phase: f32) -> Self {
         DashPattern { array, phase }
     }
+
+    pub fn array(&self) -> &[f32] {
+        &self.array
+    }
+
+    pub fn phase(&self) -> f32 {
+        self.phase
+    }
+
+    /// An empty array, or one whose lengths are all zero, strokes a solid line.
+    pub fn is_solid(&self) -> bool {
+        self.array.iter().all(|l| *l <= 0.0)
+    }
 }
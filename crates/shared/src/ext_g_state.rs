@@ -0,0 +1,54 @@
+use lyon::path::{LineCap, LineJoin};
+
+use crate::{Alpha, BlendMode, DashPattern, Flatness, LineWidth, MiterLimit, RenderingIntent};
+
+/// The object number and generation of an indirect object
+pub type ObjectRef = (u32, u16);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoftMaskKind {
+    /// The mask is the group's alpha
+    Alpha,
+    /// The mask is the luminosity of the group's color
+    Luminosity,
+}
+
+/// A soft mask dictionary, i.e. the `/SMask` of an `/ExtGState`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoftMask {
+    pub kind: SoftMaskKind,
+    /// The transparency group XObject the mask is rendered from
+    pub group: ObjectRef,
+    /// The color the group is composited onto, in the group's color space
+    pub backdrop: Option<Vec<f32>>,
+    /// The transfer function `/TR` applied to mask values, sampled at
+    /// `TRANSFER_SAMPLES` evenly spaced inputs from 0 to 1
+    pub transfer: Option<Vec<f32>>,
+}
+
+/// The number of samples taken of the transfer function of a soft mask
+pub const TRANSFER_SAMPLES: usize = 256;
+
+/// The parameters set by `gs`. Only the entries present in the dictionary
+/// are `Some`, and only those change the graphics state. Device-dependent
+/// entries such as halftones and transfer functions aren't supported.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExtGState {
+    pub line_width: Option<LineWidth>,
+    pub line_cap: Option<LineCap>,
+    pub line_join: Option<LineJoin>,
+    pub miter_limit: Option<MiterLimit>,
+    pub dash_pattern: Option<DashPattern>,
+    pub rendering_intent: Option<RenderingIntent>,
+    pub flatness: Option<Flatness>,
+    pub stroke_adjustment: Option<bool>,
+    pub stroke_alpha: Option<Alpha>,
+    pub non_stroke_alpha: Option<Alpha>,
+    pub alpha_is_shape: Option<bool>,
+    pub text_knockout: Option<bool>,
+    pub blend_mode: Option<BlendMode>,
+    /// `Some(None)` is `/SMask /None`, which removes the current mask
+    pub soft_mask: Option<Option<SoftMask>>,
+    /// A font dictionary and the size to use it at
+    pub font: Option<(ObjectRef, f32)>,
+}
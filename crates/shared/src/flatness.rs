@@ -0,0 +1,31 @@
+use derive_more::{Deref, Display, From, Into};
+
+#[derive(Debug, Copy, Clone, Display, From, Into, PartialEq, Deref)]
+pub struct Flatness(f32);
+
+impl Flatness {
+    pub fn new(v: f32) -> Self {
+        Flatness(v.clamp(0.0, 100.0))
+    }
+
+    pub fn set(&mut self, v: Flatness) {
+        self.0 = *v;
+    }
+
+    /// The maximum distance in device pixels between a curve and its
+    /// flattened approximation. A flatness of 0 means "use the device
+    /// default", which is `device_default`.
+    pub fn tolerance(&self, device_default: f32) -> f32 {
+        if self.0 > 0.0 {
+            self.0
+        } else {
+            device_default
+        }
+    }
+}
+
+impl Default for Flatness {
+    fn default() -> Self {
+        Flatness::new(0.0)
+    }
+}
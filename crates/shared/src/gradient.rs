@@ -0,0 +1,107 @@
+use crate::Rgb;
+
+/// The identity `[a b c d e f]` matrix
+pub const IDENTITY: [f32; 6] = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+/// The geometry of an axial or radial shading, in shading space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientKind {
+    /// Varies along the line from `start` to `end`
+    Axial { start: [f32; 2], end: [f32; 2] },
+    /// Varies between the circle around `start` and the one around `end`
+    Radial {
+        start: [f32; 2],
+        start_radius: f32,
+        end: [f32; 2],
+        end_radius: f32,
+    },
+}
+
+/// An axial or radial shading whose color function has already been
+/// evaluated, so that it can be drawn without knowing about PDF functions
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    /// Colors at positions from 0.0 at the start of the gradient to 1.0 at
+    /// its end, in increasing order
+    pub stops: Vec<(f32, Rgb)>,
+    /// Whether the first and last colors continue past the start and end
+    pub extend: [bool; 2],
+    /// Painted outside the gradient when filling with a shading pattern.
+    /// Ignored by `sh`.
+    pub background: Option<Rgb>,
+    /// `[x_min y_min x_max y_max]` limits of the painted area, in shading space
+    pub bbox: Option<[f32; 4]>,
+    /// Maps shading space to user space. Only shading patterns have one.
+    pub matrix: [f32; 6],
+}
+
+impl Gradient {
+    /// The color at `t`, interpolated between the surrounding stops
+    pub fn color_at(&self, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let next = self.stops.iter().position(|(s, _)| *s >= t);
+        match next {
+            None => self.stops.last().map(|(_, c)| *c).unwrap_or_default(),
+            Some(0) => self.stops[0].1,
+            Some(i) => {
+                let (s0, c0) = self.stops[i - 1];
+                let (s1, c1) = self.stops[i];
+                let f = (t - s0) / (s1 - s0);
+                let mix = |a: f32, b: f32| a + (b - a) * f;
+                Rgb::new(
+                    mix(c0.red(), c1.red()),
+                    mix(c0.green(), c1.green()),
+                    mix(c0.blue(), c1.blue()),
+                )
+            }
+        }
+    }
+
+    /// Applies `matrix` to a point in shading space
+    pub fn transform(&self, p: [f32; 2]) -> [f32; 2] {
+        apply(self.matrix, p)
+    }
+}
+
+/// Applies an `[a b c d e f]` matrix to a point
+pub(crate) fn apply(matrix: [f32; 6], p: [f32; 2]) -> [f32; 2] {
+    let [a, b, c, d, e, f] = matrix;
+    [a * p[0] + c * p[1] + e, b * p[0] + d * p[1] + f]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Gradient, GradientKind, IDENTITY};
+    use crate::rgb::test_utils::assert_relative_eq_rgb;
+    use crate::Rgb;
+
+    #[test]
+    fn color_at() {
+        let gradient = Gradient {
+            kind: GradientKind::Axial {
+                start: [0.0, 0.0],
+                end: [100.0, 0.0],
+            },
+            stops: vec![
+                (0.0, Rgb::new(1.0, 0.0, 0.0)),
+                (0.5, Rgb::new(0.0, 0.0, 1.0)),
+                (1.0, Rgb::new(0.0, 0.0, 0.0)),
+            ],
+            extend: [false, false],
+            background: None,
+            bbox: None,
+            matrix: [2.0, 0.0, 0.0, 1.0, 10.0, 0.0],
+        };
+        assert_relative_eq_rgb(gradient.color_at(0.25), Rgb::new(0.5, 0.0, 0.5));
+        assert_relative_eq_rgb(gradient.color_at(0.75), Rgb::new(0.0, 0.0, 0.5));
+        assert_relative_eq_rgb(gradient.color_at(-1.0), Rgb::new(1.0, 0.0, 0.0));
+        assert_eq!(gradient.transform([1.0, 1.0]), [12.0, 1.0]);
+
+        let identity = Gradient {
+            matrix: IDENTITY,
+            ..gradient
+        };
+        assert_eq!(identity.transform([1.0, 1.0]), [1.0, 1.0]);
+    }
+}
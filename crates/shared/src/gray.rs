@@ -1,3 +1,6 @@
+use crate::Rgb;
+
+#[cfg(test)]
 mod test_utils;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -19,14 +22,18 @@ impl Gray {
         *self = c;
     }
 
+    pub fn gray(&self) -> f32 {
+        self.0
+    }
+
     fn clamp(v: f32) -> f32 {
-        if v > 255.0 {
-            255.0
-        } else if v < 0.0 {
-            0.0
-        } else {
-            v
-        }
+        v.clamp(0.0, 1.0)
+    }
+}
+
+impl From<Gray> for Rgb {
+    fn from(g: Gray) -> Self {
+        Rgb::new(g.0, g.0, g.0)
     }
 }
 
@@ -34,10 +41,18 @@ impl Gray {
 mod test {
     use super::test_utils::assert_relative_eq_gray;
     use super::Gray;
+    use crate::Rgb;
 
     #[test]
     fn min_max() {
-        let rgb = Gray::new(256.0);
-        assert_relative_eq_gray(rgb, Gray::new(255.0))
+        let rgb = Gray::new(1.5);
+        assert_relative_eq_gray(rgb, Gray::new(1.0));
+        let rgb = Gray::new(-1.0);
+        assert_relative_eq_gray(rgb, Gray::new(0.0))
+    }
+
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Rgb::from(Gray::new(0.25)), Rgb::new(0.25, 0.25, 0.25));
     }
 }
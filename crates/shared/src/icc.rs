@@ -0,0 +1,516 @@
+//! Just enough of an ICC color management module to render `/ICCBased`
+//! colors: profiles are parsed into a device to profile connection space
+//! (PCS) transform, which is then converted to sRGB.
+//!
+//! Supported are the monochrome and three-component matrix/TRC profiles and
+//! `lut8`/`lut16` device to PCS tables, which covers the vast majority of
+//! embedded profiles. Anything else is reported as unsupported so that the
+//! caller can fall back to the alternate color space.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use thiserror::Error;
+
+use crate::cie::{lab_to_xyz, multiply, xyz_to_srgb, D50};
+use crate::Rgb;
+
+/// Size of the profile header, before the tag table
+const HEADER_SIZE: usize = 128;
+
+#[derive(Error, Debug)]
+pub enum IccError {
+    #[error("ICC profile is truncated")]
+    Truncated,
+    #[error("Data is not an ICC profile")]
+    InvalidSignature,
+    #[error("Unsupported ICC profile: {0}")]
+    Unsupported(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pcs {
+    Xyz,
+    Lab,
+}
+
+/// A tone reproduction curve
+#[derive(Debug, Clone, PartialEq)]
+enum Curve {
+    Gamma(f32),
+    Table(Vec<f32>),
+    Parametric(u16, [f32; 7]),
+}
+
+impl Curve {
+    fn parse(data: &[u8]) -> Result<Curve> {
+        match data.get(..4).ok_or(IccError::Truncated)? {
+            b"curv" => {
+                let count = read_u32(data, 8)? as usize;
+                match count {
+                    0 => Ok(Curve::Gamma(1.0)),
+                    1 => Ok(Curve::Gamma(read_u16(data, 12)? as f32 / 256.0)),
+                    _ => Ok(Curve::Table(read_u16_table(data, 12, count)?)),
+                }
+            }
+            b"para" => {
+                let kind = read_u16(data, 8)?;
+                let count = match kind {
+                    0 => 1,
+                    1 => 3,
+                    2 => 4,
+                    3 => 5,
+                    4 => 7,
+                    _ => {
+                        return Err(
+                            IccError::Unsupported(format!("parametric curve {}", kind)).into()
+                        )
+                    }
+                };
+                let mut params = [0.0; 7];
+                for (i, param) in params.iter_mut().enumerate().take(count) {
+                    *param = read_s15_fixed16(data, 12 + 4 * i)?;
+                }
+                Ok(Curve::Parametric(kind, params))
+            }
+            other => Err(IccError::Unsupported(format!(
+                "curve type {}",
+                String::from_utf8_lossy(other)
+            ))
+            .into()),
+        }
+    }
+
+    fn eval(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        match self {
+            Curve::Gamma(g) => x.powf(*g),
+            Curve::Table(table) => interpolate(table, x),
+            Curve::Parametric(kind, p) => {
+                let [g, a, b, c, d, e, f] = *p;
+                match kind {
+                    0 => x.powf(g),
+                    1 if x >= -b / a => (a * x + b).powf(g),
+                    1 => 0.0,
+                    2 if x >= -b / a => (a * x + b).powf(g) + c,
+                    2 => c,
+                    3 if x >= d => (a * x + b).powf(g),
+                    3 => c * x,
+                    _ if x >= d => (a * x + b).powf(g) + e,
+                    _ => c * x + f,
+                }
+            }
+        }
+    }
+}
+
+/// Linearly interpolates a one-dimensional lookup table at `x` in 0.0 to 1.0
+fn interpolate(table: &[f32], x: f32) -> f32 {
+    match table.len() {
+        0 => x,
+        1 => table[0],
+        len => {
+            let position = x.clamp(0.0, 1.0) * (len - 1) as f32;
+            let i = (position.floor() as usize).min(len - 2);
+            let fraction = position - i as f32;
+            table[i] + (table[i + 1] - table[i]) * fraction
+        }
+    }
+}
+
+/// A `lut8` or `lut16` table: input curves, a multidimensional color
+/// lookup table and output curves.
+#[derive(Debug, Clone, PartialEq)]
+struct Lut {
+    inputs: usize,
+    outputs: usize,
+    grid_points: usize,
+    input_tables: Vec<Vec<f32>>,
+    clut: Vec<f32>,
+    output_tables: Vec<Vec<f32>>,
+    /// `lut16` uses the legacy 16-bit PCS encoding
+    legacy_encoding: bool,
+}
+
+impl Lut {
+    fn parse(data: &[u8]) -> Result<Lut> {
+        let (bytes, legacy_encoding) = match data.get(..4).ok_or(IccError::Truncated)? {
+            b"mft1" => (1, false),
+            b"mft2" => (2, true),
+            other => {
+                return Err(IccError::Unsupported(format!(
+                    "lookup table type {}",
+                    String::from_utf8_lossy(other)
+                ))
+                .into())
+            }
+        };
+        let inputs = *data.get(8).ok_or(IccError::Truncated)? as usize;
+        let outputs = *data.get(9).ok_or(IccError::Truncated)? as usize;
+        let grid_points = *data.get(10).ok_or(IccError::Truncated)? as usize;
+        // ICC allows at most 15 channels
+        if !(1..=15).contains(&inputs) || outputs == 0 || grid_points < 2 {
+            return Err(IccError::Unsupported("lookup table size".to_owned()).into());
+        }
+        let (input_entries, output_entries, mut offset) = if bytes == 1 {
+            (256, 256, 48)
+        } else {
+            (
+                read_u16(data, 48)? as usize,
+                read_u16(data, 50)? as usize,
+                52,
+            )
+        };
+        let mut table = |count: usize| -> Result<Vec<f32>> {
+            let values = if bytes == 1 {
+                read_u8_table(data, offset, count)?
+            } else {
+                read_u16_table(data, offset, count)?
+            };
+            offset = offset.saturating_add(count.saturating_mul(bytes));
+            Ok(values)
+        };
+        let input_tables = (0..inputs)
+            .map(|_| table(input_entries))
+            .collect::<Result<_>>()?;
+        let clut_entries = grid_points
+            .checked_pow(inputs as u32)
+            .and_then(|entries| entries.checked_mul(outputs))
+            .ok_or_else(|| IccError::Unsupported("lookup table size".to_owned()))?;
+        let clut = table(clut_entries)?;
+        let output_tables = (0..outputs)
+            .map(|_| table(output_entries))
+            .collect::<Result<_>>()?;
+        Ok(Lut {
+            inputs,
+            outputs,
+            grid_points,
+            input_tables,
+            clut,
+            output_tables,
+            legacy_encoding,
+        })
+    }
+
+    fn eval(&self, input: &[f32]) -> Vec<f32> {
+        let input: Vec<f32> = self
+            .input_tables
+            .iter()
+            .zip(input)
+            .map(|(table, x)| interpolate(table, *x))
+            .collect();
+        self.interpolate_clut(&input)
+            .iter()
+            .zip(&self.output_tables)
+            .map(|(x, table)| interpolate(table, *x))
+            .collect()
+    }
+
+    /// Multilinear interpolation between the grid points surrounding `input`
+    fn interpolate_clut(&self, input: &[f32]) -> Vec<f32> {
+        let last = (self.grid_points - 1) as f32;
+        let mut lower = Vec::with_capacity(self.inputs);
+        let mut fractions = Vec::with_capacity(self.inputs);
+        let mut strides = vec![self.outputs; self.inputs];
+        for i in (0..self.inputs).rev() {
+            if i + 1 < self.inputs {
+                strides[i] = strides[i + 1] * self.grid_points;
+            }
+        }
+        for x in input {
+            let position = x.clamp(0.0, 1.0) * last;
+            let i = (position.floor() as usize).min(self.grid_points - 2);
+            lower.push(i);
+            fractions.push(position - i as f32);
+        }
+        let mut output = vec![0.0; self.outputs];
+        for corner in 0..(1 << self.inputs) {
+            let mut weight = 1.0;
+            let mut index = 0;
+            for dimension in 0..self.inputs {
+                let upper = corner & (1 << dimension) != 0;
+                let fraction = fractions[dimension];
+                weight *= if upper { fraction } else { 1.0 - fraction };
+                index += (lower[dimension] + upper as usize) * strides[dimension];
+            }
+            if weight == 0.0 {
+                continue;
+            }
+            for (o, value) in output.iter_mut().enumerate() {
+                *value += weight * self.clut[index + o];
+            }
+        }
+        output
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Transform {
+    Gray(Curve),
+    MatrixTrc {
+        curves: [Curve; 3],
+        matrix: [[f32; 3]; 3],
+    },
+    Lut(Lut),
+}
+
+/// A parsed ICC profile which converts device colors to sRGB
+#[derive(Debug, Clone, PartialEq)]
+pub struct IccProfile {
+    components: usize,
+    pcs: Pcs,
+    transform: Transform,
+}
+
+impl IccProfile {
+    pub fn parse(data: &[u8]) -> Result<IccProfile> {
+        if data.len() < HEADER_SIZE + 4 {
+            return Err(IccError::Truncated.into());
+        }
+        if &data[36..40] != b"acsp" {
+            return Err(IccError::InvalidSignature.into());
+        }
+        let components = match &data[16..20] {
+            b"GRAY" => 1,
+            b"RGB " | b"CMY " | b"Lab " | b"XYZ " => 3,
+            b"CMYK" => 4,
+            // nCLR, i.e. 2CLR to FCLR
+            [n, b'C', b'L', b'R'] => match (*n as char).to_digit(16) {
+                Some(n) if n >= 2 => n as usize,
+                _ => return Err(IccError::Unsupported("color space".to_owned()).into()),
+            },
+            other => {
+                return Err(IccError::Unsupported(format!(
+                    "color space {}",
+                    String::from_utf8_lossy(other)
+                ))
+                .into())
+            }
+        };
+        let pcs = match &data[20..24] {
+            b"XYZ " => Pcs::Xyz,
+            b"Lab " => Pcs::Lab,
+            other => {
+                return Err(IccError::Unsupported(format!(
+                    "connection space {}",
+                    String::from_utf8_lossy(other)
+                ))
+                .into())
+            }
+        };
+        let tags = read_tags(data)?;
+
+        // The colorimetric table matches the default rendering intent, with
+        // the perceptual one as the fallback that every LUT profile has
+        let lut = tags.get(b"A2B1").or_else(|| tags.get(b"A2B0"));
+        let transform = if let Some(lut) = lut {
+            let lut = Lut::parse(lut)?;
+            if lut.inputs != components || lut.outputs != 3 {
+                return Err(IccError::Unsupported("lookup table size".to_owned()).into());
+            }
+            Transform::Lut(lut)
+        } else if components == 1 {
+            let curve = tags.get(b"kTRC").ok_or(IccError::Truncated)?;
+            Transform::Gray(Curve::parse(curve)?)
+        } else if components == 3 && pcs == Pcs::Xyz {
+            let tag = |name: &[u8; 4]| tags.get(name).ok_or(IccError::Truncated);
+            let column = |name: &[u8; 4]| -> Result<[f32; 3]> {
+                let data = tag(name)?;
+                Ok([
+                    read_s15_fixed16(data, 8)?,
+                    read_s15_fixed16(data, 12)?,
+                    read_s15_fixed16(data, 16)?,
+                ])
+            };
+            let [r, g, b] = [column(b"rXYZ")?, column(b"gXYZ")?, column(b"bXYZ")?];
+            Transform::MatrixTrc {
+                curves: [
+                    Curve::parse(tag(b"rTRC")?)?,
+                    Curve::parse(tag(b"gTRC")?)?,
+                    Curve::parse(tag(b"bTRC")?)?,
+                ],
+                matrix: [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]],
+            }
+        } else {
+            return Err(IccError::Unsupported("no device to PCS transform".to_owned()).into());
+        };
+        Ok(IccProfile {
+            components,
+            pcs,
+            transform,
+        })
+    }
+
+    /// The number of color components the profile expects
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Converts a color to XYZ relative to the D50 profile connection space
+    pub fn to_xyz(&self, color: &[f32]) -> [f32; 3] {
+        match &self.transform {
+            Transform::Gray(curve) => {
+                let y = curve.eval(color.first().copied().unwrap_or(0.0));
+                [D50[0] * y, D50[1] * y, D50[2] * y]
+            }
+            Transform::MatrixTrc { curves, matrix } => {
+                let mut linear = [0.0; 3];
+                for (i, curve) in curves.iter().enumerate() {
+                    linear[i] = curve.eval(color.get(i).copied().unwrap_or(0.0));
+                }
+                multiply(matrix, linear)
+            }
+            Transform::Lut(lut) => {
+                let mut input = color.to_vec();
+                input.resize(lut.inputs, 0.0);
+                let output = lut.eval(&input);
+                let pcs = [output[0], output[1], output[2]];
+                self.decode_pcs(pcs, lut.legacy_encoding)
+            }
+        }
+    }
+
+    pub fn to_srgb(&self, color: &[f32]) -> Rgb {
+        xyz_to_srgb(self.to_xyz(color), D50)
+    }
+
+    /// Converts normalized lookup table output to XYZ
+    fn decode_pcs(&self, pcs: [f32; 3], legacy_encoding: bool) -> [f32; 3] {
+        match self.pcs {
+            Pcs::Xyz => {
+                // u1Fixed15, where 0xFFFF is just under 2.0
+                let scale = 65535.0 / 32768.0;
+                [pcs[0] * scale, pcs[1] * scale, pcs[2] * scale]
+            }
+            Pcs::Lab => {
+                // Legacy 16-bit encoding has 100.0 at 0xFF00 rather than 0xFFFF
+                let scale = if legacy_encoding {
+                    65535.0 / 65280.0
+                } else {
+                    1.0
+                };
+                let lab = [
+                    pcs[0] * scale * 100.0,
+                    pcs[1] * scale * 255.0 - 128.0,
+                    pcs[2] * scale * 255.0 - 128.0,
+                ];
+                lab_to_xyz(lab, D50)
+            }
+        }
+    }
+}
+
+fn read_tags(data: &[u8]) -> Result<HashMap<[u8; 4], &[u8]>> {
+    let count = read_u32(data, HEADER_SIZE)? as usize;
+    // Each entry takes 12 bytes, which bounds how many a profile can hold
+    let mut tags = HashMap::with_capacity(count.min(data.len() / 12));
+    for i in 0..count {
+        let entry = HEADER_SIZE + 4 + 12 * i;
+        let signature = data.get(entry..entry + 4).ok_or(IccError::Truncated)?;
+        let offset = read_u32(data, entry + 4)? as usize;
+        let size = read_u32(data, entry + 8)? as usize;
+        let tag = data
+            .get(offset..offset.saturating_add(size))
+            .ok_or(IccError::Truncated)?;
+        tags.insert(
+            [signature[0], signature[1], signature[2], signature[3]],
+            tag,
+        );
+    }
+    Ok(tags)
+}
+
+fn read_u16(data: &[u8], at: usize) -> Result<u16> {
+    let bytes = data.get(at..at + 2).ok_or(IccError::Truncated)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], at: usize) -> Result<u32> {
+    let bytes = data.get(at..at + 4).ok_or(IccError::Truncated)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_s15_fixed16(data: &[u8], at: usize) -> Result<f32> {
+    Ok(read_u32(data, at)? as i32 as f32 / 65536.0)
+}
+
+/// Reads `count` 8-bit values, normalized to 0.0 to 1.0
+fn read_u8_table(data: &[u8], at: usize, count: usize) -> Result<Vec<f32>> {
+    let bytes = data
+        .get(at..at.saturating_add(count))
+        .ok_or(IccError::Truncated)?;
+    Ok(bytes.iter().map(|b| *b as f32 / 255.0).collect())
+}
+
+/// Reads `count` 16-bit values, normalized to 0.0 to 1.0
+fn read_u16_table(data: &[u8], at: usize, count: usize) -> Result<Vec<f32>> {
+    let bytes = data
+        .get(at..at.saturating_add(count.saturating_mul(2)))
+        .ok_or(IccError::Truncated)?;
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]) as f32 / 65535.0)
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{IccProfile, Lut};
+    use crate::rgb::test_utils::assert_relative_eq_rgb_epsilon;
+    use crate::Rgb;
+
+    const SRGB: &[u8] = include_bytes!("../assets/icc/sRGB-v4.icc");
+    const GREY: &[u8] = include_bytes!("../assets/icc/sGrey-v4.icc");
+    const CMYK: &[u8] = include_bytes!("../assets/icc/CMYK-to-XYZ.icc");
+
+    #[test]
+    fn matrix_trc() {
+        let profile = IccProfile::parse(SRGB).unwrap();
+        assert_eq!(profile.components(), 3);
+        for color in [[1.0, 0.0, 0.0], [0.2, 0.5, 0.8], [1.0, 1.0, 1.0]] {
+            assert_relative_eq_rgb_epsilon(
+                profile.to_srgb(&color),
+                Rgb::new(color[0], color[1], color[2]),
+                0.01,
+            );
+        }
+    }
+
+    #[test]
+    fn gray_trc() {
+        let profile = IccProfile::parse(GREY).unwrap();
+        assert_eq!(profile.components(), 1);
+        assert_relative_eq_rgb_epsilon(profile.to_srgb(&[0.5]), Rgb::new(0.5, 0.5, 0.5), 0.01);
+        assert_relative_eq_rgb_epsilon(profile.to_srgb(&[0.0]), Rgb::new(0.0, 0.0, 0.0), 0.01);
+    }
+
+    #[test]
+    fn lut() {
+        let profile = IccProfile::parse(CMYK).unwrap();
+        assert_eq!(profile.components(), 4);
+        let white = profile.to_srgb(&[0.0, 0.0, 0.0, 0.0]);
+        assert!(white.red() > 0.9 && white.green() > 0.9 && white.blue() > 0.9);
+        let black = profile.to_srgb(&[0.0, 0.0, 0.0, 1.0]);
+        assert!(black.red() < 0.2 && black.green() < 0.2 && black.blue() < 0.2);
+        // Cyan has lots of green and blue, but little red
+        let cyan = profile.to_srgb(&[1.0, 0.0, 0.0, 0.0]);
+        assert!(cyan.red() < 0.2 && cyan.green() > 0.4 && cyan.blue() > 0.7);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(IccProfile::parse(&[]).is_err());
+        assert!(IccProfile::parse(&SRGB[..200]).is_err());
+        let mut not_a_profile = SRGB.to_vec();
+        not_a_profile[36] = b'x';
+        assert!(IccProfile::parse(&not_a_profile).is_err());
+        // More tags than could fit
+        let mut too_many_tags = SRGB.to_vec();
+        too_many_tags[128..132].copy_from_slice(&[0xff; 4]);
+        assert!(IccProfile::parse(&too_many_tags).is_err());
+        // A lookup table with more entries than can be counted
+        let mut lut = vec![0; 48];
+        lut[..4].copy_from_slice(b"mft1");
+        lut[8..11].copy_from_slice(&[15, 255, 255]);
+        assert!(Lut::parse(&lut).is_err());
+    }
+}
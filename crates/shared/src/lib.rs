@@ -1,24 +1,50 @@
 //! 1. Only put small concepts here. Nothing major
 //! 2. This crate *must* have no dependencies on other local crates in the project
 
+mod alpha;
+mod blend_mode;
+mod cie;
 mod cmyk;
 mod color;
 mod dash;
 mod dimensions;
 mod error;
+mod ext_g_state;
+mod flatness;
+mod gradient;
 mod gray;
+mod icc;
 mod line_width;
+mod mesh;
+mod miter_limit;
 mod non_stroke_color;
+mod rendering_intent;
 mod rgb;
+mod shading;
+mod special;
 mod stroke_color;
+mod text_rendering_mode;
 
+pub use alpha::Alpha;
+pub use blend_mode::BlendMode;
+pub use cie::{CalGray, CalRgb, Lab};
 pub use cmyk::Cmyk;
-pub use color::{Color, ColorError, ColorSpace, ColorSpaceWithColor};
+pub use color::{Color, ColorError, ColorSpace, ColorSpaceError, ColorSpaceWithColor};
 pub use dash::DashPattern;
 pub use dimensions::{Height, Width};
 pub use error::NumberError;
+pub use ext_g_state::{ExtGState, ObjectRef, SoftMask, SoftMaskKind, TRANSFER_SAMPLES};
+pub use flatness::Flatness;
+pub use gradient::{Gradient, GradientKind, IDENTITY};
 pub use gray::Gray;
+pub use icc::{IccError, IccProfile};
 pub use line_width::LineWidth;
+pub use mesh::{Mesh, MeshVertex};
+pub use miter_limit::MiterLimit;
 pub use non_stroke_color::NonStrokeColor;
+pub use rendering_intent::RenderingIntent;
 pub use rgb::Rgb;
+pub use shading::Shading;
+pub use special::{DeviceN, Indexed, Separation, SpecialColorSpaceError, TintTransform};
 pub use stroke_color::StrokeColor;
+pub use text_rendering_mode::TextRenderingMode;
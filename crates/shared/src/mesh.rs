@@ -0,0 +1,37 @@
+use crate::gradient::apply;
+use crate::Rgb;
+
+/// A corner of a triangle in a mesh, and its color there
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshVertex {
+    pub position: [f32; 2],
+    pub color: Rgb,
+}
+
+impl MeshVertex {
+    pub fn new(position: [f32; 2], color: Rgb) -> Self {
+        MeshVertex { position, color }
+    }
+}
+
+/// A function-based, triangle mesh or patch mesh shading, already broken
+/// down into triangles whose colors are interpolated between their corners
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mesh {
+    /// Triangles in shading space, in the order they're painted
+    pub triangles: Vec<[MeshVertex; 3]>,
+    /// Painted outside the mesh when filling with a shading pattern.
+    /// Ignored by `sh`.
+    pub background: Option<Rgb>,
+    /// `[x_min y_min x_max y_max]` limits of the painted area, in shading space
+    pub bbox: Option<[f32; 4]>,
+    /// Maps shading space to user space. Only shading patterns have one.
+    pub matrix: [f32; 6],
+}
+
+impl Mesh {
+    /// Applies `matrix` to a point in shading space
+    pub fn transform(&self, p: [f32; 2]) -> [f32; 2] {
+        apply(self.matrix, p)
+    }
+}
@@ -0,0 +1,26 @@
+use derive_more::{Deref, Display, From, Into};
+
+#[derive(Debug, Copy, Clone, Display, From, Into, PartialEq, Deref)]
+pub struct MiterLimit(f32);
+
+impl MiterLimit {
+    pub fn new(v: f32) -> Self {
+        MiterLimit(MiterLimit::clamp(v))
+    }
+
+    pub fn set(&mut self, v: MiterLimit) {
+        self.0 = *v;
+    }
+
+    /// Miter limits below 1 are meaningless: the miter is never shorter than
+    /// the line width.
+    fn clamp(v: f32) -> f32 {
+        f32::max(v, 1.0)
+    }
+}
+
+impl Default for MiterLimit {
+    fn default() -> Self {
+        MiterLimit::new(10.0)
+    }
+}
@@ -0,0 +1,38 @@
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RenderingIntent {
+    AbsoluteColorimetric,
+    #[default]
+    RelativeColorimetric,
+    Saturation,
+    Perceptual,
+}
+
+impl From<&str> for RenderingIntent {
+    /// Per the PDF spec, unrecognized intents fall back to
+    /// `RelativeColorimetric` rather than being an error.
+    fn from(input: &str) -> Self {
+        match input {
+            "AbsoluteColorimetric" => RenderingIntent::AbsoluteColorimetric,
+            "Saturation" => RenderingIntent::Saturation,
+            "Perceptual" => RenderingIntent::Perceptual,
+            _ => RenderingIntent::RelativeColorimetric,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RenderingIntent;
+
+    #[test]
+    fn from_name() {
+        assert_eq!(
+            RenderingIntent::from("Perceptual"),
+            RenderingIntent::Perceptual
+        );
+        assert_eq!(
+            RenderingIntent::from("SomethingElse"),
+            RenderingIntent::RelativeColorimetric
+        );
+    }
+}
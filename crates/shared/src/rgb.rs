@@ -1,4 +1,5 @@
-mod test_utils;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Rgb {
@@ -19,9 +20,9 @@ impl Default for Rgb {
 
 impl Rgb {
     pub fn new(r: f32, g: f32, b: f32) -> Self {
-        let red = Rgb::normalize(Rgb::clamp(r));
-        let green = Rgb::normalize(Rgb::clamp(g));
-        let blue = Rgb::normalize(Rgb::clamp(b));
+        let red = Rgb::clamp(r);
+        let green = Rgb::clamp(g);
+        let blue = Rgb::clamp(b);
         Rgb { red, green, blue }
     }
 
@@ -41,21 +42,8 @@ impl Rgb {
         self.green
     }
 
-    fn normalize(v: f32) -> f32 {
-        if v <= 1.0 {
-            return v;
-        }
-        v / 255.0
-    }
-
     fn clamp(v: f32) -> f32 {
-        if v > 255.0 {
-            255.0
-        } else if v < 0.0 {
-            0.0
-        } else {
-            v
-        }
+        v.clamp(0.0, 1.0)
     }
 }
 
@@ -66,7 +54,7 @@ mod test {
 
     #[test]
     fn min_max() {
-        let rgb = Rgb::new(256.0, -1.0, 130.0);
-        assert_relative_eq_rgb(rgb, Rgb::new(255.0, 0.0, 130.0))
+        let rgb = Rgb::new(1.5, -1.0, 0.5);
+        assert_relative_eq_rgb(rgb, Rgb::new(1.0, 0.0, 0.5))
     }
 }
@@ -5,6 +5,10 @@ pub fn assert_relative_eq_rgb(left: Rgb, right: Rgb) {
     assert_relative_eq!(AssertableRgb(left), AssertableRgb(right),)
 }
 
+pub fn assert_relative_eq_rgb_epsilon(left: Rgb, right: Rgb, epsilon: f32) {
+    assert_relative_eq!(AssertableRgb(left), AssertableRgb(right), epsilon = epsilon)
+}
+
 #[derive(PartialEq, Debug)]
 pub(crate) struct AssertableRgb(pub Rgb);
 
@@ -0,0 +1,49 @@
+use crate::{Gradient, Mesh, Rgb};
+
+/// Any shading that can be painted by `sh` or used by a shading pattern
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shading {
+    /// Axial and radial shadings
+    Gradient(Gradient),
+    /// All the other shading types
+    Mesh(Mesh),
+}
+
+impl Shading {
+    pub fn background(&self) -> Option<Rgb> {
+        match self {
+            Shading::Gradient(gradient) => gradient.background,
+            Shading::Mesh(mesh) => mesh.background,
+        }
+    }
+
+    pub fn bbox(&self) -> Option<[f32; 4]> {
+        match self {
+            Shading::Gradient(gradient) => gradient.bbox,
+            Shading::Mesh(mesh) => mesh.bbox,
+        }
+    }
+
+    pub fn matrix(&self) -> [f32; 6] {
+        match self {
+            Shading::Gradient(gradient) => gradient.matrix,
+            Shading::Mesh(mesh) => mesh.matrix,
+        }
+    }
+
+    /// The same shading, mapped to user space by `matrix` instead
+    pub fn with_matrix(self, matrix: [f32; 6]) -> Shading {
+        match self {
+            Shading::Gradient(gradient) => Shading::Gradient(Gradient { matrix, ..gradient }),
+            Shading::Mesh(mesh) => Shading::Mesh(Mesh { matrix, ..mesh }),
+        }
+    }
+
+    /// Applies the matrix to a point in shading space
+    pub fn transform(&self, p: [f32; 2]) -> [f32; 2] {
+        match self {
+            Shading::Gradient(gradient) => gradient.transform(p),
+            Shading::Mesh(mesh) => mesh.transform(p),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! The special color spaces, which describe their colors in terms of
+//! another "base" or "alternate" color space.
+
+use anyhow::Result;
+use std::fmt::Debug;
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::{Cmyk, ColorSpace, ColorSpaceWithColor};
+
+/// Converts the tint components of a `Separation` or `DeviceN` color to the
+/// components of its alternate color space. These are PDF function objects,
+/// which are implemented by the `pdf` crate.
+pub trait TintTransform: Debug + Send + Sync {
+    fn transform(&self, input: &[f32]) -> Vec<f32>;
+}
+
+#[derive(Error, Debug)]
+pub enum SpecialColorSpaceError {
+    #[error("Lookup table has {0} bytes, but {1} are needed")]
+    LookupTableTooShort(usize, usize),
+    #[error("Indexed color spaces can't be based on {0:?}")]
+    InvalidBase(ColorSpace),
+}
+
+/// An `/Indexed` color space, whose colors are indices into a table of
+/// colors in the base space
+#[derive(Debug, Clone, PartialEq)]
+pub struct Indexed {
+    pub base: ColorSpace,
+    pub hival: u8,
+    lookup: Vec<u8>,
+}
+
+impl Indexed {
+    pub fn new(base: ColorSpace, hival: u8, lookup: Vec<u8>) -> Result<Indexed> {
+        if matches!(base, ColorSpace::Indexed(_)) {
+            return Err(SpecialColorSpaceError::InvalidBase(base).into());
+        }
+        let needed = (hival as usize + 1) * base.components();
+        if lookup.len() < needed {
+            return Err(SpecialColorSpaceError::LookupTableTooShort(lookup.len(), needed).into());
+        }
+        Ok(Indexed {
+            base,
+            hival,
+            lookup,
+        })
+    }
+
+    /// The color in the base space at `index`, which is rounded and clamped
+    /// to the table like the spec asks. Each byte of the table is spread over
+    /// the range of its component.
+    pub fn lookup(&self, index: f32) -> ColorSpaceWithColor {
+        let n = self.base.components();
+        let index = (index.round().max(0.0) as usize).min(self.hival as usize);
+        let components = self.lookup[index * n..(index + 1) * n]
+            .iter()
+            .zip(self.base.component_ranges())
+            .map(|(&b, [min, max])| min + b as f32 / 255.0 * (max - min))
+            .collect();
+        self.base
+            .with_components(components)
+            .expect("lookup table has an entry for every base component")
+    }
+}
+
+/// A `/Separation` color space, a single colorant such as a spot color
+#[derive(Debug, Clone)]
+pub struct Separation {
+    pub name: Vec<u8>,
+    pub alternate: ColorSpace,
+    pub tint_transform: Arc<dyn TintTransform>,
+}
+
+impl Separation {
+    /// The tint converted to the alternate space. `All` applies the tint to
+    /// every process colorant, whatever the alternate space is.
+    pub fn convert(&self, tint: f32) -> ColorSpaceWithColor {
+        if self.name == b"All" {
+            return ColorSpaceWithColor::DeviceCMYK(Cmyk::new(tint, tint, tint, tint));
+        }
+        convert(&self.alternate, self.tint_transform.as_ref(), &[tint])
+    }
+}
+
+impl PartialEq for Separation {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.alternate == other.alternate
+            && Arc::ptr_eq(&self.tint_transform, &other.tint_transform)
+    }
+}
+
+/// A `/DeviceN` color space, several colorants such as process and spot
+/// colors together
+#[derive(Debug, Clone)]
+pub struct DeviceN {
+    pub names: Vec<Vec<u8>>,
+    pub alternate: ColorSpace,
+    pub tint_transform: Arc<dyn TintTransform>,
+}
+
+impl DeviceN {
+    /// The tints converted to the alternate space
+    pub fn convert(&self, tints: &[f32]) -> ColorSpaceWithColor {
+        convert(&self.alternate, self.tint_transform.as_ref(), tints)
+    }
+}
+
+impl PartialEq for DeviceN {
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names
+            && self.alternate == other.alternate
+            && Arc::ptr_eq(&self.tint_transform, &other.tint_transform)
+    }
+}
+
+fn convert(
+    alternate: &ColorSpace,
+    tint_transform: &dyn TintTransform,
+    tints: &[f32],
+) -> ColorSpaceWithColor {
+    let mut components = tint_transform.transform(tints);
+    // A function with the wrong number of outputs is an error in the file,
+    // but it's better to draw something than nothing
+    components.resize(alternate.components(), 0.0);
+    alternate
+        .with_components(components)
+        .expect("components were resized to fit the alternate space")
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::{DeviceN, Indexed, Separation, TintTransform};
+    use crate::{Cmyk, ColorSpace, ColorSpaceWithColor, Lab, Rgb};
+
+    /// Puts the tint in the black component of CMYK
+    #[derive(Debug)]
+    struct Black;
+
+    impl TintTransform for Black {
+        fn transform(&self, input: &[f32]) -> Vec<f32> {
+            vec![0.0, 0.0, 0.0, input[0]]
+        }
+    }
+
+    #[test]
+    fn indexed() {
+        let lookup = vec![255, 0, 0, 0, 0, 255];
+        let indexed = Indexed::new(ColorSpace::DeviceRGB, 1, lookup.clone()).unwrap();
+        assert_eq!(
+            indexed.lookup(0.0),
+            ColorSpaceWithColor::DeviceRGB(Rgb::new(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            indexed.lookup(0.7),
+            ColorSpaceWithColor::DeviceRGB(Rgb::new(0.0, 0.0, 1.0))
+        );
+        // Out of range indices are clamped to the table
+        assert_eq!(indexed.lookup(5.0), indexed.lookup(1.0));
+        assert!(Indexed::new(ColorSpace::DeviceRGB, 2, lookup).is_err());
+
+        // Bytes are spread over the ranges of L*, a* and b*
+        let lab = Lab {
+            white_point: [0.9505, 1.0, 1.089],
+            range: [-100.0, 100.0, 0.0, 255.0],
+        };
+        let indexed = Indexed::new(ColorSpace::Lab(lab), 0, vec![255, 0, 255]).unwrap();
+        assert_eq!(
+            indexed.lookup(0.0),
+            ColorSpaceWithColor::Lab(lab, [100.0, -100.0, 255.0])
+        );
+    }
+
+    #[test]
+    fn separation() {
+        let separation = Separation {
+            name: b"Black".to_vec(),
+            alternate: ColorSpace::DeviceCMYK,
+            tint_transform: Arc::new(Black),
+        };
+        assert_eq!(
+            separation.convert(0.25),
+            ColorSpaceWithColor::DeviceCMYK(Cmyk::new(0.0, 0.0, 0.0, 0.25))
+        );
+        let color = ColorSpaceWithColor::Separation(Arc::new(separation), 0.25);
+        assert!(color.paints());
+
+        // `None` paints nothing, and `All` paints every colorant
+        let none = Separation {
+            name: b"None".to_vec(),
+            alternate: ColorSpace::DeviceCMYK,
+            tint_transform: Arc::new(Black),
+        };
+        assert!(!ColorSpaceWithColor::Separation(Arc::new(none), 1.0).paints());
+        let all = Separation {
+            name: b"All".to_vec(),
+            alternate: ColorSpace::DeviceCMYK,
+            tint_transform: Arc::new(Black),
+        };
+        assert_eq!(
+            all.convert(0.5),
+            ColorSpaceWithColor::DeviceCMYK(Cmyk::new(0.5, 0.5, 0.5, 0.5))
+        );
+    }
+
+    #[test]
+    fn device_n_pads_outputs() {
+        #[derive(Debug)]
+        struct Short;
+
+        impl TintTransform for Short {
+            fn transform(&self, input: &[f32]) -> Vec<f32> {
+                input.to_vec()
+            }
+        }
+
+        let device_n = DeviceN {
+            names: vec![b"Cyan".to_vec(), b"Magenta".to_vec()],
+            alternate: ColorSpace::DeviceCMYK,
+            tint_transform: Arc::new(Short),
+        };
+        assert_eq!(
+            device_n.convert(&[0.5, 1.0]),
+            ColorSpaceWithColor::DeviceCMYK(Cmyk::new(0.5, 1.0, 0.0, 0.0))
+        );
+        let color = ColorSpaceWithColor::DeviceN(Arc::new(device_n), vec![0.5, 1.0]);
+        assert!(color.paints());
+
+        // Only colorants that are all `None` paint nothing
+        let device_n = DeviceN {
+            names: vec![b"None".to_vec(), b"None".to_vec()],
+            alternate: ColorSpace::DeviceCMYK,
+            tint_transform: Arc::new(Short),
+        };
+        assert!(!ColorSpaceWithColor::DeviceN(Arc::new(device_n), vec![0.5, 1.0]).paints());
+    }
+}
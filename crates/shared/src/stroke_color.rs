@@ -1,74 +1,128 @@
 use anyhow::Result;
 
-use crate::color::{Color, ColorError, ColorSpace};
+use crate::color::{Color, ColorSpace};
 use crate::{Cmyk, ColorSpaceWithColor, Gray, Rgb};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct StrokeColor {
     pub color_space: ColorSpace,
     pub rgb: Rgb,
     pub cmyk: Cmyk,
     pub gray: Gray,
+    /// Components for color spaces other than the device ones
+    pub components: Vec<f32>,
+    /// Name of the pattern selected by `scn`, if any
+    pub pattern: Option<Vec<u8>>,
 }
 
 impl Default for StrokeColor {
     fn default() -> Self {
         StrokeColor {
-            // TODO: Confirm these values with the PDF spec
             color_space: ColorSpace::DeviceGray,
             rgb: Rgb::new(0.0, 0.0, 0.0),
             gray: Gray::new(0.0),
-            cmyk: Cmyk::new(0.0, 0.0, 0.0, 0.0),
+            cmyk: Cmyk::new(0.0, 0.0, 0.0, 1.0),
+            components: vec![],
+            pattern: None,
         }
     }
 }
 
 impl Color for StrokeColor {
     fn set_color_space(&mut self, c: ColorSpace) {
-        self.color_space = c;
+        self.pattern = None;
+        self.components = c.initial_components();
+        // The device spaces keep their colors apart from the components
+        match c.with_components(self.components.clone()) {
+            Ok(
+                initial @ (ColorSpaceWithColor::DeviceGray(_)
+                | ColorSpaceWithColor::DeviceRGB(_)
+                | ColorSpaceWithColor::DeviceCMYK(_)),
+            ) => self.set_device_color(initial),
+            _ => self.color_space = c,
+        }
     }
 
     fn set_color(&mut self, c: Vec<f32>) -> Result<()> {
-        match c.len() {
-            0 => Err(ColorError::TooFewParams.into()),
-            1 => {
-                // TODO: see how PDF spec handles it
-                match self.color_space {
-                    ColorSpace::DeviceCMYK => unimplemented!(),
-                    ColorSpace::DeviceGray => unimplemented!(),
-                    ColorSpace::DeviceRGB => unimplemented!(),
-                }
+        match self.color_space.with_components(c.clone())? {
+            // Keeps the base space, which can't be told from the color
+            ColorSpaceWithColor::Pattern(_) => {
+                self.pattern = None;
+                self.components = c;
+            }
+            color => self.set_device_color(color),
+        }
+        Ok(())
+    }
+
+    fn set_device_color(&mut self, c: ColorSpaceWithColor) {
+        self.pattern = None;
+        match c {
+            ColorSpaceWithColor::DeviceRGB(rgb) => {
+                self.color_space = ColorSpace::DeviceRGB;
+                self.rgb = rgb;
+            }
+            ColorSpaceWithColor::DeviceGray(gray) => {
+                self.color_space = ColorSpace::DeviceGray;
+                self.gray = gray;
+            }
+            ColorSpaceWithColor::DeviceCMYK(cmyk) => {
+                self.color_space = ColorSpace::DeviceCMYK;
+                self.cmyk = cmyk;
+            }
+            ColorSpaceWithColor::CalGray(cal, a) => {
+                self.color_space = ColorSpace::CalGray(cal);
+                self.components = vec![a];
             }
-            3 => {
-                // see how PDF spec handles it
-                match self.color_space {
-                    ColorSpace::DeviceCMYK => unimplemented!(),
-                    ColorSpace::DeviceGray => unimplemented!(),
-                    ColorSpace::DeviceRGB => {
-                        use std::convert::TryInto;
-                        let [r, g, b]: [f32; 3] = c.try_into().unwrap();
-                        self.rgb = Rgb::new(r, g, b);
-                        Ok(())
-                    }
-                }
+            ColorSpaceWithColor::CalRGB(cal, abc) => {
+                self.color_space = ColorSpace::CalRGB(cal);
+                self.components = abc.to_vec();
             }
-            4 => {
-                // see how PDF spec handles it
-                match self.color_space {
-                    ColorSpace::DeviceCMYK => unimplemented!(),
-                    ColorSpace::DeviceGray => unimplemented!(),
-                    ColorSpace::DeviceRGB => unimplemented!(),
-                }
+            ColorSpaceWithColor::Lab(lab, c) => {
+                self.color_space = ColorSpace::Lab(lab);
+                self.components = c.to_vec();
+            }
+            ColorSpaceWithColor::ICCBased(profile, components) => {
+                self.color_space = ColorSpace::ICCBased(profile);
+                self.components = components;
+            }
+            ColorSpaceWithColor::Indexed(indexed, index) => {
+                self.color_space = ColorSpace::Indexed(indexed);
+                self.components = vec![index];
+            }
+            ColorSpaceWithColor::Separation(separation, tint) => {
+                self.color_space = ColorSpace::Separation(separation);
+                self.components = vec![tint];
+            }
+            ColorSpaceWithColor::DeviceN(device_n, tints) => {
+                self.color_space = ColorSpace::DeviceN(device_n);
+                self.components = tints;
+            }
+            ColorSpaceWithColor::Pattern(_) => {
+                self.color_space = ColorSpace::Pattern(None);
+                self.components = vec![];
             }
-            other => Err(ColorError::TooManyParams(other).into()),
         }
     }
 
+    fn set_pattern(&mut self, name: Vec<u8>) {
+        self.pattern = Some(name);
+    }
+
     fn get_current_color(&self) -> ColorSpaceWithColor {
         match self.color_space {
             ColorSpace::DeviceCMYK => ColorSpaceWithColor::DeviceCMYK(self.cmyk),
             ColorSpace::DeviceRGB => ColorSpaceWithColor::DeviceRGB(self.rgb),
             ColorSpace::DeviceGray => ColorSpaceWithColor::DeviceGray(self.gray),
+            ColorSpace::ICCBased(ref profile) => {
+                ColorSpaceWithColor::ICCBased(profile.clone(), self.components.clone())
+            }
+            // The CIE-based spaces keep their components, and the special
+            // ones are given in their base or alternate space
+            ref other => other
+                .with_components(self.components.clone())
+                .expect("components always match the color space")
+                .to_base(),
         }
     }
 }
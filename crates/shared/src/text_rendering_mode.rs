@@ -0,0 +1,78 @@
+/// How glyphs are painted, set by `Tr`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TextRenderingMode {
+    #[default]
+    Fill,
+    Stroke,
+    FillStroke,
+    Invisible,
+    FillClip,
+    StrokeClip,
+    FillStrokeClip,
+    Clip,
+}
+
+impl TextRenderingMode {
+    /// The mode numbered `mode` by `Tr`, if it's one of the eight
+    pub fn from_number(mode: i64) -> Option<Self> {
+        match mode {
+            0 => Some(TextRenderingMode::Fill),
+            1 => Some(TextRenderingMode::Stroke),
+            2 => Some(TextRenderingMode::FillStroke),
+            3 => Some(TextRenderingMode::Invisible),
+            4 => Some(TextRenderingMode::FillClip),
+            5 => Some(TextRenderingMode::StrokeClip),
+            6 => Some(TextRenderingMode::FillStrokeClip),
+            7 => Some(TextRenderingMode::Clip),
+            _ => None,
+        }
+    }
+
+    pub fn fills(&self) -> bool {
+        matches!(
+            self,
+            TextRenderingMode::Fill
+                | TextRenderingMode::FillStroke
+                | TextRenderingMode::FillClip
+                | TextRenderingMode::FillStrokeClip
+        )
+    }
+
+    pub fn strokes(&self) -> bool {
+        matches!(
+            self,
+            TextRenderingMode::Stroke
+                | TextRenderingMode::FillStroke
+                | TextRenderingMode::StrokeClip
+                | TextRenderingMode::FillStrokeClip
+        )
+    }
+
+    /// Whether the glyphs are added to the clipping path at `ET`
+    pub fn clips(&self) -> bool {
+        matches!(
+            self,
+            TextRenderingMode::FillClip
+                | TextRenderingMode::StrokeClip
+                | TextRenderingMode::FillStrokeClip
+                | TextRenderingMode::Clip
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TextRenderingMode;
+
+    #[test]
+    fn from_number() {
+        assert_eq!(
+            TextRenderingMode::from_number(2),
+            Some(TextRenderingMode::FillStroke)
+        );
+        assert_eq!(TextRenderingMode::from_number(8), None);
+        let clip = TextRenderingMode::from_number(7).unwrap();
+        assert!(clip.clips() && !clip.fills() && !clip.strokes());
+        assert!(TextRenderingMode::StrokeClip.strokes());
+    }
+}
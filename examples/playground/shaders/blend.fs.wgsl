@@ -0,0 +1,25 @@
+// Paints with one of the blend modes other than Normal, which need the
+// color that's already on the page, or in a knockout group. Appended to
+// `blend_modes.wgsl`.
+
+struct Output {
+    [[location(0)]] out_color: vec4<f32>;
+};
+
+[[group(1), binding(0)]] var backdrop: texture_2d<f32>;
+
+[[stage(fragment)]]
+fn main(
+    [[location(0)]] v_color: vec4<f32>,
+    [[location(1)]] v_position: vec2<f32>,
+    [[location(2), interpolate(flat)]] v_clip: vec4<f32>,
+    [[location(3), interpolate(flat)]] v_blend_mode: i32,
+    [[builtin(position)]] position: vec4<f32>
+) -> Output {
+    // v_clip is the (min x, min y, max x, max y) bounds of the clipping path
+    if (v_position.x < v_clip.x || v_position.y < v_clip.y || v_position.x > v_clip.z || v_position.y > v_clip.w) {
+        discard;
+    }
+    let cb = textureLoad(backdrop, vec2<i32>(position.xy), 0);
+    return Output(composite(v_blend_mode, cb, v_color.xyz, v_color.a));
+}
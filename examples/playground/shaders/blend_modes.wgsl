@@ -0,0 +1,154 @@
+// The blend modes, numbered as in `shared::BlendMode`, whose `blend` this
+// mirrors. This is prepended to the shaders that composite.
+
+fn multiply(cb: f32, cs: f32) -> f32 {
+    return cb * cs;
+}
+
+fn screen(cb: f32, cs: f32) -> f32 {
+    return cb + cs - cb * cs;
+}
+
+fn hard_light(cb: f32, cs: f32) -> f32 {
+    if (cs <= 0.5) {
+        return multiply(cb, 2.0 * cs);
+    }
+    return screen(cb, 2.0 * cs - 1.0);
+}
+
+fn color_dodge(cb: f32, cs: f32) -> f32 {
+    if (cb <= 0.0) {
+        return 0.0;
+    }
+    if (cb >= 1.0 - cs) {
+        return 1.0;
+    }
+    return cb / (1.0 - cs);
+}
+
+fn color_burn(cb: f32, cs: f32) -> f32 {
+    if (cb >= 1.0) {
+        return 1.0;
+    }
+    if (1.0 - cb >= cs) {
+        return 0.0;
+    }
+    return 1.0 - (1.0 - cb) / cs;
+}
+
+fn soft_light(cb: f32, cs: f32) -> f32 {
+    if (cs <= 0.5) {
+        return cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb);
+    }
+    var d = sqrt(cb);
+    if (cb <= 0.25) {
+        d = ((16.0 * cb - 12.0) * cb + 4.0) * cb;
+    }
+    return cb + (2.0 * cs - 1.0) * (d - cb);
+}
+
+fn blend_component(mode: i32, cb: f32, cs: f32) -> f32 {
+    if (mode == 1) {
+        return multiply(cb, cs);
+    }
+    if (mode == 2) {
+        return screen(cb, cs);
+    }
+    if (mode == 3) {
+        return hard_light(cs, cb);
+    }
+    if (mode == 4) {
+        return min(cb, cs);
+    }
+    if (mode == 5) {
+        return max(cb, cs);
+    }
+    if (mode == 6) {
+        return color_dodge(cb, cs);
+    }
+    if (mode == 7) {
+        return color_burn(cb, cs);
+    }
+    if (mode == 8) {
+        return hard_light(cb, cs);
+    }
+    if (mode == 9) {
+        return soft_light(cb, cs);
+    }
+    if (mode == 10) {
+        return abs(cb - cs);
+    }
+    if (mode == 11) {
+        return cb + cs - 2.0 * cb * cs;
+    }
+    return cs;
+}
+
+fn lum(c: vec3<f32>) -> f32 {
+    return dot(c, vec3<f32>(0.3, 0.59, 0.11));
+}
+
+fn clip_color(c: vec3<f32>) -> vec3<f32> {
+    let l = lum(c);
+    let n = min(min(c.x, c.y), c.z);
+    let x = max(max(c.x, c.y), c.z);
+    var result = c;
+    if (n < 0.0) {
+        result = vec3<f32>(l) + (result - vec3<f32>(l)) * l / (l - n);
+    }
+    if (x > 1.0) {
+        result = vec3<f32>(l) + (result - vec3<f32>(l)) * (1.0 - l) / (x - l);
+    }
+    return result;
+}
+
+fn set_lum(c: vec3<f32>, l: f32) -> vec3<f32> {
+    return clip_color(c + vec3<f32>(l - lum(c)));
+}
+
+fn sat(c: vec3<f32>) -> f32 {
+    return max(max(c.x, c.y), c.z) - min(min(c.x, c.y), c.z);
+}
+
+fn set_sat(c: vec3<f32>, s: f32) -> vec3<f32> {
+    let n = min(min(c.x, c.y), c.z);
+    let x = max(max(c.x, c.y), c.z);
+    if (x > n) {
+        return (c - vec3<f32>(n)) * s / (x - n);
+    }
+    return vec3<f32>(0.0);
+}
+
+fn blend(mode: i32, cb: vec3<f32>, cs: vec3<f32>) -> vec3<f32> {
+    if (mode == 12) {
+        return set_lum(set_sat(cs, sat(cb)), lum(cb));
+    }
+    if (mode == 13) {
+        return set_lum(set_sat(cb, sat(cs)), lum(cb));
+    }
+    if (mode == 14) {
+        return set_lum(cs, lum(cb));
+    }
+    if (mode == 15) {
+        return set_lum(cb, lum(cs));
+    }
+    return vec3<f32>(
+        blend_component(mode, cb.x, cs.x),
+        blend_component(mode, cb.y, cs.y),
+        blend_component(mode, cb.z, cs.z)
+    );
+}
+
+// Composites a color with alpha `a_s` onto a premultiplied backdrop, giving
+// a premultiplied result. Colors are in BGR order (see `make_rgb_slice`),
+// which only matters for the luminosity of the non-separable modes.
+fn composite(mode: i32, backdrop: vec4<f32>, cs: vec3<f32>, a_s: f32) -> vec4<f32> {
+    let a_b = backdrop.w;
+    var cb = vec3<f32>(0.0);
+    if (a_b > 0.0) {
+        cb = backdrop.xyz / a_b;
+    }
+    let blended = blend(mode, cb.zyx, cs.zyx).zyx;
+    let color = (1.0 - a_s) * backdrop.xyz + a_s * ((1.0 - a_b) * cs + a_b * blended);
+    return vec4<f32>(color, a_b + a_s - a_b * a_s);
+}
@@ -0,0 +1,65 @@
+// Composites a transparency group that was rendered to its own layer onto
+// the target, through its soft mask if it has one. Appended to
+// `blend_modes.wgsl`.
+
+struct Output {
+    [[location(0)]] out_color: vec4<f32>;
+};
+
+[[block]]
+struct Params {
+    alpha: f32;
+    blend_mode: i32;
+    // 0 for no mask, 1 for an alpha mask and 2 for a luminosity mask
+    mask_kind: i32;
+    isolated: i32;
+};
+
+// A copy of the target
+[[group(0), binding(0)]] var backdrop: texture_2d<f32>;
+[[group(0), binding(1)]] var layer: texture_2d<f32>;
+[[group(0), binding(2)]] var mask: texture_2d<f32>;
+// The transfer function of the mask, sampled
+[[group(0), binding(3)]] var transfer: texture_2d<f32>;
+[[group(0), binding(4)]] var<uniform> params: Params;
+// The group rendered onto the copy of the target, if it isn't isolated
+[[group(0), binding(5)]] var over_backdrop: texture_2d<f32>;
+
+[[stage(fragment)]]
+fn main([[builtin(position)]] position: vec4<f32>) -> Output {
+    let p = vec2<i32>(position.xy);
+    let source = textureLoad(layer, p, 0);
+    var a_s = source.w * params.alpha;
+    if (params.mask_kind != 0) {
+        let m = textureLoad(mask, p, 0);
+        // Luminosity masks are drawn onto an opaque backdrop, so their
+        // colors aren't premultiplied
+        var value = m.w;
+        if (params.mask_kind == 2) {
+            value = lum(m.zyx);
+        }
+        let last = f32(textureDimensions(transfer).x - 1);
+        let index = i32(floor(clamp(value, 0.0, 1.0) * last + 0.5));
+        a_s = a_s * textureLoad(transfer, vec2<i32>(index, 0), 0).x;
+    }
+    let cb = textureLoad(backdrop, p, 0);
+    var cs = vec3<f32>(0.0);
+    if (source.w > 0.0) {
+        cs = source.xyz / source.w;
+        if (params.isolated == 0) {
+            // The color the group's elements made together with the
+            // backdrop, with the backdrop's share removed again
+            let over = textureLoad(over_backdrop, p, 0);
+            var cn = vec3<f32>(0.0);
+            if (over.w > 0.0) {
+                cn = over.xyz / over.w;
+            }
+            var c0 = vec3<f32>(0.0);
+            if (cb.w > 0.0) {
+                c0 = cb.xyz / cb.w;
+            }
+            cs = clamp(cn + (cn - c0) * (cb.w / source.w - cb.w), vec3<f32>(0.0), vec3<f32>(1.0));
+        }
+    }
+    return Output(composite(params.blend_mode, cb, cs, a_s));
+}
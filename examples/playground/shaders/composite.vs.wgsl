@@ -0,0 +1,8 @@
+// A triangle that covers the whole target, for compositing a layer onto it
+
+[[stage(vertex)]]
+fn main([[builtin(vertex_index)]] index: u32) -> [[builtin(position)]] vec4<f32> {
+    let x = f32(i32(index & 1u) * 4 - 1);
+    let y = f32(i32(index >> 1u) * 4 - 1);
+    return vec4<f32>(x, y, 0.0, 1.0);
+}
@@ -3,6 +3,14 @@ struct Output {
 };
 
 [[stage(fragment)]]
-fn main([[location(0)]] v_color: vec4<f32>) -> Output {
+fn main(
+    [[location(0)]] v_color: vec4<f32>,
+    [[location(1)]] v_position: vec2<f32>,
+    [[location(2), interpolate(flat)]] v_clip: vec4<f32>
+) -> Output {
+    // v_clip is the (min x, min y, max x, max y) bounds of the clipping path
+    if (v_position.x < v_clip.x || v_position.y < v_clip.y || v_position.x > v_clip.z || v_position.y > v_clip.w) {
+        discard;
+    }
     return Output(v_color);
 }
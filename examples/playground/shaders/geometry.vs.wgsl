@@ -12,13 +12,14 @@ struct Primitive {
     width: f32;
     angle: f32;
     scale: f32;
-    pad1: i32;
+    blend_mode: i32;
     pad2: i32;
+    clip: vec4<f32>;
 };
 
 [[block]]
 struct Primitives {
-    primitives: [[stride(48)]] array<Primitive, 256>;
+    primitives: [[stride(64)]] array<Primitive, 256>;
 };
 
 [[group(0), binding(0)]] var<uniform> globals: Globals;
@@ -26,6 +27,9 @@ struct Primitives {
 
 struct VertexOutput {
     [[location(0)]] v_color: vec4<f32>;
+    [[location(1)]] v_position: vec2<f32>;
+    [[location(2), interpolate(flat)]] v_clip: vec4<f32>;
+    [[location(3), interpolate(flat)]] v_blend_mode: i32;
     [[builtin(position)]] position: vec4<f32>;
 };
 
@@ -34,6 +38,7 @@ fn main(
     [[location(0)]] a_position: vec2<f32>,
     [[location(1)]] a_normal: vec2<f32>,
     [[location(2)]] a_prim_id: u32,
+    [[location(3)]] a_color: vec4<f32>,
     [[builtin(instance_index)]] instance_idx: u32
 ) -> VertexOutput {
     var prim: Primitive = u_primitives.primitives[a_prim_id + instance_idx];
@@ -52,5 +57,11 @@ fn main(
     var z = f32(prim.z_index) / 4096.0;
     var position = vec4<f32>(transformed_pos.x, transformed_pos.y, z, 1.0);
 
-    return VertexOutput(prim.color, position);
+    // Shadings give each vertex its own color, but the alpha is constant
+    var color = prim.color;
+    if (a_color.a > 0.0) {
+        color = vec4<f32>(a_color.xyz, prim.color.a);
+    }
+
+    return VertexOutput(color, world_pos, prim.clip, prim.blend_mode, position);
 }
@@ -0,0 +1,115 @@
+use lyon::math::{point, vector, Point, Transform};
+use lyon::path::{Path, PathEvent, Winding};
+use shared::{Gradient, GradientKind, Rgb};
+
+/// Number of solid bands a gradient is approximated with. Every band uses
+/// up a primitive, so this can't be very high.
+const BANDS: usize = 24;
+
+/// Approximates `gradient` with solid bands to be filled with the even-odd
+/// rule in order. `extent` is how far in output coordinates the gradient
+/// needs to reach when it's extended.
+pub fn gradient_bands(gradient: &Gradient, extent: f32) -> Vec<(Vec<PathEvent>, Rgb)> {
+    let [a, b, c, d, e, f] = gradient.matrix;
+    let transform = Transform::new(a, b, c, d, e, f);
+    // The extent in shading space
+    let scale = (a * d - b * c).abs().sqrt().max(f32::EPSILON);
+    let extent = extent / scale;
+
+    let mut bands = vec![];
+    if let Some(background) = gradient.background {
+        let mut builder = Path::builder();
+        builder.add_circle(point(0.0, 0.0), extent * 4.0, Winding::Positive);
+        bands.push((builder.build(), background));
+    }
+    match gradient.kind {
+        GradientKind::Axial { start, end } => {
+            let start = point(start[0], start[1]);
+            let axis = point(end[0], end[1]) - start;
+            let length = axis.length();
+            if length == 0.0 {
+                return vec![];
+            }
+            let normal = vector(-axis.y, axis.x) / length * extent;
+            let at = |t: f32| start + axis * t;
+            let band = |t0: f32, t1: f32| {
+                let mut builder = Path::builder();
+                builder.begin(at(t0) - normal);
+                builder.line_to(at(t1) - normal);
+                builder.line_to(at(t1) + normal);
+                builder.line_to(at(t0) + normal);
+                builder.close();
+                builder.build()
+            };
+            let beyond = extent / length;
+            if gradient.extend[0] {
+                bands.push((band(-beyond, 0.0), gradient.color_at(0.0)));
+            }
+            for i in 0..BANDS {
+                let (t0, t1) = (i as f32 / BANDS as f32, (i + 1) as f32 / BANDS as f32);
+                bands.push((band(t0, t1), gradient.color_at((t0 + t1) / 2.0)));
+            }
+            if gradient.extend[1] {
+                bands.push((band(1.0, 1.0 + beyond), gradient.color_at(1.0)));
+            }
+        }
+        GradientKind::Radial {
+            start,
+            start_radius,
+            end,
+            end_radius,
+        } => {
+            let circle = |t: f32| -> (Point, f32) {
+                let center = point(
+                    start[0] + (end[0] - start[0]) * t,
+                    start[1] + (end[1] - start[1]) * t,
+                );
+                (center, start_radius + (end_radius - start_radius) * t)
+            };
+            // The area between two circles. This is exact when one circle
+            // is inside the other, which is the usual case.
+            let ring = |inner: (Point, f32), outer: (Point, f32)| {
+                let mut builder = Path::builder();
+                builder.add_circle(inner.0, inner.1, Winding::Positive);
+                builder.add_circle(outer.0, outer.1, Winding::Positive);
+                builder.build()
+            };
+            let disk = |(center, radius): (Point, f32)| {
+                let mut builder = Path::builder();
+                builder.add_circle(center, radius, Winding::Positive);
+                builder.build()
+            };
+            // Past the end that grows the circles cover everything, and past
+            // the end that shrinks them they converge on a point
+            let (first, last) = (circle(0.0), circle(1.0));
+            let huge = |(center, _): (Point, f32)| (center, extent * 4.0);
+            if gradient.extend[0] {
+                let path = if start_radius <= end_radius {
+                    disk(first)
+                } else {
+                    ring(first, huge(first))
+                };
+                bands.push((path, gradient.color_at(0.0)));
+            }
+            if gradient.extend[1] {
+                let path = if end_radius <= start_radius {
+                    disk(last)
+                } else {
+                    ring(last, huge(last))
+                };
+                bands.push((path, gradient.color_at(1.0)));
+            }
+            for i in 0..BANDS {
+                let (t0, t1) = (i as f32 / BANDS as f32, (i + 1) as f32 / BANDS as f32);
+                bands.push((
+                    ring(circle(t0), circle(t1)),
+                    gradient.color_at((t0 + t1) / 2.0),
+                ));
+            }
+        }
+    }
+    bands
+        .into_iter()
+        .map(|(path, color)| (path.transformed(&transform).iter().collect(), color))
+        .collect()
+}
@@ -1,26 +1,27 @@
-use graphics_state::{GraphicsState, Height, Width};
+use graphics_state::{Clip, GraphicsState, Height, Width};
 use lyon::geom::Box2D;
-use lyon::lyon_tessellation::StrokeOptions;
 use lyon::math::*;
 
 use lyon::algorithms::path::math::Point;
-use lyon::path::PathEvent;
 use lyon::tessellation;
 use lyon::tessellation::geometry_builder::*;
-use lyon::tessellation::StrokeTessellator;
-use lyon::tessellation::{FillOptions, FillTessellator};
+use lyon::tessellation::FillOptions;
 use pdf::utils::read_file_bytes;
-use pdf::{Pdf as PdfDocument, StreamObject};
-use shared::DashPattern;
-use shared::{Color, ColorSpaceWithColor};
-use std::io::Write;
+use pdf::Pdf as PdfDocument;
+use shared::{ColorSpaceWithColor, Rgb};
 
 // For create_buffer_init()
 use wgpu::util::DeviceExt;
 
 use futures::executor::block_on;
+use render::Renderer;
+use scene::Scene;
 use std::num::NonZeroU32;
 
+mod gradient;
+mod render;
+mod scene;
+
 const PRIM_BUFFER_LEN: usize = 256;
 
 const OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/img/page-2.png");
@@ -43,6 +44,9 @@ struct GpuVertex {
     position: [f32; 2],
     normal: [f32; 2],
     prim_id: u32,
+    /// Overrides the color of the primitive unless fully transparent. Only
+    /// shadings vary their color within a primitive.
+    color: [f32; 4],
 }
 unsafe impl bytemuck::Pod for GpuVertex {}
 unsafe impl bytemuck::Zeroable for GpuVertex {}
@@ -56,8 +60,10 @@ struct Primitive {
     width: f32,
     angle: f32,
     scale: f32,
-    _pad1: i32,
+    /// A `shared::BlendMode`, for primitives drawn with the blend pipeline
+    blend_mode: i32,
     _pad2: i32,
+    clip: [f32; 4],
 }
 
 impl Primitive {
@@ -68,20 +74,33 @@ impl Primitive {
         width: 0.0,
         angle: 0.0,
         scale: 1.0,
-        _pad1: 0,
+        blend_mode: 0,
         _pad2: 0,
+        clip: UNCLIPPED,
     };
 }
 
-fn make_color_slice(color: ColorSpaceWithColor) -> [f32; 4] {
-    match color {
-        ColorSpaceWithColor::DeviceCMYK(_) => unimplemented!(),
-        // FIXME: annoying that the shader requires the order to be inverted...
-        ColorSpaceWithColor::DeviceRGB(c) => [c.blue(), c.green(), c.red(), 1.0],
-        ColorSpaceWithColor::DeviceGray(_) => unimplemented!(),
+const UNCLIPPED: [f32; 4] = [f32::MIN, f32::MIN, f32::MAX, f32::MAX];
+
+/// The bounds of a clipping path, outside of which the shaders discard
+/// fragments. The path itself is applied with the stencil buffer, see
+/// `Scene::clip_mask`.
+fn make_clip_rect(clip: &Clip) -> [f32; 4] {
+    match clip.bounds() {
+        Some(b) => [b.min.x, b.min.y, b.max.x, b.max.y],
+        None => UNCLIPPED,
     }
 }
 
+fn make_color_slice(color: ColorSpaceWithColor) -> [f32; 4] {
+    make_rgb_slice(color.to_rgb())
+}
+
+fn make_rgb_slice(c: Rgb) -> [f32; 4] {
+    // FIXME: annoying that the shader requires the order to be inverted...
+    [c.blue(), c.green(), c.red(), 1.0]
+}
+
 unsafe impl bytemuck::Pod for Primitive {}
 unsafe impl bytemuck::Zeroable for Primitive {}
 
@@ -109,132 +128,44 @@ fn main() {
         .document
         .get_object((11, 0))
         .expect("couldn't find the drawing instructions");
-
-    // Set to 1 to disable
-    let sample_count = 1;
+    // Named color spaces are looked up in the resources of the page
+    let page = pdf
+        .document
+        .get_pages()
+        .ok()
+        .and_then(|pages| pages.into_iter().next());
 
     let tolerance = 0.02;
 
-    let mut cpu_primitives = Vec::with_capacity(PRIM_BUFFER_LEN);
-    for _ in 0..PRIM_BUFFER_LEN {
-        cpu_primitives.push(Primitive {
-            color: [0.0, 0.0, 0.0, 1.0],
-            z_index: 0,
-            width: 0.0,
-            translate: [0.0, 0.0],
-            angle: 0.0,
-            ..Primitive::DEFAULT
-        });
-    }
-
-    let mut running_prim_id = 0;
-
-    let mut fill_geometry: VertexBuffers<GpuVertex, u16> = VertexBuffers::new();
-    let mut stroke_geometry: VertexBuffers<GpuVertex, u16> = VertexBuffers::new();
-
-    let mut fill_tess = FillTessellator::new();
-    let mut stroke_tess = StrokeTessellator::new();
+    let mut scene = Scene::new(tolerance);
 
     let draw_instructions = drawing.as_stream().unwrap().get_content().unwrap();
     let width = Width::new(DEFAULT_WINDOW_WIDTH);
     let height = Height::new(DEFAULT_WINDOW_HEIGHT);
     let mut graphics_state = GraphicsState::new(width, height);
-    for inst in draw_instructions {
-        match inst {
-            StreamObject::Text(_) => unimplemented!(),
-            StreamObject::CapStyle(c) => {
-                graphics_state.set_cap_style(c).unwrap();
-            }
-            StreamObject::MoveTo(p) => {
-                graphics_state.move_to(p).unwrap();
-            }
-            StreamObject::LineTo(p) => {
-                graphics_state.line_to(p).unwrap();
-            }
-            StreamObject::Rect(low_left, width, height) => {
-                graphics_state.rect(low_left, width, height).unwrap();
-            }
-            StreamObject::Fill => {
-                let color = graphics_state
-                    .properties
-                    .non_stroke_color
-                    .get_current_color();
-                cpu_primitives[running_prim_id].color = make_color_slice(color);
-                let paths = graphics_state.fill().unwrap();
-                fill_tess
-                    .tessellate(
-                        paths,
-                        &FillOptions::tolerance(tolerance)
-                            .with_fill_rule(tessellation::FillRule::NonZero),
-                        &mut BuffersBuilder::new(
-                            &mut fill_geometry,
-                            WithId(running_prim_id as u32),
-                        ),
-                    )
-                    .unwrap();
-                running_prim_id += 1;
-            }
-            StreamObject::Stroke(close) => {
-                let properties = graphics_state.properties();
-                let options = StrokeOptions::tolerance(tolerance)
-                    .with_line_cap(properties.line_cap)
-                    .with_line_width(*properties.line_width);
-                let color = graphics_state.properties.stroke_color.get_current_color();
-                cpu_primitives[running_prim_id].color = make_color_slice(color);
-                cpu_primitives[running_prim_id].width = (*properties.line_width) / 2.0;
-                pub trait MaybeDashed {
-                    fn maybe_dashed(self, d: &DashPattern) -> Vec<PathEvent>;
-                }
-
-                impl MaybeDashed for std::vec::IntoIter<PathEvent> {
-                    fn maybe_dashed(self, d: &DashPattern) -> Vec<PathEvent> {
-                        // TODO: implement dashing
-                        self.collect::<Vec<PathEvent>>()
-                    }
-                }
-
-                let paths = graphics_state
-                    .stroke(close)
-                    .unwrap()
-                    .into_iter()
-                    .maybe_dashed(&graphics_state.properties.dash_pattern);
-
-                stroke_tess
-                    .tessellate(
-                        paths,
-                        &options,
-                        &mut BuffersBuilder::new(
-                            &mut stroke_geometry,
-                            WithId(running_prim_id as u32),
-                        ),
-                    )
-                    .unwrap();
-                running_prim_id += 1;
-            }
-            StreamObject::LineWidth(w) => {
-                graphics_state.set_line_width(w).unwrap();
-            }
-            StreamObject::NonStrokeColor(c) => {
-                graphics_state.set_non_stroke_color(c).unwrap();
-            }
-            StreamObject::StrokeColor(c) => {
-                graphics_state.set_stroke_color(c).unwrap();
-            }
-            StreamObject::StrokeColorSpace(cs) => {
-                graphics_state.set_stroke_color_space(cs).unwrap();
-            }
-            StreamObject::NonStrokeColorSpace(cs) => {
-                graphics_state.set_non_stroke_color_space(cs).unwrap();
-            }
-            StreamObject::DashPattern(d) => {
-                graphics_state.set_dash_pattern(d).unwrap();
-            }
-        }
-    }
+    let resources = page.as_ref().map(|page| page.named_resources());
+    scene.draw(draw_instructions, resources.as_ref(), &mut graphics_state);
 
-    let fill_range = 0..(fill_geometry.indices.len() as u32);
+    let pixels = render_page(scene).expect("couldn't find a graphics adapter");
+    write_png(OUTPUT, &pixels);
+}
+
+/// Renders a scene onto a page-sized texture and reads it back, as rows of
+/// four bytes per pixel. `None` if there's no adapter to render with, not
+/// even a software one.
+fn render_page(scene: Scene) -> Option<Vec<u8>> {
+    // Number of samples for anti-aliasing. Set to 1 to disable.
+    let sample_count = 1;
 
-    let stroke_range = 0..(stroke_geometry.indices.len() as u32);
+    let Scene {
+        cpu_primitives,
+        fill_geometry,
+        stroke_geometry,
+        clip_geometry,
+        mut fill_tess,
+        draws,
+        ..
+    } = scene;
 
     let mut bg_geometry: VertexBuffers<BgPoint, u16> = VertexBuffers::new();
 
@@ -263,13 +194,15 @@ fn main() {
     // create an instance
     let instance = wgpu::Instance::new(wgpu::Backends::all());
 
-    // create an adapter
-    let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
-        power_preference: wgpu::PowerPreference::LowPower,
-        compatible_surface: None,
-        force_fallback_adapter: false,
-    }))
-    .unwrap();
+    // create an adapter, falling back to a software one without a GPU
+    let request_adapter = |force_fallback_adapter| {
+        block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            compatible_surface: None,
+            force_fallback_adapter,
+        }))
+    };
+    let adapter = request_adapter(false).or_else(|| request_adapter(true))?;
     // create a device and a queue
     let (device, queue) = block_on(adapter.request_device(
         &wgpu::DeviceDescriptor {
@@ -305,6 +238,18 @@ fn main() {
         usage: wgpu::BufferUsages::INDEX,
     });
 
+    let vbo_clip = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: None,
+        contents: bytemuck::cast_slice(&clip_geometry.vertices),
+        usage: wgpu::BufferUsages::VERTEX,
+    });
+
+    let ibo_clip = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: None,
+        contents: bytemuck::cast_slice(&clip_geometry.indices),
+        usage: wgpu::BufferUsages::INDEX,
+    });
+
     let bg_vbo = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
         label: None,
         contents: bytemuck::cast_slice(&bg_geometry.vertices),
@@ -346,6 +291,30 @@ fn main() {
         label: Some("Background vs"),
         source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/background.vs.wgsl").into()),
     });
+    let blend_fs_module = &device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+        label: Some("Blend fs"),
+        source: wgpu::ShaderSource::Wgsl(
+            concat!(
+                include_str!("../shaders/blend_modes.wgsl"),
+                include_str!("../shaders/blend.fs.wgsl")
+            )
+            .into(),
+        ),
+    });
+    let composite_vs_module = &device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+        label: Some("Composite vs"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/composite.vs.wgsl").into()),
+    });
+    let composite_fs_module = &device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+        label: Some("Composite fs"),
+        source: wgpu::ShaderSource::Wgsl(
+            concat!(
+                include_str!("../shaders/blend_modes.wgsl"),
+                include_str!("../shaders/composite.fs.wgsl")
+            )
+            .into(),
+        ),
+    });
     let bg_fs_module = &device.create_shader_module(&wgpu::ShaderModuleDescriptor {
         label: Some("Background fs"),
         source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/background.fs.wgsl").into()),
@@ -397,6 +366,69 @@ fn main() {
         label: None,
     });
 
+    // Blend modes other than Normal read what's already been painted from a
+    // copy of the target texture
+    let backdrop_bind_group_layout =
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Backdrop bind group layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+    let blend_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        bind_group_layouts: &[&bind_group_layout, &backdrop_bind_group_layout],
+        push_constant_ranges: &[],
+        label: None,
+    });
+
+    // Transparency groups are composited from a copy of the target, the
+    // group's own layer, its mask, the mask's transfer function and, unless
+    // isolated, the group rendered onto the copy
+    let layer_entry = |binding| wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Texture {
+            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+            view_dimension: wgpu::TextureViewDimension::D2,
+            multisampled: false,
+        },
+        count: None,
+    };
+    let composite_bind_group_layout =
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Composite bind group layout"),
+            entries: &[
+                layer_entry(0),
+                layer_entry(1),
+                layer_entry(2),
+                layer_entry(3),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                layer_entry(5),
+            ],
+        });
+    let composite_pipeline_layout =
+        device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            bind_group_layouts: &[&composite_bind_group_layout],
+            push_constant_ranges: &[],
+            label: None,
+        });
+
     let render_pipeline_descriptor = wgpu::RenderPipelineDescriptor {
         label: None,
         layout: Some(&pipeline_layout),
@@ -425,6 +457,12 @@ fn main() {
                         format: wgpu::VertexFormat::Uint32,
                         shader_location: 2,
                     },
+                    // color:
+                    wgpu::VertexAttribute {
+                        offset: 20,
+                        format: wgpu::VertexFormat::Float32x4,
+                        shader_location: 3,
+                    },
                 ],
             }],
         },
@@ -433,7 +471,21 @@ fn main() {
             entry_point: "main",
             targets: &[wgpu::ColorTargetState {
                 format: wgpu::TextureFormat::Bgra8Unorm,
-                blend: None,
+                // The Normal blend mode, which is regular alpha blending.
+                // Targets hold premultiplied colors, so that groups can be
+                // rendered onto transparent layers.
+                blend: Some(wgpu::BlendState {
+                    color: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::SrcAlpha,
+                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    alpha: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::One,
+                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                }),
                 write_mask: wgpu::ColorWrites::ALL,
             }],
         }),
@@ -446,7 +498,13 @@ fn main() {
             clamp_depth: false,
             conservative: false,
         },
-        depth_stencil: None,
+        // Paints only where the stencil buffer counts every path of the clip,
+        // see `stencil_state`
+        depth_stencil: Some(stencil_state(
+            wgpu::CompareFunction::Equal,
+            0,
+            wgpu::StencilOperation::Keep,
+        )),
         multisample: wgpu::MultisampleState {
             count: sample_count,
             mask: !0,
@@ -456,6 +514,78 @@ fn main() {
 
     let render_pipeline = device.create_render_pipeline(&render_pipeline_descriptor);
 
+    let blend_targets = [wgpu::ColorTargetState {
+        format: wgpu::TextureFormat::Bgra8Unorm,
+        // The shader does the compositing itself
+        blend: None,
+        write_mask: wgpu::ColorWrites::ALL,
+    }];
+    let blend_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout: Some(&blend_pipeline_layout),
+        fragment: Some(wgpu::FragmentState {
+            module: blend_fs_module,
+            entry_point: "main",
+            targets: &blend_targets,
+        }),
+        ..render_pipeline_descriptor.clone()
+    });
+
+    // The paths of a clip don't overlap themselves once tessellated, so each
+    // counts every pixel inside of it once. They're drawn again to reset the
+    // count, which is cheaper than clearing the whole stencil buffer.
+    let clip_targets = [wgpu::ColorTargetState {
+        format: wgpu::TextureFormat::Bgra8Unorm,
+        blend: None,
+        write_mask: wgpu::ColorWrites::empty(),
+    }];
+    let clip_pipeline_descriptor = wgpu::RenderPipelineDescriptor {
+        fragment: Some(wgpu::FragmentState {
+            module: fs_module,
+            entry_point: "main",
+            targets: &clip_targets,
+        }),
+        primitive: wgpu::PrimitiveState {
+            cull_mode: None,
+            ..render_pipeline_descriptor.primitive
+        },
+        depth_stencil: Some(stencil_state(
+            wgpu::CompareFunction::Always,
+            !0,
+            wgpu::StencilOperation::IncrementClamp,
+        )),
+        ..render_pipeline_descriptor.clone()
+    };
+    let clip_pipeline = device.create_render_pipeline(&clip_pipeline_descriptor);
+    let unclip_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        depth_stencil: Some(stencil_state(
+            wgpu::CompareFunction::Always,
+            !0,
+            wgpu::StencilOperation::Zero,
+        )),
+        ..clip_pipeline_descriptor.clone()
+    });
+
+    let composite_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: None,
+        layout: Some(&composite_pipeline_layout),
+        vertex: wgpu::VertexState {
+            module: composite_vs_module,
+            entry_point: "main",
+            buffers: &[],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: composite_fs_module,
+            entry_point: "main",
+            targets: &blend_targets,
+        }),
+        primitive: wgpu::PrimitiveState {
+            cull_mode: None,
+            ..render_pipeline_descriptor.primitive
+        },
+        depth_stencil: None,
+        multisample: render_pipeline_descriptor.multisample,
+    });
+
     let bg_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
         label: None,
         layout: Some(&pipeline_layout),
@@ -543,13 +673,53 @@ fn main() {
     let texture = device.create_texture(&texture_desc);
     let texture_view = texture.create_view(&Default::default());
 
+    let stencil = device.create_texture(&wgpu::TextureDescriptor {
+        format: STENCIL_FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        label: Some("Stencil"),
+        ..texture_desc
+    });
+    let stencil_view = stencil.create_view(&Default::default());
+
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: None,
+        color_attachments: &[wgpu::RenderPassColorAttachment {
+            view: &texture_view,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                store: true,
+            },
+            resolve_target: None,
+        }],
+        depth_stencil_attachment: None,
+    });
+
+    let renderer = Renderer {
+        device: &device,
+        queue: &queue,
+        size: texture_desc.size,
+        bind_group: &bind_group,
+        render_pipeline: &render_pipeline,
+        blend_pipeline: &blend_pipeline,
+        composite_pipeline: &composite_pipeline,
+        clip_pipeline: &clip_pipeline,
+        unclip_pipeline: &unclip_pipeline,
+        stencil: &stencil_view,
+        backdrop_bind_group_layout: &backdrop_bind_group_layout,
+        composite_bind_group_layout: &composite_bind_group_layout,
+        fill_buffers: (&vbo_fill, &ibo_fill),
+        stroke_buffers: (&vbo_stroke, &ibo_stroke),
+        clip_buffers: (&vbo_clip, &ibo_clip),
+    };
+    renderer.render(&mut encoder, &texture, &draws, None);
+
     {
         let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: None,
             color_attachments: &[wgpu::RenderPassColorAttachment {
                 view: &texture_view,
                 ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                    load: wgpu::LoadOp::Load,
                     store: true,
                 },
                 resolve_target: None,
@@ -557,19 +727,6 @@ fn main() {
             depth_stencil_attachment: None,
         });
 
-        pass.set_pipeline(&render_pipeline);
-        pass.set_bind_group(0, &bind_group, &[]);
-
-        pass.set_index_buffer(ibo_fill.slice(..), wgpu::IndexFormat::Uint16);
-        pass.set_vertex_buffer(0, vbo_fill.slice(..));
-        // 0..1 = red fill, 1..2 = black fill, 2..3 = black
-        pass.draw_indexed(fill_range, 0, 0..1);
-
-        pass.set_index_buffer(ibo_stroke.slice(..), wgpu::IndexFormat::Uint16);
-        pass.set_vertex_buffer(0, vbo_stroke.slice(..));
-        // 0..1 = green fill, 1..2 = red fill, 2..3 = white/clear
-        pass.draw_indexed(stroke_range, 0, 0..1);
-
         // Draw background
         pass.set_pipeline(&bg_pipeline);
         pass.set_bind_group(0, &bind_group, &[]);
@@ -622,13 +779,40 @@ fn main() {
 
     queue.submit(Some(encoder.finish()));
 
-    block_on(write_to_disk(
-        OUTPUT,
-        device,
-        buffer_dimensions,
-        output_buffer,
-    ))
-    .unwrap();
+    Some(block_on(read_pixels(
+        &device,
+        &buffer_dimensions,
+        &output_buffer,
+    )))
+}
+
+const STENCIL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24PlusStencil8;
+
+/// The stencil test and operation of a pipeline that draws geometry. Depth
+/// isn't used.
+fn stencil_state(
+    compare: wgpu::CompareFunction,
+    write_mask: u32,
+    pass_op: wgpu::StencilOperation,
+) -> wgpu::DepthStencilState {
+    let face = wgpu::StencilFaceState {
+        compare,
+        fail_op: wgpu::StencilOperation::Keep,
+        depth_fail_op: wgpu::StencilOperation::Keep,
+        pass_op,
+    };
+    wgpu::DepthStencilState {
+        format: STENCIL_FORMAT,
+        depth_write_enabled: false,
+        depth_compare: wgpu::CompareFunction::Always,
+        stencil: wgpu::StencilState {
+            front: face,
+            back: face,
+            read_mask: !0,
+            write_mask,
+        },
+        bias: wgpu::DepthBiasState::default(),
+    }
 }
 
 /// This vertex constructor forwards the positions and normals provided by the
@@ -641,6 +825,7 @@ impl FillVertexConstructor<GpuVertex> for WithId {
             position: vertex.position().to_array(),
             normal: [0.0, 0.0],
             prim_id: self.0,
+            color: [0.0; 4],
         }
     }
 }
@@ -651,6 +836,7 @@ impl StrokeVertexConstructor<GpuVertex> for WithId {
             position: vertex.position_on_path().to_array(),
             normal: vertex.normal().to_array(),
             prim_id: self.0,
+            color: [0.0; 4],
         }
     }
 }
@@ -666,7 +852,6 @@ impl FillVertexConstructor<BgPoint> for Custom {
 }
 
 struct BufferDimensions {
-    width: usize,
     height: usize,
     unpadded_bytes_per_row: usize,
     padded_bytes_per_row: usize,
@@ -680,7 +865,6 @@ impl BufferDimensions {
         let padded_bytes_per_row_padding = (align - unpadded_bytes_per_row % align) % align;
         let padded_bytes_per_row = unpadded_bytes_per_row + padded_bytes_per_row_padding;
         Self {
-            width,
             height,
             unpadded_bytes_per_row,
             padded_bytes_per_row,
@@ -688,12 +872,12 @@ impl BufferDimensions {
     }
 }
 
-async fn write_to_disk<'a, 'b>(
-    filename: &str,
-    device: wgpu::Device,
-    buffer_dimensions: BufferDimensions,
-    output_buffer: wgpu::Buffer,
-) -> Result<(), ()> {
+/// Copies the rows of pixels out of the padded output buffer
+async fn read_pixels(
+    device: &wgpu::Device,
+    buffer_dimensions: &BufferDimensions,
+    output_buffer: &wgpu::Buffer,
+) -> Vec<u8> {
     // Note that we're not calling `.await` here.
     let buffer_slice = output_buffer.slice(..);
     let buffer_future = buffer_slice.map_async(wgpu::MapMode::Read);
@@ -701,41 +885,40 @@ async fn write_to_disk<'a, 'b>(
     // In an actual application, `device.poll(...)` should
     // be called in an event loop or on another thread.
     device.poll(wgpu::Maintain::Wait);
-    // If a file system is available, write the buffer as a PNG
+    buffer_future.await.expect("couldn't map the output buffer");
+
+    let padded_buffer = buffer_slice.get_mapped_range();
+    // from the padded_buffer we keep just the unpadded bytes of each row
+    let pixels = padded_buffer
+        .chunks(buffer_dimensions.padded_bytes_per_row)
+        .flat_map(|chunk| &chunk[..buffer_dimensions.unpadded_bytes_per_row])
+        .copied()
+        .collect();
+
+    // With the current interface, we have to make sure all mapped views are
+    // dropped before we unmap the buffer.
+    drop(padded_buffer);
+    output_buffer.unmap();
+    pixels
+}
+
+fn write_png(filename: &str, pixels: &[u8]) {
+    // If a file system is available, write the pixels as a PNG
     let has_file_system_available = cfg!(not(target_arch = "wasm32"));
     if !has_file_system_available {
-        return Ok(());
+        return;
     }
 
-    if let Ok(()) = buffer_future.await {
-        let padded_buffer = buffer_slice.get_mapped_range();
-
-        let mut png_encoder = png::Encoder::new(
-            std::fs::File::create(filename).unwrap(),
-            buffer_dimensions.width as u32,
-            buffer_dimensions.height as u32,
-        );
-        png_encoder.set_depth(png::BitDepth::Eight);
-        png_encoder.set_color(png::ColorType::RGBA);
-        let mut png_writer = png_encoder
-            .write_header()
-            .unwrap()
-            .into_stream_writer_with_size(buffer_dimensions.unpadded_bytes_per_row);
-
-        // from the padded_buffer we write just the unpadded bytes into the image
-        for chunk in padded_buffer.chunks(buffer_dimensions.padded_bytes_per_row) {
-            png_writer
-                .write_all(&chunk[..buffer_dimensions.unpadded_bytes_per_row])
-                .unwrap();
-        }
-        png_writer.finish().unwrap();
-
-        // With the current interface, we have to make sure all mapped views are
-        // dropped before we unmap the buffer.
-        drop(padded_buffer);
-
-        output_buffer.unmap();
-    }
-
-    Ok(())
+    let mut png_encoder = png::Encoder::new(
+        std::fs::File::create(filename).unwrap(),
+        DEFAULT_WINDOW_WIDTH as u32,
+        DEFAULT_WINDOW_HEIGHT as u32,
+    );
+    png_encoder.set_depth(png::BitDepth::Eight);
+    png_encoder.set_color(png::ColorType::RGBA);
+    png_encoder
+        .write_header()
+        .unwrap()
+        .write_image_data(pixels)
+        .unwrap();
 }
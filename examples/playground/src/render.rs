@@ -0,0 +1,500 @@
+use shared::{BlendMode, SoftMaskKind, TRANSFER_SAMPLES};
+use wgpu::util::DeviceExt;
+
+use crate::make_rgb_slice;
+use crate::scene::{Draw, Group};
+
+/// The uniforms of `composite.fs.wgsl`
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct CompositeParams {
+    alpha: f32,
+    blend_mode: i32,
+    mask_kind: i32,
+    isolated: i32,
+}
+unsafe impl bytemuck::Pod for CompositeParams {}
+unsafe impl bytemuck::Zeroable for CompositeParams {}
+
+/// Encodes the draws of a scene. Transparency groups and soft masks are
+/// rendered to layers of their own, which are then composited. Draws are
+/// clipped by counting the paths of their clip that cover each pixel in the
+/// stencil buffer, and only painting where all of them do.
+pub struct Renderer<'a> {
+    pub device: &'a wgpu::Device,
+    pub queue: &'a wgpu::Queue,
+    pub size: wgpu::Extent3d,
+    pub bind_group: &'a wgpu::BindGroup,
+    pub render_pipeline: &'a wgpu::RenderPipeline,
+    pub blend_pipeline: &'a wgpu::RenderPipeline,
+    pub composite_pipeline: &'a wgpu::RenderPipeline,
+    /// Counts the paths of a clip into the stencil buffer
+    pub clip_pipeline: &'a wgpu::RenderPipeline,
+    /// Resets the stencil buffer where a clip was counted
+    pub unclip_pipeline: &'a wgpu::RenderPipeline,
+    pub stencil: &'a wgpu::TextureView,
+    pub backdrop_bind_group_layout: &'a wgpu::BindGroupLayout,
+    pub composite_bind_group_layout: &'a wgpu::BindGroupLayout,
+    pub fill_buffers: (&'a wgpu::Buffer, &'a wgpu::Buffer),
+    pub stroke_buffers: (&'a wgpu::Buffer, &'a wgpu::Buffer),
+    pub clip_buffers: (&'a wgpu::Buffer, &'a wgpu::Buffer),
+}
+
+impl<'a> Renderer<'a> {
+    /// Renders `draws` onto `target`, which holds premultiplied colors. In a
+    /// knockout group, everything is composited with the group's initial
+    /// backdrop instead of with what was drawn before it.
+    pub fn render(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::Texture,
+        draws: &[Draw],
+        knockout: Option<&wgpu::Texture>,
+    ) {
+        let target_view = target.create_view(&Default::default());
+        let mut backdrop = None;
+        let mut remaining = draws;
+        while let Some(first) = remaining.first() {
+            if let Draw::Group(group) = first {
+                // Painting the elements of such a group straight onto its
+                // backdrop is equivalent to compositing the group
+                if !group.isolated
+                    && !group.knockout
+                    && group.alpha == 1.0
+                    && group.blend_mode == BlendMode::Normal
+                    && group.mask.is_none()
+                {
+                    self.render(encoder, target, &group.draws, knockout);
+                } else {
+                    self.composite(encoder, target, group, knockout);
+                }
+                remaining = &remaining[1..];
+                continue;
+            }
+            // Each pass starts with a draw that may need a fresh copy of the
+            // backdrop, followed by any that don't
+            let end = remaining[1..]
+                .iter()
+                .position(|draw| match draw {
+                    Draw::Group(_) => true,
+                    Draw::Geometry { blend_mode, .. } => {
+                        knockout.is_none() && *blend_mode != BlendMode::Normal
+                    }
+                })
+                .map_or(remaining.len(), |i| i + 1);
+            let (batch, rest) = remaining.split_at(end);
+            remaining = rest;
+            let backdrop = match knockout {
+                Some(initial) => initial,
+                None => {
+                    let backdrop = backdrop.get_or_insert_with(|| self.layer());
+                    if matches!(first, Draw::Geometry { blend_mode, .. } if *blend_mode != BlendMode::Normal)
+                    {
+                        encoder.copy_texture_to_texture(
+                            target.as_image_copy(),
+                            backdrop.as_image_copy(),
+                            self.size,
+                        );
+                    }
+                    backdrop
+                }
+            };
+            let backdrop_bind_group = self.backdrop_bind_group(backdrop);
+
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[wgpu::RenderPassColorAttachment {
+                    view: &target_view,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                    resolve_target: None,
+                }],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: self.stencil,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: false,
+                    }),
+                    stencil_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(0),
+                        store: false,
+                    }),
+                }),
+            });
+            pass.set_bind_group(0, self.bind_group, &[]);
+            pass.set_bind_group(1, &backdrop_bind_group, &[]);
+            for draw in batch {
+                if let Draw::Geometry {
+                    stroke,
+                    indices,
+                    blend_mode,
+                    clip,
+                } = draw
+                {
+                    let (clip_vbo, clip_ibo) = self.clip_buffers;
+                    if let Some(clip) = clip {
+                        pass.set_pipeline(self.clip_pipeline);
+                        pass.set_index_buffer(clip_ibo.slice(..), wgpu::IndexFormat::Uint32);
+                        pass.set_vertex_buffer(0, clip_vbo.slice(..));
+                        pass.draw_indexed(clip.indices.clone(), 0, 0..1);
+                    }
+                    pass.set_stencil_reference(clip.as_ref().map_or(0, |clip| clip.paths));
+
+                    if knockout.is_none() && *blend_mode == BlendMode::Normal {
+                        pass.set_pipeline(self.render_pipeline);
+                    } else {
+                        pass.set_pipeline(self.blend_pipeline);
+                    }
+                    let (vbo, ibo) = if *stroke {
+                        self.stroke_buffers
+                    } else {
+                        self.fill_buffers
+                    };
+                    pass.set_index_buffer(ibo.slice(..), wgpu::IndexFormat::Uint32);
+                    pass.set_vertex_buffer(0, vbo.slice(..));
+                    pass.draw_indexed(indices.clone(), 0, 0..1);
+
+                    if let Some(clip) = clip {
+                        pass.set_pipeline(self.unclip_pipeline);
+                        pass.set_index_buffer(clip_ibo.slice(..), wgpu::IndexFormat::Uint32);
+                        pass.set_vertex_buffer(0, clip_vbo.slice(..));
+                        pass.draw_indexed(clip.indices.clone(), 0, 0..1);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Renders a group to a layer, along with its mask, and composites it
+    /// onto `target`. A group that isn't isolated is also rendered onto its
+    /// backdrop, which the blend modes of its elements see, and the
+    /// backdrop's contribution is then taken back out of that.
+    fn composite(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::Texture,
+        group: &Group,
+        knockout: Option<&wgpu::Texture>,
+    ) {
+        let copy;
+        let backdrop = match knockout {
+            Some(initial) => initial,
+            None => {
+                copy = self.layer();
+                encoder.copy_texture_to_texture(
+                    target.as_image_copy(),
+                    copy.as_image_copy(),
+                    self.size,
+                );
+                &copy
+            }
+        };
+
+        let layer = self.layer();
+        self.clear(encoder, &layer, wgpu::Color::TRANSPARENT);
+        let initial = if group.knockout {
+            let initial = self.layer();
+            self.clear(encoder, &initial, wgpu::Color::TRANSPARENT);
+            Some(initial)
+        } else {
+            None
+        };
+        self.render(encoder, &layer, &group.draws, initial.as_ref());
+        let over_backdrop = if group.isolated {
+            None
+        } else {
+            let over = self.layer();
+            encoder.copy_texture_to_texture(
+                backdrop.as_image_copy(),
+                over.as_image_copy(),
+                self.size,
+            );
+            let initial = if group.knockout { Some(backdrop) } else { None };
+            self.render(encoder, &over, &group.draws, initial);
+            Some(over)
+        };
+
+        let mask_layer = self.layer();
+        let (mask_kind, transfer) = match &group.mask {
+            Some(mask) => {
+                let (kind, backdrop) = match mask.kind {
+                    SoftMaskKind::Alpha => (1, wgpu::Color::TRANSPARENT),
+                    SoftMaskKind::Luminosity => {
+                        // In the same channel order as the colors of primitives
+                        let [r, g, b, a] = make_rgb_slice(mask.backdrop).map(f64::from);
+                        (2, wgpu::Color { r, g, b, a })
+                    }
+                };
+                self.clear(encoder, &mask_layer, backdrop);
+                self.render(encoder, &mask_layer, &mask.draws, None);
+                (kind, mask.transfer.clone())
+            }
+            None => (0, None),
+        };
+        let transfer = transfer.unwrap_or_else(|| {
+            (0..TRANSFER_SAMPLES)
+                .map(|i| i as f32 / (TRANSFER_SAMPLES - 1) as f32)
+                .collect()
+        });
+        let transfer = self.device.create_texture_with_data(
+            self.queue,
+            &wgpu::TextureDescriptor {
+                size: wgpu::Extent3d {
+                    width: transfer.len() as u32,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::R32Float,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                label: Some("Transfer function"),
+            },
+            bytemuck::cast_slice(&transfer),
+        );
+
+        let params = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Composite params"),
+                contents: bytemuck::cast_slice(&[CompositeParams {
+                    alpha: group.alpha,
+                    blend_mode: group.blend_mode as i32,
+                    mask_kind,
+                    isolated: group.isolated as i32,
+                }]),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        // Isolated groups don't read the layer rendered over the backdrop
+        let over_backdrop = over_backdrop.as_ref().unwrap_or(&layer);
+        let views = [backdrop, &layer, &mask_layer, &transfer, over_backdrop]
+            .map(|texture| texture.create_view(&Default::default()));
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Composite bind group"),
+            layout: self.composite_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&views[0]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&views[1]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(&views[2]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::TextureView(&views[3]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: wgpu::BindingResource::Buffer(params.as_entire_buffer_binding()),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::TextureView(&views[4]),
+                },
+            ],
+        });
+
+        let target_view = target.create_view(&Default::default());
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view: &target_view,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: true,
+                },
+                resolve_target: None,
+            }],
+            depth_stencil_attachment: None,
+        });
+        pass.set_pipeline(self.composite_pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+
+    /// A texture the size of the page that can be rendered to, copied and
+    /// read by shaders
+    fn layer(&self) -> wgpu::Texture {
+        self.device.create_texture(&wgpu::TextureDescriptor {
+            size: self.size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Bgra8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC
+                | wgpu::TextureUsages::COPY_DST
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+            label: Some("Layer"),
+        })
+    }
+
+    fn clear(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        color: wgpu::Color,
+    ) {
+        let view = texture.create_view(&Default::default());
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view: &view,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(color),
+                    store: true,
+                },
+                resolve_target: None,
+            }],
+            depth_stencil_attachment: None,
+        });
+    }
+
+    fn backdrop_bind_group(&self, backdrop: &wgpu::Texture) -> wgpu::BindGroup {
+        let view = backdrop.create_view(&Default::default());
+        self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Backdrop bind group"),
+            layout: self.backdrop_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::Scene;
+    use crate::{render_page, DEFAULT_WINDOW_HEIGHT, DEFAULT_WINDOW_WIDTH};
+    use graphics_state::{GraphicsState, Height, Width};
+    use lyon::math::vector;
+    use pdf::StreamObject;
+    use shared::{ColorSpace, Rgb};
+
+    const BACKDROP: [f32; 3] = [0.2, 0.6, 0.9];
+    const SOURCE: [f32; 3] = [0.8, 0.5, 0.3];
+
+    /// The page filled with `BACKDROP`, and a square in the middle of it
+    /// filled with `SOURCE` in the given blend mode
+    fn overlapping_squares(blend_mode: BlendMode) -> Scene {
+        let fill = |color: [f32; 3], x, y, size| {
+            vec![
+                StreamObject::NonStrokeColorSpace(ColorSpace::DeviceRGB),
+                StreamObject::NonStrokeColor(color.to_vec()),
+                StreamObject::Rect(vector(x, y), Width::new(size), Height::new(size)),
+                StreamObject::Fill,
+            ]
+        };
+        let mut scene = Scene::new(0.02);
+        let mut graphics_state = GraphicsState::new(
+            Width::new(DEFAULT_WINDOW_WIDTH),
+            Height::new(DEFAULT_WINDOW_HEIGHT),
+        );
+        let page = DEFAULT_WINDOW_WIDTH.max(DEFAULT_WINDOW_HEIGHT);
+        scene.draw(fill(BACKDROP, 0.0, 0.0, page), None, &mut graphics_state);
+        graphics_state.properties.blend_mode = blend_mode;
+        let (x, y) = (DEFAULT_WINDOW_WIDTH / 2.0, DEFAULT_WINDOW_HEIGHT / 2.0);
+        scene.draw(
+            fill(SOURCE, x - 50.0, y - 50.0, 100.0),
+            None,
+            &mut graphics_state,
+        );
+        scene
+    }
+
+    /// Moves the last draw of the scene into a group of its own
+    fn group_last(scene: &mut Scene, isolated: bool, alpha: f32, blend_mode: BlendMode) {
+        let draw = scene.draws.pop().unwrap();
+        scene.draws.push(Draw::Group(Group {
+            draws: vec![draw],
+            isolated,
+            knockout: false,
+            alpha,
+            blend_mode,
+            mask: None,
+        }));
+    }
+
+    /// The color in the middle of the rendered page
+    fn render_center(scene: Scene) -> Rgb {
+        let pixels = render_page(scene).expect("couldn't find a graphics adapter");
+        let (x, y) = (
+            DEFAULT_WINDOW_WIDTH as usize / 2,
+            DEFAULT_WINDOW_HEIGHT as usize / 2,
+        );
+        let offset = (y * DEFAULT_WINDOW_WIDTH as usize + x) * 4;
+        let [r, g, b] = [0, 1, 2].map(|i| pixels[offset + i] as f32 / 255.0);
+        Rgb::new(r, g, b)
+    }
+
+    fn assert_renders(scene: Scene, expected: Rgb) {
+        let actual = render_center(scene);
+        let channels = |c: Rgb| [c.red(), c.green(), c.blue()];
+        for (a, e) in channels(actual).iter().zip(channels(expected)) {
+            assert!(
+                (a - e).abs() <= 2.0 / 255.0,
+                "rendered {:?}, expected {:?}",
+                channels(actual),
+                channels(expected)
+            );
+        }
+    }
+
+    fn expected(blend_mode: BlendMode, alpha: f32) -> Rgb {
+        let [r, g, b] = BACKDROP;
+        let backdrop = Rgb::new(r, g, b);
+        let [r, g, b] = SOURCE;
+        blend_mode.composite(backdrop, Rgb::new(r, g, b), alpha)
+    }
+
+    #[test]
+    #[ignore = "needs a graphics adapter, run with `make test-render`"]
+    fn test_render_separable_blend_mode() {
+        let scene = overlapping_squares(BlendMode::Multiply);
+        assert_renders(scene, expected(BlendMode::Multiply, 1.0));
+    }
+
+    #[test]
+    #[ignore = "needs a graphics adapter, run with `make test-render`"]
+    fn test_render_non_separable_blend_mode() {
+        let scene = overlapping_squares(BlendMode::Hue);
+        assert_renders(scene, expected(BlendMode::Hue, 1.0));
+    }
+
+    #[test]
+    #[ignore = "needs a graphics adapter, run with `make test-render`"]
+    fn test_composite_group_with_separable_blend_mode() {
+        let mut scene = overlapping_squares(BlendMode::Normal);
+        group_last(&mut scene, true, 0.5, BlendMode::Screen);
+        assert_renders(scene, expected(BlendMode::Screen, 0.5));
+    }
+
+    #[test]
+    #[ignore = "needs a graphics adapter, run with `make test-render`"]
+    fn test_composite_group_with_non_separable_blend_mode() {
+        let mut scene = overlapping_squares(BlendMode::Normal);
+        group_last(&mut scene, true, 0.5, BlendMode::Luminosity);
+        assert_renders(scene, expected(BlendMode::Luminosity, 0.5));
+    }
+
+    #[test]
+    #[ignore = "needs a graphics adapter, run with `make test-render`"]
+    fn test_composite_non_isolated_group() {
+        // The backdrop is taken back out of the group, so it isn't blended
+        // with itself
+        let mut scene = overlapping_squares(BlendMode::Normal);
+        group_last(&mut scene, false, 0.5, BlendMode::Color);
+        assert_renders(scene, expected(BlendMode::Color, 0.5));
+    }
+}
@@ -0,0 +1,1306 @@
+use graphics_state::{dash, Clip, ClipPath, GraphicsState, Height, Properties, Width};
+use lyon::geom::Box2D;
+use lyon::lyon_tessellation::StrokeOptions;
+use lyon::math::{point, vector, Transform};
+use lyon::path::{FillRule, PathEvent};
+use lyon::tessellation;
+use lyon::tessellation::geometry_builder::*;
+use lyon::tessellation::StrokeTessellator;
+use lyon::tessellation::{FillOptions, FillTessellator};
+use pdf::{Form, PaintType, Pattern, Resources, StreamObject, Tiling};
+use shared::{
+    Alpha, BlendMode, Color, ColorSpace, ColorSpaceWithColor, Mesh, ObjectRef, Rgb, Shading,
+    SoftMask, SoftMaskKind,
+};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::Range;
+
+use crate::gradient::gradient_bands;
+use crate::{
+    make_clip_rect, make_color_slice, make_rgb_slice, GpuVertex, Primitive, WithId,
+    DEFAULT_WINDOW_HEIGHT, DEFAULT_WINDOW_WIDTH, PRIM_BUFFER_LEN,
+};
+
+/// How deeply tiling patterns may be nested in the cells of others, which
+/// protects against patterns that use themselves
+const MAX_PATTERN_DEPTH: usize = 8;
+
+/// Limit on how many times a tiling pattern's cell is repeated per fill
+const MAX_TILES: usize = 10_000;
+
+/// How deeply forms may be nested in each other, which protects against
+/// forms that use themselves
+const MAX_FORM_DEPTH: usize = 16;
+
+/// The primitive that clipping paths are tessellated with. It's never
+/// painted with, so it stays unclipped and untransformed.
+pub const CLIP_PRIM_ID: usize = PRIM_BUFFER_LEN - 1;
+
+#[derive(Clone)]
+pub enum Draw {
+    /// A range of the fill or stroke indices to draw at once
+    Geometry {
+        stroke: bool,
+        indices: Range<u32>,
+        blend_mode: BlendMode,
+        clip: Option<ClipMask>,
+    },
+    /// Draws that are composited together before being painted
+    Group(Group),
+}
+
+/// A transparency group, or the element that a soft mask applies to
+#[derive(Clone)]
+pub struct Group {
+    pub draws: Vec<Draw>,
+    pub isolated: bool,
+    pub knockout: bool,
+    /// The alpha and blend mode the group as a whole is painted with
+    pub alpha: f32,
+    pub blend_mode: BlendMode,
+    pub mask: Option<Mask>,
+}
+
+/// A soft mask, whose values come from how its group is drawn
+#[derive(Clone)]
+pub struct Mask {
+    pub kind: SoftMaskKind,
+    pub draws: Vec<Draw>,
+    /// What a luminosity mask's group is drawn onto
+    pub backdrop: Rgb,
+    /// Maps the luminosity or alpha to the mask value, if it isn't identity
+    pub transfer: Option<Vec<f32>>,
+}
+
+/// The paths of a clip, tessellated one after another. Where all of them
+/// cover the page is visible, which the renderer finds by counting them in
+/// the stencil buffer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClipMask {
+    /// A range of the clip indices
+    pub indices: Range<u32>,
+    pub paths: u32,
+}
+
+/// Everything tessellated so far, along with the primitives it uses
+pub struct Scene {
+    pub cpu_primitives: Vec<Primitive>,
+    pub running_prim_id: usize,
+    pub fill_geometry: VertexBuffers<GpuVertex, u32>,
+    pub stroke_geometry: VertexBuffers<GpuVertex, u32>,
+    /// The paths of clips, which are only drawn to the stencil buffer
+    pub clip_geometry: VertexBuffers<GpuVertex, u32>,
+    pub fill_tess: FillTessellator,
+    pub stroke_tess: StrokeTessellator,
+    pub tolerance: f32,
+    /// The order to draw geometry in, which matters for blend modes
+    pub draws: Vec<Draw>,
+    pattern_depth: usize,
+    form_depth: usize,
+    /// The draws of each soft mask's group, which are shared by everything
+    /// the mask applies to, along with the space the group is blended in
+    masks: HashMap<ObjectRef, (Vec<Draw>, Option<ColorSpace>)>,
+    /// The clip of what the operator being run paints
+    paint_clip: Option<ClipMask>,
+    /// The clip that was tessellated last, which usually applies to many
+    /// operators in a row
+    last_clip: Option<(Clip, ClipMask)>,
+}
+
+impl Scene {
+    pub fn new(tolerance: f32) -> Self {
+        let mut cpu_primitives = Vec::with_capacity(PRIM_BUFFER_LEN);
+        for _ in 0..PRIM_BUFFER_LEN {
+            cpu_primitives.push(Primitive {
+                color: [0.0, 0.0, 0.0, 1.0],
+                z_index: 0,
+                width: 0.0,
+                translate: [0.0, 0.0],
+                angle: 0.0,
+                ..Primitive::DEFAULT
+            });
+        }
+        Scene {
+            cpu_primitives,
+            running_prim_id: 0,
+            fill_geometry: VertexBuffers::new(),
+            stroke_geometry: VertexBuffers::new(),
+            clip_geometry: VertexBuffers::new(),
+            fill_tess: FillTessellator::new(),
+            stroke_tess: StrokeTessellator::new(),
+            tolerance,
+            draws: vec![],
+            pattern_depth: 0,
+            form_depth: 0,
+            masks: HashMap::new(),
+            paint_clip: None,
+            last_clip: None,
+        }
+    }
+
+    /// Tessellates the painting operators of a content stream. Names are
+    /// looked up in `resources`.
+    pub fn draw(
+        &mut self,
+        instructions: Vec<StreamObject>,
+        resources: Option<&Resources>,
+        graphics_state: &mut GraphicsState,
+    ) {
+        let tolerance = self.tolerance;
+        // How many states this content has saved, since a `Q` without a
+        // matching `q` mustn't restore one saved outside of it
+        let mut saved = 0;
+        for inst in instructions {
+            let start = (
+                self.running_prim_id,
+                self.draws.len(),
+                self.fill_geometry.indices.len(),
+                self.stroke_geometry.indices.len(),
+            );
+            let alpha = match inst {
+                StreamObject::Stroke(_) => graphics_state.properties.stroke_alpha,
+                _ => graphics_state.properties.non_stroke_alpha,
+            };
+            match inst {
+                StreamObject::BeginText => {
+                    or_skip(graphics_state.begin_text(), "BT");
+                }
+                StreamObject::EndText => {
+                    or_skip(graphics_state.end_text(), "ET");
+                }
+                StreamObject::CharSpacing(spacing) => {
+                    or_skip(graphics_state.set_char_spacing(spacing), "Tc");
+                }
+                StreamObject::WordSpacing(spacing) => {
+                    or_skip(graphics_state.set_word_spacing(spacing), "Tw");
+                }
+                StreamObject::HorizontalScaling(scaling) => {
+                    or_skip(graphics_state.set_horizontal_scaling(scaling), "Tz");
+                }
+                StreamObject::Leading(leading) => {
+                    or_skip(graphics_state.set_leading(leading), "TL");
+                }
+                StreamObject::Font(name, size) => {
+                    or_skip(graphics_state.set_font(name, size), "Tf");
+                }
+                StreamObject::TextRenderingMode(mode) => {
+                    or_skip(graphics_state.set_text_rendering_mode(mode), "Tr");
+                }
+                StreamObject::TextRise(rise) => {
+                    or_skip(graphics_state.set_text_rise(rise), "Ts");
+                }
+                StreamObject::TextMove(offset) => {
+                    or_skip(graphics_state.move_text(offset), "Td");
+                }
+                StreamObject::TextMoveSetLeading(offset) => {
+                    or_skip(graphics_state.move_text_set_leading(offset), "TD");
+                }
+                StreamObject::TextMatrix(matrix) => {
+                    or_skip(graphics_state.set_text_matrix(matrix), "Tm");
+                }
+                StreamObject::NextLine => {
+                    or_skip(graphics_state.next_line(), "T*");
+                }
+                // TODO: Draw glyphs once fonts are loaded
+                StreamObject::ShowText(_) | StreamObject::ShowTextAdjusted(_) => {}
+                StreamObject::NextLineShowText(_) => {
+                    or_skip(graphics_state.next_line(), "'");
+                }
+                StreamObject::NextLineShowTextSpaced(word_spacing, char_spacing, _) => {
+                    let result = graphics_state
+                        .set_word_spacing(word_spacing)
+                        .and_then(|()| graphics_state.set_char_spacing(char_spacing))
+                        .and_then(|()| graphics_state.next_line());
+                    or_skip(result, "\"");
+                }
+                StreamObject::CapStyle(c) => {
+                    or_skip(graphics_state.set_cap_style(c), "J");
+                }
+                StreamObject::JoinStyle(j) => {
+                    or_skip(graphics_state.set_join_style(j), "j");
+                }
+                StreamObject::MiterLimit(m) => {
+                    or_skip(graphics_state.set_miter_limit(m), "M");
+                }
+                StreamObject::Flatness(f) => {
+                    or_skip(graphics_state.set_flatness(f), "i");
+                }
+                StreamObject::RenderingIntent(r) => {
+                    or_skip(graphics_state.set_rendering_intent(r), "ri");
+                }
+                StreamObject::MoveTo(p) => {
+                    or_skip(graphics_state.move_to(p), "m");
+                }
+                StreamObject::LineTo(p) => {
+                    or_skip(graphics_state.line_to(p), "l");
+                }
+                StreamObject::Rect(low_left, width, height) => {
+                    or_skip(graphics_state.rect(low_left, width, height), "re");
+                }
+                StreamObject::Fill => {
+                    if let Some(name) = graphics_state.properties.non_stroke_color.pattern.clone() {
+                        let pattern = resolve(resources, "pattern", &name, |r| r.pattern(&name));
+                        let clip = make_clip_rect(&graphics_state.properties.clip);
+                        let fill_tolerance =
+                            graphics_state.properties.flatness.tolerance(tolerance);
+                        let color = graphics_state
+                            .properties
+                            .non_stroke_color
+                            .get_current_color();
+                        let mut area = graphics_state.properties.clip.clone();
+                        // The path is used up even if the pattern can't be found
+                        let paths = match or_skip(graphics_state.fill(), "f") {
+                            Some(paths) => paths,
+                            None => continue,
+                        };
+                        let pattern = match pattern {
+                            Some(pattern) => pattern,
+                            None => continue,
+                        };
+                        // A pattern paints the clip rectangle, narrowed to the
+                        // bounds of the filled path, and is masked to the path
+                        // itself along with the clip
+                        let clip = intersect_clip_rect(clip, &paths);
+                        area.intersect(ClipPath::new(paths, FillRule::NonZero));
+                        match pattern {
+                            Pattern::Shading(shading) => {
+                                let shading = graphics_state.place_shading(shading);
+                                self.paint_shading(&shading, clip, fill_tolerance);
+                            }
+                            Pattern::Tiling(tiling) => {
+                                self.paint_tiling(&tiling, color, clip, graphics_state);
+                            }
+                        }
+                        // After the cell, whose own painting takes its clip
+                        self.paint_clip = self.clip_mask(&area);
+                    } else {
+                        let color = graphics_state
+                            .properties
+                            .non_stroke_color
+                            .get_current_color();
+                        // The path is painted under the clip from before it
+                        let clip = graphics_state.properties.clip.clone();
+                        let fill_tolerance =
+                            graphics_state.properties.flatness.tolerance(tolerance);
+                        let paths = match or_skip(graphics_state.fill(), "f") {
+                            Some(paths) => paths,
+                            None => continue,
+                        };
+                        if !color.paints() {
+                            continue;
+                        }
+                        self.paint_clip = self.clip_mask(&clip);
+                        self.cpu_primitives[self.running_prim_id].color = make_color_slice(color);
+                        self.cpu_primitives[self.running_prim_id].clip = make_clip_rect(&clip);
+                        self.fill_tess
+                            .tessellate(
+                                paths,
+                                &FillOptions::tolerance(fill_tolerance)
+                                    .with_fill_rule(tessellation::FillRule::NonZero),
+                                &mut BuffersBuilder::new(
+                                    &mut self.fill_geometry,
+                                    WithId(self.running_prim_id as u32),
+                                ),
+                            )
+                            .unwrap();
+                        self.running_prim_id += 1;
+                    }
+                }
+                StreamObject::Stroke(close) => {
+                    let properties = graphics_state.properties();
+                    let options =
+                        StrokeOptions::tolerance(properties.flatness.tolerance(tolerance))
+                            .with_line_cap(properties.line_cap)
+                            .with_line_join(properties.line_join)
+                            .with_miter_limit(*properties.miter_limit)
+                            .with_line_width(*properties.line_width);
+                    let color = graphics_state.properties.stroke_color.get_current_color();
+                    let op = if close { "s" } else { "S" };
+                    if !color.paints() {
+                        or_skip(graphics_state.stroke(close), op);
+                        continue;
+                    }
+                    self.paint_clip = self.clip_mask(&properties.clip);
+                    let prim = &mut self.cpu_primitives[self.running_prim_id];
+                    prim.color = make_color_slice(color);
+                    prim.width = (*properties.line_width) / 2.0;
+                    prim.clip = make_clip_rect(&properties.clip);
+                    let paths = match or_skip(graphics_state.stroke(close), op) {
+                        Some(paths) => paths,
+                        None => continue,
+                    };
+                    let paths = dash(&paths, &graphics_state.properties.dash_pattern, tolerance);
+
+                    self.stroke_tess
+                        .tessellate(
+                            paths,
+                            &options,
+                            &mut BuffersBuilder::new(
+                                &mut self.stroke_geometry,
+                                WithId(self.running_prim_id as u32),
+                            ),
+                        )
+                        .unwrap();
+                    self.running_prim_id += 1;
+                }
+                StreamObject::LineWidth(w) => {
+                    or_skip(graphics_state.set_line_width(w), "w");
+                }
+                StreamObject::NonStrokeColor(c) => {
+                    or_skip(graphics_state.set_non_stroke_color(c), "sc");
+                }
+                StreamObject::StrokeColor(c) => {
+                    or_skip(graphics_state.set_stroke_color(c), "SC");
+                }
+                StreamObject::StrokeColorSpace(cs) => {
+                    or_skip(graphics_state.set_stroke_color_space(cs), "CS");
+                }
+                StreamObject::NonStrokeColorSpace(cs) => {
+                    or_skip(graphics_state.set_non_stroke_color_space(cs), "cs");
+                }
+                StreamObject::NamedStrokeColorSpace(name) => {
+                    if let Some(cs) =
+                        resolve(resources, "color space", &name, |r| r.color_space(&name))
+                    {
+                        or_skip(graphics_state.set_stroke_color_space(cs), "CS");
+                    }
+                }
+                StreamObject::NamedNonStrokeColorSpace(name) => {
+                    if let Some(cs) =
+                        resolve(resources, "color space", &name, |r| r.color_space(&name))
+                    {
+                        or_skip(graphics_state.set_non_stroke_color_space(cs), "cs");
+                    }
+                }
+                StreamObject::NonStrokeDeviceColor(c) => {
+                    or_skip(graphics_state.set_non_stroke_device_color(c), "g, rg or k");
+                }
+                StreamObject::StrokeDeviceColor(c) => {
+                    or_skip(graphics_state.set_stroke_device_color(c), "G, RG or K");
+                }
+                StreamObject::NonStrokeColorN(c, pattern) => {
+                    or_skip(graphics_state.set_non_stroke_color_n(c, pattern), "scn");
+                }
+                StreamObject::StrokeColorN(c, pattern) => {
+                    or_skip(graphics_state.set_stroke_color_n(c, pattern), "SCN");
+                }
+                StreamObject::Shade(name) => {
+                    let shade = match resolve(resources, "shading", &name, |r| r.shading(&name))
+                        .and_then(|shading| or_skip(graphics_state.shade(shading), "sh"))
+                    {
+                        Some(shade) => shade,
+                        None => continue,
+                    };
+                    self.paint_clip = self.clip_mask(&graphics_state.properties.clip);
+                    let clip = make_clip_rect(&graphics_state.properties.clip);
+                    self.paint_shading(
+                        &shade.shading,
+                        intersect_clip_rect(clip, &shade.area),
+                        graphics_state.properties.flatness.tolerance(tolerance),
+                    );
+                }
+                StreamObject::ExtGState(name) => {
+                    let ext_g_state =
+                        match resolve(resources, "ExtGState", &name, |r| r.ext_g_state(&name)) {
+                            Some(ext_g_state) => ext_g_state,
+                            None => continue,
+                        };
+                    let sets_mask = matches!(ext_g_state.soft_mask, Some(Some(_)));
+                    let set = or_skip(graphics_state.set_ext_g_state(ext_g_state), "gs");
+                    if let (Some(()), true, Some(resources)) = (set, sets_mask, resources) {
+                        self.paint_mask(&graphics_state.properties, resources);
+                    }
+                    // Nothing was painted, and the mask's group mustn't be
+                    // taken for something that was
+                    continue;
+                }
+                StreamObject::XObject(name) => {
+                    // Images aren't painted yet, so they're skipped along with
+                    // anything else that isn't a form
+                    if let Some(form) = resources.and_then(|r| r.form(&name).ok()) {
+                        // The form's content was already painted with the
+                        // alpha and blend mode in effect
+                        self.paint_form(&form, graphics_state);
+                    }
+                    continue;
+                }
+                StreamObject::DashPattern(d) => {
+                    or_skip(graphics_state.set_dash_pattern(d), "d");
+                }
+                StreamObject::Clip(rule) => {
+                    or_skip(graphics_state.clip(rule), "W");
+                }
+                StreamObject::EndPath => {
+                    or_skip(graphics_state.end_path(), "n");
+                }
+                StreamObject::SaveState => {
+                    if or_skip(graphics_state.save(), "q").is_some() {
+                        saved += 1;
+                    }
+                }
+                StreamObject::RestoreState => {
+                    if saved == 0 {
+                        log::warn!("Skipping Q: no state was saved by q");
+                    } else if or_skip(graphics_state.restore(), "Q").is_some() {
+                        saved -= 1;
+                    }
+                }
+                // Only found when extracting text, since `get_content` doesn't
+                // read `cm`
+                StreamObject::ConcatMatrix(_) => {}
+            }
+            let properties = &graphics_state.properties;
+            let mask = properties.soft_mask.clone();
+            self.finish_painting(start, *alpha, properties.blend_mode, mask);
+        }
+        // States the content left saved are restored, so that they don't
+        // leak into what's painted after it
+        for _ in 0..saved {
+            or_skip(graphics_state.restore(), "a q without a matching Q");
+        }
+    }
+
+    /// Applies the alpha, blend mode and soft mask that were in effect to
+    /// everything painted since `start`, and records the order it has to be
+    /// drawn in
+    fn finish_painting(
+        &mut self,
+        (first_prim_id, first_draw, fill_start, stroke_start): (usize, usize, usize, usize),
+        alpha: f32,
+        blend_mode: BlendMode,
+        soft_mask: Option<SoftMask>,
+    ) {
+        let clip = self.paint_clip.take();
+        // A masked element is composited on its own, with the blend mode
+        let mask = soft_mask.and_then(|soft_mask| self.mask(&soft_mask));
+        let prim_blend_mode = match mask {
+            Some(_) => BlendMode::Normal,
+            None => blend_mode,
+        };
+        for prim in &mut self.cpu_primitives[first_prim_id..self.running_prim_id] {
+            // Tiling pattern cells have their own alpha, which this compounds
+            prim.color[3] *= alpha;
+            if prim_blend_mode != BlendMode::Normal {
+                prim.blend_mode = prim_blend_mode as i32;
+            }
+        }
+        self.record_draw(false, fill_start, prim_blend_mode, clip.clone());
+        self.record_draw(true, stroke_start, prim_blend_mode, clip);
+        if let Some(mask) = mask {
+            self.group(first_draw, false, false, 1.0, blend_mode, Some(mask));
+        }
+    }
+
+    /// Tessellates the paths of a clip for the renderer to mask what's painted
+    /// with, or `None` if nothing is clipped
+    fn clip_mask(&mut self, clip: &Clip) -> Option<ClipMask> {
+        if clip.is_unbounded() {
+            return None;
+        }
+        if let Some((last, mask)) = &self.last_clip {
+            if last == clip {
+                return Some(mask.clone());
+            }
+        }
+        let start = self.clip_geometry.indices.len() as u32;
+        for path in clip.paths() {
+            self.fill_tess
+                .tessellate(
+                    path.events.iter().cloned(),
+                    &FillOptions::tolerance(self.tolerance).with_fill_rule(path.fill_rule),
+                    &mut BuffersBuilder::new(&mut self.clip_geometry, WithId(CLIP_PRIM_ID as u32)),
+                )
+                .unwrap();
+        }
+        let mask = ClipMask {
+            indices: start..self.clip_geometry.indices.len() as u32,
+            // Counted in 8 bits of stencil
+            paths: clip.paths().len().min(u8::MAX as usize) as u32,
+        };
+        self.last_clip = Some((clip.clone(), mask.clone()));
+        Some(mask)
+    }
+
+    /// Wraps the draws since `first_draw` in a group, unless there are none
+    fn group(
+        &mut self,
+        first_draw: usize,
+        isolated: bool,
+        knockout: bool,
+        alpha: f32,
+        blend_mode: BlendMode,
+        mask: Option<Mask>,
+    ) {
+        let draws = self.draws.split_off(first_draw);
+        if draws.is_empty() {
+            return;
+        }
+        self.draws.push(Draw::Group(Group {
+            draws,
+            isolated,
+            knockout,
+            alpha,
+            blend_mode,
+            mask,
+        }));
+    }
+
+    /// The soft mask that `gs` set, whose group was painted then. `None` if
+    /// its group couldn't be painted, in which case nothing is masked.
+    fn mask(&self, soft_mask: &SoftMask) -> Option<Mask> {
+        let (draws, color_space) = self.masks.get(&soft_mask.group)?;
+        let backdrop = match &soft_mask.backdrop {
+            Some(components) => backdrop_color(color_space.as_ref(), components),
+            None => Rgb::new(0.0, 0.0, 0.0),
+        };
+        Some(Mask {
+            kind: soft_mask.kind,
+            draws: draws.clone(),
+            backdrop,
+            transfer: soft_mask.transfer.clone(),
+        })
+    }
+
+    /// Paints the group of the soft mask that `gs` just set, with the graphics
+    /// state it was set in rather than the one of what it masks. This happens
+    /// before anything that it masks is painted, so its geometry is never
+    /// taken for theirs. A mask that's set again is painted again, since the
+    /// state may have changed.
+    fn paint_mask(&mut self, properties: &Properties, resources: &Resources) {
+        let soft_mask = match &properties.soft_mask {
+            Some(soft_mask) => soft_mask,
+            None => return,
+        };
+        self.masks.remove(&soft_mask.group);
+        let form = match or_skip(
+            resources.soft_mask_group(soft_mask),
+            "the group of a soft mask",
+        ) {
+            Some(form) => form,
+            None => return,
+        };
+        let mut mask_state = GraphicsState::new(
+            Width::new(DEFAULT_WINDOW_WIDTH),
+            Height::new(DEFAULT_WINDOW_HEIGHT),
+        );
+        mask_state.properties = properties.clone();
+        // The mask doesn't apply to its own content
+        mask_state.properties.soft_mask = None;
+        let first_draw = self.draws.len();
+        self.paint_form(&form, &mut mask_state);
+        let draws = self.draws.split_off(first_draw);
+        let color_space = form.group.and_then(|group| group.color_space);
+        self.masks.insert(soft_mask.group, (draws, color_space));
+    }
+
+    /// Paints a form XObject with the current graphics state. A form that's
+    /// a transparency group is composited on its own first, using the alpha,
+    /// blend mode and soft mask that are in effect.
+    fn paint_form(&mut self, form: &Form, graphics_state: &mut GraphicsState) {
+        // The innermost forms are left out, like a form that uses itself
+        // would be
+        if self.form_depth >= MAX_FORM_DEPTH {
+            return;
+        }
+        let content = match or_skip(form.content(), "the content of a form") {
+            Some(content) => content,
+            None => return,
+        };
+        let first_prim_id = self.running_prim_id;
+        let first_draw = self.draws.len();
+        let (fill_start, stroke_start) = (
+            (
+                self.fill_geometry.vertices.len(),
+                self.fill_geometry.indices.len(),
+            ),
+            (
+                self.stroke_geometry.vertices.len(),
+                self.stroke_geometry.indices.len(),
+            ),
+        );
+
+        if or_skip(graphics_state.save(), "Do").is_none() {
+            return;
+        }
+        let properties = &mut graphics_state.properties;
+        let (alpha, blend_mode, soft_mask) = (
+            *properties.non_stroke_alpha,
+            properties.blend_mode,
+            properties.soft_mask.clone(),
+        );
+        // Inside a group, these start over and apply to the group instead
+        if form.group.is_some() {
+            properties.stroke_alpha = Alpha::default();
+            properties.non_stroke_alpha = Alpha::default();
+            properties.blend_mode = BlendMode::Normal;
+            properties.soft_mask = None;
+        }
+        self.form_depth += 1;
+        self.draw(content, Some(&form.resources), graphics_state);
+        self.form_depth -= 1;
+        // Balanced, since `draw` restores what the content saved, but it
+        // fails if the content left a path unpainted
+        or_skip(graphics_state.restore(), "the end of a form");
+
+        // Maps the output coordinates of the content to the page's, through
+        // form space. The clips of the content are only narrowed to the
+        // bounding box, not transformed.
+        let [a, b, c, d, e, f] = graphics_state.place_matrix(form.matrix);
+        let to_page = Transform::new(a, b, c, d, e, f);
+        if form.matrix != shared::IDENTITY {
+            let (w, h) = (DEFAULT_WINDOW_WIDTH / 2.0, DEFAULT_WINDOW_HEIGHT / 2.0);
+            let transform = Transform::new(1.0, 0.0, 0.0, -1.0, w, h).then(&to_page);
+            let fill = split_off(&mut self.fill_geometry, fill_start);
+            append_transformed(&mut self.fill_geometry, &fill, &transform);
+            let stroke = split_off(&mut self.stroke_geometry, stroke_start);
+            append_transformed(&mut self.stroke_geometry, &stroke, &transform);
+        }
+        let [x0, y0, x1, y1] = form.bbox;
+        let bbox: Vec<PathEvent> = [point(x0, y0), point(x1, y0), point(x1, y1), point(x0, y1)]
+            .iter()
+            .map(|&p| PathEvent::Begin {
+                at: to_page.transform_point(p),
+            })
+            .collect();
+        for prim in &mut self.cpu_primitives[first_prim_id..self.running_prim_id] {
+            prim.clip = intersect_clip_rect(prim.clip, &bbox);
+        }
+
+        if let Some(group) = &form.group {
+            let mask = soft_mask.and_then(|soft_mask| self.mask(&soft_mask));
+            self.group(
+                first_draw,
+                group.isolated,
+                group.knockout,
+                alpha,
+                blend_mode,
+                mask,
+            );
+        }
+    }
+
+    fn record_draw(
+        &mut self,
+        stroke: bool,
+        start: usize,
+        blend_mode: BlendMode,
+        clip: Option<ClipMask>,
+    ) {
+        let geometry = if stroke {
+            &self.stroke_geometry
+        } else {
+            &self.fill_geometry
+        };
+        let indices = start as u32..geometry.indices.len() as u32;
+        if indices.is_empty() {
+            return;
+        }
+        match self.draws.last_mut() {
+            // Normal draws of the same geometry can be batched, since they
+            // don't read what's under them and keep their order
+            Some(Draw::Geometry {
+                stroke: last_stroke,
+                indices: last_indices,
+                blend_mode: BlendMode::Normal,
+                clip: last_clip,
+            }) if *last_stroke == stroke
+                && blend_mode == BlendMode::Normal
+                && last_indices.end == indices.start
+                && *last_clip == clip =>
+            {
+                last_indices.end = indices.end
+            }
+            _ => self.draws.push(Draw::Geometry {
+                stroke,
+                indices,
+                blend_mode,
+                clip,
+            }),
+        }
+    }
+
+    /// Fills each band of a gradient as its own primitive, since primitives
+    /// only have a single color.
+    fn fill_bands(&mut self, bands: Vec<(Vec<PathEvent>, Rgb)>, clip: [f32; 4], tolerance: f32) {
+        for (band, color) in bands {
+            // The rest of the gradient is dropped once the primitives run out
+            if self.running_prim_id >= CLIP_PRIM_ID {
+                return;
+            }
+            self.cpu_primitives[self.running_prim_id].color = make_rgb_slice(color);
+            self.cpu_primitives[self.running_prim_id].clip = clip;
+            self.fill_tess
+                .tessellate(
+                    band,
+                    &FillOptions::tolerance(tolerance)
+                        .with_fill_rule(tessellation::FillRule::EvenOdd),
+                    &mut BuffersBuilder::new(
+                        &mut self.fill_geometry,
+                        WithId(self.running_prim_id as u32),
+                    ),
+                )
+                .unwrap();
+            self.running_prim_id += 1;
+        }
+    }
+
+    /// Adds the triangles of a mesh as a single primitive, with their colors
+    /// given per vertex
+    fn fill_mesh(&mut self, mesh: &Mesh, clip: [f32; 4]) {
+        // As is the whole mesh, which uses a single primitive
+        if self.running_prim_id >= CLIP_PRIM_ID {
+            return;
+        }
+        self.cpu_primitives[self.running_prim_id].clip = clip;
+        for triangle in &mesh.triangles {
+            let [a, b, c] = triangle.map(|v| (mesh.transform(v.position), v.color));
+            // Back faces are culled, so match the winding lyon outputs
+            let area =
+                (b.0[0] - a.0[0]) * (c.0[1] - a.0[1]) - (b.0[1] - a.0[1]) * (c.0[0] - a.0[0]);
+            let corners = if area > 0.0 { [a, c, b] } else { [a, b, c] };
+            for (position, color) in corners {
+                let geometry = &mut self.fill_geometry;
+                geometry.indices.push(geometry.vertices.len() as u32);
+                geometry.vertices.push(GpuVertex {
+                    position,
+                    normal: [0.0, 0.0],
+                    prim_id: self.running_prim_id as u32,
+                    color: make_rgb_slice(color),
+                });
+            }
+        }
+        self.running_prim_id += 1;
+    }
+
+    /// Paints a shading, which has already been placed on the page
+    fn paint_shading(&mut self, shading: &Shading, clip: [f32; 4], tolerance: f32) {
+        let extent = DEFAULT_WINDOW_WIDTH + DEFAULT_WINDOW_HEIGHT;
+        match shading {
+            Shading::Gradient(gradient) => {
+                self.fill_bands(gradient_bands(gradient, extent), clip, tolerance)
+            }
+            Shading::Mesh(mesh) => {
+                if let Some(background) = mesh.background {
+                    let mut builder = lyon::path::Path::builder();
+                    builder.add_rectangle(
+                        &Box2D::new(point(-extent, -extent), point(extent, extent)),
+                        lyon::path::Winding::Positive,
+                    );
+                    let band = builder.build().iter().collect();
+                    self.fill_bands(vec![(band, background)], clip, tolerance);
+                }
+                self.fill_mesh(mesh, clip);
+            }
+        }
+    }
+
+    /// Draws the cell of a tiling pattern once, then repeats its geometry
+    /// over `clip`. Each tile is clipped to `clip` rather than to the cell.
+    fn paint_tiling(
+        &mut self,
+        tiling: &Tiling,
+        color: ColorSpaceWithColor,
+        clip: [f32; 4],
+        graphics_state: &GraphicsState,
+    ) {
+        // Too deeply nested patterns are left unpainted, the same as forms
+        if self.pattern_depth >= MAX_PATTERN_DEPTH {
+            return;
+        }
+        let first_prim_id = self.running_prim_id;
+        let first_draw = self.draws.len();
+        let (fill_start, stroke_start) = (
+            (
+                self.fill_geometry.vertices.len(),
+                self.fill_geometry.indices.len(),
+            ),
+            (
+                self.stroke_geometry.vertices.len(),
+                self.stroke_geometry.indices.len(),
+            ),
+        );
+
+        // The cell is drawn with a fresh graphics state, in pattern space
+        let mut cell_state = GraphicsState::new(
+            Width::new(DEFAULT_WINDOW_WIDTH),
+            Height::new(DEFAULT_WINDOW_HEIGHT),
+        );
+        let content = match or_skip(tiling.content(), "the content of a tiling pattern") {
+            Some(content) => content,
+            None => return,
+        };
+        self.pattern_depth += 1;
+        self.draw(content, Some(&tiling.resources), &mut cell_state);
+        self.pattern_depth -= 1;
+        // The cell's geometry is about to be replaced by the tiles
+        self.draws.truncate(first_draw);
+
+        for prim in &mut self.cpu_primitives[first_prim_id..self.running_prim_id] {
+            prim.clip = clip;
+            // Uncolored cells are stencils painted in the color from `scn`
+            if tiling.paint_type == PaintType::Uncolored {
+                prim.color = make_color_slice(color.clone());
+            }
+        }
+
+        // Maps the cell's output coordinates to the page's, through pattern space
+        let [a, b, c, d, e, f] = graphics_state.place_matrix(tiling.matrix);
+        let to_page = Transform::new(a, b, c, d, e, f);
+        let (w, h) = (DEFAULT_WINDOW_WIDTH / 2.0, DEFAULT_WINDOW_HEIGHT / 2.0);
+        let to_pattern = Transform::new(1.0, 0.0, 0.0, -1.0, w, h);
+        let from_page = match to_page.inverse() {
+            Some(inverse) => inverse,
+            None => return,
+        };
+
+        // Each cell only shows what's inside the pattern's bounding box, which
+        // is axis-aligned in the cell's output coordinates too
+        let [bx0, by0, bx1, by1] = tiling.bbox;
+        let cell_bbox =
+            Box2D::from_points([point(bx0, by0), point(bx1, by1)].map(|p| point(p.x - w, h - p.y)));
+        let primitives = &self.cpu_primitives;
+        let cell_fill = split_off(&mut self.fill_geometry, fill_start);
+        let cell_fill = clip_triangles(&cell_fill, primitives, &cell_bbox);
+        let cell_stroke = split_off(&mut self.stroke_geometry, stroke_start);
+        let cell_stroke = clip_triangles(&cell_stroke, primitives, &cell_bbox);
+
+        // The tiles whose cell overlaps the clipped area
+        let corners = [
+            point(clip[0], clip[1]),
+            point(clip[2], clip[1]),
+            point(clip[2], clip[3]),
+            point(clip[0], clip[3]),
+        ]
+        .map(|p| from_page.transform_point(p));
+        let (x_min, x_max) = corners.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+            (lo.min(p.x), hi.max(p.x))
+        });
+        let (y_min, y_max) = corners.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| {
+            (lo.min(p.y), hi.max(p.y))
+        });
+        let (x_step, y_step) = (tiling.x_step.abs(), tiling.y_step.abs());
+        let columns =
+            ((x_min - bx1) / x_step).floor() as i64..=((x_max - bx0) / x_step).ceil() as i64;
+        let rows = ((y_min - by1) / y_step).floor() as i64..=((y_max - by0) / y_step).ceil() as i64;
+        let count = (columns.end() - columns.start() + 1) * (rows.end() - rows.start() + 1);
+        // A pattern needing more tiles than that isn't painted at all, rather
+        // than being painted partially
+        if count < 0 || count as usize > MAX_TILES {
+            return;
+        }
+
+        for row in rows {
+            for column in columns.clone() {
+                let offset = vector(column as f32 * x_step, row as f32 * y_step);
+                let transform = to_pattern.then_translate(offset).then(&to_page);
+                append_transformed(&mut self.fill_geometry, &cell_fill, &transform);
+                append_transformed(&mut self.stroke_geometry, &cell_stroke, &transform);
+            }
+        }
+    }
+}
+
+/// The value of `result`, or `None` once its error has been logged. What
+/// fails is skipped, as one broken operator or object shouldn't stop the
+/// rest of the page from rendering.
+fn or_skip<T, E: Display>(result: Result<T, E>, what: &str) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(error) => {
+            log::warn!("Skipping {}: {}", what, error);
+            None
+        }
+    }
+}
+
+/// Looks up the resource that an operator names, or logs why it can't be
+/// and returns `None` for the operator to be skipped
+fn resolve<'r, 'd, 'a, T, E: Display>(
+    resources: Option<&'r Resources<'d, 'a>>,
+    kind: &str,
+    name: &[u8],
+    lookup: impl FnOnce(&'r Resources<'d, 'a>) -> Result<T, E>,
+) -> Option<T> {
+    let what = format!("{} /{}", kind, String::from_utf8_lossy(name));
+    match resources {
+        Some(resources) => or_skip(lookup(resources), &what),
+        None => or_skip(Err("there are no resources to look it up in"), &what),
+    }
+}
+
+/// The clipping rectangle shrunk to the bounds of `area`, which is how far
+/// a gradient or pattern is allowed to paint.
+fn intersect_clip_rect(clip: [f32; 4], area: &[PathEvent]) -> [f32; 4] {
+    let b = lyon::algorithms::aabb::bounding_box(area.iter().cloned());
+    [
+        clip[0].max(b.min.x),
+        clip[1].max(b.min.y),
+        clip[2].min(b.max.x),
+        clip[3].min(b.max.y),
+    ]
+}
+
+/// Converts the `/BC` of a soft mask. Without a color space for the group,
+/// the space is guessed from the number of components.
+fn backdrop_color(color_space: Option<&ColorSpace>, components: &[f32]) -> Rgb {
+    let color_space = match (color_space, components.len()) {
+        (Some(color_space), _) => color_space.clone(),
+        (None, 1) => ColorSpace::DeviceGray,
+        (None, 4) => ColorSpace::DeviceCMYK,
+        (None, _) => ColorSpace::DeviceRGB,
+    };
+    match color_space.with_components(components.to_vec()) {
+        Ok(color) => color.to_rgb(),
+        Err(_) => Rgb::new(0.0, 0.0, 0.0),
+    }
+}
+
+/// Removes the vertices and indices added since `start`
+fn split_off(
+    geometry: &mut VertexBuffers<GpuVertex, u32>,
+    (vertices, indices): (usize, usize),
+) -> VertexBuffers<GpuVertex, u32> {
+    let mut removed = VertexBuffers::new();
+    removed.vertices = geometry.vertices.split_off(vertices);
+    removed.indices = geometry
+        .indices
+        .split_off(indices)
+        .into_iter()
+        .map(|i| i - vertices as u32)
+        .collect();
+    removed
+}
+
+/// Cuts triangles down to the part inside `rect`. Vertices are first moved
+/// out by the widths of their primitives, as the shader does for strokes.
+fn clip_triangles(
+    geometry: &VertexBuffers<GpuVertex, u32>,
+    primitives: &[Primitive],
+    rect: &Box2D<f32>,
+) -> VertexBuffers<GpuVertex, u32> {
+    let placed = |v: GpuVertex| {
+        let width = primitives[v.prim_id as usize].width;
+        GpuVertex {
+            position: [
+                v.position[0] + v.normal[0] * width,
+                v.position[1] + v.normal[1] * width,
+            ],
+            normal: [0.0; 2],
+            ..v
+        }
+    };
+    // The point where an edge crosses a side, with its color interpolated
+    let lerp = |a: &GpuVertex, b: &GpuVertex, t: f32| GpuVertex {
+        position: [0, 1].map(|i| a.position[i] + (b.position[i] - a.position[i]) * t),
+        color: [0, 1, 2, 3].map(|i| a.color[i] + (b.color[i] - a.color[i]) * t),
+        ..*a
+    };
+
+    let mut clipped = VertexBuffers::new();
+    for triangle in geometry.indices.chunks_exact(3) {
+        let mut polygon: Vec<GpuVertex> = triangle
+            .iter()
+            .map(|&i| placed(geometry.vertices[i as usize]))
+            .collect();
+        // Sutherland-Hodgman, one side of the rectangle at a time
+        for (axis, bound, keep_below) in [
+            (0, rect.min.x, false),
+            (0, rect.max.x, true),
+            (1, rect.min.y, false),
+            (1, rect.max.y, true),
+        ] {
+            let inside = |v: &GpuVertex| (v.position[axis] <= bound) == keep_below;
+            let mut output: Vec<GpuVertex> = Vec::with_capacity(polygon.len() + 1);
+            // Vertices on the side would otherwise be added twice
+            let mut push = |v: GpuVertex| {
+                if output.last().map(|last| last.position) != Some(v.position) {
+                    output.push(v);
+                }
+            };
+            for (i, current) in polygon.iter().enumerate() {
+                let previous = &polygon[(i + polygon.len() - 1) % polygon.len()];
+                if inside(current) != inside(previous) {
+                    let t = (bound - previous.position[axis])
+                        / (current.position[axis] - previous.position[axis]);
+                    push(lerp(previous, current, t));
+                }
+                if inside(current) {
+                    push(*current);
+                }
+            }
+            if output.len() > 1 && output[0].position == output[output.len() - 1].position {
+                output.pop();
+            }
+            polygon = output;
+            if polygon.is_empty() {
+                break;
+            }
+        }
+        if polygon.len() < 3 {
+            continue;
+        }
+        let base = clipped.vertices.len() as u32;
+        clipped.vertices.extend(polygon.iter().copied());
+        for i in 1..polygon.len() as u32 - 1 {
+            clipped.indices.extend([base, base + i, base + i + 1]);
+        }
+    }
+    clipped
+}
+
+fn append_transformed(
+    geometry: &mut VertexBuffers<GpuVertex, u32>,
+    cell: &VertexBuffers<GpuVertex, u32>,
+    transform: &Transform,
+) {
+    let base = geometry.vertices.len() as u32;
+    geometry.vertices.extend(cell.vertices.iter().map(|v| {
+        let position = transform.transform_point(point(v.position[0], v.position[1]));
+        let normal = transform.transform_vector(vector(v.normal[0], v.normal[1]));
+        GpuVertex {
+            position: position.to_array(),
+            normal: normal.to_array(),
+            ..*v
+        }
+    }));
+    geometry
+        .indices
+        .extend(cell.indices.iter().map(|i| i + base));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::ColorSpace;
+
+    /// The page filled normally, then a square over it filled with the given
+    /// alpha and blend mode
+    fn blended_square(alpha: f32, blend_mode: BlendMode) -> Scene {
+        let fill = |x, y, size| {
+            vec![
+                StreamObject::NonStrokeColorSpace(ColorSpace::DeviceRGB),
+                StreamObject::NonStrokeColor(vec![0.2, 0.6, 0.9]),
+                StreamObject::Rect(vector(x, y), Width::new(size), Height::new(size)),
+                StreamObject::Fill,
+            ]
+        };
+        let mut scene = Scene::new(0.02);
+        let mut graphics_state = GraphicsState::new(
+            Width::new(DEFAULT_WINDOW_WIDTH),
+            Height::new(DEFAULT_WINDOW_HEIGHT),
+        );
+        scene.draw(fill(0.0, 0.0, 100.0), None, &mut graphics_state);
+        graphics_state.properties.non_stroke_alpha = Alpha::new(alpha);
+        graphics_state.properties.blend_mode = blend_mode;
+        scene.draw(fill(25.0, 25.0, 50.0), None, &mut graphics_state);
+        scene
+    }
+
+    /// The fill indices and blend mode of each draw, which are all geometry
+    fn geometry_draws(scene: &Scene) -> Vec<(Range<u32>, BlendMode)> {
+        scene
+            .draws
+            .iter()
+            .map(|draw| match draw {
+                Draw::Geometry {
+                    stroke: false,
+                    indices,
+                    blend_mode,
+                    clip: None,
+                } => (indices.clone(), *blend_mode),
+                _ => panic!("expected an unclipped fill"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_constant_alpha() {
+        let scene = blended_square(0.5, BlendMode::Normal);
+        assert_eq!(scene.running_prim_id, 2);
+        assert_eq!(scene.cpu_primitives[0].color[3], 1.0);
+        assert_eq!(scene.cpu_primitives[1].color[3], 0.5);
+        // Normal fills are drawn together, as they don't read what's under them
+        let indices = 0..scene.fill_geometry.indices.len() as u32;
+        assert_eq!(geometry_draws(&scene), [(indices, BlendMode::Normal)]);
+    }
+
+    #[test]
+    fn test_blend_mode() {
+        let scene = blended_square(1.0, BlendMode::Multiply);
+        assert_eq!(scene.cpu_primitives[0].blend_mode, BlendMode::Normal as i32);
+        assert_eq!(
+            scene.cpu_primitives[1].blend_mode,
+            BlendMode::Multiply as i32
+        );
+        // The blended square is drawn on its own, after the page
+        let draws = geometry_draws(&scene);
+        let end = scene.fill_geometry.indices.len() as u32;
+        assert_eq!(draws.len(), 2);
+        assert_eq!(draws[0].0.start, 0);
+        assert_eq!(draws[0].1, BlendMode::Normal);
+        assert_eq!(draws[1], (draws[0].0.end..end, BlendMode::Multiply));
+    }
+
+    fn vertex(x: f32, y: f32) -> GpuVertex {
+        GpuVertex {
+            position: [x, y],
+            normal: [0.0; 2],
+            prim_id: 0,
+            color: [x, 0.0, 0.0, 1.0],
+        }
+    }
+
+    #[test]
+    fn test_skips_misplaced_text_operators() {
+        let mut scene = Scene::new(0.02);
+        let mut graphics_state = GraphicsState::new(
+            Width::new(DEFAULT_WINDOW_WIDTH),
+            Height::new(DEFAULT_WINDOW_HEIGHT),
+        );
+        scene.draw(
+            vec![
+                StreamObject::EndText,
+                StreamObject::NextLine,
+                StreamObject::Rect(vector(0.0, 0.0), Width::new(100.0), Height::new(100.0)),
+                StreamObject::Fill,
+            ],
+            None,
+            &mut graphics_state,
+        );
+        // What follows them is still painted
+        assert_eq!(scene.running_prim_id, 1);
+    }
+
+    #[test]
+    fn test_skips_misplaced_path_operators() {
+        let mut scene = Scene::new(0.02);
+        let mut graphics_state = GraphicsState::new(
+            Width::new(DEFAULT_WINDOW_WIDTH),
+            Height::new(DEFAULT_WINDOW_HEIGHT),
+        );
+        scene.draw(
+            vec![
+                StreamObject::Clip(FillRule::NonZero),
+                StreamObject::EndPath,
+                StreamObject::Stroke(false),
+                // Too many components for DeviceGray
+                StreamObject::NonStrokeColorN(vec![1.0, 1.0], None),
+                StreamObject::Rect(vector(0.0, 0.0), Width::new(100.0), Height::new(100.0)),
+                StreamObject::Fill,
+            ],
+            None,
+            &mut graphics_state,
+        );
+        // The color was skipped, and what follows is still painted
+        assert_eq!(scene.running_prim_id, 1);
+        assert_eq!(scene.cpu_primitives[0].color, [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    /// The indices of the geometry of draws, including that of their groups
+    fn draw_indices(draws: &[Draw]) -> Vec<Range<u32>> {
+        draws
+            .iter()
+            .flat_map(|draw| match draw {
+                Draw::Geometry { indices, .. } => vec![indices.clone()],
+                Draw::Group(group) => draw_indices(&group.draws),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_soft_mask() {
+        let pdf = pdf::Pdf::from_bytes(
+            b"%PDF-1.7
+1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj
+2 0 obj << /Type /Pages /Kids [3 0 R] /Count 1 >> endobj
+3 0 obj << /Type /Page /Parent 2 0 R /Resources << /ExtGState << /GS0 4 0 R >> >> >> endobj
+4 0 obj << /Type /ExtGState /ca 0.5 /SMask << /Type /Mask /S /Luminosity /G 5 0 R >> >> endobj
+5 0 obj << /Type /XObject /Subtype /Form /BBox [0 0 612 792] /Group << /S /Transparency >>
+/Length 19 >>
+stream
+1 g 0 0 300 300 re f
+endstream
+endobj
+trailer << /Root 1 0 R >>
+%%EOF",
+        )
+        .unwrap();
+        let pages = pdf.document.get_pages().unwrap();
+        let resources = pages[0].named_resources();
+        let mut scene = Scene::new(0.02);
+        let mut graphics_state = GraphicsState::new(
+            Width::new(DEFAULT_WINDOW_WIDTH),
+            Height::new(DEFAULT_WINDOW_HEIGHT),
+        );
+        scene.draw(
+            vec![
+                StreamObject::ExtGState(b"GS0".to_vec()),
+                StreamObject::NonStrokeColorSpace(ColorSpace::DeviceRGB),
+                StreamObject::NonStrokeColor(vec![0.2, 0.6, 0.9]),
+                StreamObject::Rect(vector(0.0, 0.0), Width::new(100.0), Height::new(100.0)),
+                StreamObject::Fill,
+            ],
+            Some(&resources),
+            &mut graphics_state,
+        );
+
+        // The mask's group is painted first, and only its own draws use it
+        let end = scene.fill_geometry.indices.len() as u32;
+        let (mask, rect) = (0..end / 2, end / 2..end);
+        let group = match scene.draws.as_slice() {
+            [Draw::Group(group)] => group,
+            _ => panic!("expected the rect to be in a group of its own"),
+        };
+        assert_eq!(draw_indices(&group.draws), [rect]);
+        assert_eq!(draw_indices(&group.mask.as_ref().unwrap().draws), [mask]);
+        // Only the rect is painted with the page's alpha
+        assert_eq!(scene.running_prim_id, 2);
+        assert_eq!(scene.cpu_primitives[0].color[3], 1.0);
+        assert_eq!(scene.cpu_primitives[1].color[3], 0.5);
+    }
+
+    #[test]
+    fn test_clip_triangles() {
+        let mut geometry = VertexBuffers::new();
+        geometry.vertices = vec![vertex(0.0, 0.0), vertex(4.0, 0.0), vertex(0.0, 4.0)];
+        geometry.indices = vec![0, 1, 2];
+        let rect = Box2D::new(point(-1.0, -1.0), point(2.0, 2.0));
+        let clipped = clip_triangles(&geometry, &[Primitive::DEFAULT], &rect);
+
+        // The corner of the triangle inside of the rectangle, which is cut
+        // off along x = 2 and y = 2
+        let positions: Vec<_> = clipped.vertices.iter().map(|v| v.position).collect();
+        assert_eq!(positions, [[0.0, 2.0], [0.0, 0.0], [2.0, 0.0], [2.0, 2.0]]);
+        assert_eq!(clipped.indices, [0, 1, 2, 0, 2, 3]);
+        // Colors are interpolated along the cut edges
+        assert_eq!(clipped.vertices[2].color[0], 2.0);
+    }
+
+    #[test]
+    fn test_clip_triangles_outside() {
+        let mut geometry = VertexBuffers::new();
+        geometry.vertices = vec![vertex(3.0, 3.0), vertex(4.0, 3.0), vertex(3.0, 4.0)];
+        geometry.indices = vec![0, 1, 2];
+        let rect = Box2D::new(point(0.0, 0.0), point(2.0, 2.0));
+        let clipped = clip_triangles(&geometry, &[Primitive::DEFAULT], &rect);
+        assert!(clipped.vertices.is_empty());
+        assert!(clipped.indices.is_empty());
+    }
+
+    #[test]
+    fn test_clip_triangles_widens_strokes() {
+        let mut geometry = VertexBuffers::new();
+        geometry.vertices = vec![vertex(0.0, 0.0), vertex(1.0, 0.0), vertex(0.0, 1.0)];
+        geometry.vertices[1].normal = [1.0, 0.0];
+        geometry.indices = vec![0, 1, 2];
+        let primitive = Primitive {
+            width: 2.0,
+            ..Primitive::DEFAULT
+        };
+        let rect = Box2D::new(point(-10.0, -10.0), point(10.0, 10.0));
+        let clipped = clip_triangles(&geometry, &[primitive], &rect);
+        assert_eq!(clipped.vertices[1].position, [3.0, 0.0]);
+        assert_eq!(clipped.vertices[1].normal, [0.0, 0.0]);
+    }
+}